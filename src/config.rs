@@ -42,6 +42,8 @@ pub struct AppConfig {
     pub options: AppOptions,
     pub sort_preferences: SortPreferences,
     pub ai_settings: AiSettings,
+    #[serde(default)]
+    pub saved_queries: Vec<String>,
 }
 
 pub fn app_root_path() -> PathBuf {
@@ -74,6 +76,7 @@ pub fn write_config_to_file(
     amazon_bedrock_model_id: String,
     amazon_bedrock_region: String,
     mcp_server_url: String,
+    saved_queries: Vec<String>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut config_file_path = app_root_path();
     config_file_path.push(crate::MAIN_CONFIG_FILE_NAME);
@@ -108,7 +111,8 @@ pub fn write_config_to_file(
             amazon_bedrock_model_id,
             amazon_bedrock_region,
             mcp_server_url,
-        }
+        },
+        saved_queries,
     };
 
     let toml_string = toml::to_string(&app_config)?;
@@ -157,6 +161,7 @@ pub fn read_app_config_from_file() -> AppConfig {
             amazon_bedrock_region: "".to_string(),
             mcp_server_url: "".to_string(),
         },
+        saved_queries: Vec::new(),
     };
 
     let toml_str = match std::fs::read_to_string(config_file_path) {
@@ -183,6 +188,7 @@ pub fn read_app_config_from_file() -> AppConfig {
                 "".to_string(),
                 "".to_string(),
                 "".to_string(),
+                Vec::new(),
             ).unwrap();
             to_string(&new_config).unwrap()
         }
@@ -215,6 +221,7 @@ pub fn read_app_config_from_file() -> AppConfig {
                 "".to_string(),
                 "".to_string(),
                 "".to_string(),
+                Vec::new(),
             ).unwrap();
             new_config
         }
@@ -1,6 +1,8 @@
 use eframe::egui::Color32;
 use futures::{AsyncBufReadExt};
-use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
 use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
 use k8s_openapi::api::storage::v1::StorageClass;
 use k8s_openapi::{ClusterResourceScope, Metadata, NamespaceResourceScope, Resource};
@@ -10,7 +12,8 @@ use kube::config::{Kubeconfig, NamedContext};
 use kube::discovery;
 use k8s_openapi::api::core::v1::{ConfigMap, Event, Node, Namespace, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service, ServiceAccount};
 use kube::api::EvictParams;
-use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::apps::v1::{ControllerRevision, DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
 use log::{error, info, Record};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -193,11 +196,103 @@ pub fn load_embedded_icon() -> Result<crate::egui::IconData, String> {
     Ok(crate::egui::IconData { rgba, width, height })
 }
 
-pub fn spawn_watcher<T, F>(client: Arc<Client>, state: Arc<Mutex<Vec<T>>>, loading_flag: Arc<AtomicBool>, watch_fn: F) where
+pub fn spawn_watcher<T, F>(client: Arc<Client>, state: Arc<Mutex<Vec<T>>>, loading_flag: Arc<AtomicBool>, watch_fn: F) -> tokio::task::JoinHandle<()> where
     T: Send + 'static,
     F: FnOnce(Arc<Client>, Arc<Mutex<Vec<T>>>, Arc<AtomicBool>) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + 'static,
 {
-    tokio::spawn(watch_fn(client, state, loading_flag));
+    tokio::spawn(watch_fn(client, state, loading_flag))
+}
+
+/// metav1.LabelSelector semantics: matchLabels and matchExpressions are AND-ed, an empty selector matches everything
+pub fn label_selector_matches(selector: &k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    let labels_match = selector.match_labels.as_ref()
+        .is_none_or(|m| m.iter().all(|(k, v)| labels.get(k) == Some(v)));
+    let expressions_match = selector.match_expressions.as_ref().is_none_or(|expressions| expressions.iter().all(|e| {
+        let values = e.values.as_deref().unwrap_or_default();
+        match e.operator.as_str() {
+            "In" => labels.get(&e.key).is_some_and(|v| values.contains(v)),
+            "NotIn" => labels.get(&e.key).is_none_or(|v| !values.contains(v)),
+            "Exists" => labels.contains_key(&e.key),
+            "DoesNotExist" => !labels.contains_key(&e.key),
+            _ => false,
+        }
+    }));
+    labels_match && expressions_match
+}
+
+pub fn format_label_selector(selector: &k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector) -> String {
+    let mut parts: Vec<String> = selector.match_labels.iter().flatten().map(|(k, v)| format!("{}={}", k, v)).collect();
+    for expression in selector.match_expressions.iter().flatten() {
+        let values = expression.values.as_ref().map(|v| format!(" ({})", v.join(", "))).unwrap_or_default();
+        parts.push(format!("{} {}{}", expression.key, expression.operator, values));
+    }
+    parts.join(", ")
+}
+
+/// resource.Quantity to a plain number: "250m" -> 0.25, "1Gi" -> 1073741824, "1e3" -> 1000
+pub fn parse_quantity(quantity: &str) -> Option<f64> {
+    let quantity = quantity.trim();
+    const SUFFIXES: [(&str, f64); 15] = [
+        ("Ki", 1024.0), ("Mi", 1048576.0), ("Gi", 1073741824.0), ("Ti", 1099511627776.0), ("Pi", 1125899906842624.0), ("Ei", 1152921504606846976.0),
+        ("n", 1e-9), ("u", 1e-6), ("m", 1e-3), ("k", 1e3), ("M", 1e6), ("G", 1e9), ("T", 1e12), ("P", 1e15), ("E", 1e18),
+    ];
+    for (suffix, multiplier) in SUFFIXES {
+        if let Some(number) = quantity.strip_suffix(suffix) {
+            return number.parse::<f64>().ok().map(|n| n * multiplier);
+        }
+    }
+    // Plain numbers, including the decimal exponent form
+    quantity.parse::<f64>().ok()
+}
+
+/// Formats a parsed quantity back for display: cores for cpu, binary units for bytes, plain counts otherwise
+pub fn format_quantity(resource: &str, value: f64) -> String {
+    let resource = resource.rsplit('.').next().unwrap_or(resource);
+    match resource {
+        "cpu" => {
+            if value < 1.0 && value > 0.0 {
+                format!("{}m", (value * 1000.0).round())
+            } else {
+                format!("{:.2}", value).trim_end_matches('0').trim_end_matches('.').to_string()
+            }
+        }
+        "memory" | "storage" | "ephemeral-storage" => {
+            const UNITS: [&str; 5] = ["B", "Ki", "Mi", "Gi", "Ti"];
+            let mut value = value;
+            let mut unit = 0;
+            while value >= 1024.0 && unit < UNITS.len() - 1 {
+                value /= 1024.0;
+                unit += 1;
+            }
+            format!("{:.1}{}", value, UNITS[unit]).replace(".0", "")
+        }
+        _ => format!("{}", value.round()),
+    }
+}
+
+/// Asks the API server whether the user/groups may do something, this also covers webhook and other authorizers
+pub async fn subject_access_review(
+        client: Arc<Client>,
+        user: Option<String>,
+        groups: Vec<String>,
+        attributes: k8s_openapi::api::authorization::v1::ResourceAttributes,
+) -> Result<(bool, String), kube::Error> {
+    use k8s_openapi::api::authorization::v1::{SubjectAccessReview, SubjectAccessReviewSpec};
+
+    let review = SubjectAccessReview {
+        spec: SubjectAccessReviewSpec {
+            user,
+            groups: Some(groups),
+            resource_attributes: Some(attributes),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let api: Api<SubjectAccessReview> = Api::all(client.as_ref().clone());
+    let result = api.create(&PostParams::default(), &review).await?;
+    let status = result.status.unwrap_or_default();
+    let reason = status.reason.or(status.evaluation_error).unwrap_or_default();
+    Ok((status.allowed, reason))
 }
 
 pub fn format_age(ts: &Time) -> String {
@@ -417,6 +512,40 @@ pub async fn patch_resource(cl: Arc<Client>, yaml_str: &str) -> Result<(), anyho
     Ok(())
 }
 
+/// Validates custom resource YAML against the openAPIV3Schema of its CRD.
+/// Returns no errors for built-in kinds or CRDs without schema.
+pub async fn validate_cr_yaml(cl: Arc<Client>, yaml_str: &str) -> Result<Vec<crate::SchemaError>, anyhow::Error> {
+    use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+
+    let value: serde_json::Value = yaml_serde::from_str(yaml_str)?;
+    let api_version = value.get("apiVersion").and_then(|v| v.as_str()).unwrap_or_default();
+    let kind = value.get("kind").and_then(|k| k.as_str()).unwrap_or_default();
+    let Some((group, version)) = api_version.split_once('/') else {
+        return Ok(vec![]); // core group, never a CRD
+    };
+
+    let gvk = kube::api::GroupVersionKind::gvk(group, version, kind);
+    let Ok((ar, _caps)) = discovery::pinned_kind(&cl, &gvk).await else {
+        return Ok(vec![]);
+    };
+
+    let crds: Api<CustomResourceDefinition> = Api::all(cl.as_ref().clone());
+    let Some(crd) = crds.get_opt(&format!("{}.{}", ar.plural, group)).await? else {
+        return Ok(vec![]);
+    };
+
+    let schema = crd.spec.versions.iter()
+        .find(|v| v.name == version)
+        .and_then(|v| v.schema.as_ref())
+        .and_then(|s| s.open_api_v3_schema.as_ref());
+    let Some(schema) = schema else {
+        return Ok(vec![]);
+    };
+    let schema = serde_json::to_value(schema)?;
+
+    Ok(crate::validate_resource(&value, &schema, &|_| None))
+}
+
 pub async fn apply_yaml(client: Arc<Client>, yaml: &str, resource_type: super::ResourceType) -> Result<(), anyhow::Error> {
     let value: yaml_serde::Value = yaml_serde::from_str(yaml)?;
 
@@ -424,55 +553,55 @@ pub async fn apply_yaml(client: Arc<Client>, yaml: &str, resource_type: super::R
         crate::ResourceType::Blank => {},
         crate::ResourceType::ServiceAccount => {
             let obj: ServiceAccount = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<ServiceAccount> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::Role => {
             let obj: Role = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<Role> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::RoleBinding => {
             let obj: RoleBinding = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<RoleBinding> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::DaemonSet => {
             let obj: DaemonSet = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<DaemonSet> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::ReplicaSet => {
             let obj: ReplicaSet = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<ReplicaSet> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::Ingress => {
             let obj: Ingress = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<Ingress> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::Service => {
             let obj: Service = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<Service> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::Deployment => {
             let obj: Deployment = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<Deployment> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::ConfigMap => {
             let obj: ConfigMap = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<ConfigMap> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
@@ -506,21 +635,57 @@ pub async fn apply_yaml(client: Arc<Client>, yaml: &str, resource_type: super::R
         },
         crate::ResourceType::PersistenceVolumeClaim => {
             let obj: PersistentVolumeClaim = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<PersistentVolumeClaim> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::Pod | crate::ResourceType::PodWithPvc => {
             let obj: Pod = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<Pod> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
         },
         crate::ResourceType::Secret => {
             let obj: Secret = yaml_serde::from_value(value)?;
-            let ns = obj.namespace().unwrap();
+            let ns = obj.namespace().unwrap_or("default".into());
             let api: Api<Secret> = Api::namespaced(client.as_ref().clone(), &ns);
             api.create(&PostParams::default(), &obj).await?;
+        },
+        crate::ResourceType::StatefulSet => {
+            let obj: StatefulSet = yaml_serde::from_value(value)?;
+            let ns = obj.namespace().unwrap_or("default".into());
+            let api: Api<StatefulSet> = Api::namespaced(client.as_ref().clone(), &ns);
+            api.create(&PostParams::default(), &obj).await?;
+        },
+        crate::ResourceType::Job => {
+            let obj: Job = yaml_serde::from_value(value)?;
+            let ns = obj.namespace().unwrap_or("default".into());
+            let api: Api<Job> = Api::namespaced(client.as_ref().clone(), &ns);
+            api.create(&PostParams::default(), &obj).await?;
+        },
+        crate::ResourceType::CronJob => {
+            let obj: CronJob = yaml_serde::from_value(value)?;
+            let ns = obj.namespace().unwrap_or("default".into());
+            let api: Api<CronJob> = Api::namespaced(client.as_ref().clone(), &ns);
+            api.create(&PostParams::default(), &obj).await?;
+        },
+        crate::ResourceType::NetworkPolicy => {
+            let obj: NetworkPolicy = yaml_serde::from_value(value)?;
+            let ns = obj.namespace().unwrap_or("default".into());
+            let api: Api<NetworkPolicy> = Api::namespaced(client.as_ref().clone(), &ns);
+            api.create(&PostParams::default(), &obj).await?;
+        },
+        crate::ResourceType::PodDisruptionBudget => {
+            let obj: PodDisruptionBudget = yaml_serde::from_value(value)?;
+            let ns = obj.namespace().unwrap_or("default".into());
+            let api: Api<PodDisruptionBudget> = Api::namespaced(client.as_ref().clone(), &ns);
+            api.create(&PostParams::default(), &obj).await?;
+        },
+        crate::ResourceType::HorizontalPodAutoscaler => {
+            let obj: HorizontalPodAutoscaler = yaml_serde::from_value(value)?;
+            let ns = obj.namespace().unwrap_or("default".into());
+            let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.as_ref().clone(), &ns);
+            api.create(&PostParams::default(), &obj).await?;
         }
     };
 
@@ -535,14 +700,15 @@ fn get_kubeconfig_path() -> Option<PathBuf> {
     })
 }
 
+fn kubeconfig_file(kubeconfig_path: Option<&str>) -> Result<PathBuf, anyhow::Error> {
+    match kubeconfig_path {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => get_kubeconfig_path().ok_or_else(|| anyhow::anyhow!("Impossible to get your home dir!")),
+    }
+}
+
 pub fn get_current_context_info(kubeconfig_path: Option<&str>, context_name: Option<&str>) -> Result<NamedContext, anyhow::Error> {
-    let config = match kubeconfig_path {
-        Some(path) => Kubeconfig::read_from(path)?,
-        None => {
-            let config_path = get_kubeconfig_path().ok_or_else(|| anyhow::anyhow!("Impossible to get your home dir!"))?;
-            Kubeconfig::read_from(config_path)?
-        }
-    };
+    let config = Kubeconfig::read_from(kubeconfig_file(kubeconfig_path)?)?;
 
     let current_context = match context_name {
         Some(name) => name.to_string(),
@@ -558,6 +724,123 @@ pub fn get_current_context_info(kubeconfig_path: Option<&str>, context_name: Opt
     Ok(context.clone())
 }
 
+/// Server and CA of the cluster the current context points to
+#[derive(Debug, Clone)]
+pub struct ClusterEndpoint {
+    pub cluster: String,
+    pub server: String,
+    /// Base64 encoded PEM, as in certificate-authority-data
+    pub certificate_authority_data: Option<String>,
+    pub insecure_skip_tls_verify: bool,
+}
+
+pub fn get_cluster_endpoint(kubeconfig_path: Option<&str>, context_name: Option<&str>) -> Result<ClusterEndpoint, anyhow::Error> {
+    let context = get_current_context_info(kubeconfig_path, context_name)?;
+    let cluster_name = context.context.map(|c| c.cluster).ok_or_else(|| anyhow::anyhow!("Context '{}' has no cluster", context.name))?;
+
+    let file = kubeconfig_file(kubeconfig_path)?;
+    let config = Kubeconfig::read_from(&file)?;
+    let cluster = config.clusters.into_iter()
+        .find(|c| c.name == cluster_name)
+        .and_then(|c| c.cluster)
+        .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", cluster_name))?;
+    let server = cluster.server.ok_or_else(|| anyhow::anyhow!("Cluster '{}' has no server", cluster_name))?;
+
+    // A CA file is inlined so the generated kubeconfig works on another machine
+    let certificate_authority_data = match (cluster.certificate_authority_data, cluster.certificate_authority) {
+        (Some(data), _) => Some(data),
+        (None, Some(path)) => {
+            let mut ca_path = PathBuf::from(&path);
+            if ca_path.is_relative() && let Some(dir) = file.parent() {
+                ca_path = dir.join(ca_path);
+            }
+            let pem = std::fs::read(&ca_path).map_err(|e| anyhow::anyhow!("Cannot read CA file {}: {}", ca_path.display(), e))?;
+            Some(base64::engine::general_purpose::STANDARD.encode(pem))
+        }
+        (None, None) => None,
+    };
+
+    Ok(ClusterEndpoint {
+        cluster: cluster_name,
+        server,
+        certificate_authority_data,
+        insecure_skip_tls_verify: cluster.insecure_skip_tls_verify.unwrap_or(false),
+    })
+}
+
+/// Bound token from the TokenRequest API, with its expiration time
+pub async fn request_service_account_token(
+        client: Arc<Client>,
+        name: &str,
+        namespace: &str,
+        audiences: Vec<String>,
+        expiration_seconds: i64,
+) -> Result<(String, Time), anyhow::Error> {
+    use k8s_openapi::api::authentication::v1::{TokenRequest, TokenRequestSpec};
+
+    let request = TokenRequest {
+        spec: Some(TokenRequestSpec {
+            // No audiences means the API server ones
+            audiences: (!audiences.is_empty()).then_some(audiences),
+            expiration_seconds: Some(expiration_seconds),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let api: Api<ServiceAccount> = Api::namespaced(client.as_ref().clone(), namespace);
+    let result = api.create_token_request(name, &PostParams::default(), &request).await?;
+    let status = result.status.unwrap_or_default();
+    match (status.token, status.expiration_timestamp) {
+        (Some(token), Some(expires)) => Ok((token, expires)),
+        _ => Err(anyhow::anyhow!("the API server returned no token")),
+    }
+}
+
+/// Kubeconfig with a single context authenticating as the service account token
+pub fn build_service_account_kubeconfig(endpoint: &ClusterEndpoint, name: &str, namespace: &str, token: &str) -> String {
+    let user = format!("{}-{}", namespace, name);
+    let context = format!("{}@{}", user, endpoint.cluster);
+
+    let mut cluster = json!({ "server": endpoint.server });
+    if let Some(ca) = &endpoint.certificate_authority_data {
+        cluster["certificate-authority-data"] = json!(ca);
+    }
+    if endpoint.insecure_skip_tls_verify {
+        cluster["insecure-skip-tls-verify"] = json!(true);
+    }
+
+    let config = json!({
+        "apiVersion": "v1",
+        "kind": "Config",
+        "clusters": [{ "name": endpoint.cluster, "cluster": cluster }],
+        "users": [{ "name": user, "user": { "token": token } }],
+        "contexts": [{ "name": context, "context": { "cluster": endpoint.cluster, "user": user, "namespace": namespace } }],
+        "current-context": context,
+    });
+    yaml_serde::to_string(&config).unwrap_or_default()
+}
+
+/// Writes a file only the current user can read, kubeconfigs carry credentials
+pub fn write_private_file(path: &std::path::Path, content: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // The mode only applies to new files, an existing one is tightened before the token is written
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(content.as_bytes())
+}
+
 pub async fn get_cluster_name(kubeconfig_path: Option<&str>, context: Option<&str>) -> Result<String, anyhow::Error> {
     let config = load_kube_config(kubeconfig_path, context).await?;
     Ok(config.cluster_url.host().unwrap_or("unknown").to_string())
@@ -580,7 +863,46 @@ async fn load_kube_config(kubeconfig_path: Option<&str>, context: Option<&str>)
     }
 }
 
-pub async fn get_kubernetes_client(kubeconfig_path: Option<&str>, context: Option<&str>) -> anyhow::Result<Client> {
+/// Identity sent with Impersonate-User/Impersonate-Group headers, like `kubectl --as/--as-group`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Impersonation {
+    pub user: String,
+    pub groups: Vec<String>,
+}
+
+impl Impersonation {
+    /// The API server adds the serviceaccount groups itself when no group is given
+    pub fn service_account(namespace: &str, name: &str) -> Self {
+        Self { user: format!("system:serviceaccount:{}:{}", namespace, name), groups: Vec::new() }
+    }
+
+    pub fn label(&self) -> String {
+        if self.groups.is_empty() {
+            self.user.clone()
+        } else {
+            format!("{} (groups: {})", self.user, self.groups.join(", "))
+        }
+    }
+}
+
+/// Opens another RustLens window on the same cluster, acting as `impersonation`
+pub fn launch_impersonating(impersonation: &Impersonation, kubeconfig_path: Option<&str>, context: Option<&str>) -> std::io::Result<()> {
+    let mut command = std::process::Command::new(std::env::current_exe()?);
+    if let Some(path) = kubeconfig_path {
+        command.args(["--kubeconfig", path]);
+    }
+    if let Some(context) = context {
+        command.args(["--context", context]);
+    }
+    command.args(["--as", impersonation.user.as_str()]);
+    for group in &impersonation.groups {
+        command.args(["--as-group", group.as_str()]);
+    }
+    command.spawn()?;
+    Ok(())
+}
+
+pub async fn get_kubernetes_client(kubeconfig_path: Option<&str>, context: Option<&str>, impersonation: Option<&Impersonation>) -> anyhow::Result<Client> {
     let mut config = load_kube_config(kubeconfig_path, context).await?;
 
     let is_proxy = config.cluster_url.to_string().starts_with("http://127.0.0.1");
@@ -609,9 +931,25 @@ pub async fn get_kubernetes_client(kubeconfig_path: Option<&str>, context: Optio
         log::info!("Detected kubectl proxy at {}, bypass authentication.", config.cluster_url);
     }
 
+    // kube sends these as Impersonate-User and Impersonate-Group headers
+    if let Some(impersonation) = impersonation {
+        config.auth_info.impersonate = Some(impersonation.user.clone());
+        config.auth_info.impersonate_groups = Some(impersonation.groups.clone()).filter(|g| !g.is_empty());
+        log::info!("Impersonating {}", impersonation.label());
+    }
+
     Ok(Client::try_from(config)?)
 }
 
+pub async fn set_secret_key(client: Arc<Client>, name: &str, namespace: &str, key: &str, value: &[u8]) -> Result<(), kube::Error> {
+    let api: Api<Secret> = Api::namespaced(client.as_ref().clone(), namespace);
+    let patch = serde_json::json!({
+        "data": { key: base64::engine::general_purpose::STANDARD.encode(value) }
+    });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch)).await?;
+    Ok(())
+}
+
 pub async fn set_cronjob_suspend(client: Arc<Client>, name: &str, namespace: &str, suspend: bool) -> Result<(), kube::Error> {
     let api: Api<k8s_openapi::api::batch::v1::CronJob> = Api::namespaced(client.as_ref().clone(), namespace);
     let patch = serde_json::json!({
@@ -621,6 +959,50 @@ pub async fn set_cronjob_suspend(client: Arc<Client>, name: &str, namespace: &st
     Ok(())
 }
 
+/// Set by `kubectl create job --from=cronjob/...` on jobs created by hand
+pub const CRONJOB_INSTANTIATE_ANNOTATION: &str = "cronjob.kubernetes.io/instantiate";
+
+/// Creates a one-off Job from the CronJob jobTemplate, owned by the CronJob so it shows up in its history
+pub async fn trigger_cronjob(client: Arc<Client>, name: &str, namespace: &str) -> Result<String, kube::Error> {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+    let cronjob_api: Api<CronJob> = Api::namespaced(client.as_ref().clone(), namespace);
+    let cronjob = cronjob_api.get(name).await?;
+    let template = cronjob.spec.job_template;
+    let template_meta = template.metadata.unwrap_or_default();
+
+    // Job names end up in the job-name pod label, limited to 63 characters
+    let suffix = format!("-manual-{}", k8s_openapi::jiff::Timestamp::now().as_second());
+    let base: String = name.chars().take(63 - suffix.len()).collect();
+    let job_name = format!("{}{}", base.trim_end_matches('-'), suffix);
+
+    let mut annotations = template_meta.annotations.unwrap_or_default();
+    annotations.insert(CRONJOB_INSTANTIATE_ANNOTATION.to_string(), "manual".to_string());
+
+    let job = Job {
+        metadata: ObjectMeta {
+            name: Some(job_name.clone()),
+            namespace: Some(namespace.to_string()),
+            labels: template_meta.labels,
+            annotations: Some(annotations),
+            owner_references: Some(vec![OwnerReference {
+                api_version: "batch/v1".to_string(),
+                kind: "CronJob".to_string(),
+                name: name.to_string(),
+                uid: cronjob.metadata.uid.unwrap_or_default(),
+                controller: Some(true),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        },
+        spec: template.spec,
+        ..Default::default()
+    };
+    let jobs_api: Api<Job> = Api::namespaced(client.as_ref().clone(), namespace);
+    jobs_api.create(&PostParams::default(), &job).await?;
+    Ok(job_name)
+}
+
 #[derive(Debug, Clone)]
 pub enum ScaleTarget {
     Deployment,
@@ -654,6 +1036,29 @@ pub async fn scale_workload(client: Arc<Client>, name: &str, namespace: &str, re
     Ok(())
 }
 
+impl ScaleTarget {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ScaleTarget::Deployment => "Deployment",
+            ScaleTarget::StatefulSet => "StatefulSet",
+            ScaleTarget::ReplicaSet => "ReplicaSet",
+            ScaleTarget::DaemonSet => "DaemonSet",
+        }
+    }
+}
+
+pub async fn update_hpa_replicas(client: Arc<Client>, name: &str, namespace: &str, min_replicas: i32, max_replicas: i32) -> Result<(), kube::Error> {
+    let patch = serde_json::json!({
+        "spec": {
+            "minReplicas": min_replicas,
+            "maxReplicas": max_replicas
+        }
+    });
+    let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.as_ref().clone(), namespace);
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch)).await?;
+    Ok(())
+}
+
 pub async fn restart_workload(client: Arc<Client>, name: &str, namespace: &str, kind: ScaleTarget) -> Result<(), kube::Error> {
     let now = k8s_openapi::jiff::Timestamp::now().to_string();
     let patch = serde_json::json!({
@@ -686,6 +1091,73 @@ pub async fn restart_workload(client: Arc<Client>, name: &str, namespace: &str,
     Ok(())
 }
 
+pub async fn set_deployment_paused(client: Arc<Client>, name: &str, namespace: &str, paused: bool) -> Result<(), kube::Error> {
+    let api: Api<Deployment> = Api::namespaced(client.as_ref().clone(), namespace);
+    let patch = json!({ "spec": { "paused": paused } });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch)).await?;
+    Ok(())
+}
+
+/// Like `kubectl rollout undo --to-revision`, the deployment template is replaced by the one of the
+/// ReplicaSet that recorded the revision, the controller then scales that ReplicaSet back up
+pub async fn rollback_deployment(client: Arc<Client>, name: &str, namespace: &str, replicaset: &str) -> Result<(), anyhow::Error> {
+    let rs_api: Api<ReplicaSet> = Api::namespaced(client.as_ref().clone(), namespace);
+    let rs = rs_api.get(replicaset).await?;
+    let Some(mut template) = rs.spec.and_then(|s| s.template) else {
+        return Err(anyhow::anyhow!("ReplicaSet {} has no pod template to roll back to", replicaset));
+    };
+    if let Some(labels) = template.metadata.as_mut().and_then(|m| m.labels.as_mut()) {
+        labels.remove("pod-template-hash");
+    }
+
+    let api: Api<Deployment> = Api::namespaced(client.as_ref().clone(), namespace);
+    let mut deployment = api.get(name).await?;
+    if let Some(spec) = deployment.spec.as_mut() {
+        spec.template = template;
+    }
+    api.replace(name, &PostParams::default(), &deployment).await?;
+    Ok(())
+}
+
+/// Pods with an ordinal >= partition are updated to the new revision, lowering it step by step stages the rollout
+pub async fn set_statefulset_partition(client: Arc<Client>, name: &str, namespace: &str, partition: i32) -> Result<(), kube::Error> {
+    let api: Api<StatefulSet> = Api::namespaced(client.as_ref().clone(), namespace);
+    let patch = json!({
+        "spec": {
+            "updateStrategy": {
+                "type": "RollingUpdate",
+                "rollingUpdate": { "partition": partition }
+            }
+        }
+    });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch)).await?;
+    Ok(())
+}
+
+/// `kubectl rollout undo` for StatefulSets and DaemonSets, the revision data is a strategic merge patch
+pub async fn rollback_controller_revision(client: Arc<Client>, name: &str, namespace: &str, revision: &str, kind: ScaleTarget) -> Result<(), anyhow::Error> {
+    let cr_api: Api<ControllerRevision> = Api::namespaced(client.as_ref().clone(), namespace);
+    let Some(data) = cr_api.get(revision).await?.data else {
+        return Err(anyhow::anyhow!("ControllerRevision {} has no data to roll back to", revision));
+    };
+    let patch = Patch::Strategic(data.0);
+
+    match kind {
+        ScaleTarget::StatefulSet => {
+            let api: Api<StatefulSet> = Api::namespaced(client.as_ref().clone(), namespace);
+            api.patch(name, &PatchParams::default(), &patch).await?;
+        }
+        ScaleTarget::DaemonSet => {
+            let api: Api<DaemonSet> = Api::namespaced(client.as_ref().clone(), namespace);
+            api.patch(name, &PatchParams::default(), &patch).await?;
+        }
+        ScaleTarget::Deployment | ScaleTarget::ReplicaSet => {
+            return Err(anyhow::anyhow!("ControllerRevisions only roll back StatefulSets and DaemonSets"));
+        }
+    }
+    Ok(())
+}
+
 pub async fn cordon_node(client: Arc<Client>, node_name: &str, cordoned: bool) -> Result<(), kube::Error> {
     let nodes: Api<Node> = Api::all(client.as_ref().clone());
     let patch = json!({ "spec": { "unschedulable": cordoned } });
@@ -716,6 +1188,435 @@ pub async fn delete_pod(client: Arc<Client>, pod_name: String, namespace: Option
     Ok(())
 }
 
+/// Image used by the debug actions when the user does not pick another one
+pub const DEFAULT_DEBUG_IMAGE: &str = "busybox:1.36";
+
+/// How long a debug container may take to pull its image and start
+const DEBUG_CONTAINER_START_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Node debug pods stop on their own after this, in case the app exits without deleting them
+const NODE_DEBUG_POD_DEADLINE_SECONDS: i64 = 4 * 60 * 60;
+const DEBUG_SESSION_LABEL: &str = "rustlens/debug-session";
+
+/// Labels the node debug pods created by this process, set on the first one
+static DEBUG_SESSION_ID: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+fn debug_name_suffix() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..5].to_string()
+}
+
+/// Adds an ephemeral container through the `ephemeralcontainers` subresource, like `kubectl debug -it --target`.
+/// Returns the name of the new container.
+pub async fn add_ephemeral_container(client: Arc<Client>, pod_name: &str, namespace: &str, image: &str, target_container: Option<&str>) -> Result<String, kube::Error> {
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), namespace);
+    let container_name = format!("debugger-{}", debug_name_suffix());
+    let mut container = json!({
+        "name": container_name,
+        "image": image,
+        "stdin": true,
+        "tty": true,
+        "terminationMessagePolicy": "File",
+    });
+    if let Some(target) = target_container {
+        container["targetContainerName"] = json!(target);
+    }
+    let patch = json!({ "spec": { "ephemeralContainers": [container] } });
+    pods.patch_ephemeral_containers(pod_name, &PatchParams::default(), &Patch::Strategic(&patch)).await?;
+    Ok(container_name)
+}
+
+/// Creates a privileged pod in the host PID/network/IPC namespaces pinned to the node, with the node root filesystem at /host.
+/// Returns the pod name and namespace.
+pub async fn create_node_debug_pod(client: Arc<Client>, node_name: &str, image: &str) -> Result<(String, String), kube::Error> {
+    let namespace = "default";
+    let node_part: String = node_name.chars().take(40).collect();
+    let pod_name = format!("node-debugger-{}-{}", node_part.trim_end_matches(['-', '.']), debug_name_suffix());
+    let pod: Pod = serde_json::from_value(json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": pod_name,
+            "namespace": namespace,
+            "labels": {
+                "app.kubernetes.io/managed-by": "rustlens",
+                DEBUG_SESSION_LABEL: DEBUG_SESSION_ID.get_or_init(|| uuid::Uuid::new_v4().simple().to_string()),
+            },
+        },
+        "spec": {
+            "nodeName": node_name,
+            "activeDeadlineSeconds": NODE_DEBUG_POD_DEADLINE_SECONDS,
+            "hostPID": true,
+            "hostNetwork": true,
+            "hostIPC": true,
+            "restartPolicy": "Never",
+            "terminationGracePeriodSeconds": 0,
+            "tolerations": [{ "operator": "Exists" }],
+            "containers": [{
+                "name": "debugger",
+                "image": image,
+                "stdin": true,
+                // The shell gets EOF once the attach session detaches
+                "stdinOnce": true,
+                "tty": true,
+                "securityContext": { "privileged": true },
+                "volumeMounts": [{ "name": "host-root", "mountPath": "/host" }],
+            }],
+            "volumes": [{ "name": "host-root", "hostPath": { "path": "/" } }],
+        },
+    })).map_err(kube::Error::SerdeError)?;
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), namespace);
+    pods.create(&PostParams::default(), &pod).await?;
+    Ok((pod_name, namespace.to_string()))
+}
+
+/// Deletes the node debug pods this process created and still knows about, called on exit
+pub async fn delete_session_debug_pods(client: Arc<Client>) -> Result<(), kube::Error> {
+    let Some(session) = DEBUG_SESSION_ID.get() else {
+        return Ok(());
+    };
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), "default");
+    let selector = format!("app.kubernetes.io/managed-by=rustlens,{}={}", DEBUG_SESSION_LABEL, session);
+    pods.delete_collection(&DeleteParams { grace_period_seconds: Some(0), ..DeleteParams::default() }, &ListParams::default().labels(&selector)).await?;
+    Ok(())
+}
+
+/// Polls the pod until the container (regular or ephemeral) is running, so it can be attached to
+pub async fn wait_for_container_running(client: Arc<Client>, pod_name: &str, namespace: &str, container: &str) -> Result<(), String> {
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), namespace);
+    let started = std::time::Instant::now();
+    loop {
+        let pod = pods.get(pod_name).await.map_err(|e| e.to_string())?;
+        let status = pod.status.unwrap_or_default();
+        let state = status.container_statuses.unwrap_or_default().into_iter()
+            .chain(status.ephemeral_container_statuses.unwrap_or_default())
+            .find(|s| s.name == container)
+            .and_then(|s| s.state);
+        if let Some(state) = state {
+            if state.running.is_some() {
+                return Ok(());
+            }
+            if let Some(terminated) = state.terminated {
+                return Err(format!("Container {} exited with code {}: {}", container, terminated.exit_code, terminated.reason.unwrap_or_default()));
+            }
+            if let Some(waiting) = state.waiting
+                && matches!(waiting.reason.as_deref(), Some("ErrImagePull" | "ImagePullBackOff" | "InvalidImageName" | "CreateContainerError")) {
+                    return Err(format!("Container {} cannot start: {} {}", container, waiting.reason.unwrap_or_default(), waiting.message.unwrap_or_default()));
+                }
+        }
+        if started.elapsed() > DEBUG_CONTAINER_START_TIMEOUT {
+            return Err(format!("Container {} did not start within {}s", container, DEBUG_CONTAINER_START_TIMEOUT.as_secs()));
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+}
+
+/// Drops terminal escape sequences, carriage returns and applies backspaces, the attach window is plain text
+pub fn strip_terminal_codes(input: &str, output: &mut String) {
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // CSI: parameters until a final byte in @..~
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC: until BEL or ESC \
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            '\r' | '\x07' => {}
+            '\x08' => {
+                output.pop();
+            }
+            _ => output.push(c),
+        }
+    }
+}
+
+/// Keeps the attach output bounded for long sessions
+const ATTACH_OUTPUT_LIMIT: usize = 256 * 1024;
+
+/// Attaches to a running container with a TTY, streaming its output into `output` and forwarding `input` to stdin.
+/// Returns when the process exits or the input channel is closed.
+pub async fn attach_container(
+        client: Arc<Client>,
+        pod_name: &str,
+        namespace: &str,
+        container: &str,
+        output: Arc<Mutex<String>>,
+        mut input: tokio::sync::mpsc::UnboundedReceiver<Vec<u8>>,
+) -> Result<(), kube::Error> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), namespace);
+    let mut attached = pods.attach(pod_name, &kube::api::AttachParams::interactive_tty().container(container)).await?;
+    let (Some(mut stdin), Some(mut stdout)) = (attached.stdin(), attached.stdout()) else {
+        attached.abort();
+        return Ok(());
+    };
+
+    // Ask the shell for a prompt, attaching does not replay what was printed before
+    let _ = stdin.write_all(b"\n").await;
+    let mut buf = vec![0u8; 4096];
+    loop {
+        tokio::select! {
+            read = stdout.read(&mut buf) => match read {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    let mut out = output.lock().unwrap();
+                    strip_terminal_codes(&String::from_utf8_lossy(&buf[..n]), &mut out);
+                    if out.len() > ATTACH_OUTPUT_LIMIT {
+                        let cut = out.len() - ATTACH_OUTPUT_LIMIT;
+                        let cut = (cut..out.len()).find(|i| out.is_char_boundary(*i)).unwrap_or(cut);
+                        out.drain(..cut);
+                    }
+                }
+            },
+            data = input.recv() => match data {
+                Some(data) => {
+                    if stdin.write_all(&data).await.is_err() {
+                        break;
+                    }
+                }
+                None => break,
+            },
+        }
+    }
+    attached.abort();
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteEntry {
+    pub name: String,
+    pub is_dir: bool,
+    pub is_link: bool,
+    pub size: u64,
+    pub modified: Option<k8s_openapi::jiff::Timestamp>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferState {
+    Running,
+    Done(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct TransferProgress {
+    pub label: String,
+    pub transferred: u64,
+    /// Unknown for directory downloads
+    pub total: Option<u64>,
+    pub state: TransferState,
+}
+
+impl TransferProgress {
+    pub fn new(label: String, total: Option<u64>) -> Self {
+        Self { label, transferred: 0, total, state: TransferState::Running }
+    }
+}
+
+async fn read_exec_stderr(stderr: Option<impl tokio::io::AsyncRead + Unpin>) -> String {
+    use tokio::io::AsyncReadExt;
+
+    let mut text = String::new();
+    if let Some(mut stderr) = stderr {
+        let _ = stderr.read_to_string(&mut text).await;
+    }
+    text
+}
+
+/// The exec status only says "Failure", stderr usually has the reason
+async fn check_exec_status(
+        status: Option<impl std::future::Future<Output = Option<k8s_openapi::apimachinery::pkg::apis::meta::v1::Status>>>,
+        stderr: String,
+) -> Result<(), String> {
+    if let Some(status) = status
+        && let Some(status) = status.await
+        && status.status.as_deref() == Some("Failure") {
+            let stderr = stderr.trim();
+            return Err(if stderr.is_empty() { status.message.unwrap_or_default() } else { stderr.to_string() });
+        }
+    Ok(())
+}
+
+/// Splits "/var/log/app.log" into ("/var/log", "app.log") for `tar -C`
+fn split_remote_path(path: &str) -> Option<(String, String)> {
+    let trimmed = path.trim_end_matches('/');
+    let (parent, name) = match trimmed.rsplit_once('/') {
+        Some(("", name)) => ("/".to_string(), name.to_string()),
+        Some((parent, name)) => (parent.to_string(), name.to_string()),
+        None => (".".to_string(), trimmed.to_string()),
+    };
+    // "/" has no name to archive under its parent
+    (!name.is_empty()).then_some((parent, name))
+}
+
+/// Lists a container directory with `stat`, which busybox and coreutils images both have.
+/// Needs a shell in the container, like `kubectl cp`.
+pub async fn list_container_dir(client: Arc<Client>, pod_name: &str, namespace: &str, container: &str, path: &str) -> Result<Vec<RemoteEntry>, String> {
+    use tokio::io::AsyncReadExt;
+
+    const LIST_SCRIPT: &str = r#"cd -- "$1" || exit 1; for f in * .*; do case "$f" in .|..) continue;; esac; [ -e "$f" ] || [ -L "$f" ] || continue; stat -c '%f|%s|%Y|%n' -- "$f"; done"#;
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), namespace);
+    let params = kube::api::AttachParams::default().container(container);
+    let mut process = pods.exec(pod_name, vec!["sh", "-c", LIST_SCRIPT, "sh", path], &params).await.map_err(|e| e.to_string())?;
+    let mut stdout = process.stdout().ok_or("exec has no stdout")?;
+    let stderr = process.stderr();
+    let status = process.take_status();
+
+    let mut output = String::new();
+    let (read, stderr) = tokio::join!(stdout.read_to_string(&mut output), read_exec_stderr(stderr));
+    read.map_err(|e| e.to_string())?;
+    check_exec_status(status, stderr).await?;
+
+    let mut entries: Vec<RemoteEntry> = output.lines().filter_map(|line| {
+        let mut parts = line.splitn(4, '|');
+        let mode = u32::from_str_radix(parts.next()?, 16).ok()?;
+        let size = parts.next()?.parse().ok()?;
+        let modified = parts.next()?.parse().ok().and_then(|s| k8s_openapi::jiff::Timestamp::from_second(s).ok());
+        let name = parts.next()?.to_string();
+        let file_type = mode & 0o170000;
+        Some(RemoteEntry { name, is_dir: file_type == 0o040000, is_link: file_type == 0o120000, size, modified })
+    }).collect();
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    Ok(entries)
+}
+
+/// Streams `tar cf -` from the container into a temporary file, then unpacks it into `local_dir`.
+/// Returns the local path of the downloaded file or directory.
+pub async fn download_from_container(
+        client: Arc<Client>,
+        pod_name: &str,
+        namespace: &str,
+        container: &str,
+        remote_path: &str,
+        local_dir: PathBuf,
+        progress: Arc<Mutex<TransferProgress>>,
+) -> Result<PathBuf, String> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let (parent, name) = split_remote_path(remote_path).ok_or_else(|| format!("Can't download {}, pick a file or directory", remote_path))?;
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), namespace);
+    let params = kube::api::AttachParams::default().container(container);
+    let mut process = pods.exec(pod_name, vec!["tar", "cf", "-", "-C", parent.as_str(), "--", name.as_str()], &params).await.map_err(|e| e.to_string())?;
+    let mut stdout = process.stdout().ok_or("exec has no stdout")?;
+    let stderr = process.stderr();
+    let status = process.take_status();
+
+    let archive_path = std::env::temp_dir().join(format!("rustlens-download-{}.tar", uuid::Uuid::new_v4().simple()));
+    let copy = async {
+        let mut file = tokio::fs::File::create(&archive_path).await?;
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            let n = stdout.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            file.write_all(&buf[..n]).await?;
+            progress.lock().unwrap().transferred += n as u64;
+        }
+        file.flush().await
+    };
+    let (copied, stderr) = tokio::join!(copy, read_exec_stderr(stderr));
+    let result = match copied {
+        Ok(_) => check_exec_status(status, stderr).await,
+        Err(e) => Err(e.to_string()),
+    };
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&archive_path);
+        return Err(e);
+    }
+
+    let destination = local_dir.clone();
+    let unpacked = tokio::task::spawn_blocking(move || {
+        let result = std::fs::File::open(&archive_path).and_then(|file| tar::Archive::new(file).unpack(&destination));
+        let _ = std::fs::remove_file(&archive_path);
+        result
+    }).await;
+    match unpacked {
+        Ok(Ok(_)) => Ok(local_dir.join(name)),
+        Ok(Err(e)) => Err(format!("Failed to unpack archive: {}", e)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Packs a local file or directory into a temporary tar and streams it into `tar xf -` in `remote_dir`
+pub async fn upload_to_container(
+        client: Arc<Client>,
+        pod_name: &str,
+        namespace: &str,
+        container: &str,
+        local_path: PathBuf,
+        remote_dir: &str,
+        progress: Arc<Mutex<TransferProgress>>,
+) -> Result<(), String> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let name = local_path.file_name().map(|n| n.to_string_lossy().to_string()).ok_or("Nothing to upload")?;
+    let archive_path = std::env::temp_dir().join(format!("rustlens-upload-{}.tar", uuid::Uuid::new_v4().simple()));
+    let archive = archive_path.clone();
+    let packed = tokio::task::spawn_blocking(move || -> std::io::Result<u64> {
+        let mut builder = tar::Builder::new(std::fs::File::create(&archive)?);
+        if local_path.is_dir() {
+            builder.append_dir_all(&name, &local_path)?;
+        } else {
+            builder.append_path_with_name(&local_path, &name)?;
+        }
+        builder.into_inner()?.sync_all()?;
+        Ok(std::fs::metadata(&archive)?.len())
+    }).await;
+    let total = match packed {
+        Ok(Ok(total)) => total,
+        Ok(Err(e)) => {
+            let _ = std::fs::remove_file(&archive_path);
+            return Err(format!("Failed to pack: {}", e));
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+    progress.lock().unwrap().total = Some(total);
+
+    let pods: Api<Pod> = Api::namespaced(client.as_ref().clone(), namespace);
+    let params = kube::api::AttachParams::default().container(container).stdin(true).stdout(false);
+    let result = async {
+        let mut process = pods.exec(pod_name, vec!["tar", "xf", "-", "-C", remote_dir], &params).await.map_err(|e| e.to_string())?;
+        let mut stdin = process.stdin().ok_or("exec has no stdin")?;
+        let stderr = process.stderr();
+        let status = process.take_status();
+
+        let copy = async {
+            let mut file = tokio::fs::File::open(&archive_path).await?;
+            let mut buf = vec![0u8; 64 * 1024];
+            loop {
+                let n = file.read(&mut buf).await?;
+                if n == 0 {
+                    break;
+                }
+                stdin.write_all(&buf[..n]).await?;
+                progress.lock().unwrap().transferred += n as u64;
+            }
+            // tar only finishes once stdin is closed
+            stdin.shutdown().await
+        };
+        let (copied, stderr) = tokio::join!(copy, read_exec_stderr(stderr));
+        copied.map_err(|e| e.to_string())?;
+        check_exec_status(status, stderr).await
+    }.await;
+    let _ = std::fs::remove_file(&archive_path);
+    result
+}
+
 pub async fn delete_cluster_role(client: Arc<Client>, cluster_role_name: &str) -> Result<(), kube::Error> {
     let roles: Api<ClusterRole> = Api::all(client.as_ref().clone());
     roles.delete(cluster_role_name, &DeleteParams::default()).await?;
@@ -791,6 +1692,34 @@ pub async fn delete_namespace(client: Arc<Client>, name: &str) -> Result<(), kub
     Ok(())
 }
 
+fn dynamic_api(client: Arc<Client>, ar: &kube::api::ApiResource, namespace: Option<&str>) -> Api<kube::api::DynamicObject> {
+    match namespace {
+        Some(ns) => Api::namespaced_with(client.as_ref().clone(), ns, ar),
+        None => Api::all_with(client.as_ref().clone(), ar),
+    }
+}
+
+pub async fn delete_dynamic(client: Arc<Client>, ar: &kube::api::ApiResource, name: &str, namespace: Option<&str>) -> Result<(), kube::Error> {
+    let api = dynamic_api(client, ar, namespace);
+    api.delete(name, &DeleteParams::default()).await?;
+    Ok(())
+}
+
+/// Merge patch of metadata.labels or metadata.annotations, None value removes the key
+pub async fn patch_metadata_dynamic(client: Arc<Client>, ar: &kube::api::ApiResource, name: &str, namespace: Option<&str>, field: &str, changes: &BTreeMap<String, Option<String>>) -> Result<(), kube::Error> {
+    let api = dynamic_api(client, ar, namespace);
+    let patch = json!({ "metadata": { field: changes } });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch)).await?;
+    Ok(())
+}
+
+pub async fn get_dynamic_yaml(client: Arc<Client>, ar: &kube::api::ApiResource, name: &str, namespace: Option<&str>) -> Result<String, anyhow::Error> {
+    let api = dynamic_api(client, ar, namespace);
+    let mut obj = api.get(name).await?;
+    obj.metadata.managed_fields = None;
+    Ok(yaml_serde::to_string(&obj)?)
+}
+
 pub async fn delete_namespaced_component_for<K>(name: String, namespace: Option<&str>, client: Arc<Client>) -> Result<(), kube::Error> where
     K: Clone
         + Resource<Scope = kube::core::NamespaceResourceScope>
@@ -805,6 +1734,19 @@ pub async fn delete_namespaced_component_for<K>(name: String, namespace: Option<
     Ok(())
 }
 
+pub async fn delete_cluster_component_for<K>(name: String, client: Arc<Client>) -> Result<(), kube::Error> where
+    K: Clone
+        + Resource<Scope = kube::core::ClusterResourceScope>
+        + serde::de::DeserializeOwned
+        + K8sResource
+        + Metadata<Ty = kube::core::ObjectMeta>
+        + std::fmt::Debug
+{
+    let api: Api<K> = Api::all(client.as_ref().clone());
+    api.delete(&name, &DeleteParams::default()).await?;
+    Ok(())
+}
+
 pub fn app_log_format(
     w: &mut dyn Write,
     now: &mut flexi_logger::DeferredNow,
@@ -1048,3 +1990,52 @@ pub async fn helm_uninstall(release_name: &str, namespace: &str) -> Result<(), a
     log::info!("Helm release '{}' uninstalled from namespace '{}'", name, namespace);
     Ok(())
 }
+
+async fn list_matching<K, T>(client: &Client, convert: fn(K) -> Option<T>, query: &crate::ResourceQuery) -> anyhow::Result<Vec<T>> where
+    K: Clone
+        + DeserializeOwned
+        + std::fmt::Debug
+        + Metadata<Ty = kube::core::ObjectMeta>
+        + Resource
+        + 'static,
+    T: crate::Queryable,
+{
+    let api: Api<K> = Api::all(client.clone());
+    let list = api.list(&ListParams::default()).await?;
+    Ok(list.items.into_iter().filter_map(convert).filter(|item| query.matches(item)).collect())
+}
+
+fn print_query_rows<T: crate::Queryable>(items: &[T]) {
+    println!("{:<30} {:<60} AGE", "NAMESPACE", "NAME");
+    for item in items {
+        println!(
+            "{:<30} {:<60} {}",
+            item.query_namespace().unwrap_or("-"),
+            item.query_name(),
+            item.query_created().map(format_age).unwrap_or_default(),
+        );
+    }
+}
+
+/// `rustlens --list pods --query "status=CrashLoopBackOff,restarts>5"`
+pub async fn run_cli_query(client: Client, kind: &str, query: &str) -> anyhow::Result<()> {
+    let query = crate::ResourceQuery::try_parse(query).map_err(|e| anyhow::anyhow!("Invalid query: {}", e))?;
+    match kind.to_lowercase().as_str() {
+        "pods" | "pod" | "po" => print_query_rows(&list_matching(&client, crate::convert_pod, &query).await?),
+        "deployments" | "deployment" | "deploy" => print_query_rows(&list_matching(&client, crate::convert_deployment, &query).await?),
+        "statefulsets" | "statefulset" | "sts" => print_query_rows(&list_matching(&client, crate::convert_statefulset, &query).await?),
+        "daemonsets" | "daemonset" | "ds" => print_query_rows(&list_matching(&client, crate::convert_daemonset, &query).await?),
+        "replicasets" | "replicaset" | "rs" => print_query_rows(&list_matching(&client, crate::convert_replicaset, &query).await?),
+        "jobs" | "job" => print_query_rows(&list_matching(&client, crate::convert_job, &query).await?),
+        "cronjobs" | "cronjob" | "cj" => print_query_rows(&list_matching(&client, crate::convert_cronjob, &query).await?),
+        "services" | "service" | "svc" => print_query_rows(&list_matching(&client, crate::convert_service, &query).await?),
+        "configmaps" | "configmap" | "cm" => print_query_rows(&list_matching(&client, crate::convert_configmap, &query).await?),
+        "secrets" | "secret" => print_query_rows(&list_matching(&client, crate::convert_secret, &query).await?),
+        "ingresses" | "ingress" | "ing" => print_query_rows(&list_matching(&client, crate::convert_ingress, &query).await?),
+        "pvcs" | "pvc" | "persistentvolumeclaims" => print_query_rows(&list_matching(&client, crate::convert_pvc, &query).await?),
+        "nodes" | "node" | "no" => print_query_rows(&list_matching(&client, crate::convert_node, &query).await?),
+        "namespaces" | "namespace" | "ns" => print_query_rows(&list_matching(&client, crate::convert_namespace, &query).await?),
+        other => anyhow::bail!("Unsupported resource kind '{}'", other),
+    }
+    Ok(())
+}
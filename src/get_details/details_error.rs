@@ -0,0 +1,75 @@
+use std::fmt;
+use std::time::Duration;
+use kube::Api;
+use serde::de::DeserializeOwned;
+
+/// Requests slower than this are reported as timed out instead of leaving the window empty
+pub const DETAILS_FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DetailsError {
+    NotFound(String),
+    Forbidden(String),
+    TimedOut,
+    Other(String),
+}
+
+impl DetailsError {
+    pub fn title(&self) -> &'static str {
+        match self {
+            DetailsError::NotFound(_) => "Not found",
+            DetailsError::Forbidden(_) => "Access denied",
+            DetailsError::TimedOut => "Request timed out",
+            DetailsError::Other(_) => "Failed to load",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            DetailsError::NotFound(_) => "🔍",
+            DetailsError::Forbidden(_) => "🔒",
+            DetailsError::TimedOut => "⏱",
+            DetailsError::Other(_) => "⚠",
+        }
+    }
+}
+
+impl fmt::Display for DetailsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetailsError::NotFound(msg) => write!(f, "{}", msg),
+            DetailsError::Forbidden(msg) => write!(f, "{}", msg),
+            DetailsError::TimedOut => write!(f, "The API server did not answer within {}s", DETAILS_FETCH_TIMEOUT.as_secs()),
+            DetailsError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DetailsError {}
+
+impl From<kube::Error> for DetailsError {
+    fn from(err: kube::Error) -> Self {
+        match &err {
+            kube::Error::Api(status) => match status.code {
+                404 => DetailsError::NotFound(status.message.clone()),
+                401 | 403 => DetailsError::Forbidden(status.message.clone()),
+                408 | 504 => DetailsError::TimedOut,
+                _ => DetailsError::Other(err.to_string()),
+            },
+            // Connection level timeouts come wrapped in hyper/tower errors
+            _ if err.to_string().to_lowercase().contains("timed out") => DetailsError::TimedOut,
+            _ => DetailsError::Other(err.to_string()),
+        }
+    }
+}
+
+/// `Api::get` bounded by `DETAILS_FETCH_TIMEOUT`
+pub async fn fetch_details_object<K>(api: &Api<K>, name: &str) -> Result<K, DetailsError>
+where
+    K: Clone + DeserializeOwned + fmt::Debug,
+{
+    match tokio::time::timeout(DETAILS_FETCH_TIMEOUT, api.get(name)).await {
+        Ok(result) => result.map_err(DetailsError::from),
+        Err(_) => Err(DetailsError::TimedOut),
+    }
+}
@@ -16,13 +16,23 @@ pub struct ClusterRoleBindingDetails {
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_cluster_rb_details(client: Arc<Client>, name: &str, details: Arc<Mutex<ClusterRoleBindingDetails>>) -> Result<(), kube::Error> {
+pub async fn get_cluster_rb_details(client: Arc<Client>, name: &str, details: Arc<Mutex<ClusterRoleBindingDetails>>) -> Result<(), crate::DetailsError> {
     let api: Api<ClusterRoleBinding> = Api::all(client.as_ref().clone());
-    let cluster_rb = api.get(name).await.unwrap();
-    let cluster_rb_events = crate::get_resource_events(client.clone(), "ClusterRoleBinding", "default", name).await.unwrap();
+    let cluster_rb = match crate::fetch_details_object(&api, name).await {
+        Ok(cluster_rb) => cluster_rb,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let cluster_rb_events = crate::get_resource_events(client.clone(), "ClusterRoleBinding", "default", name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = cluster_rb.metadata.clone();
 
     details_items.name = metadata.name;
@@ -16,13 +16,23 @@ pub struct ClusterRoleDetails {
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_cluster_role_details(client: Arc<Client>, name: &str, details: Arc<Mutex<ClusterRoleDetails>>) -> Result<(), kube::Error> {
+pub async fn get_cluster_role_details(client: Arc<Client>, name: &str, details: Arc<Mutex<ClusterRoleDetails>>) -> Result<(), crate::DetailsError> {
     let api: Api<ClusterRole> = Api::all(client.as_ref().clone());
-    let cluster_role = api.get(name).await.unwrap();
-    let cluster_role_events = crate::get_resource_events(client.clone(), "ClusterRole", "default", name).await.unwrap();
+    let cluster_role = match crate::fetch_details_object(&api, name).await {
+        Ok(cluster_role) => cluster_role,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let cluster_role_events = crate::get_resource_events(client.clone(), "ClusterRole", "default", name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = cluster_role.metadata.clone();
 
     details_items.name = metadata.name;
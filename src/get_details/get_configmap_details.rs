@@ -17,14 +17,25 @@ pub struct ConfigMapDetails {
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_configmap_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<ConfigMapDetails>>) -> Result<(), kube::Error> {
+pub async fn get_configmap_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<ConfigMapDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<ConfigMap> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let configmap = api.get(name).await.unwrap();
-    let configmap_events = crate::get_resource_events(client.clone(), "ConfigMap", ns.clone().as_str(), name).await.unwrap();
+    let configmap = match crate::fetch_details_object(&api, name).await {
+        Ok(configmap) => configmap,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let configmap_events = crate::get_resource_events(client.clone(), "ConfigMap", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = configmap.metadata.clone();
 
     details_items.name = metadata.name;
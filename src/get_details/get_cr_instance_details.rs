@@ -0,0 +1,51 @@
+use kube::Client;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone)]
+pub struct EventDetails {
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub event_type: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct CrInstanceDetails {
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+pub async fn get_cr_instance_details(client: Arc<Client>, name: &str, ns: Option<String>, kind: &str, details: Arc<Mutex<CrInstanceDetails>>) -> Result<(), crate::DetailsError> {
+    let cr_events = match ns.as_deref() {
+        Some(ns) => crate::get_resource_events(client.clone(), kind, ns, name).await,
+        None => crate::get_cluster_resource_events(client.clone(), kind, name).await,
+    };
+    let mut details_items = details.lock().unwrap();
+
+    details_items.name = Some(name.to_string());
+    details_items.namespace = ns;
+    let cr_events = match cr_events {
+        Ok(events) => {
+            details_items.error = None;
+            events
+        }
+        Err(e) => {
+            let e = crate::DetailsError::from(e);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+
+    details_items.events = cr_events.iter().map(|e| {
+        EventDetails {
+            reason: e.reason.clone(),
+            message: e.message.clone(),
+            event_type: e.type_.clone(),
+            timestamp: e.last_timestamp.as_ref().map(|ts| ts.0.to_string()),
+        }
+    }).collect();
+
+    Ok(())
+}
@@ -8,19 +8,50 @@ pub struct CrdDetails {
     pub name: Option<String>,
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
+    pub versions: Vec<CrdVersionSchema>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_crd_details(client: Arc<Client>, name: &str, details: Arc<Mutex<CrdDetails>>) -> Result<(), kube::Error> {
-    let (ar, _caps) = discovery::pinned_kind(&client, &GroupVersionKind::gvk("apiextensions.k8s.io", "v1", "CustomResourceDefinition")).await.unwrap();
-    let api: Api<DynamicObject> = Api::all_with(client.as_ref().clone(), &ar);
+#[derive(Default, Debug, Clone)]
+pub struct CrdVersionSchema {
+    pub name: String,
+    pub served: bool,
+    pub storage: bool,
+    pub schema: Option<serde_json::Value>,
+}
 
-    let crd = api.get(name).await.unwrap();
+pub async fn get_crd_details(client: Arc<Client>, name: &str, details: Arc<Mutex<CrdDetails>>) -> Result<(), crate::DetailsError> {
+    let crd = async {
+        let (ar, _caps) = discovery::pinned_kind(&client, &GroupVersionKind::gvk("apiextensions.k8s.io", "v1", "CustomResourceDefinition")).await?;
+        let api: Api<DynamicObject> = Api::all_with(client.as_ref().clone(), &ar);
+        crate::fetch_details_object(&api, name).await
+    }.await;
+    let crd = match crd {
+        Ok(crd) => crd,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = crd.metadata.clone();
 
     details_items.name = metadata.name;
     details_items.labels = metadata.labels.clone();
     details_items.annotations = metadata.annotations.clone();
+    details_items.versions = crd.data.get("spec")
+        .and_then(|s| s.get("versions"))
+        .and_then(|v| v.as_array())
+        .map(|versions| versions.iter().map(|v| CrdVersionSchema {
+            name: v.get("name").and_then(|n| n.as_str()).unwrap_or_default().to_string(),
+            served: v.get("served").and_then(|s| s.as_bool()).unwrap_or(false),
+            storage: v.get("storage").and_then(|s| s.as_bool()).unwrap_or(false),
+            schema: v.get("schema").and_then(|s| s.get("openAPIV3Schema")).cloned(),
+        }).collect())
+        .unwrap_or_default();
 
     Ok(())
 }
@@ -1,7 +1,192 @@
-use kube::{Api, Client};
-use k8s_openapi::api::batch::v1::CronJob;
+use kube::{Api, Client, api::ListParams};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use k8s_openapi::jiff::{civil::Date, tz::TimeZone, Timestamp, Zoned};
 use std::{collections::BTreeMap, sync::{Arc, Mutex}};
 
+const MONTH_NAMES: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Standard 5 field cron expression as accepted by the CronJob controller
+#[derive(Debug, Clone, Default)]
+pub struct CronSchedule {
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days: Vec<bool>,
+    months: Vec<bool>,
+    weekdays: Vec<bool>,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+fn parse_cron_value(value: &str, names: &[&str], first_name: u32) -> Result<u32, String> {
+    if let Ok(number) = value.parse() {
+        return Ok(number);
+    }
+    names.iter()
+        .position(|n| n.eq_ignore_ascii_case(value))
+        .map(|i| i as u32 + first_name)
+        .ok_or_else(|| format!("invalid value \"{}\"", value))
+}
+
+fn parse_cron_field(field: &str, min: u32, max: u32, names: &[&str], first_name: u32) -> Result<(Vec<bool>, bool), String> {
+    let mut set = vec![false; max as usize + 1];
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<usize>().map_err(|_| format!("invalid step \"{}\"", step))?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err(format!("invalid step in \"{}\"", part));
+        }
+        let (start, end) = if range == "*" || range == "?" {
+            (min, max)
+        } else if let Some((from, to)) = range.split_once('-') {
+            (parse_cron_value(from, names, first_name)?, parse_cron_value(to, names, first_name)?)
+        } else {
+            let value = parse_cron_value(range, names, first_name)?;
+            // "5/15" means from 5 to the end of the range
+            (value, if part.contains('/') { max } else { value })
+        };
+        if start < min || end > max || start > end {
+            return Err(format!("\"{}\" is out of range {}-{}", part, min, max));
+        }
+        for value in (start..=end).step_by(step) {
+            set[value as usize] = true;
+        }
+    }
+    Ok((set, field != "*" && field != "?"))
+}
+
+impl CronSchedule {
+    pub fn parse(schedule: &str) -> Result<Self, String> {
+        let expanded = match schedule.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!("expected 5 fields, found {}", fields.len()));
+        }
+        let (minutes, _) = parse_cron_field(fields[0], 0, 59, &[], 0)?;
+        let (hours, _) = parse_cron_field(fields[1], 0, 23, &[], 0)?;
+        let (days, days_restricted) = parse_cron_field(fields[2], 1, 31, &[], 0)?;
+        let (months, _) = parse_cron_field(fields[3], 1, 12, &MONTH_NAMES, 1)?;
+        let (mut weekdays, weekdays_restricted) = parse_cron_field(fields[4], 0, 7, &WEEKDAY_NAMES, 0)?;
+        // 7 is another name for Sunday
+        if weekdays.pop() == Some(true) {
+            weekdays[0] = true;
+        }
+        Ok(Self { minutes, hours, days, months, weekdays, days_restricted, weekdays_restricted })
+    }
+
+    /// Day of month and day of week are OR-ed when both are restricted, like in cron
+    fn day_matches(&self, date: Date) -> bool {
+        if !self.months[date.month() as usize] {
+            return false;
+        }
+        let day = self.days[date.day() as usize];
+        let weekday = self.weekdays[date.weekday().to_sunday_zero_offset() as usize];
+        if self.days_restricted && self.weekdays_restricted {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+
+    pub fn next_runs(&self, zone: &TimeZone, after: Timestamp, count: usize) -> Vec<Zoned> {
+        let mut runs = Vec::with_capacity(count);
+        let mut date = after.to_zoned(zone.clone()).date();
+        // Long enough for schedules like "0 0 29 2 *"
+        for _ in 0..366 * 8 {
+            if self.day_matches(date) {
+                for hour in (0..24).filter(|h| self.hours[*h]) {
+                    for minute in (0..60).filter(|m| self.minutes[*m]) {
+                        let Ok(run) = date.at(hour as i8, minute as i8, 0, 0).to_zoned(zone.clone()) else {
+                            continue;
+                        };
+                        if run.timestamp() > after {
+                            runs.push(run);
+                            if runs.len() == count {
+                                return runs;
+                            }
+                        }
+                    }
+                }
+            }
+            let Ok(next) = date.tomorrow() else {
+                break;
+            };
+            date = next;
+        }
+        runs
+    }
+}
+
+/// `CRON_TZ=`/`TZ=` prefixes are rejected by recent API servers but still found in old objects
+fn split_schedule_time_zone(schedule: &str) -> (Option<String>, String) {
+    let schedule = schedule.trim();
+    for prefix in ["CRON_TZ=", "TZ="] {
+        if let Some(rest) = schedule.strip_prefix(prefix)
+            && let Some((zone, expression)) = rest.split_once(' ') {
+                return (Some(zone.to_string()), expression.trim().to_string());
+            }
+    }
+    (None, schedule.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JobOutcome {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct JobRunDetails {
+    pub name: String,
+    pub start: Option<Time>,
+    pub completion: Option<Time>,
+    pub outcome: JobOutcome,
+    pub manual: bool,
+}
+
+impl JobRunDetails {
+    /// Running jobs are measured up to now
+    pub fn duration_seconds(&self) -> Option<i64> {
+        let start = self.start.as_ref()?.0;
+        let end = self.completion.as_ref().map(|t| t.0).unwrap_or_else(Timestamp::now);
+        Some(end.as_second() - start.as_second())
+    }
+}
+
+fn job_run_from_job(job: &Job) -> JobRunDetails {
+    let status = job.status.as_ref();
+    let condition_true = |type_: &str| status
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|c| c.iter().any(|c| c.type_ == type_ && c.status == "True"));
+    let outcome = if condition_true("Failed") {
+        JobOutcome::Failed
+    } else if condition_true("Complete") {
+        JobOutcome::Succeeded
+    } else {
+        JobOutcome::Running
+    };
+    JobRunDetails {
+        name: job.metadata.name.clone().unwrap_or_default(),
+        start: status.and_then(|s| s.start_time.clone()).or_else(|| job.metadata.creation_timestamp.clone()),
+        completion: status.and_then(|s| s.completion_time.clone()),
+        outcome,
+        manual: job.metadata.annotations.as_ref()
+            .and_then(|a| a.get(crate::CRONJOB_INSTANTIATE_ANNOTATION))
+            .is_some_and(|v| v == "manual"),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EventDetails {
     pub reason: Option<String>,
@@ -16,15 +201,71 @@ pub struct CronJobDetails {
     pub namespace: Option<String>,
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
+    pub schedule: String,
+    pub time_zone: Option<String>,
+    pub cron: Option<CronSchedule>,
+    pub schedule_error: Option<String>,
+    pub zone: Option<TimeZone>,
+    pub zone_error: Option<String>,
+    pub concurrency_policy: Option<String>,
+    pub successful_jobs_history_limit: Option<i32>,
+    pub failed_jobs_history_limit: Option<i32>,
+    pub jobs: Vec<JobRunDetails>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_cronjob_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<CronJobDetails>>) -> Result<(), kube::Error> {
+pub async fn get_cronjob_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<CronJobDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<CronJob> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let cronjob = api.get(name).await.unwrap();
-    let cronjob_events = crate::get_resource_events(client.clone(), "CronJob", ns.clone().as_str(), name).await.unwrap();
+    let cronjob = match crate::fetch_details_object(&api, name).await {
+        Ok(cronjob) => cronjob,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let cronjob_events = crate::get_resource_events(client.clone(), "CronJob", ns.clone().as_str(), name).await.unwrap_or_default();
+
+    let jobs_api: Api<Job> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let mut jobs: Vec<JobRunDetails> = jobs_api.list(&ListParams::default()).await.map(|l| l.items).unwrap_or_default()
+        .iter()
+        .filter(|job| crate::is_owned_by(job.metadata.owner_references.as_ref(), cronjob.metadata.uid.as_ref()))
+        .map(job_run_from_job)
+        .collect();
+    jobs.sort_by(|a, b| b.start.as_ref().map(|t| t.0).cmp(&a.start.as_ref().map(|t| t.0)));
+
+    let spec = cronjob.spec.clone();
+    let (prefix_zone, expression) = split_schedule_time_zone(&spec.schedule);
+    let time_zone = spec.time_zone.clone().or(prefix_zone);
+    // Without a timeZone the controller manager local time is used, which is UTC in most clusters
+    let (zone, zone_error) = match time_zone.as_deref() {
+        Some(tz) => match TimeZone::get(tz) {
+            Ok(zone) => (Some(zone), None),
+            Err(e) => (Some(TimeZone::UTC), Some(format!("Unknown time zone {}, showing UTC: {}", tz, e))),
+        },
+        None => (Some(TimeZone::UTC), None),
+    };
+    let (cron, schedule_error) = match CronSchedule::parse(&expression) {
+        Ok(cron) => (Some(cron), None),
+        Err(e) => (None, Some(e)),
+    };
+
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    details_items.schedule = spec.schedule.clone();
+    details_items.time_zone = time_zone;
+    details_items.cron = cron;
+    details_items.schedule_error = schedule_error;
+    details_items.zone = zone;
+    details_items.zone_error = zone_error;
+    details_items.concurrency_policy = spec.concurrency_policy.clone();
+    details_items.successful_jobs_history_limit = spec.successful_jobs_history_limit;
+    details_items.failed_jobs_history_limit = spec.failed_jobs_history_limit;
+    details_items.jobs = jobs;
     let metadata = cronjob.metadata.clone();
 
     details_items.name = metadata.name;
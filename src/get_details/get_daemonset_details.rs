@@ -1,7 +1,26 @@
-use kube::{Api, Client};
+use kube::{Api, Client, api::ListParams};
 use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::core::v1::{Node, NodeSelectorRequirement, Pod, PodSpec, Taint, Toleration};
 use std::{collections::BTreeMap, sync::{Arc, Mutex}};
 
+/// Tolerations the DaemonSet controller adds to every daemon pod
+const DAEMON_TOLERATED_TAINTS: [&str; 6] = [
+    "node.kubernetes.io/not-ready",
+    "node.kubernetes.io/unreachable",
+    "node.kubernetes.io/disk-pressure",
+    "node.kubernetes.io/memory-pressure",
+    "node.kubernetes.io/pid-pressure",
+    "node.kubernetes.io/unschedulable",
+];
+
+#[derive(Debug, Clone)]
+pub struct NodeCoverage {
+    pub node: String,
+    pub pod: Option<String>,
+    pub pod_ready: bool,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct EventDetails {
     pub reason: Option<String>,
@@ -16,15 +35,117 @@ pub struct DaemonSetDetails {
     pub namespace: Option<String>,
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
+    pub update_strategy: Option<String>,
+    pub revisions: Vec<crate::RevisionDetails>,
+    pub coverage: Vec<NodeCoverage>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_daemonset_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<DaemonSetDetails>>) -> Result<(), kube::Error> {
+fn tolerates(tolerations: &[Toleration], taint: &Taint) -> bool {
+    tolerations.iter().any(|t| {
+        let effect_matches = t.effect.as_deref().is_none_or(|e| e.is_empty() || e == taint.effect);
+        let key_matches = match t.key.as_deref() {
+            None | Some("") => t.operator.as_deref() == Some("Exists"),
+            Some(key) => key == taint.key,
+        };
+        let value_matches = t.operator.as_deref() == Some("Exists") || t.value == taint.value;
+        effect_matches && key_matches && value_matches
+    })
+}
+
+fn requirement_matches(requirement: &NodeSelectorRequirement, labels: &BTreeMap<String, String>) -> bool {
+    let value = labels.get(&requirement.key);
+    let values = requirement.values.clone().unwrap_or_default();
+    let as_number = |v: &str| v.parse::<i64>().ok();
+    match requirement.operator.as_str() {
+        "In" => value.is_some_and(|v| values.contains(v)),
+        "NotIn" => value.is_none_or(|v| !values.contains(v)),
+        "Exists" => value.is_some(),
+        "DoesNotExist" => value.is_none(),
+        "Gt" => matches!((value.and_then(|v| as_number(v)), values.first().and_then(|v| as_number(v))), (Some(a), Some(b)) if a > b),
+        "Lt" => matches!((value.and_then(|v| as_number(v)), values.first().and_then(|v| as_number(v))), (Some(a), Some(b)) if a < b),
+        _ => false,
+    }
+}
+
+/// Why the scheduler would keep a daemon pod off the node, None when it fits
+fn node_mismatch_reason(spec: &PodSpec, node: &Node) -> Option<String> {
+    let labels = node.metadata.labels.clone().unwrap_or_default();
+    if let Some(selector) = &spec.node_selector
+        && let Some((k, v)) = selector.iter().find(|(k, v)| labels.get(*k) != Some(*v)) {
+            return Some(format!("nodeSelector {}={} does not match", k, v));
+        }
+
+    let required_terms = spec.affinity.as_ref()
+        .and_then(|a| a.node_affinity.as_ref())
+        .and_then(|a| a.required_during_scheduling_ignored_during_execution.as_ref())
+        .map(|s| s.node_selector_terms.clone());
+    if let Some(terms) = required_terms
+        && !terms.is_empty()
+        && !terms.iter().any(|term| term.match_expressions.as_deref().unwrap_or_default().iter().all(|r| requirement_matches(r, &labels))) {
+            return Some("required node affinity does not match".to_string());
+        }
+
+    let tolerations = spec.tolerations.clone().unwrap_or_default();
+    let taints = node.spec.as_ref().and_then(|s| s.taints.clone()).unwrap_or_default();
+    taints.iter()
+        .filter(|t| t.effect == "NoSchedule" || t.effect == "NoExecute")
+        .filter(|t| !DAEMON_TOLERATED_TAINTS.contains(&t.key.as_str()))
+        .find(|t| !tolerates(&tolerations, t))
+        .map(|t| format!("untolerated taint {}={}:{}", t.key, t.value.clone().unwrap_or_default(), t.effect))
+}
+
+pub async fn get_daemonset_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<DaemonSetDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<DaemonSet> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let daemonset = api.get(name).await.unwrap();
-    let daemonset_events = crate::get_resource_events(client.clone(), "DaemonSet", ns.clone().as_str(), name).await.unwrap();
+    let daemonset = match crate::fetch_details_object(&api, name).await {
+        Ok(daemonset) => daemonset,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let daemonset_events = crate::get_resource_events(client.clone(), "DaemonSet", ns.clone().as_str(), name).await.unwrap_or_default();
+
+    let spec = daemonset.spec.clone().unwrap_or_default();
+    let selector = crate::label_selector(spec.selector.match_labels.as_ref());
+    let revisions = crate::get_controller_revisions(client.clone(), &ns, &selector, daemonset.metadata.uid.as_ref()).await;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let pods: Vec<Pod> = pods_api.list(&ListParams::default().labels(&selector)).await.map(|l| l.items).unwrap_or_default()
+        .into_iter()
+        .filter(|pod| crate::is_owned_by(pod.metadata.owner_references.as_ref(), daemonset.metadata.uid.as_ref()))
+        .collect();
+    let nodes_api: Api<Node> = Api::all(client.as_ref().clone());
+    let nodes = nodes_api.list(&ListParams::default()).await.map(|l| l.items).unwrap_or_default();
+    let pod_spec = spec.template.spec.clone().unwrap_or_default();
+    let coverage = nodes.iter().map(|node| {
+        let node_name = node.metadata.name.clone().unwrap_or_default();
+        let pod = pods.iter().find(|p| p.spec.as_ref().and_then(|s| s.node_name.as_deref()) == Some(node_name.as_str()));
+        let reason = match pod {
+            Some(_) => None,
+            None => Some(node_mismatch_reason(&pod_spec, node).unwrap_or_else(|| "fits the node, pod not created yet".to_string())),
+        };
+        NodeCoverage {
+            node: node_name,
+            pod: pod.and_then(|p| p.metadata.name.clone()),
+            pod_ready: pod
+                .and_then(|p| p.status.as_ref())
+                .and_then(|s| s.conditions.as_ref())
+                .is_some_and(|c| c.iter().any(|c| c.type_ == "Ready" && c.status == "True")),
+            reason,
+        }
+    }).collect();
+
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    details_items.update_strategy = spec.update_strategy.as_ref().and_then(|s| s.type_.clone());
+    details_items.revisions = revisions;
+    details_items.coverage = coverage;
     let metadata = daemonset.metadata.clone();
 
     details_items.name = metadata.name;
@@ -1,14 +1,115 @@
 use std::{collections::BTreeMap, sync::{Arc, Mutex}};
-use kube::{Api, Client};
-use k8s_openapi::api::{apps::v1::Deployment};
+use kube::{Api, Client, api::ListParams};
+use k8s_openapi::api::{apps::v1::{Deployment, ReplicaSet}, core::v1::PodTemplateSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{OwnerReference, Time};
+
+pub const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+pub const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
 
 #[derive(Debug, Clone, Default)]
 pub struct ConditionDetails {
     pub type_: String,
-    // pub status: String,
-    // pub reason: Option<String>,
-    // pub message: Option<String>,
-    // pub last_transition_time: Option<String>,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub last_transition_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RolloutState {
+    Progressing,
+    Complete,
+    Paused,
+    Failed,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RolloutStatus {
+    pub generation: i64,
+    pub observed_generation: i64,
+    pub desired_replicas: Option<i32>,
+    pub replicas: i32,
+    pub updated_replicas: i32,
+    pub available_replicas: i32,
+    pub paused: bool,
+    pub progress_deadline_seconds: Option<i32>,
+}
+
+impl RolloutStatus {
+    /// Same checks as `kubectl rollout status`
+    pub fn state(&self, conditions: &[ConditionDetails]) -> (RolloutState, String) {
+        if self.paused {
+            return (RolloutState::Paused, "Rollout is paused".to_string());
+        }
+        if self.observed_generation < self.generation {
+            return (RolloutState::Progressing, "Waiting for the controller to observe the new spec".to_string());
+        }
+        if let Some(progressing) = conditions.iter().find(|c| c.type_ == "Progressing")
+            && progressing.reason.as_deref() == Some("ProgressDeadlineExceeded") {
+                return (RolloutState::Failed, format!("Progress deadline exceeded: {}", progressing.message.clone().unwrap_or_default()));
+            }
+        if let Some(desired) = self.desired_replicas
+            && self.updated_replicas < desired {
+                return (RolloutState::Progressing, format!("{} of {} new replicas have been updated", self.updated_replicas, desired));
+            }
+        if self.replicas > self.updated_replicas {
+            return (RolloutState::Progressing, format!("{} old replicas are pending termination", self.replicas - self.updated_replicas));
+        }
+        if self.available_replicas < self.updated_replicas {
+            return (RolloutState::Progressing, format!("{} of {} updated replicas are available", self.available_replicas, self.updated_replicas));
+        }
+        (RolloutState::Complete, "Successfully rolled out".to_string())
+    }
+}
+
+/// One entry of a workload rollout history
+#[derive(Debug, Clone, Default)]
+pub struct RevisionDetails {
+    pub revision: i64,
+    pub source: String,
+    pub created: Option<Time>,
+    pub replicas: Option<i32>,
+    pub images: Vec<String>,
+    pub change_cause: Option<String>,
+    pub template: String,
+}
+
+/// Pod template rendered for diffing, the per-revision hash label would show up as a change every time
+pub fn template_to_yaml(template: &PodTemplateSpec) -> String {
+    let mut template = template.clone();
+    if let Some(labels) = template.metadata.as_mut().and_then(|m| m.labels.as_mut()) {
+        labels.remove("pod-template-hash");
+        labels.remove("controller-revision-hash");
+    }
+    yaml_serde::to_string(&template).unwrap_or_default()
+}
+
+pub fn template_images(template: &PodTemplateSpec) -> Vec<String> {
+    template.spec.as_ref()
+        .map(|spec| spec.containers.iter().filter_map(|c| c.image.clone()).collect())
+        .unwrap_or_default()
+}
+
+pub fn is_owned_by(owners: Option<&Vec<OwnerReference>>, uid: Option<&String>) -> bool {
+    match (owners, uid) {
+        (Some(owners), Some(uid)) => owners.iter().any(|o| &o.uid == uid),
+        _ => false,
+    }
+}
+
+fn revision_from_replicaset(rs: &ReplicaSet) -> Option<RevisionDetails> {
+    let annotations = rs.metadata.annotations.as_ref();
+    let revision = annotations?.get(REVISION_ANNOTATION)?.parse().ok()?;
+    let template = rs.spec.as_ref().and_then(|s| s.template.clone()).unwrap_or_default();
+    Some(RevisionDetails {
+        revision,
+        source: rs.metadata.name.clone().unwrap_or_default(),
+        created: rs.metadata.creation_timestamp.clone(),
+        replicas: rs.status.as_ref().map(|s| s.replicas),
+        images: template_images(&template),
+        change_cause: annotations.and_then(|a| a.get(CHANGE_CAUSE_ANNOTATION).cloned()),
+        template: template_to_yaml(&template),
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -28,15 +129,50 @@ pub struct DeploymentDetails {
     pub strategy: Option<String>,
     pub selector: Vec<(String, String)>,
     pub conditions: Vec<ConditionDetails>,
+    pub rollout: RolloutStatus,
+    pub current_revision: Option<i64>,
+    pub revisions: Vec<RevisionDetails>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_deployment_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<DeploymentDetails>>) -> Result<(), kube::Error> {
+pub async fn get_deployment_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<DeploymentDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<Deployment> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let deployment = api.get(name).await.unwrap();
-    let deployment_events = crate::get_resource_events(client.clone(), "Deployment", ns.clone().as_str(), name).await.unwrap();
+    let deployment = match crate::fetch_details_object(&api, name).await {
+        Ok(deployment) => deployment,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let deployment_events = crate::get_resource_events(client.clone(), "Deployment", ns.clone().as_str(), name).await.unwrap_or_default();
+
+    // Every ReplicaSet the deployment ever created is one revision of its history
+    let selector = deployment.spec.as_ref()
+        .and_then(|s| s.selector.match_labels.clone())
+        .unwrap_or_default()
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(",");
+    let rs_api: Api<ReplicaSet> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let replicasets = rs_api.list(&ListParams::default().labels(&selector)).await.map(|l| l.items).unwrap_or_default();
+    let mut revisions: Vec<RevisionDetails> = replicasets.iter()
+        .filter(|rs| is_owned_by(rs.metadata.owner_references.as_ref(), deployment.metadata.uid.as_ref()))
+        .filter_map(revision_from_replicaset)
+        .collect();
+    revisions.sort_by_key(|r| std::cmp::Reverse(r.revision));
+
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    details_items.revisions = revisions;
+    details_items.current_revision = deployment.metadata.annotations.as_ref()
+        .and_then(|a| a.get(REVISION_ANNOTATION))
+        .and_then(|r| r.parse().ok());
 
     let metadata = deployment.metadata.clone();
     let deployment_spec = deployment.spec;
@@ -46,8 +182,18 @@ pub async fn get_deployment_details(client: Arc<Client>, name: &str, ns: Option<
     details_items.labels = metadata.labels;
     details_items.namespace = Some(ns);
     details_items.annotations = metadata.annotations;
+    details_items.rollout = RolloutStatus {
+        generation: metadata.generation.unwrap_or_default(),
+        observed_generation: status.and_then(|s| s.observed_generation).unwrap_or_default(),
+        replicas: status.and_then(|s| s.replicas).unwrap_or_default(),
+        desired_replicas: deployment_spec.as_ref().and_then(|s| s.replicas),
+        updated_replicas: status.and_then(|s| s.updated_replicas).unwrap_or_default(),
+        available_replicas: status.and_then(|s| s.available_replicas).unwrap_or_default(),
+        paused: deployment_spec.as_ref().and_then(|s| s.paused).unwrap_or(false),
+        progress_deadline_seconds: deployment_spec.as_ref().and_then(|s| s.progress_deadline_seconds),
+    };
     if let Some(spec) = deployment_spec {
-        details_items.strategy = spec.strategy.unwrap().type_;
+        details_items.strategy = spec.strategy.and_then(|s| s.type_);
         details_items.selector = spec.selector.match_labels
             .clone()
             .unwrap_or_default()
@@ -59,10 +205,10 @@ pub async fn get_deployment_details(client: Arc<Client>, name: &str, ns: Option<
         details_items.conditions = status.conditions.clone().unwrap_or_default().into_iter().map(|c| {
             ConditionDetails {
                 type_: c.type_,
-                // status: c.status,
-                // reason: c.reason,
-                // message: c.message,
-                // last_transition_time: c.last_transition_time.map(|t| t.0.to_string()),
+                status: c.status,
+                reason: c.reason,
+                message: c.message,
+                last_transition_time: c.last_transition_time.map(|t| t.0.to_string()),
             }
         }).collect();
     }
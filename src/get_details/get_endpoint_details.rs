@@ -1,5 +1,5 @@
 use kube::{Api, Client};
-use k8s_openapi::api::core::v1::Endpoints;
+use k8s_openapi::api::core::v1::Service;
 use std::{collections::BTreeMap, sync::{Arc, Mutex}};
 
 #[derive(Debug, Clone)]
@@ -10,29 +10,50 @@ pub struct EventDetails {
     pub timestamp: Option<String>,
 }
 
+/// Endpoints are shown per Service, the slices themselves come from the EndpointSlice watcher
 #[derive(Default, Debug, Clone)]
 pub struct EndpointDetails {
     pub name: Option<String>,
     pub namespace: Option<String>,
+    /// Slices can be managed by hand or by a mesh without a Service behind them
+    pub service_found: bool,
+    pub service_selector: Option<BTreeMap<String, String>>,
+    pub ip_families: Vec<String>,
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_endpoint_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<EndpointDetails>>) -> Result<(), kube::Error> {
+pub async fn get_endpoint_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<EndpointDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
-    let api: Api<Endpoints> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let endpoint = api.get(name).await.unwrap();
-    let endpoint_events = crate::get_resource_events(client.clone(), "Endpoint", ns.clone().as_str(), name).await.unwrap();
+    let api: Api<Service> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let service = match crate::fetch_details_object(&api, name).await {
+        Ok(service) => Some(service),
+        Err(crate::DetailsError::NotFound(_)) => None,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    // The EndpointSlice controller reports slice update failures on the Service
+    let service_events = crate::get_resource_events(client.clone(), "Service", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
-    let metadata = endpoint.metadata.clone();
+    details_items.error = None;
 
-    details_items.name = metadata.name;
+    details_items.name = Some(name.to_string());
     details_items.namespace = Some(ns);
-    details_items.labels = metadata.labels.clone();
-    details_items.annotations = metadata.annotations.clone();
+    details_items.service_found = service.is_some();
+    let spec = service.as_ref().and_then(|s| s.spec.as_ref());
+    details_items.service_selector = spec.and_then(|s| s.selector.clone());
+    details_items.ip_families = spec.and_then(|s| s.ip_families.clone()).unwrap_or_default();
+    details_items.labels = service.as_ref().and_then(|s| s.metadata.labels.clone());
+    details_items.annotations = service.as_ref().and_then(|s| s.metadata.annotations.clone());
 
-    details_items.events = endpoint_events.iter().map(|e| {
+    details_items.events = service_events.iter().map(|e| {
         EventDetails {
             reason: e.reason.clone(),
             message: e.message.clone(),
@@ -0,0 +1,96 @@
+use std::{collections::BTreeMap, sync::{Arc, Mutex}};
+use kube::{Api, Client};
+use k8s_openapi::api::autoscaling::v2::{HPAScalingRules, HorizontalPodAutoscaler};
+
+#[derive(Debug, Clone)]
+pub struct EventDetails {
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub event_type: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct HpaDetails {
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub labels: Option<BTreeMap<String, String>>,
+    pub annotations: Option<BTreeMap<String, String>>,
+    pub metrics: Vec<crate::HpaMetricValue>,
+    pub conditions: Vec<crate::ConditionDetails>,
+    /// Scale up and scale down behavior, in that order
+    pub behavior: Vec<(String, String)>,
+    /// Newest first, SuccessfulRescale events are the scaling history
+    pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+/// Describes a behavior block the way `kubectl describe hpa` does, with the API defaults when unset
+fn describe_scaling_rules(rules: Option<&HPAScalingRules>, default_window: i32) -> String {
+    let window = rules.and_then(|r| r.stabilization_window_seconds).unwrap_or(default_window);
+    let mut parts = vec![format!("stabilization window {}s", window)];
+    if let Some(rules) = rules {
+        if let Some(select) = &rules.select_policy {
+            parts.push(format!("select {}", select));
+        }
+        for policy in rules.policies.iter().flatten() {
+            parts.push(format!("{} {} per {}s", policy.value, policy.type_, policy.period_seconds));
+        }
+    }
+    parts.join(", ")
+}
+
+pub async fn get_hpa_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<HpaDetails>>) -> Result<(), crate::DetailsError> {
+    let ns = ns.unwrap_or("default".to_string());
+    let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let hpa = match crate::fetch_details_object(&api, name).await {
+        Ok(hpa) => hpa,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let mut hpa_events = crate::get_resource_events(client.clone(), "HorizontalPodAutoscaler", ns.as_str(), name).await.unwrap_or_default();
+    hpa_events.sort_by(|a, b| b.last_timestamp.as_ref().map(|t| t.0).cmp(&a.last_timestamp.as_ref().map(|t| t.0)));
+
+    let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    let metadata = hpa.metadata.clone();
+
+    details_items.name = metadata.name;
+    details_items.namespace = Some(ns);
+    details_items.labels = metadata.labels.clone();
+    details_items.annotations = metadata.annotations.clone();
+    details_items.metrics = crate::hpa_metric_values(&hpa);
+
+    details_items.conditions = hpa.status.as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map(|conditions| conditions.iter().map(|c| crate::ConditionDetails {
+            type_: c.type_.clone(),
+            status: c.status.clone(),
+            reason: c.reason.clone(),
+            message: c.message.clone(),
+            last_transition_time: c.last_transition_time.as_ref().map(|t| t.0.to_string()),
+        }).collect())
+        .unwrap_or_default();
+
+    let behavior = hpa.spec.behavior.as_ref();
+    details_items.behavior = vec![
+        ("Scale up".to_string(), describe_scaling_rules(behavior.and_then(|b| b.scale_up.as_ref()), 0)),
+        ("Scale down".to_string(), describe_scaling_rules(behavior.and_then(|b| b.scale_down.as_ref()), 300)),
+    ];
+
+    details_items.events = hpa_events.iter().map(|e| {
+        EventDetails {
+            reason: e.reason.clone(),
+            message: e.message.clone(),
+            event_type: e.type_.clone(),
+            timestamp: e.last_timestamp.as_ref().map(|ts| ts.0.to_string()),
+        }
+    }).collect();
+
+    Ok(())
+}
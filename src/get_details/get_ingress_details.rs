@@ -17,14 +17,25 @@ pub struct IngressDetails {
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_ingress_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<IngressDetails>>) -> Result<(), kube::Error> {
+pub async fn get_ingress_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<IngressDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<Ingress> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let ingress = api.get(name).await.unwrap();
-    let ingress_events = crate::get_resource_events(client.clone(), "Ingress", ns.clone().as_str(), name).await.unwrap();
+    let ingress = match crate::fetch_details_object(&api, name).await {
+        Ok(ingress) => ingress,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let ingress_events = crate::get_resource_events(client.clone(), "Ingress", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = ingress.metadata.clone();
 
     details_items.name = metadata.name;
@@ -15,14 +15,25 @@ pub struct LeaseDetails {
     pub name: Option<String>,
     pub namespace: Option<String>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_lease_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<LeaseDetails>>) -> Result<(), kube::Error> {
+pub async fn get_lease_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<LeaseDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<Lease> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let lease = api.get(name).await.unwrap();
-    let lease_events = crate::get_resource_events(client.clone(), "Lease", ns.clone().as_str(), name).await.unwrap();
+    let lease = match crate::fetch_details_object(&api, name).await {
+        Ok(lease) => lease,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let lease_events = crate::get_resource_events(client.clone(), "Lease", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = lease.metadata.clone();
 
     details_items.name = metadata.name;
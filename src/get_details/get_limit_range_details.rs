@@ -0,0 +1,56 @@
+use std::{collections::BTreeMap, sync::{Arc, Mutex}};
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::LimitRange;
+
+#[derive(Debug, Clone)]
+pub struct EventDetails {
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub event_type: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct LimitRangeDetails {
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub labels: Option<BTreeMap<String, String>>,
+    pub annotations: Option<BTreeMap<String, String>>,
+    pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+pub async fn get_limit_range_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<LimitRangeDetails>>) -> Result<(), crate::DetailsError> {
+    let ns = ns.unwrap_or("default".to_string());
+    let api: Api<LimitRange> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let limit_range = match crate::fetch_details_object(&api, name).await {
+        Ok(limit_range) => limit_range,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let limit_range_events = crate::get_resource_events(client.clone(), "LimitRange", ns.as_str(), name).await.unwrap_or_default();
+    let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    let metadata = limit_range.metadata.clone();
+
+    details_items.name = metadata.name;
+    details_items.namespace = Some(ns);
+    details_items.labels = metadata.labels;
+    details_items.annotations = metadata.annotations;
+
+    details_items.events = limit_range_events.iter().map(|e| {
+        EventDetails {
+            reason: e.reason.clone(),
+            message: e.message.clone(),
+            event_type: e.type_.clone(),
+            timestamp: e.last_timestamp.as_ref().map(|ts| ts.0.to_string()),
+        }
+    }).collect();
+
+    Ok(())
+}
@@ -14,6 +14,7 @@ pub struct NodeDetails {
     pub os_image: Option<String>,
     pub kernel_version: Option<String>,
     pub container_runtime: Option<String>,
+    pub error: Option<crate::DetailsError>,
 }
 
 impl NodeDetails {
@@ -29,21 +30,32 @@ impl NodeDetails {
             os_image: None,
             kernel_version: None,
             container_runtime: None,
+            error: None,
         }
     }
 }
 
-pub async fn get_node_details(client: Arc<Client>, name: &str, details: Arc<Mutex<NodeDetails>>) -> Result<(), kube::Error> {
+pub async fn get_node_details(client: Arc<Client>, name: &str, details: Arc<Mutex<NodeDetails>>) -> Result<(), crate::DetailsError> {
     let api: Api<Node> = Api::all(client.as_ref().clone());
-    let node = api.get(name).await.unwrap();
+    let node = match crate::fetch_details_object(&api, name).await {
+        Ok(node) => node,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
 
     let metadata = node.metadata.clone();
     details_items.name = metadata.name;
     details_items.labels = metadata.labels;
     details_items.annotations = metadata.annotations;
 
-    if let Some(addrs) = node.status.clone().unwrap().addresses {
+    details_items.addresses.clear();
+    if let Some(addrs) = node.status.as_ref().and_then(|s| s.addresses.clone()) {
         for addr in addrs {
             details_items.addresses.insert(addr.type_, addr.address);
         }
@@ -61,13 +73,12 @@ pub async fn get_node_details(client: Arc<Client>, name: &str, details: Arc<Mute
         .and_then(|s| s.node_info.as_ref())
         .map(|info| info.kubelet_version.clone());
 
-    let status = node.status.ok_or_else(|| anyhow::anyhow!("Missing node status")).unwrap();
-    let node_info =  status.node_info.ok_or_else(|| anyhow::anyhow!("Missing nodeInfo")).unwrap();
-
-    details_items.os = Some(format!("{} ({})", node_info.operating_system, node_info.architecture));
-    details_items.os_image = Some(node_info.os_image);
-    details_items.kernel_version = Some(node_info.kernel_version);
-    details_items.container_runtime = Some(node_info.container_runtime_version);
+    // nodeInfo is missing until the kubelet reports for the first time
+    let node_info = node.status.and_then(|s| s.node_info);
+    details_items.os = node_info.as_ref().map(|info| format!("{} ({})", info.operating_system, info.architecture));
+    details_items.os_image = node_info.as_ref().map(|info| info.os_image.clone());
+    details_items.kernel_version = node_info.as_ref().map(|info| info.kernel_version.clone());
+    details_items.container_runtime = node_info.map(|info| info.container_runtime_version);
 
     Ok(())
 }
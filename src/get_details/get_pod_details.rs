@@ -61,16 +61,27 @@ pub struct PodDetails {
     pub conditions: Vec<PodCondition>,
     pub containers: Vec<ContainerDetails>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_pod_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<PodDetails>>) -> Result<(), kube::Error> {
+pub async fn get_pod_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<PodDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<Pod> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let pod = api.get(name).await.unwrap();
+    let pod = match crate::fetch_details_object(&api, name).await {
+        Ok(pod) => pod,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
 
-    let pod_events = crate::get_resource_events(client.clone(), "Pod", ns.clone().as_str(), name).await.unwrap();
+    let pod_events = crate::get_resource_events(client.clone(), "Pod", ns.clone().as_str(), name).await.unwrap_or_default();
 
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
 
     let metadata = pod.metadata.clone();
     let spec = pod.spec.as_ref();
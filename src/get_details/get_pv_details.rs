@@ -18,13 +18,23 @@ pub struct PvDetails {
     pub finalizers: Option<Vec<String>>,
     pub access_modes: Option<Vec<String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_pv_details(client: Arc<Client>, name: &str, details: Arc<Mutex<PvDetails>>) -> Result<(), kube::Error> {
+pub async fn get_pv_details(client: Arc<Client>, name: &str, details: Arc<Mutex<PvDetails>>) -> Result<(), crate::DetailsError> {
     let api: Api<PersistentVolume> = Api::all(client.as_ref().clone());
-    let pv = api.get(name).await.unwrap();
-    let pv_events = crate::get_cluster_resource_events(client.clone(), "PersistentVolume", name).await.unwrap();
+    let pv = match crate::fetch_details_object(&api, name).await {
+        Ok(pv) => pv,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let pv_events = crate::get_cluster_resource_events(client.clone(), "PersistentVolume", name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = pv.metadata.clone();
     let spec = pv.spec.as_ref();
 
@@ -17,14 +17,25 @@ pub struct PvcDetails {
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_pvc_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<PvcDetails>>) -> Result<(), kube::Error> {
+pub async fn get_pvc_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<PvcDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<PersistentVolumeClaim> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let pvc = api.get(name).await.unwrap();
-    let pvc_events = crate::get_resource_events(client.clone(), "PersistentVolumeClaim", ns.clone().as_str(), name).await.unwrap();
+    let pvc = match crate::fetch_details_object(&api, name).await {
+        Ok(pvc) => pvc,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let pvc_events = crate::get_resource_events(client.clone(), "PersistentVolumeClaim", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = pvc.metadata.clone();
 
     details_items.name = metadata.name;
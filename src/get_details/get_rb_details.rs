@@ -17,14 +17,25 @@ pub struct RoleBindingDetails {
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_rb_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<RoleBindingDetails>>) -> Result<(), kube::Error> {
+pub async fn get_rb_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<RoleBindingDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<RoleBinding> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let rb = api.get(name).await.unwrap();
-    let rb_events = crate::get_resource_events(client.clone(), "RoleBinding", ns.clone().as_str(), name).await.unwrap();
+    let rb = match crate::fetch_details_object(&api, name).await {
+        Ok(rb) => rb,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let rb_events = crate::get_resource_events(client.clone(), "RoleBinding", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = rb.metadata.clone();
 
     details_items.name = metadata.name;
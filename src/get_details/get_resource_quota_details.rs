@@ -0,0 +1,56 @@
+use std::{collections::BTreeMap, sync::{Arc, Mutex}};
+use kube::{Api, Client};
+use k8s_openapi::api::core::v1::ResourceQuota;
+
+#[derive(Debug, Clone)]
+pub struct EventDetails {
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub event_type: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ResourceQuotaDetails {
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub labels: Option<BTreeMap<String, String>>,
+    pub annotations: Option<BTreeMap<String, String>>,
+    pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+pub async fn get_resource_quota_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<ResourceQuotaDetails>>) -> Result<(), crate::DetailsError> {
+    let ns = ns.unwrap_or("default".to_string());
+    let api: Api<ResourceQuota> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let quota = match crate::fetch_details_object(&api, name).await {
+        Ok(quota) => quota,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let quota_events = crate::get_resource_events(client.clone(), "ResourceQuota", ns.as_str(), name).await.unwrap_or_default();
+    let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    let metadata = quota.metadata.clone();
+
+    details_items.name = metadata.name;
+    details_items.namespace = Some(ns);
+    details_items.labels = metadata.labels;
+    details_items.annotations = metadata.annotations;
+
+    details_items.events = quota_events.iter().map(|e| {
+        EventDetails {
+            reason: e.reason.clone(),
+            message: e.message.clone(),
+            event_type: e.type_.clone(),
+            timestamp: e.last_timestamp.as_ref().map(|ts| ts.0.to_string()),
+        }
+    }).collect();
+
+    Ok(())
+}
@@ -17,14 +17,25 @@ pub struct RoleDetails {
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_role_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<RoleDetails>>) -> Result<(), kube::Error> {
+pub async fn get_role_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<RoleDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<Role> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let role = api.get(name).await.unwrap();
-    let role_events = crate::get_resource_events(client.clone(), "Role", ns.clone().as_str(), name).await.unwrap();
+    let role = match crate::fetch_details_object(&api, name).await {
+        Ok(role) => role,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let role_events = crate::get_resource_events(client.clone(), "Role", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = role.metadata.clone();
 
     details_items.name = metadata.name;
@@ -19,13 +19,23 @@ pub struct ScDetails {
     pub mount_options: Option<Vec<String>>,
     pub parameters: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_sc_details(client: Arc<Client>, name: &str, details: Arc<Mutex<ScDetails>>) -> Result<(), kube::Error> {
+pub async fn get_sc_details(client: Arc<Client>, name: &str, details: Arc<Mutex<ScDetails>>) -> Result<(), crate::DetailsError> {
     let api: Api<StorageClass> = Api::all(client.as_ref().clone());
-    let sc = api.get(name).await.unwrap();
-    let sc_events = crate::get_cluster_resource_events(client.clone(), "StorageClass", name).await.unwrap();
+    let sc = match crate::fetch_details_object(&api, name).await {
+        Ok(sc) => sc,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let sc_events = crate::get_cluster_resource_events(client.clone(), "StorageClass", name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = sc.metadata.clone();
 
     details_items.name = metadata.name;
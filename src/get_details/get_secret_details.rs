@@ -1,6 +1,9 @@
 use kube::{Api, Client};
 use k8s_openapi::api::core::v1::Secret;
+use k8s_openapi::jiff::Timestamp;
 use std::{collections::BTreeMap, sync::{Arc, Mutex}};
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::Pem;
 
 #[derive(Debug, Clone)]
 pub struct EventDetails {
@@ -10,28 +13,104 @@ pub struct EventDetails {
     pub timestamp: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    pub sans: Vec<String>,
+    pub serial: String,
+    pub not_before: Option<Timestamp>,
+    pub not_after: Option<Timestamp>,
+    pub is_ca: bool,
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct SecretDetails {
     pub name: Option<String>,
     pub namespace: Option<String>,
+    pub secret_type: Option<String>,
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
+    pub data: BTreeMap<String, Vec<u8>>,
+    pub certificates: BTreeMap<String, Vec<CertificateInfo>>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+/// Every CERTIFICATE block of a PEM bundle, other blocks (keys) are skipped
+pub fn parse_pem_certificates(data: &[u8]) -> Vec<CertificateInfo> {
+    let mut certificates = Vec::new();
+    for pem in Pem::iter_from_buffer(data) {
+        let Ok(pem) = pem else {
+            break;
+        };
+        if pem.label != "CERTIFICATE" {
+            continue;
+        }
+        let Ok(cert) = pem.parse_x509() else {
+            continue;
+        };
+        let sans = match cert.subject_alternative_name() {
+            Ok(Some(san)) => san.value.general_names.iter().map(|name| match name {
+                GeneralName::DNSName(dns) => format!("DNS:{}", dns),
+                GeneralName::IPAddress(ip) => match ip.len() {
+                    4 => format!("IP:{}", std::net::Ipv4Addr::new(ip[0], ip[1], ip[2], ip[3])),
+                    16 => format!("IP:{}", std::net::Ipv6Addr::from(<[u8; 16]>::try_from(&ip[..]).unwrap_or_default())),
+                    _ => "IP:<invalid>".to_string(),
+                },
+                GeneralName::RFC822Name(email) => format!("email:{}", email),
+                GeneralName::URI(uri) => format!("URI:{}", uri),
+                other => format!("{:?}", other),
+            }).collect(),
+            _ => vec![],
+        };
+        certificates.push(CertificateInfo {
+            subject: cert.subject().to_string(),
+            issuer: cert.issuer().to_string(),
+            sans,
+            serial: cert.raw_serial_as_string(),
+            not_before: Timestamp::from_second(cert.validity().not_before.timestamp()).ok(),
+            not_after: Timestamp::from_second(cert.validity().not_after.timestamp()).ok(),
+            is_ca: cert.is_ca(),
+        });
+    }
+    certificates
 }
 
-pub async fn get_secret_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<SecretDetails>>) -> Result<(), kube::Error> {
+pub async fn get_secret_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<SecretDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<Secret> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let secret = api.get(name).await.unwrap();
-    let secret_events = crate::get_resource_events(client.clone(), "Secret", ns.clone().as_str(), name).await.unwrap();
+    let secret = match crate::fetch_details_object(&api, name).await {
+        Ok(secret) => secret,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let secret_events = crate::get_resource_events(client.clone(), "Secret", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = secret.metadata.clone();
 
     details_items.name = metadata.name;
     details_items.namespace = Some(ns);
+    details_items.secret_type = secret.type_.clone();
     details_items.labels = metadata.labels.clone();
     details_items.annotations = metadata.annotations.clone();
 
+    details_items.data = secret.data.clone().unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| (key, value.0))
+        .collect();
+    details_items.certificates = details_items.data.iter()
+        .filter(|(_, value)| value.windows(10).any(|w| w == b"-----BEGIN"))
+        .map(|(key, value)| (key.clone(), parse_pem_certificates(value)))
+        .filter(|(_, certs)| !certs.is_empty())
+        .collect();
+
     details_items.events = secret_events.iter().map(|e| {
         EventDetails {
             reason: e.reason.clone(),
@@ -18,14 +18,25 @@ pub struct ServiceDetails {
     pub annotations: Option<BTreeMap<String, String>>,
     pub events: Vec<EventDetails>,
     pub selector: Option<BTreeMap<String, String>>,
+    pub error: Option<crate::DetailsError>,
 }
 
-pub async fn get_service_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<ServiceDetails>>) -> Result<(), kube::Error> {
+pub async fn get_service_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<ServiceDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<Service> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let service = api.get(name).await.unwrap();
-    let service_events = crate::get_resource_events(client.clone(), "Service", ns.clone().as_str(), name).await.unwrap();
+    let service = match crate::fetch_details_object(&api, name).await {
+        Ok(service) => service,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let service_events = crate::get_resource_events(client.clone(), "Service", ns.clone().as_str(), name).await.unwrap_or_default();
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
     let metadata = service.metadata.clone();
     let spec = service.spec.as_ref();
 
@@ -33,7 +44,7 @@ pub async fn get_service_details(client: Arc<Client>, name: &str, ns: Option<Str
     details_items.namespace = Some(ns);
     details_items.labels = metadata.labels.clone();
     details_items.annotations = metadata.annotations.clone();
-    details_items.selector = spec.unwrap().selector.clone();
+    details_items.selector = spec.and_then(|s| s.selector.clone());
 
     details_items.events = service_events.iter().map(|e| {
         EventDetails {
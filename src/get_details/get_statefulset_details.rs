@@ -1,7 +1,20 @@
-use kube::{Api, Client};
-use k8s_openapi::api::apps::v1::StatefulSet;
+use kube::{Api, Client, api::ListParams};
+use k8s_openapi::api::apps::v1::{ControllerRevision, StatefulSet};
+use k8s_openapi::api::core::v1::{Pod, PodTemplateSpec};
 use std::{collections::BTreeMap, sync::{Arc, Mutex}};
 
+/// Pod label holding the name of the ControllerRevision the pod was created from
+pub const CONTROLLER_REVISION_LABEL: &str = "controller-revision-hash";
+
+#[derive(Debug, Clone)]
+pub struct OrdinalPodDetails {
+    pub ordinal: i32,
+    pub name: String,
+    pub revision: Option<String>,
+    pub ready: bool,
+    pub phase: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct EventDetails {
     pub reason: Option<String>,
@@ -16,15 +29,115 @@ pub struct StatefulSetDetails {
     pub namespace: Option<String>,
     pub labels: Option<BTreeMap<String, String>>,
     pub annotations: Option<BTreeMap<String, String>>,
+    pub replicas: i32,
+    pub update_strategy: Option<String>,
+    pub partition: Option<i32>,
+    pub current_revision: Option<String>,
+    pub update_revision: Option<String>,
+    pub updated_replicas: i32,
+    pub pods: Vec<OrdinalPodDetails>,
+    pub revisions: Vec<crate::RevisionDetails>,
     pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+pub fn label_selector(match_labels: Option<&BTreeMap<String, String>>) -> String {
+    match_labels
+        .map(|labels| labels.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(","))
+        .unwrap_or_default()
+}
+
+/// ControllerRevision data is the strategic merge patch restoring the pod template
+fn revision_from_controller_revision(cr: &ControllerRevision) -> crate::RevisionDetails {
+    let template: PodTemplateSpec = cr.data.as_ref()
+        .and_then(|data| data.0.get("spec"))
+        .and_then(|spec| spec.get("template"))
+        .map(|template| {
+            let mut template = template.clone();
+            if let Some(obj) = template.as_object_mut() {
+                obj.remove("$patch");
+            }
+            template
+        })
+        .and_then(|template| serde_json::from_value(template).ok())
+        .unwrap_or_default();
+    crate::RevisionDetails {
+        revision: cr.revision,
+        source: cr.metadata.name.clone().unwrap_or_default(),
+        created: cr.metadata.creation_timestamp.clone(),
+        replicas: None,
+        images: crate::template_images(&template),
+        change_cause: cr.metadata.annotations.as_ref().and_then(|a| a.get(crate::CHANGE_CAUSE_ANNOTATION).cloned()),
+        template: crate::template_to_yaml(&template),
+    }
+}
+
+/// History of a StatefulSet or DaemonSet, newest first
+pub async fn get_controller_revisions(client: Arc<Client>, ns: &str, selector: &str, owner_uid: Option<&String>) -> Vec<crate::RevisionDetails> {
+    let api: Api<ControllerRevision> = Api::namespaced(client.as_ref().clone(), ns);
+    let list = api.list(&ListParams::default().labels(selector)).await.map(|l| l.items).unwrap_or_default();
+    let mut revisions: Vec<crate::RevisionDetails> = list.iter()
+        .filter(|cr| crate::is_owned_by(cr.metadata.owner_references.as_ref(), owner_uid))
+        .map(revision_from_controller_revision)
+        .collect();
+    revisions.sort_by_key(|r| std::cmp::Reverse(r.revision));
+    revisions
 }
 
-pub async fn get_statefulset_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<StatefulSetDetails>>) -> Result<(), kube::Error> {
+pub async fn get_statefulset_details(client: Arc<Client>, name: &str, ns: Option<String>, details: Arc<Mutex<StatefulSetDetails>>) -> Result<(), crate::DetailsError> {
     let ns = ns.unwrap_or("default".to_string());
     let api: Api<StatefulSet> = Api::namespaced(client.as_ref().clone(), ns.as_str());
-    let statefulset = api.get(name).await.unwrap();
-    let statefulset_events = crate::get_resource_events(client.clone(), "StatefulSet", ns.clone().as_str(), name).await.unwrap();
+    let statefulset = match crate::fetch_details_object(&api, name).await {
+        Ok(statefulset) => statefulset,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.namespace = Some(ns);
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let statefulset_events = crate::get_resource_events(client.clone(), "StatefulSet", ns.clone().as_str(), name).await.unwrap_or_default();
+
+    let spec = statefulset.spec.clone().unwrap_or_default();
+    let status = statefulset.status.clone().unwrap_or_default();
+    let selector = label_selector(spec.selector.match_labels.as_ref());
+    let revisions = get_controller_revisions(client.clone(), &ns, &selector, statefulset.metadata.uid.as_ref()).await;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.as_ref().clone(), ns.as_str());
+    let pods = pods_api.list(&ListParams::default().labels(&selector)).await.map(|l| l.items).unwrap_or_default();
+    let prefix = format!("{}-", name);
+    let mut ordinal_pods: Vec<OrdinalPodDetails> = pods.iter()
+        .filter(|pod| crate::is_owned_by(pod.metadata.owner_references.as_ref(), statefulset.metadata.uid.as_ref()))
+        .filter_map(|pod| {
+            let pod_name = pod.metadata.name.clone()?;
+            let ordinal = pod_name.strip_prefix(&prefix)?.parse().ok()?;
+            let pod_status = pod.status.as_ref();
+            Some(OrdinalPodDetails {
+                ordinal,
+                revision: pod.metadata.labels.as_ref().and_then(|l| l.get(CONTROLLER_REVISION_LABEL).cloned()),
+                ready: pod_status
+                    .and_then(|s| s.conditions.as_ref())
+                    .is_some_and(|c| c.iter().any(|c| c.type_ == "Ready" && c.status == "True")),
+                phase: pod_status.and_then(|s| s.phase.clone()).unwrap_or_default(),
+                name: pod_name,
+            })
+        })
+        .collect();
+    ordinal_pods.sort_by_key(|p| p.ordinal);
+
     let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    details_items.replicas = spec.replicas.unwrap_or(1);
+    details_items.update_strategy = spec.update_strategy.as_ref().and_then(|s| s.type_.clone());
+    details_items.partition = spec.update_strategy.as_ref()
+        .and_then(|s| s.rolling_update.as_ref())
+        .and_then(|r| r.partition);
+    details_items.current_revision = status.current_revision;
+    details_items.update_revision = status.update_revision;
+    details_items.updated_replicas = status.updated_replicas.unwrap_or_default();
+    details_items.pods = ordinal_pods;
+    details_items.revisions = revisions;
     let metadata = statefulset.metadata.clone();
 
     details_items.name = metadata.name;
@@ -0,0 +1,113 @@
+use std::{collections::BTreeMap, sync::{Arc, Mutex}};
+use kube::{Api, Client, api::ListParams};
+use k8s_openapi::api::admissionregistration::v1::{ValidatingAdmissionPolicy, ValidatingAdmissionPolicyBinding};
+
+#[derive(Debug, Clone)]
+pub struct EventDetails {
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub event_type: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyBindingDetails {
+    pub name: String,
+    /// Deny, Warn and/or Audit
+    pub validation_actions: Vec<String>,
+    pub param_ref: Option<String>,
+    pub match_rules: Vec<String>,
+    pub namespace_selector: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ValidatingAdmissionPolicyDetails {
+    pub name: Option<String>,
+    pub labels: Option<BTreeMap<String, String>>,
+    pub annotations: Option<BTreeMap<String, String>>,
+    pub conditions: Vec<crate::ConditionDetails>,
+    /// A policy without bindings is never enforced
+    pub bindings: Vec<PolicyBindingDetails>,
+    /// Listing bindings failed, so an empty list says nothing about enforcement
+    pub bindings_error: Option<String>,
+    pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+pub async fn get_validating_admission_policy_details(client: Arc<Client>, name: &str, details: Arc<Mutex<ValidatingAdmissionPolicyDetails>>) -> Result<(), crate::DetailsError> {
+    let api: Api<ValidatingAdmissionPolicy> = Api::all(client.as_ref().clone());
+    let policy = match crate::fetch_details_object(&api, name).await {
+        Ok(policy) => policy,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    // Bindings refer to the policy by name, there is no field selector for it
+    let bindings_api: Api<ValidatingAdmissionPolicyBinding> = Api::all(client.as_ref().clone());
+    let (bindings, bindings_error) = match bindings_api.list(&ListParams::default()).await {
+        Ok(list) => (list.items, None),
+        Err(e) => (vec![], Some(e.to_string())),
+    };
+    let policy_events = crate::get_cluster_resource_events(client.clone(), "ValidatingAdmissionPolicy", name).await.unwrap_or_default();
+    let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+    let metadata = policy.metadata.clone();
+
+    details_items.name = metadata.name;
+    details_items.labels = metadata.labels.clone();
+    details_items.annotations = metadata.annotations.clone();
+
+    details_items.conditions = policy.status.as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map(|conditions| conditions.iter().map(|c| crate::ConditionDetails {
+            type_: c.type_.clone(),
+            status: c.status.clone(),
+            reason: Some(c.reason.clone()),
+            message: Some(c.message.clone()),
+            last_transition_time: Some(c.last_transition_time.0.to_string()),
+        }).collect())
+        .unwrap_or_default();
+
+    details_items.bindings_error = bindings_error;
+    details_items.bindings = bindings.iter()
+        .filter(|b| b.spec.policy_name == name)
+        .map(|b| {
+            let spec = &b.spec;
+            let match_resources = spec.match_resources.as_ref();
+            PolicyBindingDetails {
+                name: b.metadata.name.clone().unwrap_or_default(),
+                validation_actions: spec.validation_actions.clone(),
+                param_ref: spec.param_ref.as_ref().map(|p| {
+                    let target = match (&p.namespace, &p.name) {
+                        (Some(ns), Some(name)) => format!("{}/{}", ns, name),
+                        (None, Some(name)) => name.clone(),
+                        _ => p.selector.as_ref().map(crate::format_label_selector).unwrap_or("(selector)".to_string()),
+                    };
+                    match &p.parameter_not_found_action {
+                        Some(action) => format!("{} (not found: {})", target, action),
+                        None => target,
+                    }
+                }),
+                match_rules: crate::format_match_resources(match_resources),
+                namespace_selector: match_resources
+                    .and_then(|m| m.namespace_selector.as_ref())
+                    .map(crate::format_label_selector)
+                    .filter(|s| !s.is_empty()),
+            }
+        })
+        .collect();
+
+    details_items.events = policy_events.iter().map(|e| {
+        EventDetails {
+            reason: e.reason.clone(),
+            message: e.message.clone(),
+            event_type: e.type_.clone(),
+            timestamp: e.last_timestamp.as_ref().map(|ts| ts.0.to_string()),
+        }
+    }).collect();
+
+    Ok(())
+}
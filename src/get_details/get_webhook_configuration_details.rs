@@ -0,0 +1,139 @@
+use std::{collections::BTreeMap, fmt::Debug, sync::{Arc, Mutex}};
+use kube::{Api, Client, Resource, api::ListParams};
+use k8s_openapi::api::admissionregistration::v1::{MutatingWebhookConfiguration, ValidatingWebhookConfiguration};
+use k8s_openapi::api::core::v1::Service;
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone)]
+pub struct EventDetails {
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub event_type: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+/// Backing Service of one webhook as the API server would resolve it
+#[derive(Debug, Clone)]
+pub struct WebhookBackendDetails {
+    pub webhook: String,
+    pub service_found: bool,
+    /// The webhook port is a Service port, not the container port
+    pub port_found: bool,
+    pub ready: usize,
+    pub not_ready: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct WebhookConfigurationDetails {
+    pub name: Option<String>,
+    pub labels: Option<BTreeMap<String, String>>,
+    pub annotations: Option<BTreeMap<String, String>>,
+    pub backends: Vec<WebhookBackendDetails>,
+    pub events: Vec<EventDetails>,
+    pub error: Option<crate::DetailsError>,
+}
+
+async fn resolve_webhook_backend(client: Arc<Client>, webhook: &crate::WebhookInfo) -> Option<WebhookBackendDetails> {
+    let Some(crate::WebhookTarget::Service { namespace, name, port, .. }) = &webhook.target else {
+        return None;
+    };
+    let mut backend = WebhookBackendDetails {
+        webhook: webhook.name.clone(),
+        service_found: false,
+        port_found: false,
+        ready: 0,
+        not_ready: 0,
+        error: None,
+    };
+
+    let services: Api<Service> = Api::namespaced(client.as_ref().clone(), namespace);
+    match services.get_opt(name).await {
+        Ok(Some(service)) => {
+            backend.service_found = true;
+            backend.port_found = service.spec.as_ref()
+                .and_then(|s| s.ports.as_ref())
+                .is_some_and(|ports| ports.iter().any(|p| p.port == *port));
+        }
+        Ok(None) => return Some(backend),
+        Err(e) => {
+            backend.error = Some(e.to_string());
+            return Some(backend);
+        }
+    }
+
+    let slices: Api<EndpointSlice> = Api::namespaced(client.as_ref().clone(), namespace);
+    let lp = ListParams::default().labels(&format!("kubernetes.io/service-name={}", name));
+    match slices.list(&lp).await {
+        Ok(list) => {
+            for endpoint in list.items.iter().flat_map(|s| s.endpoints.iter().flatten()) {
+                // A missing ready condition means ready
+                if endpoint.conditions.as_ref().and_then(|c| c.ready).unwrap_or(true) {
+                    backend.ready += 1;
+                } else {
+                    backend.not_ready += 1;
+                }
+            }
+        }
+        Err(e) => backend.error = Some(e.to_string()),
+    }
+    Some(backend)
+}
+
+async fn get_webhook_configuration_details<K>(
+        client: Arc<Client>,
+        name: &str,
+        details: Arc<Mutex<WebhookConfigurationDetails>>,
+        kind: crate::WebhookKind,
+        convert: fn(K) -> Option<crate::WebhookConfigurationItem>,
+) -> Result<(), crate::DetailsError>
+where
+    K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug,
+{
+    let api: Api<K> = Api::all(client.as_ref().clone());
+    let config = match crate::fetch_details_object(&api, name).await {
+        Ok(config) => config,
+        Err(e) => {
+            let mut details_items = details.lock().unwrap();
+            details_items.name = Some(name.to_string());
+            details_items.error = Some(e.clone());
+            return Err(e);
+        }
+    };
+    let metadata = config.meta().clone();
+    let webhooks = convert(config).map(|item| item.webhooks).unwrap_or_default();
+    let mut backends = Vec::new();
+    for webhook in &webhooks {
+        if let Some(backend) = resolve_webhook_backend(client.clone(), webhook).await {
+            backends.push(backend);
+        }
+    }
+    let config_events = crate::get_cluster_resource_events(client.clone(), kind.kind(), name).await.unwrap_or_default();
+    let mut details_items = details.lock().unwrap();
+    details_items.error = None;
+
+    details_items.name = metadata.name;
+    details_items.labels = metadata.labels;
+    details_items.annotations = metadata.annotations;
+    details_items.backends = backends;
+
+    details_items.events = config_events.iter().map(|e| {
+        EventDetails {
+            reason: e.reason.clone(),
+            message: e.message.clone(),
+            event_type: e.type_.clone(),
+            timestamp: e.last_timestamp.as_ref().map(|ts| ts.0.to_string()),
+        }
+    }).collect();
+
+    Ok(())
+}
+
+pub async fn get_mutating_webhook_details(client: Arc<Client>, name: &str, details: Arc<Mutex<WebhookConfigurationDetails>>) -> Result<(), crate::DetailsError> {
+    get_webhook_configuration_details::<MutatingWebhookConfiguration>(client, name, details, crate::WebhookKind::Mutating, crate::convert_mutating_webhook_configuration).await
+}
+
+pub async fn get_validating_webhook_details(client: Arc<Client>, name: &str, details: Arc<Mutex<WebhookConfigurationDetails>>) -> Result<(), crate::DetailsError> {
+    get_webhook_configuration_details::<ValidatingWebhookConfiguration>(client, name, details, crate::WebhookKind::Validating, crate::convert_validating_webhook_configuration).await
+}
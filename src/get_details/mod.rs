@@ -1,3 +1,6 @@
+pub mod details_error;
+pub use details_error::*;
+
 pub mod get_node_details;
 pub use get_node_details::*;
 
@@ -64,11 +67,26 @@ pub use get_service_details::*;
 pub mod get_endpoint_details;
 pub use get_endpoint_details::*;
 
-pub mod get_cr_instances;
-pub use get_cr_instances::*;
+pub mod get_cr_instance_details;
+pub use get_cr_instance_details::*;
 
 pub mod get_sc_details;
 pub use get_sc_details::*;
 
+pub mod get_hpa_details;
+pub use get_hpa_details::*;
+
+pub mod get_resource_quota_details;
+pub use get_resource_quota_details::*;
+
+pub mod get_limit_range_details;
+pub use get_limit_range_details::*;
+
+pub mod get_webhook_configuration_details;
+pub use get_webhook_configuration_details::*;
+
+pub mod get_validating_admission_policy_details;
+pub use get_validating_admission_policy_details::*;
+
 pub mod get_k8s_released_version;
 pub use get_k8s_released_version::*;
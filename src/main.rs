@@ -22,13 +22,13 @@ use get_details::*;
 
 use eframe::egui::{CursorIcon};
 use eframe::*;
-use egui::{Color32, FontId, TextStyle};
+use egui::{Color32, FontId, Key, Modifiers, TextStyle};
 use std::collections::{BTreeMap, BTreeSet};
 use std::f32;
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
 use kube::{Client};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use serde_json::{Map, Value};
 
 const ICON_BYTES: &[u8] = include_bytes!("../assets/icon.png");
@@ -60,10 +60,15 @@ enum Category {
     ReplicaSets,
     Jobs,
     CronJobs,
+    HorizontalPodAutoscalers,
     Services,
     Leases,
+    ResourceQuotas,
+    LimitRanges,
+    NamespaceCapacity,
     Endpoints,
     Ingresses,
+    GatewayApi,
     PersistentVolumeClaims,
     PersistentVolumes,
     StorageClasses,
@@ -72,6 +77,7 @@ enum Category {
     ProxyProcess,
     PodDisruptionBudgets,
     NetworkPolicies,
+    NetworkPolicyAnalyzer,
     CustomResourcesDefinitions,
     CustomResources,
     HelmReleases,
@@ -83,6 +89,10 @@ enum Category {
     ClusterRoles,
     ClusterRoleBindings,
     RoleBindings,
+    RbacAnalyzer,
+    MutatingWebhooks,
+    ValidatingWebhooks,
+    ValidatingAdmissionPolicies,
 }
 
 #[derive(Clone)]
@@ -90,7 +100,7 @@ struct EnvVars {
     cluster_name: String,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
 enum ResourceType {
     Blank,
     NameSpace,
@@ -111,6 +121,12 @@ enum ResourceType {
     Service,
     Deployment,
     StorageClass,
+    StatefulSet,
+    Job,
+    CronJob,
+    NetworkPolicy,
+    PodDisruptionBudget,
+    HorizontalPodAutoscaler,
 }
 
 #[derive(Default, Clone, Debug)]
@@ -129,6 +145,18 @@ async fn main() {
         std::process::exit(0);
     }
 
+    if let Some(kind) = cli.list.as_deref() {
+        let result = match get_kubernetes_client(cli.kubeconfig.as_deref(), cli.context.as_deref(), cli.impersonation().as_ref()).await {
+            Ok(client) => run_cli_query(client, kind, cli.query.as_deref().unwrap_or_default()).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    }
+
     let mut title = String::from("RustLens v");
 
     // App config
@@ -172,6 +200,7 @@ async fn main() {
     let mut scale_window = ui::scale::ScaleWindow::new();
     let mut node_details_window = ui::node_details::NodeDetailsWindow::new();
     let mut pod_details_window = ui::pod_details::PodDetailsWindow::new();
+    let mut debug_session_window = ui::debug_session::DebugSessionWindow::new();
     let mut deployment_details_window = ui::deployment_details::DeploymentDetailsWindow::new();
     let mut daemonset_details_window = ui::daemonset_details::DaemonSetDetailsWindow::new();
     let mut replicaset_details_window = ui::replicaset_details::ReplicaSetDetailsWindow::new();
@@ -179,6 +208,10 @@ async fn main() {
     let mut configmap_details_window = ui::configmap_details::ConfigMapDetailsWindow::new();
     let mut job_details_window = ui::job_details::JobDetailsWindow::new();
     let mut lease_details_window = ui::lease_details::LeaseDetailsWindow::new();
+    let mut hpa_details_window = ui::hpa_details::HpaDetailsWindow::new();
+    let mut resource_quota_details_window = ui::resource_quota_details::ResourceQuotaDetailsWindow::new();
+    let mut limit_range_details_window = ui::limit_range_details::LimitRangeDetailsWindow::new();
+    let mut namespace_capacity = ui::namespace_capacity::NamespaceCapacityState::new();
     let mut pvc_details_window = ui::pvc_details::PvcDetailsWindow::new();
     let mut pv_details_window = ui::pv_details::PvDetailsWindow::new();
     let mut cronjob_details_window = ui::cronjob_details::CronJobDetailsWindow::new();
@@ -188,8 +221,12 @@ async fn main() {
     let mut rb_details_window = ui::rb_details::RoleBindingDetailsWindow::new();
     let mut crd_details_window = ui::crd_details::CrdDetailsWindow::new();
     let mut cluster_role_details_window = ui::cluster_role_details::ClusterRoleDetailsWindow::new();
+    let mut mutating_webhook_details_window = ui::webhook_configuration_details::WebhookConfigurationDetailsWindow::new(WebhookKind::Mutating);
+    let mut validating_webhook_details_window = ui::webhook_configuration_details::WebhookConfigurationDetailsWindow::new(WebhookKind::Validating);
+    let mut admission_policy_details_window = ui::validating_admission_policy_details::ValidatingAdmissionPolicyDetailsWindow::new();
     let mut cluster_rb_details_window = ui::cluster_rb_details::ClusterRoleBindingDetailsWindow::new();
     let mut ingress_details_window = ui::ingress_details::IngressDetailsWindow::new();
+    let mut gateway_api_details_window = ui::gateway_api::GatewayApiDetailsWindow::new();
     let mut endpoint_details_window = ui::endpoint_details::EndpointDetailsWindow::new();
     let mut secret_details_window = ui::secret_details::SecretDetailsWindow::new();
     let mut sc_details_window = ui::sc_details::ScDetailsWindow::new();
@@ -203,7 +240,12 @@ async fn main() {
     let mut uuid_gen_window = ui::uuid_generator::UUIDGenWindow::default();
     let mut jwt_decoder_window = ui::jwt_decoder::JwtDecoderWindow::default();
     let cr_grouped_list = Arc::new(Mutex::new(BTreeMap::<String, Vec<CRDItem>>::new()));
-    let cr_instances: Arc<Mutex<Vec<CrdInstance>>> = Arc::new(Mutex::new(Vec::new()));
+    let mut cr_instances: Arc<Mutex<Vec<CrdInstance>>> = Arc::new(Mutex::new(Vec::new()));
+    let mut cr_instances_loading = Arc::new(AtomicBool::new(false));
+    let mut cr_instances_watcher: Option<tokio::task::JoinHandle<()>> = None;
+    let cr_instance_details = Arc::new(Mutex::new(CrInstanceDetails::default()));
+    let mut cr_instance_details_window = ui::cr_instance_details::CrInstanceDetailsWindow::new();
+    let mut cr_views = load_cr_views();
 
     let mut proxy_process = ui::kubectl_proxy::ProxyProcess::default();
 
@@ -223,6 +265,9 @@ async fn main() {
     let user_name = ctx_info.name;
 
     let mut confirmation_dialog = DeleteConfirmation::new();
+    let mut bulk_selection = BulkSelection::new();
+    let mut command_palette = CommandPalette::new();
+    let bulk_report = Arc::new(Mutex::new(BulkReport::default()));
 
     let selected_category = Arc::new(Mutex::new(Category::ClusterOverview));
     let selected_category_ui = Arc::clone(&selected_category);
@@ -240,7 +285,13 @@ async fn main() {
     let mut filter_roles = String::new();
     let mut filter_rbs = String::new();
     let mut filter_leases = String::new();
+    let mut filter_hpas = String::new();
+    let mut filter_resource_quotas = String::new();
+    let mut filter_limit_ranges = String::new();
     let mut filter_cluster_roles = String::new();
+    let mut filter_mutating_webhooks = String::new();
+    let mut filter_validating_webhooks = String::new();
+    let mut filter_admission_policies = String::new();
     let mut filter_cluster_rb = String::new();
     let mut filter_statefulsets = String::new();
     let mut filter_jobs = String::new();
@@ -260,6 +311,10 @@ async fn main() {
     let mut filter_crds = String::new();
     let mut filter_helm_releases = String::new();
     let mut filter_warnings: bool = false;
+    let mut event_timeline = EventTimelineState::new();
+    let mut rbac_analyzer = RbacAnalyzerState::new();
+    let mut netpol_analyzer = NetpolAnalyzerState::new();
+    let mut gateway_api_state = GatewayApiState::new();
     let mut hl_item = String::new();
 
     // Fetched latest released Kubernetes version
@@ -267,7 +322,8 @@ async fn main() {
 
     // Client connection
     log::info!("Connecting to kubernetes cluster...");
-    let client = match get_kubernetes_client(cli.kubeconfig.as_deref(), cli.context.as_deref()).await {
+    let impersonation = cli.impersonation();
+    let client = match get_kubernetes_client(cli.kubeconfig.as_deref(), cli.context.as_deref(), impersonation.as_ref()).await {
         Ok(c) => Arc::new(c),
         Err(e) => {
             log::error!("Error connecting to kubernetes cluster: {:?}", e);
@@ -317,8 +373,28 @@ async fn main() {
     let leases_loading = Arc::new(AtomicBool::new(false));
     let leases_started = Arc::new(AtomicBool::new(false));
 
+    // HORIZONTAL POD AUTOSCALERS
+    let hpas = Arc::new(Mutex::new(Vec::<HpaItem>::new()));
+    let hpa_details = Arc::new(Mutex::new(HpaDetails::default()));
+    let hpas_loading = Arc::new(AtomicBool::new(false));
+    let hpas_started = Arc::new(AtomicBool::new(false));
+
+    // RESOURCE QUOTAS
+    let resource_quotas = Arc::new(Mutex::new(Vec::<ResourceQuotaItem>::new()));
+    let resource_quota_details = Arc::new(Mutex::new(ResourceQuotaDetails::default()));
+    let resource_quotas_loading = Arc::new(AtomicBool::new(false));
+    let resource_quotas_started = Arc::new(AtomicBool::new(false));
+
+    // LIMIT RANGES
+    let limit_ranges = Arc::new(Mutex::new(Vec::<LimitRangeItem>::new()));
+    let limit_range_details = Arc::new(Mutex::new(LimitRangeDetails::default()));
+    let limit_ranges_loading = Arc::new(AtomicBool::new(false));
+    let limit_ranges_started = Arc::new(AtomicBool::new(false));
+
     // ENDPOINTS
-    let endpoints = Arc::new(Mutex::new(Vec::<EndpointItem>::new()));
+    let endpoint_slices = Arc::new(Mutex::new(Vec::<EndpointSliceItem>::new()));
+    let endpoint_slices_generation = Arc::new(AtomicU64::new(0));
+    let mut service_endpoints_cache = ServiceEndpointsCache::default();
     let endpoint_details = Arc::new(Mutex::new(EndpointDetails::default()));
     let endpoints_loading = Arc::new(AtomicBool::new(false));
     let endpoints_started = Arc::new(AtomicBool::new(false));
@@ -335,6 +411,22 @@ async fn main() {
     let rb_loading = Arc::new(AtomicBool::new(false));
     let rbs_started = Arc::new(AtomicBool::new(false));
 
+    // ADMISSION WEBHOOKS
+    let mutating_webhooks = Arc::new(Mutex::new(Vec::<WebhookConfigurationItem>::new()));
+    let mutating_webhook_details = Arc::new(Mutex::new(WebhookConfigurationDetails::default()));
+    let mutating_webhooks_loading = Arc::new(AtomicBool::new(false));
+    let mutating_webhooks_started = Arc::new(AtomicBool::new(false));
+    let validating_webhooks = Arc::new(Mutex::new(Vec::<WebhookConfigurationItem>::new()));
+    let validating_webhook_details = Arc::new(Mutex::new(WebhookConfigurationDetails::default()));
+    let validating_webhooks_loading = Arc::new(AtomicBool::new(false));
+    let validating_webhooks_started = Arc::new(AtomicBool::new(false));
+
+    // VALIDATING ADMISSION POLICIES
+    let admission_policies = Arc::new(Mutex::new(Vec::<ValidatingAdmissionPolicyItem>::new()));
+    let admission_policy_details = Arc::new(Mutex::new(ValidatingAdmissionPolicyDetails::default()));
+    let admission_policies_loading = Arc::new(AtomicBool::new(false));
+    let admission_policies_started = Arc::new(AtomicBool::new(false));
+
     // CLUSTER ROLES
     let cluster_roles = Arc::new(Mutex::new(Vec::<ClusterRoleItem>::new()));
     let cluster_role_details = Arc::new(Mutex::new(ClusterRoleDetails::default()));
@@ -381,6 +473,12 @@ async fn main() {
     let ingresses_loading = Arc::new(AtomicBool::new(false));
     let ingresses_started = Arc::new(AtomicBool::new(false));
 
+    // GATEWAY API
+    let gateway_api_objects = Arc::new(Mutex::new(Vec::<GatewayApiObject>::new()));
+    let gateway_api_discovery = Arc::new(Mutex::new(GatewayApiDiscovery::default()));
+    let gateway_api_loading = Arc::new(AtomicBool::new(false));
+    let gateway_api_started = Arc::new(AtomicBool::new(false));
+
     // CRDS
     let crds = Arc::new(Mutex::new(Vec::<CRDItem>::new()));
     let crd_details = Arc::new(Mutex::new(CrdDetails::default()));
@@ -509,13 +607,22 @@ async fn main() {
     let helm_releases_loading = Arc::new(AtomicBool::new(false));
     let helm_releases_started = Arc::new(AtomicBool::new(false));
 
+    let ui_kubeconfig = cli.kubeconfig.clone();
+    let ui_context = cli.context.clone();
+
+    // Node debug pods are privileged, whatever is left of them goes away with the app
+    let debug_cleanup_client = Arc::clone(&client);
+
     let mut title_updated = false;
     eframe::run_ui_native(&title, options, move |ui: &mut egui::Ui, _frame| {
         let ctx = ui.ctx().clone();
         ctx.set_visuals(egui::Visuals::dark());
 
         if !title_updated {
-            let new_title = format!("RustLens v{} - {}", env!("CARGO_PKG_VERSION"), cluster_name);
+            let mut new_title = format!("RustLens v{} - {}", env!("CARGO_PKG_VERSION"), cluster_name);
+            if let Some(impersonation) = &impersonation {
+                new_title.push_str(&format!(" (as {})", impersonation.user));
+            }
             ctx.send_viewport_cmd(egui::ViewportCommand::Title(new_title));
             title_updated = true;
         }
@@ -540,6 +647,23 @@ async fn main() {
 
         ctx.set_global_style(style);
 
+        // Taken before any text field can see the shortcut
+        let mut open_command_palette = ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::K));
+
+        if let Some(impersonation) = &impersonation {
+            egui::Panel::top("impersonation banner").frame(egui::Frame::new().fill(IMPERSONATION_BANNER_COLOR).inner_margin(egui::Margin::symmetric(10, 6))).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format!("👤 Impersonating {}", impersonation.label())).size(16.0).strong().color(Color32::WHITE));
+                    ui.label(egui::RichText::new("- everything here is shown and changed with the permissions of this identity").color(Color32::LIGHT_GRAY));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(egui::RichText::new("✖ Close window").color(Color32::WHITE)).clicked() {
+                            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                        }
+                    });
+                });
+            });
+        }
+
         egui::Panel::top("top panel").show(ui, |ui| {
             ui.add_space(7.0);
             ui.horizontal(|ui| {
@@ -570,10 +694,21 @@ async fn main() {
                 if ui.button(egui::RichText::new("💬 AI Consultant").size(17.0).color(egui::Color32::LIGHT_BLUE)).on_hover_text("AI Consultant").clicked() {
                     ai_window.show = true;
                 }
+                ui.separator();
+                if ui.button(egui::RichText::new("🔍 Search").size(17.0).color(egui::Color32::LIGHT_GRAY)).on_hover_text("Command palette (Ctrl+K)").clicked() {
+                    open_command_palette = true;
+                }
             });
             ui.add_space(7.0);
         });
 
+        // Custom resource instances are only watched while their category is open
+        if *selected_category_ui.lock().unwrap() != Category::CustomResources
+            && let Some(handle) = cr_instances_watcher.take() {
+                handle.abort();
+                selected_cr.clear();
+            }
+
         egui::Panel::left("tasks panel").resizable(false).exact_size(290.0).show(ui, |ui| {
             egui::ScrollArea::vertical().auto_shrink(false).id_salt("menu_scroll").show(ui, |ui| {
                 let current = selected_category_ui.lock().unwrap().clone();
@@ -624,6 +759,10 @@ async fn main() {
                     if ui.selectable_label(current == Category::CronJobs, "📅 CronJobs").clicked() {
                         *selected_category_ui.lock().unwrap() = Category::CronJobs;
                     }
+
+                    if ui.selectable_label(current == Category::HorizontalPodAutoscalers, "📈 HorizontalPodAutoscalers").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::HorizontalPodAutoscalers;
+                    }
                 });
 
                 egui::CollapsingHeader::new("🛠 Config").default_open(false).show(ui, |ui| {
@@ -642,6 +781,18 @@ async fn main() {
                     if ui.selectable_label(current == Category::Leases, "📂 Leases").clicked() {
                         *selected_category_ui.lock().unwrap() = Category::Leases;
                     }
+
+                    if ui.selectable_label(current == Category::ResourceQuotas, "⚖ ResourceQuotas").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::ResourceQuotas;
+                    }
+
+                    if ui.selectable_label(current == Category::LimitRanges, "📏 LimitRanges").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::LimitRanges;
+                    }
+
+                    if ui.selectable_label(current == Category::NamespaceCapacity, "📊 Namespace capacity").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::NamespaceCapacity;
+                    }
                 });
 
                 egui::CollapsingHeader::new("🖧 Network").default_open(false).show(ui, |ui| {
@@ -657,10 +808,18 @@ async fn main() {
                         *selected_category_ui.lock().unwrap() = Category::Ingresses;
                     }
 
+                    if ui.selectable_label(current == Category::GatewayApi, "🚪 Gateway API").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::GatewayApi;
+                    }
+
                     if ui.selectable_label(current == Category::NetworkPolicies, "📋 Network Policies").clicked() {
                         *selected_category_ui.lock().unwrap() = Category::NetworkPolicies;
                     }
 
+                    if ui.selectable_label(current == Category::NetworkPolicyAnalyzer, "🚦 Network policy analyzer").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::NetworkPolicyAnalyzer;
+                    }
+
                     if ui.selectable_label(current == Category::ProxyProcess, "💨 Proxy").clicked() {
                         *selected_category_ui.lock().unwrap() = Category::ProxyProcess;
                     }
@@ -704,6 +863,22 @@ async fn main() {
                     if ui.selectable_label(current == Category::ClusterRoleBindings, "🔐 Cluster role bindings").clicked() {
                         *selected_category_ui.lock().unwrap() = Category::ClusterRoleBindings;
                     }
+
+                    if ui.selectable_label(current == Category::RbacAnalyzer, "🔎 RBAC analyzer").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::RbacAnalyzer;
+                    }
+
+                    if ui.selectable_label(current == Category::MutatingWebhooks, "🪝 Mutating webhooks").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::MutatingWebhooks;
+                    }
+
+                    if ui.selectable_label(current == Category::ValidatingWebhooks, "🪝 Validating webhooks").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::ValidatingWebhooks;
+                    }
+
+                    if ui.selectable_label(current == Category::ValidatingAdmissionPolicies, "📜 Admission policies").clicked() {
+                        *selected_category_ui.lock().unwrap() = Category::ValidatingAdmissionPolicies;
+                    }
                 });
 
                 egui::CollapsingHeader::new("🖥 Custom Resources").default_open(false).show(ui, |ui| {
@@ -715,28 +890,29 @@ async fn main() {
                         egui::CollapsingHeader::new(group).default_open(false).show(ui, |ui| {
                             for item in items {
 
-                                let seleted_item: bool = item.kind == selected_cr && *selected_category_ui.lock().unwrap() == Category::CustomResources;
+                                let seleted_item: bool = item.name == selected_cr && *selected_category_ui.lock().unwrap() == Category::CustomResources;
                                 if ui.selectable_label(seleted_item, &item.kind).clicked() {
                                     *selected_category_ui.lock().unwrap() = Category::CustomResources;
-                                    selected_cr = item.kind.clone();
-                                    let version = item.version.clone();
-                                    let group = item.group.clone();
-                                    let plural = item.plural.clone();
-                                    let kind = item.kind.clone();
-                                    let scope = item.scope.clone();
-                                    let client_clone = Arc::clone(&client);
-                                    let cr_instances_clone = Arc::clone(&cr_instances);
-                                    tokio::spawn(async move {
-                                        let result = get_cr_instances(
-                                                client_clone,
-                                                group,
-                                                version,
-                                                kind,
-                                                plural,
-                                                scope,
-                                            ).await;
-                                        *cr_instances_clone.lock().unwrap() = result.unwrap();
-                                    });
+                                    if selected_cr != item.name {
+                                        selected_cr = item.name.clone();
+
+                                        // Stop watching previous CRD instances
+                                        if let Some(handle) = cr_instances_watcher.take() {
+                                            handle.abort();
+                                        }
+                                        // Fresh state per watcher, an aborted task can still be finishing a write to the old one
+                                        cr_instances = Arc::new(Mutex::new(Vec::new()));
+                                        cr_instances_loading = Arc::new(AtomicBool::new(true));
+                                        cr_instance_details_window.show = false;
+
+                                        let crd = item.clone();
+                                        cr_instances_watcher = Some(spawn_watcher(
+                                            Arc::clone(&client),
+                                            Arc::clone(&cr_instances),
+                                            Arc::clone(&cr_instances_loading),
+                                            move |c, s, l| Box::pin(watch_cr_instances(c, s, l, crd)),
+                                        ));
+                                    }
                                 }
                             }
                         });
@@ -771,6 +947,7 @@ async fn main() {
             *cr_grouped_list_clone.lock().unwrap() = result;
         });
 
+        let mut gateway_api_action = None;
         egui::CentralPanel::default().show(ui, |ui| {
             macro_rules! lazy_start {
                 ($started:expr, $loading:expr, $client:expr, $state:expr, $watch_fn:expr) => {
@@ -788,6 +965,10 @@ async fn main() {
             }
 
             let current_category = selected_category_ui.lock().unwrap().clone();
+            if let Some(kind) = watched_kind(&current_category)
+                && let Some(reason) = watch_denied(kind) {
+                    show_access_denied(ui, kind, &reason);
+                }
             match current_category {
                 Category::Leases => {
                     lazy_start!(leases_started, leases_loading, client, leases, |c, s, l| Box::pin(watch_leases(c, s, l)));
@@ -901,72 +1082,107 @@ async fn main() {
                         }
                     }
                 },
-                Category::ProxyProcess => {
-                    show_kubectl_proxy_status(ui, &mut proxy_process);
-                },
-                Category::Configuration => {
-                    show_configuration(ui, &mut app_config);
-                },
-                Category::About => {
-                    show_about_info(ui);
-                },
-                Category::AppLogs => {
-                    log_viewer.ui(ui);
-                },
-                Category::CustomResources => {
-                    let cr_instances_clone = Arc::clone(&cr_instances);
-                    let cr_items = cr_instances_clone.lock().unwrap();
-
+                Category::ResourceQuotas => {
+                    lazy_start!(resource_quotas_started, resource_quotas_loading, client, resource_quotas, |c, s, l| Box::pin(watch_resource_quotas(c, s, l)));
+                    let ns = namespaces.lock().unwrap();
+                    let mut selected_ns = selected_namespace_clone.lock().unwrap();
+                    let visible_resource_quotas: Vec<_> = if let Some(ns) = selected_ns.as_ref() {
+                        resource_quotas.lock().unwrap()
+                            .iter()
+                            .filter(|p| p.namespace.as_deref() == Some(ns))
+                            .cloned()
+                            .collect()
+                    } else {
+                        resource_quotas.lock().unwrap().iter().cloned().collect()
+                    };
                     ui.horizontal(|ui| {
-                        ui.heading(format!("Custom Resources - {}", cr_items.len()));
+                        ui.heading(format!("ResourceQuotas - {}", visible_resource_quotas.len()));
+                        ui.separator();
+                        ui.heading("Namespace - ".to_string());
+                        egui::ComboBox::from_id_salt("namespace_combo").selected_text(selected_ns.as_deref().unwrap_or("all")).width(150.0).show_ui(ui, |ui| {
+                            ui.selectable_value(&mut *selected_ns, None, "all");
+                            for item in ns.iter() {
+                                let ns_name = &item.name;
+                                ui.selectable_value(
+                                    &mut *selected_ns,
+                                    Some(ns_name.clone()),
+                                    ns_name,
+                                );
+                            }
+                        });
+                        ui.separator();
+                        ui.add(egui::TextEdit::singleline(&mut filter_resource_quotas).hint_text("Filter resource quotas...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_resource_quotas = filter_resource_quotas.to_lowercase();
+                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
+                            filter_resource_quotas.clear();
+                        }
                     });
                     ui.separator();
-
-                    if let Some(first_item) = cr_items.first() {
-                        match first_item.kind.as_str() {
-                            "ClusterSecretStore" => {
-                                egui::ScrollArea::vertical().auto_shrink(false).id_salt("cr_scroll").show(ui, |ui| {
-                                    egui::Grid::new("cr_grid").striped(true).min_col_width(40.0).show(ui, |ui| {
-                                        show_cluster_secret_store_header(ui);
-                                        ui.label("Actions");
-                                        ui.end_row();
-                                        for j in cr_items.clone().iter() {
-                                            show_cluster_secret_store_details(&j.name, &j.data, ui);
+                    if resource_quotas_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        if visible_resource_quotas.is_empty() {
+                            show_empty(ui);
+                        } else {
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("resource_quotas_scroll").show(ui, |ui| {
+                                egui::Grid::new("resource_quotas_grid").striped(true).min_col_width(20.0).max_col_width(630.0).show(ui, |ui| {
+                                    ui.label("Name");
+                                    ui.label("Namespace");
+                                    ui.label("Scopes");
+                                    ui.label("Highest usage");
+                                    ui.label("Age");
+                                    ui.label("Actions");
+                                    ui.end_row();
+                                    for item in visible_resource_quotas.iter().rev().take(200) {
+                                        let cur_item_object = &item.name;
+                                        if filter_resource_quotas.is_empty() || cur_item_object.contains(&filter_resource_quotas) {
+                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                let name = cur_item_object.clone();
+                                                let client_clone = Arc::clone(&client);
+                                                let details = Arc::clone(&resource_quota_details);
+                                                let ns = item.namespace.clone();
+                                                resource_quota_details_window.show = true;
+                                                tokio::spawn({
+                                                    async move {
+                                                        if let Err(e) = get_resource_quota_details(client_clone, &name, ns, details).await {
+                                                            log::error!("ResourceQuota details fetch failed: {:?}", e);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                            if ui.label(egui::RichText::new(item.namespace.clone().unwrap_or("".to_string())).color(NAMESPACE_COLUMN_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                *selected_ns = item.namespace.clone();
+                                            }
+                                            ui.label(if item.scopes.is_empty() { "-".to_string() } else { item.scopes.join(", ") });
+                                            if let Some((resource, ratio)) = item.max_usage() {
+                                                let used = item.used.get(&resource).cloned().unwrap_or("0".to_string());
+                                                ui.label(egui::RichText::new(format!("{} {}/{}", resource, used, item.hard[&resource])).color(usage_color(ratio)));
+                                            } else {
+                                                ui.label("-");
+                                            }
+                                            ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
                                             ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
                                                 ui.set_width(200.0);
                                                 if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
-                                                    let name = j.name.clone();
-                                                    let ns = j.namespace.clone();
-                                                    let group = j.group.clone();
-                                                    let version = j.version.clone();
-                                                    let plural = j.plural.clone();
-                                                    let kind = j.kind.clone();
-                                                    let client_clone = Arc::clone(&client);
-                                                    let yaml_editor_clone = Arc::clone(&yaml_editor_window);
-                                                    tokio::spawn(async move {
-                                                        match crate::get_cr_instance_yaml(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
-                                                            Ok(yaml) => {
-                                                                let mut editor = yaml_editor_clone.lock().unwrap();
-                                                                editor.content = yaml;
-                                                                editor.show = true;
-                                                            }
-                                                            Err(e) => log::error!("Failed to get CR YAML: {}", e),
-                                                        }
-                                                    });
-                                                    ui.close_kind(egui::UiKind::Menu);
+                                                    crate::edit_yaml_for::<k8s_openapi::api::core::v1::ResourceQuota>(
+                                                        item.name.clone(),
+                                                        item.namespace.clone().unwrap_or_else(|| "default".to_string()),
+                                                        Arc::clone(&yaml_editor_window),
+                                                        Arc::clone(&client)
+                                                    );
                                                 }
                                                 if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
-                                                    let name = j.name.clone();
-                                                    let ns = j.namespace.clone();
-                                                    let group = j.group.clone();
-                                                    let version = j.version.clone();
-                                                    let plural = j.plural.clone();
-                                                    let kind = j.kind.clone();
+                                                    let cur_item = item.name.clone();
+                                                    let cur_ns = item.namespace.clone();
                                                     let client_clone = Arc::clone(&client);
-                                                    confirmation_dialog.request(name.clone(), ns.clone(), move || {
+                                                    confirmation_dialog.request(cur_item.clone(), cur_ns.clone(), move || {
                                                         tokio::spawn(async move {
-                                                            if let Err(e) = crate::delete_cr_instance(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
-                                                                log::error!("Failed to delete CR instance: {}", e);
+                                                            if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::ResourceQuota>(
+                                                                cur_item.clone(),
+                                                                cur_ns.as_deref(),
+                                                                client_clone,
+                                                            ).await {
+                                                                log::error!("Failed to delete resource quota: {}", err);
                                                             }
                                                         });
                                                     });
@@ -975,106 +1191,243 @@ async fn main() {
                                             });
                                             ui.end_row();
                                         }
-                                    });
+                                    }
                                 });
-                            },
-                            "ExternalSecret" => {
-                                egui::ScrollArea::vertical().auto_shrink(false).id_salt("cr_scroll").show(ui, |ui| {
-                                    egui::Grid::new("cr_grid").striped(true).min_col_width(40.0).show(ui, |ui| {
-                                        show_external_secret_header(ui);
-                                        ui.label("Actions");
-                                        ui.end_row();
-                                        for j in cr_items.clone().iter() {
-                                            show_external_secret_details(&j.name, &j.data, ui);
+                            });
+                        }
+                    }
+                },
+                Category::LimitRanges => {
+                    lazy_start!(limit_ranges_started, limit_ranges_loading, client, limit_ranges, |c, s, l| Box::pin(watch_limit_ranges(c, s, l)));
+                    let ns = namespaces.lock().unwrap();
+                    let mut selected_ns = selected_namespace_clone.lock().unwrap();
+                    let visible_limit_ranges: Vec<_> = if let Some(ns) = selected_ns.as_ref() {
+                        limit_ranges.lock().unwrap()
+                            .iter()
+                            .filter(|p| p.namespace.as_deref() == Some(ns))
+                            .cloned()
+                            .collect()
+                    } else {
+                        limit_ranges.lock().unwrap().iter().cloned().collect()
+                    };
+                    ui.horizontal(|ui| {
+                        ui.heading(format!("LimitRanges - {}", visible_limit_ranges.len()));
+                        ui.separator();
+                        ui.heading("Namespace - ".to_string());
+                        egui::ComboBox::from_id_salt("namespace_combo").selected_text(selected_ns.as_deref().unwrap_or("all")).width(150.0).show_ui(ui, |ui| {
+                            ui.selectable_value(&mut *selected_ns, None, "all");
+                            for item in ns.iter() {
+                                let ns_name = &item.name;
+                                ui.selectable_value(
+                                    &mut *selected_ns,
+                                    Some(ns_name.clone()),
+                                    ns_name,
+                                );
+                            }
+                        });
+                        ui.separator();
+                        ui.add(egui::TextEdit::singleline(&mut filter_limit_ranges).hint_text("Filter limit ranges...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_limit_ranges = filter_limit_ranges.to_lowercase();
+                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
+                            filter_limit_ranges.clear();
+                        }
+                    });
+                    ui.separator();
+                    if limit_ranges_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        if visible_limit_ranges.is_empty() {
+                            show_empty(ui);
+                        } else {
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("limit_ranges_scroll").show(ui, |ui| {
+                                egui::Grid::new("limit_ranges_grid").striped(true).min_col_width(20.0).max_col_width(630.0).show(ui, |ui| {
+                                    ui.label("Name");
+                                    ui.label("Namespace");
+                                    ui.label("Types");
+                                    ui.label("Age");
+                                    ui.label("Actions");
+                                    ui.end_row();
+                                    for item in visible_limit_ranges.iter().rev().take(200) {
+                                        let cur_item_object = &item.name;
+                                        if filter_limit_ranges.is_empty() || cur_item_object.contains(&filter_limit_ranges) {
+                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                let name = cur_item_object.clone();
+                                                let client_clone = Arc::clone(&client);
+                                                let details = Arc::clone(&limit_range_details);
+                                                let ns = item.namespace.clone();
+                                                limit_range_details_window.show = true;
+                                                tokio::spawn({
+                                                    async move {
+                                                        if let Err(e) = get_limit_range_details(client_clone, &name, ns, details).await {
+                                                            log::error!("LimitRange details fetch failed: {:?}", e);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                            if ui.label(egui::RichText::new(item.namespace.clone().unwrap_or("".to_string())).color(NAMESPACE_COLUMN_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                *selected_ns = item.namespace.clone();
+                                            }
+                                            ui.label(item.limits.iter().map(|l| l.type_.as_str()).collect::<Vec<_>>().join(", "));
+                                            ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
                                             ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
                                                 ui.set_width(200.0);
                                                 if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
-                                                    let name = j.name.clone();
-                                                    let ns = j.namespace.clone();
-                                                    let group = j.group.clone();
-                                                    let version = j.version.clone();
-                                                    let plural = j.plural.clone();
-                                                    let kind = j.kind.clone();
+                                                    crate::edit_yaml_for::<k8s_openapi::api::core::v1::LimitRange>(
+                                                        item.name.clone(),
+                                                        item.namespace.clone().unwrap_or_else(|| "default".to_string()),
+                                                        Arc::clone(&yaml_editor_window),
+                                                        Arc::clone(&client)
+                                                    );
+                                                }
+                                                if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
+                                                    let cur_item = item.name.clone();
+                                                    let cur_ns = item.namespace.clone();
                                                     let client_clone = Arc::clone(&client);
-                                                    let yaml_editor_clone = Arc::clone(&yaml_editor_window);
-                                                    tokio::spawn(async move {
-                                                        match crate::get_cr_instance_yaml(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
-                                                            Ok(yaml) => {
-                                                                let mut editor = yaml_editor_clone.lock().unwrap();
-                                                                editor.content = yaml;
-                                                                editor.show = true;
+                                                    confirmation_dialog.request(cur_item.clone(), cur_ns.clone(), move || {
+                                                        tokio::spawn(async move {
+                                                            if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::LimitRange>(
+                                                                cur_item.clone(),
+                                                                cur_ns.as_deref(),
+                                                                client_clone,
+                                                            ).await {
+                                                                log::error!("Failed to delete limit range: {}", err);
                                                             }
-                                                            Err(e) => log::error!("Failed to get CR YAML: {}", e),
-                                                        }
-                                                    });
-                                                    ui.close_kind(egui::UiKind::Menu);
-                                                }
-                                                if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
-                                                    let name = j.name.clone();
-                                                    let ns = j.namespace.clone();
-                                                    let group = j.group.clone();
-                                                    let version = j.version.clone();
-                                                    let plural = j.plural.clone();
-                                                    let kind = j.kind.clone();
-                                                    let client_clone = Arc::clone(&client);
-                                                    confirmation_dialog.request(name.clone(), ns.clone(), move || {
-                                                        tokio::spawn(async move {
-                                                            if let Err(e) = crate::delete_cr_instance(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
-                                                                log::error!("Failed to delete CR instance: {}", e);
-                                                            }
-                                                        });
+                                                        });
                                                     });
                                                     ui.close_kind(egui::UiKind::Menu);
                                                 }
                                             });
                                             ui.end_row();
                                         }
-                                    });
+                                    }
                                 });
-                            },
-                            "VirtualService" => {
-                                egui::ScrollArea::vertical().auto_shrink(false).id_salt("cr_scroll").show(ui, |ui| {
-                                    egui::Grid::new("cr_grid").striped(true).min_col_width(40.0).show(ui, |ui| {
-                                        show_virtual_service_header(ui);
-                                        ui.label("Actions");
-                                        ui.end_row();
-                                        for j in cr_items.clone().iter() {
-                                            show_virtual_service_details(&j.name, &j.data, ui);
+                            });
+                        }
+                    }
+                },
+                Category::NamespaceCapacity => {
+                    lazy_start!(resource_quotas_started, resource_quotas_loading, client, resource_quotas, |c, s, l| Box::pin(watch_resource_quotas(c, s, l)));
+                    lazy_start!(limit_ranges_started, limit_ranges_loading, client, limit_ranges, |c, s, l| Box::pin(watch_limit_ranges(c, s, l)));
+                    if resource_quotas_loading.load(Ordering::Relaxed) || limit_ranges_loading.load(Ordering::Relaxed) || pods_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        let namespaces_list = namespaces.lock().unwrap();
+                        let pods_list = pods.lock().unwrap();
+                        let quotas_list = resource_quotas.lock().unwrap();
+                        let limit_ranges_list = limit_ranges.lock().unwrap();
+                        let rows = build_capacity_rows(&namespaces_list, &pods_list, &quotas_list, &limit_ranges_list);
+                        show_namespace_capacity(ui, &mut namespace_capacity, rows);
+                    }
+                },
+                Category::HorizontalPodAutoscalers => {
+                    lazy_start!(hpas_started, hpas_loading, client, hpas, |c, s, l| Box::pin(watch_hpas(c, s, l)));
+                    let ns = namespaces.lock().unwrap();
+                    let mut selected_ns = selected_namespace_clone.lock().unwrap();
+                    let visible_hpas: Vec<_> = if let Some(ns) = selected_ns.as_ref() {
+                        hpas.lock().unwrap()
+                            .iter()
+                            .filter(|p| p.namespace.as_deref() == Some(ns))
+                            .cloned()
+                            .collect()
+                    } else {
+                        hpas.lock().unwrap().iter().cloned().collect()
+                    };
+                    ui.horizontal(|ui| {
+                        ui.heading(format!("HorizontalPodAutoscalers - {}", visible_hpas.len()));
+                        ui.separator();
+                        ui.heading("Namespace - ".to_string());
+                        egui::ComboBox::from_id_salt("namespace_combo").selected_text(selected_ns.as_deref().unwrap_or("all")).width(150.0).show_ui(ui, |ui| {
+                            ui.selectable_value(&mut *selected_ns, None, "all");
+                            for item in ns.iter() {
+                                let ns_name = &item.name;
+                                ui.selectable_value(
+                                    &mut *selected_ns,
+                                    Some(ns_name.clone()),
+                                    ns_name,
+                                );
+                            }
+                        });
+                        ui.separator();
+                        if ui.button(egui::RichText::new("➕ Add new").size(16.0).color(GREEN_BUTTON)).clicked() {
+                            new_resource_window.resource_type = ResourceType::HorizontalPodAutoscaler;
+                            new_resource_window.content.clear();
+                            new_resource_window.show = true;
+                        }
+                        ui.separator();
+                        ui.add(egui::TextEdit::singleline(&mut filter_hpas).hint_text("Filter HPAs...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_hpas = filter_hpas.to_lowercase();
+                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
+                            filter_hpas.clear();
+                        }
+                    });
+                    ui.separator();
+                    if hpas_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        if visible_hpas.is_empty() {
+                            show_empty(ui);
+                        } else {
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("hpas_scroll").show(ui, |ui| {
+                                egui::Grid::new("hpas_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
+                                    ui.label("Name");
+                                    ui.label("Namespace");
+                                    ui.label("Reference");
+                                    ui.label("Targets");
+                                    ui.label("Min");
+                                    ui.label("Max");
+                                    ui.label("Replicas");
+                                    ui.label("Age");
+                                    ui.label("Actions");
+                                    ui.end_row();
+                                    for item in visible_hpas.iter().rev().take(200) {
+                                        let cur_item_object = &item.name;
+                                        if filter_hpas.is_empty() || cur_item_object.contains(&filter_hpas) {
+                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                let name = cur_item_object.clone();
+                                                let client_clone = Arc::clone(&client);
+                                                let details = Arc::clone(&hpa_details);
+                                                let ns = item.namespace.clone();
+                                                hpa_details_window.show = true;
+                                                tokio::spawn({
+                                                    async move {
+                                                        if let Err(e) = get_hpa_details(client_clone, &name, ns, details).await {
+                                                            log::error!("HPA details fetch failed: {:?}", e);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                            if ui.label(egui::RichText::new(item.namespace.clone().unwrap_or("".to_string())).color(NAMESPACE_COLUMN_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                *selected_ns = item.namespace.clone();
+                                            }
+                                            ui.label(format!("{}/{}", item.target_kind, item.target_name));
+                                            ui.label(item.targets());
+                                            ui.label(item.min_replicas.to_string());
+                                            ui.label(item.max_replicas.to_string());
+                                            let replicas_color = if item.current_replicas >= item.max_replicas { WARNING_COLOR } else { Color32::LIGHT_GRAY };
+                                            ui.label(egui::RichText::new(item.current_replicas.to_string()).color(replicas_color));
+                                            ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
                                             ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
                                                 ui.set_width(200.0);
                                                 if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
-                                                    let name = j.name.clone();
-                                                    let ns = j.namespace.clone();
-                                                    let group = j.group.clone();
-                                                    let version = j.version.clone();
-                                                    let plural = j.plural.clone();
-                                                    let kind = j.kind.clone();
-                                                    let client_clone = Arc::clone(&client);
-                                                    let yaml_editor_clone = Arc::clone(&yaml_editor_window);
-                                                    tokio::spawn(async move {
-                                                        match crate::get_cr_instance_yaml(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
-                                                            Ok(yaml) => {
-                                                                let mut editor = yaml_editor_clone.lock().unwrap();
-                                                                editor.content = yaml;
-                                                                editor.show = true;
-                                                            }
-                                                            Err(e) => log::error!("Failed to get CR YAML: {}", e),
-                                                        }
-                                                    });
-                                                    ui.close_kind(egui::UiKind::Menu);
+                                                    crate::edit_yaml_for::<k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler>(
+                                                        item.name.clone(),
+                                                        item.namespace.clone().unwrap_or_else(|| "default".to_string()),
+                                                        Arc::clone(&yaml_editor_window),
+                                                        Arc::clone(&client)
+                                                    );
                                                 }
                                                 if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
-                                                    let name = j.name.clone();
-                                                    let ns = j.namespace.clone();
-                                                    let group = j.group.clone();
-                                                    let version = j.version.clone();
-                                                    let plural = j.plural.clone();
-                                                    let kind = j.kind.clone();
+                                                    let cur_item = item.name.clone();
+                                                    let cur_ns = item.namespace.clone();
                                                     let client_clone = Arc::clone(&client);
-                                                    confirmation_dialog.request(name.clone(), ns.clone(), move || {
+                                                    confirmation_dialog.request(cur_item.clone(), cur_ns.clone(), move || {
                                                         tokio::spawn(async move {
-                                                            if let Err(e) = crate::delete_cr_instance(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
-                                                                log::error!("Failed to delete CR instance: {}", e);
+                                                            if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler>(
+                                                                cur_item.clone(),
+                                                                cur_ns.as_deref(),
+                                                                client_clone,
+                                                            ).await {
+                                                                log::error!("Failed to delete HPA: {}", err);
                                                             }
                                                         });
                                                     });
@@ -1083,17 +1436,85 @@ async fn main() {
                                             });
                                             ui.end_row();
                                         }
-                                    });
+                                    }
                                 });
-                            },
-                            "CiliumLoadBalancerIPPool" => {
+                            });
+                        }
+                    }
+                },
+                Category::ProxyProcess => {
+                    show_kubectl_proxy_status(ui, &mut proxy_process);
+                },
+                Category::Configuration => {
+                    show_configuration(ui, &mut app_config);
+                },
+                Category::About => {
+                    show_about_info(ui);
+                },
+                Category::RbacAnalyzer => {
+                    lazy_start!(roles_started, roles_loading, client, roles, |c, s, l| Box::pin(watch_roles(c, s, l)));
+                    lazy_start!(cluster_roles_started, cluster_roles_loading, client, cluster_roles, |c, s, l| Box::pin(watch_cluster_roles(c, s, l)));
+                    lazy_start!(rbs_started, rb_loading, client, rbs, |c, s, l| Box::pin(watch_rbs(c, s, l)));
+                    lazy_start!(cluster_rbs_started, cluster_rb_loading, client, cluster_rbs, |c, s, l| Box::pin(watch_cluster_rbs(c, s, l)));
+                    if roles_loading.load(Ordering::Relaxed) || cluster_roles_loading.load(Ordering::Relaxed)
+                        || rb_loading.load(Ordering::Relaxed) || cluster_rb_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        let roles_list = roles.lock().unwrap();
+                        let cluster_roles_list = cluster_roles.lock().unwrap();
+                        let rbs_list = rbs.lock().unwrap();
+                        let cluster_rbs_list = cluster_rbs.lock().unwrap();
+                        let mut snapshot = RbacSnapshot::new(&roles_list, &cluster_roles_list, &rbs_list, &cluster_rbs_list);
+                        show_rbac_analyzer(ui, &mut rbac_analyzer, &mut snapshot, &client);
+                    }
+                },
+                Category::NetworkPolicyAnalyzer => {
+                    lazy_start!(network_policies_started, network_policies_loading, client, network_policies, |c, s, l| Box::pin(watch_network_policies(c, s, l)));
+                    if network_policies_loading.load(Ordering::Relaxed) || pods_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        let policies_list = network_policies.lock().unwrap();
+                        let pods_list = pods.lock().unwrap();
+                        let namespaces_list = namespaces.lock().unwrap();
+                        let snapshot = NetpolSnapshot::new(&policies_list, &pods_list, &namespaces_list);
+                        show_network_policy_analyzer(ui, &mut netpol_analyzer, &snapshot);
+                    }
+                },
+                Category::AppLogs => {
+                    log_viewer.ui(ui);
+                },
+                Category::CustomResources => {
+                    let cr_instances_clone = Arc::clone(&cr_instances);
+                    let cr_items = cr_instances_clone.lock().unwrap();
+
+                    ui.horizontal(|ui| {
+                        ui.heading(format!("Custom Resources - {}", cr_items.len()));
+                        ui.separator();
+                        if ui.button(egui::RichText::new("⟳ Reload views").size(14.0).color(GREEN_BUTTON)).on_hover_text("Reload view definitions from ~/.local/share/rustlens/views").clicked() {
+                            cr_views = load_cr_views();
+                        }
+                    });
+                    ui.separator();
+
+                    if cr_instances_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else if cr_items.is_empty() {
+                        show_empty(ui);
+                    } else if let Some(first_item) = cr_items.first() {
+                        match find_cr_view(&cr_views, &first_item.group, &first_item.version, &first_item.kind) {
+                            Some(view) => {
                                 egui::ScrollArea::vertical().auto_shrink(false).id_salt("cr_scroll").show(ui, |ui| {
                                     egui::Grid::new("cr_grid").striped(true).min_col_width(40.0).show(ui, |ui| {
-                                        show_cilium_load_balancer_ip_pool_header(ui);
+                                        show_cr_view_header(view, ui);
                                         ui.label("Actions");
                                         ui.end_row();
-                                        for j in cr_items.clone().iter() {
-                                            show_cilium_load_balancer_ip_pool_details(&j.name, &j.data, ui);
+                                        for j in cr_items.iter() {
+                                            if show_cr_view_row(view, &j.name, &j.data, ui) {
+                                                cr_instance_details_window.name = Some(j.name.clone());
+                                                cr_instance_details_window.namespace = j.namespace.clone();
+                                                cr_instance_details_window.show = true;
+                                                cr_instance_details_window.last_refresh = None;
+                                            }
                                             ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
                                                 ui.set_width(200.0);
                                                 if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
@@ -1140,7 +1561,7 @@ async fn main() {
                                     });
                                 });
                             },
-                            _ => {
+                            None => {
                                 egui::ScrollArea::vertical().auto_shrink(false).id_salt("cr_scroll").show(ui, |ui| {
                                     fn value_to_string(v: &Value) -> Option<String> {
                                         match v {
@@ -1238,7 +1659,12 @@ async fn main() {
 
                                                 // rows
                                                 for cr in cr_items.iter() {
-                                                    ui.label(cr.name.clone());
+                                                    if ui.label(egui::RichText::new(&cr.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                        cr_instance_details_window.name = Some(cr.name.clone());
+                                                        cr_instance_details_window.namespace = cr.namespace.clone();
+                                                        cr_instance_details_window.show = true;
+                                                        cr_instance_details_window.last_refresh = None;
+                                                    }
 
                                                     for col in &ordered_cols {
                                                         if let Some(val) = extract_col_value(&cr.data, col) {
@@ -1386,6 +1812,15 @@ async fn main() {
                                                         Arc::clone(&client)
                                                     );
                                                 }
+                                                if ui.button(egui::RichText::new("👤 Impersonate").size(16.0).color(ORANGE_BUTTON))
+                                                    .on_hover_text("Open a new window acting as this service account")
+                                                    .clicked() {
+                                                        let sa = Impersonation::service_account(item.namespace.as_deref().unwrap_or("default"), &item.name);
+                                                        if let Err(e) = launch_impersonating(&sa, ui_kubeconfig.as_deref(), ui_context.as_deref()) {
+                                                            log::error!("Failed to start impersonating window: {}", e);
+                                                        }
+                                                        ui.close_kind(egui::UiKind::Menu);
+                                                    }
                                                 if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
                                                     let cur_item = item.name.clone();
                                                     let cur_ns = item.namespace.clone();
@@ -1447,69 +1882,339 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_roles).hint_text("Filter roles...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_roles = filter_roles.to_lowercase();
+                        ui.add(egui::TextEdit::singleline(&mut filter_roles).hint_text("Filter roles...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_roles = filter_roles.to_lowercase();
+                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
+                            filter_roles.clear();
+                        }
+                    });
+                    ui.separator();
+                    if roles_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        if visible_roles.is_empty() {
+                            show_empty(ui);
+                        } else {
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("roles_scroll").show(ui, |ui| {
+                                egui::Grid::new("roles_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
+                                    ui.label("Name");
+                                    ui.label("Namespace");
+                                    ui.label("Age");
+                                    ui.label("Actions");
+                                    ui.end_row();
+                                    for item in visible_roles.iter().rev().take(200) {
+                                        let cur_item_object = &item.name;
+                                        if filter_roles.is_empty() || cur_item_object.contains(&filter_roles) {
+                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                let name = cur_item_object.clone();
+                                                let client_clone = Arc::clone(&client);
+                                                let details = Arc::clone(&role_details);
+                                                let ns = item.namespace.clone();
+                                                role_details_window.show = true;
+                                                tokio::spawn({
+                                                    async move {
+                                                        if let Err(e) = get_role_details(client_clone, &name, ns, details).await {
+                                                            log::error!("Role details fetch failed: {:?}", e);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                            if ui.label(egui::RichText::new(item.namespace.clone().unwrap_or("".to_string())).color(NAMESPACE_COLUMN_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                *selected_ns = item.namespace.clone();
+                                            }
+                                            ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
+                                            ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
+                                                ui.set_width(200.0);
+                                                if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
+                                                    crate::edit_yaml_for::<k8s_openapi::api::rbac::v1::Role>(
+                                                        item.name.clone(),
+                                                        item.namespace.clone().unwrap_or_else(|| "default".to_string()),
+                                                        Arc::clone(&yaml_editor_window),
+                                                        Arc::clone(&client)
+                                                    );
+                                                }
+                                                if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
+                                                    let cur_item = item.name.clone();
+                                                    let cur_ns = item.namespace.clone();
+                                                    let client_clone = Arc::clone(&client);
+                                                    confirmation_dialog.request(cur_item.clone(), cur_ns.clone(), move || {
+                                                        tokio::spawn(async move {
+                                                            if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::rbac::v1::Role>(
+                                                                cur_item.clone(),
+                                                                cur_ns.as_deref(),
+                                                                client_clone,
+                                                            ).await {
+                                                                log::error!("Failed to delete Role: {}", err);
+                                                            }
+                                                        });
+                                                    });
+                                                    ui.close_kind(egui::UiKind::Menu);
+                                                }
+                                            });
+                                            ui.end_row();
+                                        }
+                                    }
+                                });
+                            });
+                        }
+                    }
+                },
+                Category::ClusterRoles => {
+                    let visible_cluster_roles = cluster_roles.lock().unwrap();
+                    lazy_start!(cluster_roles_started, cluster_roles_loading, client, cluster_roles, |c, s, l| Box::pin(watch_cluster_roles(c, s, l)));
+                    ui.horizontal(|ui| {
+                        ui.heading(format!("Cluster roles - {}", visible_cluster_roles.len()));
+                        ui.separator();
+                        if ui.button(egui::RichText::new("➕ Add new").size(16.0).color(GREEN_BUTTON)).clicked() {
+                            new_resource_window.resource_type = ResourceType::ClusterRole;
+                            new_resource_window.content.clear();
+                            new_resource_window.show = true;
+                        }
+                        ui.separator();
+                        ui.add(egui::TextEdit::singleline(&mut filter_cluster_roles).hint_text("Filter cluster roles...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_cluster_roles = filter_cluster_roles.to_lowercase();
+                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
+                            filter_cluster_roles.clear();
+                        }
+                    });
+                    ui.separator();
+                    if cluster_roles_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        if visible_cluster_roles.is_empty() {
+                            show_empty(ui);
+                        } else {
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("cluster_roles_scroll").show(ui, |ui| {
+                                egui::Grid::new("cluster_roles_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
+                                    ui.label("Name");
+                                    ui.label("Age");
+                                    ui.label("Actions");
+                                    ui.end_row();
+                                    for item in visible_cluster_roles.iter().rev().take(200) {
+                                        let cur_item_object = &item.name;
+                                        if filter_cluster_roles.is_empty() || cur_item_object.contains(&filter_cluster_roles) {
+                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                let name = cur_item_object.clone();
+                                                let client_clone = Arc::clone(&client);
+                                                let details = Arc::clone(&cluster_role_details);
+                                                cluster_role_details_window.show = true;
+                                                tokio::spawn({
+                                                    async move {
+                                                        if let Err(e) = get_cluster_role_details(client_clone, &name, details).await {
+                                                            log::error!("Cluster role details fetch failed: {:?}", e);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                            ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
+                                            ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
+                                                ui.set_width(200.0);
+                                                if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
+                                                    crate::edit_cluster_yaml_for::<k8s_openapi::api::rbac::v1::ClusterRole>(
+                                                        item.name.clone(),
+                                                        Arc::clone(&yaml_editor_window),
+                                                        Arc::clone(&client)
+                                                    );
+                                                }
+                                                if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
+                                                    let cur_item = item.name.clone();
+                                                    let client_clone = Arc::clone(&client);
+                                                    confirmation_dialog.request(cur_item.clone(), None, move || {
+                                                        tokio::spawn(async move {
+                                                            if let Err(err) = delete_cluster_role(client_clone, &cur_item.clone()).await {
+                                                                log::error!("Failed to delete cluster role: {}", err);
+                                                            }
+                                                        });
+                                                    });
+                                                    ui.close_kind(egui::UiKind::Menu);
+                                                }
+                                            });
+                                            ui.end_row();
+                                        }
+                                    }
+                                });
+                            });
+                        }
+                    }
+                },
+                Category::MutatingWebhooks => {
+                    lazy_start!(mutating_webhooks_started, mutating_webhooks_loading, client, mutating_webhooks, |c, s, l| Box::pin(watch_mutating_webhook_configurations(c, s, l)));
+                    lazy_start!(services_started, services_loading, client, services, |c, s, l| Box::pin(watch_services(c, s, l)));
+                    let generation = Arc::clone(&endpoint_slices_generation);
+                    lazy_start!(endpoints_started, endpoints_loading, client, endpoint_slices, move |c, s, l| Box::pin(watch_endpoint_slices(c, s, l, generation)));
+                    let visible_mutating_webhooks = mutating_webhooks.lock().unwrap();
+                    ui.horizontal(|ui| {
+                        ui.heading(format!("Mutating webhooks - {}", visible_mutating_webhooks.len()));
+                        ui.separator();
+                        ui.add(egui::TextEdit::singleline(&mut filter_mutating_webhooks).hint_text("Filter mutating webhooks...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_mutating_webhooks = filter_mutating_webhooks.to_lowercase();
+                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
+                            filter_mutating_webhooks.clear();
+                        }
+                    });
+                    ui.separator();
+                    if mutating_webhooks_loading.load(Ordering::Relaxed) || services_loading.load(Ordering::Relaxed) || endpoints_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        if visible_mutating_webhooks.is_empty() {
+                            show_empty(ui);
+                        } else {
+                            let services_list = services.lock().unwrap();
+                            let service_endpoints = service_endpoints_cache.get(&endpoint_slices, &endpoint_slices_generation);
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("mutating_webhooks_scroll").show(ui, |ui| {
+                                egui::Grid::new("mutating_webhooks_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
+                                    ui.label("Name");
+                                    ui.label("Webhooks");
+                                    ui.label("Failure policy");
+                                    ui.label("Backends");
+                                    ui.label("CA expires");
+                                    ui.label("Age");
+                                    ui.label("Actions");
+                                    ui.end_row();
+                                    for item in visible_mutating_webhooks.iter().rev().take(200) {
+                                        let cur_item_object = &item.name;
+                                        if filter_mutating_webhooks.is_empty() || cur_item_object.contains(&filter_mutating_webhooks) {
+                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                                let name = cur_item_object.clone();
+                                                let client_clone = Arc::clone(&client);
+                                                let details = Arc::clone(&mutating_webhook_details);
+                                                mutating_webhook_details_window.show = true;
+                                                tokio::spawn({
+                                                    async move {
+                                                        if let Err(e) = get_mutating_webhook_details(client_clone, &name, details).await {
+                                                            log::error!("MutatingWebhookConfiguration details fetch failed: {:?}", e);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                            ui.label(item.webhooks.len().to_string());
+                                            let fail_count = item.webhooks.iter().filter(|w| w.failure_policy == "Fail").count();
+                                            ui.label(format!("{} Fail, {} Ignore", fail_count, item.webhooks.len() - fail_count));
+                                            let backends: Vec<_> = item.webhooks.iter().map(|w| (w, w.backend(&services_list, &service_endpoints))).collect();
+                                            if let Some((webhook, _)) = backends.iter().find(|(w, b)| w.blocks_requests(b)) {
+                                                ui.label(egui::RichText::new(format!("⚠ {} unreachable, requests are rejected", webhook.name)).color(ERROR_MESSAGE_COLOR));
+                                            } else if let Some((webhook, _)) = backends.iter().find(|(_, b)| matches!(b, WebhookBackend::MissingService | WebhookBackend::NoReadyEndpoints)) {
+                                                ui.label(egui::RichText::new(format!("{} unreachable, ignored", webhook.name)).color(WARNING_COLOR));
+                                            } else if backends.iter().all(|(_, b)| *b == WebhookBackend::Url) {
+                                                ui.label("URL");
+                                            } else {
+                                                ui.label(egui::RichText::new("Ready").color(GREEN_BUTTON));
+                                            }
+                                            let (ca_text, ca_color) = ca_expiry_text(item.ca_expiry());
+                                            ui.label(egui::RichText::new(ca_text).color(ca_color));
+                                            ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
+                                            ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
+                                                ui.set_width(200.0);
+                                                if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
+                                                    crate::edit_cluster_yaml_for::<k8s_openapi::api::admissionregistration::v1::MutatingWebhookConfiguration>(
+                                                        item.name.clone(),
+                                                        Arc::clone(&yaml_editor_window),
+                                                        Arc::clone(&client)
+                                                    );
+                                                }
+                                                if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
+                                                    let cur_item = item.name.clone();
+                                                    let client_clone = Arc::clone(&client);
+                                                    confirmation_dialog.request(cur_item.clone(), None, move || {
+                                                        tokio::spawn(async move {
+                                                            if let Err(err) = delete_cluster_component_for::<k8s_openapi::api::admissionregistration::v1::MutatingWebhookConfiguration>(cur_item, client_clone).await {
+                                                                log::error!("Failed to delete mutating webhook configuration: {}", err);
+                                                            }
+                                                        });
+                                                    });
+                                                    ui.close_kind(egui::UiKind::Menu);
+                                                }
+                                            });
+                                            ui.end_row();
+                                        }
+                                    }
+                                });
+                            });
+                        }
+                    }
+                },
+                Category::ValidatingWebhooks => {
+                    lazy_start!(validating_webhooks_started, validating_webhooks_loading, client, validating_webhooks, |c, s, l| Box::pin(watch_validating_webhook_configurations(c, s, l)));
+                    lazy_start!(services_started, services_loading, client, services, |c, s, l| Box::pin(watch_services(c, s, l)));
+                    let generation = Arc::clone(&endpoint_slices_generation);
+                    lazy_start!(endpoints_started, endpoints_loading, client, endpoint_slices, move |c, s, l| Box::pin(watch_endpoint_slices(c, s, l, generation)));
+                    let visible_validating_webhooks = validating_webhooks.lock().unwrap();
+                    ui.horizontal(|ui| {
+                        ui.heading(format!("Validating webhooks - {}", visible_validating_webhooks.len()));
+                        ui.separator();
+                        ui.add(egui::TextEdit::singleline(&mut filter_validating_webhooks).hint_text("Filter validating webhooks...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_validating_webhooks = filter_validating_webhooks.to_lowercase();
                         if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_roles.clear();
+                            filter_validating_webhooks.clear();
                         }
                     });
                     ui.separator();
-                    if roles_loading.load(Ordering::Relaxed) {
+                    if validating_webhooks_loading.load(Ordering::Relaxed) || services_loading.load(Ordering::Relaxed) || endpoints_loading.load(Ordering::Relaxed) {
                         show_loading(ui);
                     } else {
-                        if visible_roles.is_empty() {
+                        if visible_validating_webhooks.is_empty() {
                             show_empty(ui);
                         } else {
-                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("roles_scroll").show(ui, |ui| {
-                                egui::Grid::new("roles_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
+                            let services_list = services.lock().unwrap();
+                            let service_endpoints = service_endpoints_cache.get(&endpoint_slices, &endpoint_slices_generation);
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("validating_webhooks_scroll").show(ui, |ui| {
+                                egui::Grid::new("validating_webhooks_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
                                     ui.label("Name");
-                                    ui.label("Namespace");
+                                    ui.label("Webhooks");
+                                    ui.label("Failure policy");
+                                    ui.label("Backends");
+                                    ui.label("CA expires");
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
-                                    for item in visible_roles.iter().rev().take(200) {
+                                    for item in visible_validating_webhooks.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_roles.is_empty() || cur_item_object.contains(&filter_roles) {
+                                        if filter_validating_webhooks.is_empty() || cur_item_object.contains(&filter_validating_webhooks) {
                                             if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
-                                                let details = Arc::clone(&role_details);
-                                                let ns = item.namespace.clone();
-                                                role_details_window.show = true;
+                                                let details = Arc::clone(&validating_webhook_details);
+                                                validating_webhook_details_window.show = true;
                                                 tokio::spawn({
                                                     async move {
-                                                        if let Err(e) = get_role_details(client_clone, &name, ns, details).await {
-                                                            log::error!("Role details fetch failed: {:?}", e);
+                                                        if let Err(e) = get_validating_webhook_details(client_clone, &name, details).await {
+                                                            log::error!("ValidatingWebhookConfiguration details fetch failed: {:?}", e);
                                                         }
                                                     }
                                                 });
                                             }
-                                            if ui.label(egui::RichText::new(item.namespace.clone().unwrap_or("".to_string())).color(NAMESPACE_COLUMN_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
-                                                *selected_ns = item.namespace.clone();
+                                            ui.label(item.webhooks.len().to_string());
+                                            let fail_count = item.webhooks.iter().filter(|w| w.failure_policy == "Fail").count();
+                                            ui.label(format!("{} Fail, {} Ignore", fail_count, item.webhooks.len() - fail_count));
+                                            let backends: Vec<_> = item.webhooks.iter().map(|w| (w, w.backend(&services_list, &service_endpoints))).collect();
+                                            if let Some((webhook, _)) = backends.iter().find(|(w, b)| w.blocks_requests(b)) {
+                                                ui.label(egui::RichText::new(format!("⚠ {} unreachable, requests are rejected", webhook.name)).color(ERROR_MESSAGE_COLOR));
+                                            } else if let Some((webhook, _)) = backends.iter().find(|(_, b)| matches!(b, WebhookBackend::MissingService | WebhookBackend::NoReadyEndpoints)) {
+                                                ui.label(egui::RichText::new(format!("{} unreachable, ignored", webhook.name)).color(WARNING_COLOR));
+                                            } else if backends.iter().all(|(_, b)| *b == WebhookBackend::Url) {
+                                                ui.label("URL");
+                                            } else {
+                                                ui.label(egui::RichText::new("Ready").color(GREEN_BUTTON));
                                             }
+                                            let (ca_text, ca_color) = ca_expiry_text(item.ca_expiry());
+                                            ui.label(egui::RichText::new(ca_text).color(ca_color));
                                             ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
                                             ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
                                                 ui.set_width(200.0);
                                                 if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
-                                                    crate::edit_yaml_for::<k8s_openapi::api::rbac::v1::Role>(
+                                                    crate::edit_cluster_yaml_for::<k8s_openapi::api::admissionregistration::v1::ValidatingWebhookConfiguration>(
                                                         item.name.clone(),
-                                                        item.namespace.clone().unwrap_or_else(|| "default".to_string()),
                                                         Arc::clone(&yaml_editor_window),
                                                         Arc::clone(&client)
                                                     );
                                                 }
                                                 if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
                                                     let cur_item = item.name.clone();
-                                                    let cur_ns = item.namespace.clone();
                                                     let client_clone = Arc::clone(&client);
-                                                    confirmation_dialog.request(cur_item.clone(), cur_ns.clone(), move || {
+                                                    confirmation_dialog.request(cur_item.clone(), None, move || {
                                                         tokio::spawn(async move {
-                                                            if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::rbac::v1::Role>(
-                                                                cur_item.clone(),
-                                                                cur_ns.as_deref(),
-                                                                client_clone,
-                                                            ).await {
-                                                                log::error!("Failed to delete Role: {}", err);
+                                                            if let Err(err) = delete_cluster_component_for::<k8s_openapi::api::admissionregistration::v1::ValidatingWebhookConfiguration>(cur_item, client_clone).await {
+                                                                log::error!("Failed to delete validating webhook configuration: {}", err);
                                                             }
                                                         });
                                                     });
@@ -1524,58 +2229,62 @@ async fn main() {
                         }
                     }
                 },
-                Category::ClusterRoles => {
-                    let visible_cluster_roles = cluster_roles.lock().unwrap();
-                    lazy_start!(cluster_roles_started, cluster_roles_loading, client, cluster_roles, |c, s, l| Box::pin(watch_cluster_roles(c, s, l)));
+                Category::ValidatingAdmissionPolicies => {
+                    let visible_admission_policies = admission_policies.lock().unwrap();
+                    lazy_start!(admission_policies_started, admission_policies_loading, client, admission_policies, |c, s, l| Box::pin(watch_validating_admission_policies(c, s, l)));
                     ui.horizontal(|ui| {
-                        ui.heading(format!("Cluster roles - {}", visible_cluster_roles.len()));
-                        ui.separator();
-                        if ui.button(egui::RichText::new("➕ Add new").size(16.0).color(GREEN_BUTTON)).clicked() {
-                            new_resource_window.resource_type = ResourceType::ClusterRole;
-                            new_resource_window.content.clear();
-                            new_resource_window.show = true;
-                        }
+                        ui.heading(format!("ValidatingAdmissionPolicies - {}", visible_admission_policies.len()));
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_cluster_roles).hint_text("Filter cluster roles...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_cluster_roles = filter_cluster_roles.to_lowercase();
+                        ui.add(egui::TextEdit::singleline(&mut filter_admission_policies).hint_text("Filter policies...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+                        filter_admission_policies = filter_admission_policies.to_lowercase();
                         if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_cluster_roles.clear();
+                            filter_admission_policies.clear();
                         }
                     });
                     ui.separator();
-                    if cluster_roles_loading.load(Ordering::Relaxed) {
+                    if admission_policies_loading.load(Ordering::Relaxed) {
                         show_loading(ui);
                     } else {
-                        if visible_cluster_roles.is_empty() {
+                        if visible_admission_policies.is_empty() {
                             show_empty(ui);
                         } else {
-                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("cluster_roles_scroll").show(ui, |ui| {
-                                egui::Grid::new("cluster_roles_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
+                            egui::ScrollArea::vertical().auto_shrink(false).id_salt("admission_policies_scroll").show(ui, |ui| {
+                                egui::Grid::new("admission_policies_grid").striped(true).min_col_width(20.0).max_col_width(430.0).show(ui, |ui| {
                                     ui.label("Name");
+                                    ui.label("Failure policy");
+                                    ui.label("Validations");
+                                    ui.label("Param kind");
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
-                                    for item in visible_cluster_roles.iter().rev().take(200) {
+                                    for item in visible_admission_policies.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_cluster_roles.is_empty() || cur_item_object.contains(&filter_cluster_roles) {
+                                        if filter_admission_policies.is_empty() || cur_item_object.contains(&filter_admission_policies) {
                                             if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
-                                                let details = Arc::clone(&cluster_role_details);
-                                                cluster_role_details_window.show = true;
+                                                let details = Arc::clone(&admission_policy_details);
+                                                admission_policy_details_window.show = true;
                                                 tokio::spawn({
                                                     async move {
-                                                        if let Err(e) = get_cluster_role_details(client_clone, &name, details).await {
-                                                            log::error!("Cluster role details fetch failed: {:?}", e);
+                                                        if let Err(e) = get_validating_admission_policy_details(client_clone, &name, details).await {
+                                                            log::error!("ValidatingAdmissionPolicy details fetch failed: {:?}", e);
                                                         }
                                                     }
                                                 });
                                             }
+                                            ui.label(&item.failure_policy);
+                                            if item.type_warnings.is_empty() {
+                                                ui.label(item.validations.len().to_string());
+                                            } else {
+                                                ui.label(egui::RichText::new(format!("{} ({} type warnings)", item.validations.len(), item.type_warnings.len())).color(WARNING_COLOR));
+                                            }
+                                            ui.label(item.param_kind.clone().unwrap_or("-".to_string()));
                                             ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
                                             ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
                                                 ui.set_width(200.0);
                                                 if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
-                                                    crate::edit_cluster_yaml_for::<k8s_openapi::api::rbac::v1::ClusterRole>(
+                                                    crate::edit_cluster_yaml_for::<k8s_openapi::api::admissionregistration::v1::ValidatingAdmissionPolicy>(
                                                         item.name.clone(),
                                                         Arc::clone(&yaml_editor_window),
                                                         Arc::clone(&client)
@@ -1586,8 +2295,8 @@ async fn main() {
                                                     let client_clone = Arc::clone(&client);
                                                     confirmation_dialog.request(cur_item.clone(), None, move || {
                                                         tokio::spawn(async move {
-                                                            if let Err(err) = delete_cluster_role(client_clone, &cur_item.clone()).await {
-                                                                log::error!("Failed to delete cluster role: {}", err);
+                                                            if let Err(err) = delete_cluster_component_for::<k8s_openapi::api::admissionregistration::v1::ValidatingAdmissionPolicy>(cur_item, client_clone).await {
+                                                                log::error!("Failed to delete validating admission policy: {}", err);
                                                             }
                                                         });
                                                     });
@@ -2229,11 +2938,9 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_daemonsets).hint_text("Filter daemonsets...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_daemonsets = filter_daemonsets.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_daemonsets.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_daemonsets, "Filter daemonsets...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::DaemonSet, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if daemonsets_loading.load(Ordering::Relaxed) {
@@ -2252,10 +2959,11 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let daemonsets_query = ResourceQuery::parse(&filter_daemonsets);
                                     for item in visible_daemonsets.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_daemonsets.is_empty() || cur_item_object.contains(&filter_daemonsets) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if daemonsets_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&daemonset_details);
@@ -2417,11 +3125,9 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_replicasets).hint_text("Filter replicasets...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_replicasets = filter_replicasets.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_replicasets.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_replicasets, "Filter replicasets...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::ReplicaSet, &client, &mut confirmation_dialog, &bulk_report);
                         ui.separator();
                         let mut show_clear_button = false;
                         for i in &visible_replicasets {
@@ -2459,6 +3165,7 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let replicasets_query = ResourceQuery::parse(&filter_replicasets);
                                     for item in visible_replicasets.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
                                         let status = if item.ready == 0 {
@@ -2470,8 +3177,8 @@ async fn main() {
                                         } else {
                                             "Ready"
                                         };
-                                        if filter_replicasets.is_empty() || cur_item_object.contains(&filter_replicasets) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if replicasets_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&replicaset_details);
@@ -2537,6 +3244,20 @@ async fn main() {
                         }
                     }
                 },
+                Category::GatewayApi => {
+                    let discovered = Arc::clone(&gateway_api_discovery);
+                    lazy_start!(gateway_api_started, gateway_api_loading, client, gateway_api_objects, move |c, s, l| Box::pin(watch_gateway_api(c, s, l, discovered)));
+                    // Backend Service links
+                    lazy_start!(services_started, services_loading, client, services, |c, s, l| Box::pin(watch_services(c, s, l)));
+                    if gateway_api_loading.load(Ordering::Relaxed) {
+                        show_loading(ui);
+                    } else {
+                        let objects_list = gateway_api_objects.lock().unwrap();
+                        let services_list = services.lock().unwrap();
+                        let discovered = gateway_api_discovery.lock().unwrap();
+                        gateway_api_action = show_gateway_api(ui, &mut gateway_api_state, &objects_list, &discovered, &services_list, &yaml_editor_window, &client, &mut confirmation_dialog);
+                    }
+                },
                 Category::Ingresses => {
                     lazy_start!(ingresses_started, ingresses_loading, client, ingresses, |c, s, l| Box::pin(watch_ingresses(c, s, l)));
                     let ns = namespaces.lock().unwrap();
@@ -2572,11 +3293,7 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_ingresses).hint_text("Filter ingresses...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_ingresses = filter_ingresses.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_ingresses.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_ingresses, "Filter ingresses...", &mut app_config.saved_queries, &mut config_should_be_saved);
                     });
                     ui.separator();
                     if ingresses_loading.load(Ordering::Relaxed) {
@@ -2596,9 +3313,10 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let ingresses_query = ResourceQuery::parse(&filter_ingresses);
                                     for item in visible_ingresses.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_ingresses.is_empty() || cur_item_object.contains(&filter_ingresses) {
+                                        if ingresses_query.matches(item) {
                                             if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
@@ -2975,11 +3693,7 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_pvcs).hint_text("Filter pvcs...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_pvcs = filter_pvcs.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_pvcs.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_pvcs, "Filter pvcs...", &mut app_config.saved_queries, &mut config_should_be_saved);
                     });
                     ui.separator();
                     if pvcs_loading.load(Ordering::Relaxed) {
@@ -3027,9 +3741,10 @@ async fn main() {
                                         };
                                         if app_config.sort_preferences.pvcs_sort_asc { ord } else { ord.reverse() }
                                     });
+                                    let pvcs_query = ResourceQuery::parse(&filter_pvcs);
                                     for item in sorted_pvcs.iter() {
                                         let cur_item_object = &item.name;
-                                        if filter_pvcs.is_empty() || cur_item_object.contains(&filter_pvcs) {
+                                        if pvcs_query.matches(item) {
                                             if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
@@ -3090,18 +3805,14 @@ async fn main() {
                     }
                 },
                 Category::Endpoints => {
-                    lazy_start!(endpoints_started, endpoints_loading, client, endpoints, |c, s, l| Box::pin(watch_endpoints(c, s, l)));
+                    let generation = Arc::clone(&endpoint_slices_generation);
+                    lazy_start!(endpoints_started, endpoints_loading, client, endpoint_slices, move |c, s, l| Box::pin(watch_endpoint_slices(c, s, l, generation)));
                     let ns = namespaces.lock().unwrap();
                     let mut selected_ns = selected_namespace_clone.lock().unwrap();
-                    let visible_endpoints: Vec<_> = if let Some(ns) = selected_ns.as_ref() {
-                        endpoints.lock().unwrap()
-                            .iter()
-                            .filter(|p| p.namespace.as_deref() == Some(ns))
-                            .cloned()
-                            .collect()
-                    } else {
-                        endpoints.lock().unwrap().iter().cloned().collect()
-                    };
+                    let service_endpoints = service_endpoints_cache.get(&endpoint_slices, &endpoint_slices_generation);
+                    let visible_endpoints: Vec<_> = service_endpoints.iter()
+                        .filter(|e| selected_ns.is_none() || e.namespace == *selected_ns)
+                        .collect();
                     ui.horizontal(|ui| {
                         ui.heading(format!("Endpoints - {}", visible_endpoints.len()));
                         ui.separator();
@@ -3132,10 +3843,14 @@ async fn main() {
                         } else {
                             egui::ScrollArea::vertical().auto_shrink(false).id_salt("endpoints_scroll").show(ui, |ui| {
                                 egui::Grid::new("endpoints_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
-                                    ui.label("Name");
+                                    ui.label("Service");
                                     ui.label("Namespace");
-                                    ui.label("Addresses");
+                                    ui.label("Ready");
+                                    ui.label("Not ready");
+                                    ui.label("Terminating");
+                                    ui.label("Families");
                                     ui.label("Ports");
+                                    ui.label("Slices");
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
@@ -3159,37 +3874,28 @@ async fn main() {
                                             if ui.label(egui::RichText::new(item.namespace.clone().unwrap_or("".to_string())).color(NAMESPACE_COLUMN_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
                                                 *selected_ns = item.namespace.clone();
                                             }
-                                            //ui.label(format!("{}", &item.addresses));
-                                            ui.add(egui::Label::new(egui::RichText::new(item.addresses.to_string())).wrap());
-                                            ui.label(format!("{:?}", &item.ports));
-                                            ui.label(format_age(item.creation_timestamp.as_ref().unwrap()));
+                                            let ready = item.ready();
+                                            ui.label(egui::RichText::new(ready.to_string()).color(if ready == 0 { ERROR_MESSAGE_COLOR } else { GREEN_BUTTON }));
+                                            let not_ready = item.not_ready();
+                                            ui.label(egui::RichText::new(not_ready.to_string()).color(if not_ready > 0 { WARNING_COLOR } else { ui.visuals().text_color() }));
+                                            ui.label(item.terminating().to_string());
+                                            ui.label(item.address_types.iter().cloned().collect::<Vec<_>>().join(", "));
+                                            let ports = item.ports.iter().map(|p| p.describe()).collect::<Vec<_>>().join(", ");
+                                            ui.add(egui::Label::new(if ports.is_empty() { "-".to_string() } else { ports }).wrap());
+                                            ui.label(item.slices.len().to_string()).on_hover_text(item.slices.join("\n"));
+                                            ui.label(item.creation_timestamp.as_ref().map(format_age).unwrap_or_default());
                                             ui.menu_button(egui::RichText::new(ACTIONS_MENU_LABEL).size(ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
                                                 ui.set_width(200.0);
-                                                if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
-                                                    crate::edit_yaml_for::<k8s_openapi::api::core::v1::Endpoints>(
-                                                        item.name.clone(),
-                                                        item.namespace.clone().unwrap_or_else(|| "default".to_string()),
-                                                        Arc::clone(&yaml_editor_window),
-                                                        Arc::clone(&client)
-                                                    );
-                                                    ui.close_kind(egui::UiKind::Menu);
-                                                }
-                                                if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
-                                                    let cur_item = item.name.clone();
-                                                    let cur_ns = item.namespace.clone();
-                                                    let client_clone = Arc::clone(&client);
-                                                    confirmation_dialog.request(cur_item.clone(), cur_ns.clone(), move || {
-                                                        tokio::spawn(async move {
-                                                            if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::Endpoints>(
-                                                                cur_item.clone(),
-                                                                cur_ns.as_deref(),
-                                                                client_clone,
-                                                            ).await {
-                                                                log::error!("Failed to delete endpoints: {}", err);
-                                                            }
-                                                        });
-                                                    });
-                                                    ui.close_kind(egui::UiKind::Menu);
+                                                for slice in &item.slices {
+                                                    if ui.button(egui::RichText::new(format!("✏ Edit {}", slice)).size(16.0).color(GREEN_BUTTON)).clicked() {
+                                                        crate::edit_yaml_for::<k8s_openapi::api::discovery::v1::EndpointSlice>(
+                                                            slice.clone(),
+                                                            item.namespace.clone().unwrap_or_else(|| "default".to_string()),
+                                                            Arc::clone(&yaml_editor_window),
+                                                            Arc::clone(&client)
+                                                        );
+                                                        ui.close_kind(egui::UiKind::Menu);
+                                                    }
                                                 }
                                             });
                                             ui.end_row();
@@ -3228,11 +3934,9 @@ async fn main() {
                                 );
                             }
                         });
-                        ui.add(egui::TextEdit::singleline(&mut filter_jobs).hint_text("Filter jobs...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_jobs = filter_jobs.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_jobs.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_jobs, "Filter jobs...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::Job, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if jobs_loading.load(Ordering::Relaxed) {
@@ -3250,10 +3954,11 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let jobs_query = ResourceQuery::parse(&filter_jobs);
                                     for item in visible_jobs.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_jobs.is_empty() || cur_item_object.contains(&filter_jobs) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if jobs_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&job_details);
@@ -3345,11 +4050,9 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_services).hint_text("Filter services...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_services = filter_services.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_services.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_services, "Filter services...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::Service, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if services_loading.load(Ordering::Relaxed) {
@@ -3370,10 +4073,11 @@ async fn main() {
                                     ui.label("Ports");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let services_query = ResourceQuery::parse(&filter_services);
                                     for item in visible_services.iter().rev() {
                                         let cur_item_object = &item.name;
-                                        if filter_services.is_empty() || cur_item_object.contains(&filter_services) || item.svc_type.to_lowercase().contains(&filter_services) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if services_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&service_details);
@@ -3461,11 +4165,9 @@ async fn main() {
                                 );
                             }
                         });
-                        ui.add(egui::TextEdit::singleline(&mut filter_cronjobs).hint_text("Filter cronjobs...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_cronjobs = filter_cronjobs.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_cronjobs.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_cronjobs, "Filter cronjobs...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::CronJob, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if cronjobs_loading.load(Ordering::Relaxed) {
@@ -3485,10 +4187,11 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let cronjobs_query = ResourceQuery::parse(&filter_cronjobs);
                                     for item in visible_cronjobs.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_cronjobs.is_empty() || cur_item_object.contains(&filter_cronjobs) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if cronjobs_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&cronjob_details);
@@ -3599,11 +4302,9 @@ async fn main() {
                                 );
                             }
                         });
-                        ui.add(egui::TextEdit::singleline(&mut filter_statefulsets).hint_text("Filter statefulsets...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_statefulsets = filter_statefulsets.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_statefulsets.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_statefulsets, "Filter statefulsets...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::StatefulSet, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if statefulsets_loading.load(Ordering::Relaxed) {
@@ -3621,10 +4322,11 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let statefulsets_query = ResourceQuery::parse(&filter_statefulsets);
                                     for item in visible_statefulsets.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_statefulsets.is_empty() || cur_item_object.contains(&filter_statefulsets) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if statefulsets_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&statefulset_details);
@@ -3712,11 +4414,9 @@ async fn main() {
                     ui.horizontal(|ui| {
                         ui.heading(format!("Nodes - {}", nodes.lock().unwrap().len()));
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_nodes).hint_text("Filter nodes...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_nodes = filter_nodes.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_nodes.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_nodes, "Filter nodes...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::Node, &client, &mut confirmation_dialog, &bulk_report);
 
                         ui.separator();
                         if ui.button("💾 Export list of nodes").clicked()
@@ -3802,17 +4502,18 @@ async fn main() {
                                         };
                                         if app_config.sort_preferences.nodes_sort_asc { ord } else { ord.reverse() }
                                     });
+                                    let nodes_query = ResourceQuery::parse(&filter_nodes);
                                     for item in sorted_nodes.iter() {
                                         let cur_item_name = &item.name;
-                                        if filter_nodes.is_empty() || cur_item_name.contains(&filter_nodes) {
+                                        if nodes_query.matches(item) {
                                             app_state.nodes_list.push(cur_item_name.clone());
                                             if hl_item == *item.name {
                                                 ui.label(egui::RichText::new("⏵").color(SELECTED));
                                             } else {
                                                 ui.label("");
                                             }
-                                            let node_name_label = ui.label(egui::RichText::new(&item.name).color(egui::Color32::WHITE)).on_hover_cursor(CursorIcon::PointingHand);
-                                            if node_name_label.clicked() {
+                                            let (clicked, node_name_label) = bulk_name_label_response(ui, &mut bulk_selection, &item.name, None);
+                                            if clicked {
                                                 let name = cur_item_name.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&node_details);
@@ -4015,11 +4716,7 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_namespaces).hint_text("Filter namespaces...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_namespaces = filter_namespaces.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_namespaces.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_namespaces, "Filter namespaces...", &mut app_config.saved_queries, &mut config_should_be_saved);
                     });
                     ui.separator();
                     if namespaces_loading.load(Ordering::Relaxed) {
@@ -4067,9 +4764,9 @@ async fn main() {
                                         if app_config.sort_preferences.namespace_sort_asc { ord } else { ord.reverse() }
                                     });
 
+                                    let namespaces_query = ResourceQuery::parse(&filter_namespaces);
                                     for item in sorted_ns.iter_mut() {
-                                        let cur_item_name = &item.name;
-                                        if filter_namespaces.is_empty() || cur_item_name.contains(&filter_namespaces) {
+                                        if namespaces_query.matches(item) {
                                             if selected_namespace_clone.lock().unwrap().is_some() && selected_namespace_clone.lock().unwrap().as_ref().unwrap() == &item.name {
                                                 ui.colored_label(Color32::LIGHT_BLUE,"⏵");
                                             } else {
@@ -4173,11 +4870,9 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_pods).hint_text("Filter pods...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_pods = filter_pods.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_pods.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_pods, "Filter pods...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::Pod, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if pods_loading.load(Ordering::Relaxed) {
@@ -4228,25 +4923,17 @@ async fn main() {
                                         };
                                         if app_config.sort_preferences.pods_sort_asc { ord } else { ord.reverse() }
                                     });
+                                    let pods_query = ResourceQuery::parse(&filter_pods);
                                     for item in sorted_pods.iter() {
                                         let cur_item_name = &item.name;
-                                        let running_on_node: &str;
-                                        if item.node_name.as_ref().is_some() {
-                                            running_on_node = item.node_name.as_ref().unwrap();
-                                        } else {
-                                            running_on_node = "";
-                                        }
-                                        if filter_pods.is_empty() || cur_item_name.contains(&filter_pods) || running_on_node.contains(&filter_pods) {
+                                        if pods_query.matches(item) {
                                             if hl_item == (item.name.clone() + &item.namespace.clone().unwrap_or("".to_string())) {
                                                 ui.label(egui::RichText::new("⏵").color(SELECTED));
                                             } else {
                                                 ui.label("");
                                             }
-                                            let pod_name_label = ui.label(egui::RichText::new(&item.name)
-                                                .color(ITEM_NAME_COLOR))
-                                                .on_hover_cursor(CursorIcon::PointingHand)
-                                                .on_hover_text(&item.name);
-                                            if pod_name_label.clicked() {
+                                            let (clicked, pod_name_label) = bulk_name_label_response(ui, &mut bulk_selection, &item.name, item.namespace.as_deref());
+                                            if clicked {
                                                 let name = cur_item_name.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&pod_details);
@@ -4465,11 +5152,9 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_deployments).hint_text("Filter deployments...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_deployments = filter_deployments.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_deployments.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_deployments, "Filter deployments...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::Deployment, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if deployments_loading.load(Ordering::Relaxed) {
@@ -4489,10 +5174,11 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let deployments_query = ResourceQuery::parse(&filter_deployments);
                                     for item in visible_deployments.iter().rev() {
                                         let cur_item_object = &item.name;
-                                        if filter_deployments.is_empty() || cur_item_object.contains(&filter_deployments) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if deployments_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&deployment_details);
@@ -4611,11 +5297,9 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_secrets).hint_text("Filter secrets...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_secrets = filter_secrets.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_secrets.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_secrets, "Filter secrets...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::Secret, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if secrets_loading.load(Ordering::Relaxed) {
@@ -4632,10 +5316,11 @@ async fn main() {
                                     ui.label("Age");
                                     ui.label("Actions");
                                     ui.end_row();
+                                    let secrets_query = ResourceQuery::parse(&filter_secrets);
                                     for item in visible_secrets.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_secrets.is_empty() || cur_item_object.contains(&filter_secrets) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if secrets_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&secret_details);
@@ -4725,11 +5410,9 @@ async fn main() {
                             new_resource_window.show = true;
                         }
                         ui.separator();
-                        ui.add(egui::TextEdit::singleline(&mut filter_configmaps).hint_text("Filter configmaps...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
-                        filter_configmaps = filter_configmaps.to_lowercase();
-                        if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clean filter").clicked() {
-                            filter_configmaps.clear();
-                        }
+                        query_filter_bar(ui, &mut filter_configmaps, "Filter configmaps...", &mut app_config.saved_queries, &mut config_should_be_saved);
+                        ui.separator();
+                        show_bulk_toolbar(ui, &mut bulk_selection, BulkKind::ConfigMap, &client, &mut confirmation_dialog, &bulk_report);
                     });
                     ui.separator();
                     if configmaps_loading.load(Ordering::Relaxed) {
@@ -4748,10 +5431,11 @@ async fn main() {
                                     ui.label("Actions");
                                     ui.end_row();
 
+                                    let configmaps_query = ResourceQuery::parse(&filter_configmaps);
                                     for item in visible_configmaps.iter().rev().take(200) {
                                         let cur_item_object = &item.name;
-                                        if filter_configmaps.is_empty() || cur_item_object.contains(&filter_configmaps) {
-                                            if ui.label(egui::RichText::new(&item.name).color(ITEM_NAME_COLOR)).on_hover_cursor(CursorIcon::PointingHand).clicked() {
+                                        if configmaps_query.matches(item) {
+                                            if bulk_name_label(ui, &mut bulk_selection, &item.name, item.namespace.as_deref()) {
                                                 let name = cur_item_object.clone();
                                                 let client_clone = Arc::clone(&client);
                                                 let details = Arc::clone(&configmap_details);
@@ -4814,15 +5498,18 @@ async fn main() {
                     lazy_start!(events_started, events_loading, client, events, |c, s, l| Box::pin(watch_events(c, s, l)));
                     let ns = namespaces.lock().unwrap();
                     let mut selected_ns = selected_namespace_clone.lock().unwrap();
-                    let visible_events: Vec<_> = if let Some(ns) = selected_ns.as_ref() {
+                    let events_now = k8s_openapi::jiff::Timestamp::now();
+                    let mut visible_events: Vec<_> = if let Some(ns) = selected_ns.as_ref() {
                         events.lock().unwrap()
                             .iter()
                             .filter(|p| p.namespace.as_deref() == Some(ns))
+                            .filter(|p| event_timeline.range.contains(events_now, p.last_seen))
                             .cloned()
                             .collect()
                     } else {
-                        events.lock().unwrap().iter().cloned().collect()
+                        events.lock().unwrap().iter().filter(|p| event_timeline.range.contains(events_now, p.last_seen)).cloned().collect()
                     };
+                    visible_events.sort_by_key(|e| e.last_seen);
                     ui.horizontal(|ui| {
                         ui.heading(format!("Events - {}", visible_events.len()));
                         ui.separator();
@@ -4846,6 +5533,15 @@ async fn main() {
                         }
                         ui.separator();
                         ui.checkbox(&mut filter_warnings, "Warnings only");
+                        ui.separator();
+                        egui::ComboBox::from_id_salt("events_range_combo").selected_text(event_timeline.range.label()).width(150.0).show_ui(ui, |ui| {
+                            for range in EventTimeRange::ALL {
+                                ui.selectable_value(&mut event_timeline.range, range, range.label());
+                            }
+                        });
+                        ui.separator();
+                        ui.selectable_value(&mut event_timeline.view, EventsView::List, "☰ List");
+                        ui.selectable_value(&mut event_timeline.view, EventsView::Timeline, "🕓 Timeline");
                     });
                     ui.separator();
                     if events_loading.load(Ordering::Relaxed) {
@@ -4853,6 +5549,11 @@ async fn main() {
                     } else {
                         if visible_events.is_empty() {
                             show_empty(ui);
+                        } else if event_timeline.view == EventsView::Timeline {
+                            let groups = group_events(&visible_events, event_timeline.range, events_now, &filter_events, filter_warnings);
+                            let pods_snapshot = pods.lock().unwrap().clone();
+                            let nodes_snapshot = nodes.lock().unwrap().clone();
+                            show_event_timeline(ui, &mut event_timeline, &groups, &pods_snapshot, &nodes_snapshot, events_now);
                         } else {
                             egui::ScrollArea::vertical().auto_shrink(false).id_salt("events_scroll").show(ui, |ui| {
                                 egui::Grid::new("events_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
@@ -4957,10 +5658,193 @@ async fn main() {
                 show_yaml_editor(&ctx, &mut editor, &mut decoder_window, client_clone);
             }
 
+        // Command palette
+        if open_command_palette {
+            macro_rules! palette_list {
+                ($kind:expr, $category:expr, $list:expr) => {
+                    command_palette.add_resources($kind, $category, $list.lock().unwrap().iter().map(|i| (i.name.as_str(), i.namespace.as_deref())));
+                };
+                ($kind:expr, $category:expr, $list:expr, cluster) => {
+                    command_palette.add_resources($kind, $category, $list.lock().unwrap().iter().map(|i| (i.name.as_str(), None)));
+                };
+            }
+
+            command_palette.open();
+            for ns in namespaces.lock().unwrap().iter() {
+                command_palette.add_entry("Namespace", ns.name.clone(), None, PaletteTarget::Namespace(Some(ns.name.clone())));
+            }
+            palette_list!("Node", Category::Nodes, nodes, cluster);
+            palette_list!("Pod", Category::Pods, pods);
+            palette_list!("Deployment", Category::Deployments, deployments);
+            palette_list!("StatefulSet", Category::StatefulSets, statefulsets);
+            palette_list!("DaemonSet", Category::DaemonSets, daemonsets);
+            palette_list!("ReplicaSet", Category::ReplicaSets, replicasets);
+            palette_list!("Job", Category::Jobs, jobs);
+            palette_list!("CronJob", Category::CronJobs, cronjobs);
+            palette_list!("HPA", Category::HorizontalPodAutoscalers, hpas);
+            palette_list!("ResourceQuota", Category::ResourceQuotas, resource_quotas);
+            palette_list!("LimitRange", Category::LimitRanges, limit_ranges);
+            palette_list!("MutatingWebhook", Category::MutatingWebhooks, mutating_webhooks, cluster);
+            palette_list!("ValidatingWebhook", Category::ValidatingWebhooks, validating_webhooks, cluster);
+            palette_list!("AdmissionPolicy", Category::ValidatingAdmissionPolicies, admission_policies, cluster);
+            palette_list!("ConfigMap", Category::ConfigMaps, configmaps);
+            palette_list!("Secret", Category::Secrets, secrets);
+            palette_list!("PDB", Category::PodDisruptionBudgets, pdbs);
+            palette_list!("Lease", Category::Leases, leases);
+            palette_list!("Service", Category::Services, services);
+            let service_endpoints = service_endpoints_cache.get(&endpoint_slices, &endpoint_slices_generation);
+            command_palette.add_resources("Endpoints", Category::Endpoints, service_endpoints.iter().map(|i| (i.name.as_str(), i.namespace.as_deref())));
+            palette_list!("Ingress", Category::Ingresses, ingresses);
+            for object in gateway_api_objects.lock().unwrap().iter() {
+                command_palette.add_entry(object.kind.kind(), object.name.clone(), object.namespace.clone(), PaletteTarget::GatewayApi {
+                    kind: object.kind,
+                    name: object.name.clone(),
+                    namespace: object.namespace.clone(),
+                });
+            }
+            palette_list!("NetworkPolicy", Category::NetworkPolicies, network_policies);
+            palette_list!("PVC", Category::PersistentVolumeClaims, pvcs);
+            palette_list!("PV", Category::PersistentVolumes, pvs, cluster);
+            palette_list!("StorageClass", Category::StorageClasses, storage_classes, cluster);
+            palette_list!("CSIDriver", Category::CSIDrivers, csi_drivers, cluster);
+            palette_list!("ServiceAccount", Category::ServiceAccounts, service_accounts);
+            palette_list!("Role", Category::Roles, roles);
+            palette_list!("ClusterRole", Category::ClusterRoles, cluster_roles, cluster);
+            palette_list!("RoleBinding", Category::RoleBindings, rbs);
+            palette_list!("ClusterRoleBinding", Category::ClusterRoleBindings, cluster_rbs, cluster);
+            palette_list!("CRD", Category::CustomResourcesDefinitions, crds, cluster);
+            command_palette.add_resources("Helm release", Category::HelmReleases, helm_releases.lock().unwrap().iter().map(|i| (i.release_name.as_str(), i.namespace.as_deref())));
+            // Lists are watched lazily from their category, tell which ones can't match yet
+            for (kind, started) in [
+                ("CronJob", &cronjobs_started), ("Job", &jobs_started), ("HPA", &hpas_started),
+                ("ResourceQuota", &resource_quotas_started), ("LimitRange", &limit_ranges_started),
+                ("MutatingWebhook", &mutating_webhooks_started), ("ValidatingWebhook", &validating_webhooks_started),
+                ("AdmissionPolicy", &admission_policies_started), ("ConfigMap", &configmaps_started),
+                ("Secret", &secrets_started), ("PDB", &pdbs_started), ("Lease", &leases_started),
+                ("Service", &services_started), ("Endpoints", &endpoints_started), ("Ingress", &ingresses_started),
+                ("Gateway API", &gateway_api_started), ("NetworkPolicy", &network_policies_started),
+                ("PVC", &pvcs_started), ("PV", &pvs_started), ("StorageClass", &storage_classes_started),
+                ("CSIDriver", &csi_drivers_started), ("ServiceAccount", &service_accounts_started),
+                ("Role", &roles_started), ("ClusterRole", &cluster_roles_started), ("RoleBinding", &rbs_started),
+                ("ClusterRoleBinding", &cluster_rbs_started), ("CRD", &crds_started), ("Helm release", &helm_releases_started),
+            ] {
+                if !started.load(Ordering::Relaxed) {
+                    command_palette.add_not_loaded(kind);
+                }
+            }
+        }
+
+        if command_palette.show
+            && let Some(target) = show_command_palette(&ctx, &mut command_palette) {
+                match target {
+                    PaletteTarget::Action(action) => match action {
+                        PaletteAction::Base64Decoder => decoder_window.show = true,
+                        PaletteAction::IpCalculator => ipcalculator_window.show = true,
+                        PaletteAction::YamlJsonConverter => yaml2json_window.show = true,
+                        PaletteAction::ResourcesConverter => res_converter_window.show = true,
+                        PaletteAction::UuidGenerator => uuid_gen_window.show = true,
+                        PaletteAction::JwtDecoder => jwt_decoder_window.show = true,
+                        PaletteAction::AiConsultant => ai_window.show = true,
+                        PaletteAction::NewResource => {
+                            new_resource_window.resource_type = ResourceType::Blank;
+                            new_resource_window.content.clear();
+                            new_resource_window.show = true;
+                        }
+                    },
+                    PaletteTarget::Category(category) => {
+                        *selected_category_ui.lock().unwrap() = category;
+                    }
+                    PaletteTarget::Namespace(ns) => {
+                        *selected_namespace_clone.lock().unwrap() = ns;
+                    }
+                    PaletteTarget::GatewayApi { kind, name, namespace } => {
+                        *selected_category_ui.lock().unwrap() = Category::GatewayApi;
+                        if gateway_api_objects.lock().unwrap().iter().any(|o| o.kind == kind && o.name == name && o.namespace == namespace) {
+                            gateway_api_state.view = kind;
+                            gateway_api_action = Some(GatewayApiAction::OpenDetails(kind, name, namespace));
+                        }
+                    }
+                    PaletteTarget::Resource { category, name, namespace } => {
+                        macro_rules! open_details {
+                            ($window:ident, $details:ident, $fetch:ident, $what:expr) => {{
+                                $window.show = true;
+                                let client_clone = Arc::clone(&client);
+                                let details = Arc::clone(&$details);
+                                tokio::spawn(async move {
+                                    if let Err(e) = $fetch(client_clone, &name, details).await {
+                                        log::error!("{} details fetch failed: {:?}", $what, e);
+                                    }
+                                });
+                            }};
+                            ($window:ident, $details:ident, $fetch:ident, $what:expr, namespaced) => {{
+                                $window.show = true;
+                                let client_clone = Arc::clone(&client);
+                                let details = Arc::clone(&$details);
+                                tokio::spawn(async move {
+                                    if let Err(e) = $fetch(client_clone, &name, namespace, details).await {
+                                        log::error!("{} details fetch failed: {:?}", $what, e);
+                                    }
+                                });
+                            }};
+                        }
+
+                        *selected_category_ui.lock().unwrap() = category.clone();
+                        match category {
+                            Category::Nodes => open_details!(node_details_window, node_details, get_node_details, "Node"),
+                            Category::Pods => open_details!(pod_details_window, pod_details, get_pod_details, "Pod", namespaced),
+                            Category::Deployments => open_details!(deployment_details_window, deployment_details, get_deployment_details, "Deployment", namespaced),
+                            Category::StatefulSets => open_details!(statefulset_details_window, statefulset_details, get_statefulset_details, "StatefulSet", namespaced),
+                            Category::DaemonSets => open_details!(daemonset_details_window, daemonset_details, get_daemonset_details, "DaemonSet", namespaced),
+                            Category::ReplicaSets => open_details!(replicaset_details_window, replicaset_details, get_replicaset_details, "ReplicaSet", namespaced),
+                            Category::Jobs => open_details!(job_details_window, job_details, get_job_details, "Job", namespaced),
+                            Category::CronJobs => open_details!(cronjob_details_window, cronjob_details, get_cronjob_details, "CronJob", namespaced),
+                            Category::ConfigMaps => open_details!(configmap_details_window, configmap_details, get_configmap_details, "ConfigMap", namespaced),
+                            Category::Secrets => open_details!(secret_details_window, secret_details, get_secret_details, "Secret", namespaced),
+                            Category::Leases => open_details!(lease_details_window, lease_details, get_lease_details, "Lease", namespaced),
+                            Category::HorizontalPodAutoscalers => open_details!(hpa_details_window, hpa_details, get_hpa_details, "HPA", namespaced),
+                            Category::ResourceQuotas => open_details!(resource_quota_details_window, resource_quota_details, get_resource_quota_details, "ResourceQuota", namespaced),
+                            Category::LimitRanges => open_details!(limit_range_details_window, limit_range_details, get_limit_range_details, "LimitRange", namespaced),
+                            Category::MutatingWebhooks => open_details!(mutating_webhook_details_window, mutating_webhook_details, get_mutating_webhook_details, "MutatingWebhookConfiguration"),
+                            Category::ValidatingWebhooks => open_details!(validating_webhook_details_window, validating_webhook_details, get_validating_webhook_details, "ValidatingWebhookConfiguration"),
+                            Category::ValidatingAdmissionPolicies => open_details!(admission_policy_details_window, admission_policy_details, get_validating_admission_policy_details, "ValidatingAdmissionPolicy"),
+                            Category::Services => open_details!(service_details_window, service_details, get_service_details, "Service", namespaced),
+                            Category::Endpoints => open_details!(endpoint_details_window, endpoint_details, get_endpoint_details, "Endpoint", namespaced),
+                            Category::Ingresses => open_details!(ingress_details_window, ingress_details, get_ingress_details, "Ingress", namespaced),
+                            Category::PersistentVolumeClaims => open_details!(pvc_details_window, pvc_details, get_pvc_details, "PVC", namespaced),
+                            Category::PersistentVolumes => open_details!(pv_details_window, pv_details, get_pv_details, "PV"),
+                            Category::StorageClasses => open_details!(sc_details_window, sc_details, get_sc_details, "StorageClass"),
+                            Category::ServiceAccounts => open_details!(service_account_details_window, service_account_details, get_service_account_details, "Service account", namespaced),
+                            Category::Roles => open_details!(role_details_window, role_details, get_role_details, "Role", namespaced),
+                            Category::ClusterRoles => open_details!(cluster_role_details_window, cluster_role_details, get_cluster_role_details, "Cluster role"),
+                            Category::RoleBindings => open_details!(rb_details_window, rb_details, get_rb_details, "Role binding", namespaced),
+                            Category::ClusterRoleBindings => open_details!(cluster_rb_details_window, cluster_rb_details, get_cluster_rb_details, "Cluster role binding"),
+                            Category::CustomResourcesDefinitions => open_details!(crd_details_window, crd_details, get_crd_details, "CRD"),
+                            // No details window for these kinds, narrow their list down instead
+                            Category::PodDisruptionBudgets => filter_pdbs = name,
+                            Category::NetworkPolicies => filter_network_policies = name,
+                            Category::CSIDrivers => filter_csi_drivers = name,
+                            Category::HelmReleases => filter_helm_releases = name,
+                            _ => {}
+                        }
+                    }
+                }
+            }
+
+        // Results of bulk operations
+        if bulk_report.lock().unwrap().show {
+            show_bulk_report_window(&ctx, Arc::clone(&bulk_report));
+        }
+
         // New resource creation window
         if new_resource_window.show {
+            // Storage class parameters are picked from the watcher list
+            if !storage_classes_started.load(Ordering::Relaxed) {
+                storage_classes_started.store(true, Ordering::Relaxed);
+                storage_classes_loading.store(true, Ordering::Relaxed);
+                spawn_watcher(Arc::clone(&client), Arc::clone(&storage_classes), Arc::clone(&storage_classes_loading), |c, s, l| Box::pin(watch_storage_classes(c, s, l)));
+            }
             let client_clone = Arc::clone(&client);
-            show_new_resource_window(&ctx, &mut new_resource_window, client_clone);
+            show_new_resource_window(&ctx, &mut new_resource_window, client_clone, Arc::clone(&namespaces), Arc::clone(&storage_classes));
         }
 
         // Log parser window
@@ -4983,7 +5867,7 @@ async fn main() {
             let pods_clone = Arc::clone(&pods);
             let details = Arc::clone(&pod_details);
             let client_clone = Arc::clone(&client);
-            show_node_details_window(&ctx, &mut node_details_window, node_details_clone, nodes_clone, pods_clone, &mut pod_details_window, details, client_clone);
+            show_node_details_window(&ctx, &mut node_details_window, node_details_clone, nodes_clone, pods_clone, &mut pod_details_window, details, &mut debug_session_window, client_clone);
         }
 
         // Pod details window
@@ -4994,7 +5878,13 @@ async fn main() {
             let log_window_clone = Arc::clone(&log_window);
             let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
             let client_clone = Arc::clone(&client);
-            show_pod_details_window(&ctx, &mut pod_details_window, pod_details_clone, pods_clone, log_window_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+            show_pod_details_window(&ctx, &mut pod_details_window, pod_details_clone, pods_clone, log_window_clone, yaml_editor_window_clone, &mut debug_session_window, client_clone, &mut confirmation_dialog);
+        }
+
+        // Debug session window
+        if debug_session_window.show {
+            let client_clone = Arc::clone(&client);
+            show_debug_session_window(&ctx, &mut debug_session_window, client_clone);
         }
 
         // Deployment details window
@@ -5042,13 +5932,65 @@ async fn main() {
             show_lease_details_window(&ctx, &mut lease_details_window, lease_details_clone, leases_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
         }
 
+        // HPA details window
+        if hpa_details_window.show {
+            let hpa_details_clone = Arc::clone(&hpa_details);
+            let hpas_clone = Arc::clone(&hpas);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            show_hpa_details_window(&ctx, &mut hpa_details_window, hpa_details_clone, hpas_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+        }
+
+        // ResourceQuota details window
+        if resource_quota_details_window.show {
+            let resource_quota_details_clone = Arc::clone(&resource_quota_details);
+            let resource_quotas_clone = Arc::clone(&resource_quotas);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            show_resource_quota_details_window(&ctx, &mut resource_quota_details_window, resource_quota_details_clone, resource_quotas_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+        }
+
+        // LimitRange details window
+        if limit_range_details_window.show {
+            let limit_range_details_clone = Arc::clone(&limit_range_details);
+            let limit_ranges_clone = Arc::clone(&limit_ranges);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            show_limit_range_details_window(&ctx, &mut limit_range_details_window, limit_range_details_clone, limit_ranges_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+        }
+
+        // Webhook configuration details windows
+        if mutating_webhook_details_window.show {
+            let mutating_webhook_details_clone = Arc::clone(&mutating_webhook_details);
+            let mutating_webhooks_clone = Arc::clone(&mutating_webhooks);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            show_webhook_configuration_details_window(&ctx, &mut mutating_webhook_details_window, mutating_webhook_details_clone, mutating_webhooks_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+        }
+        if validating_webhook_details_window.show {
+            let validating_webhook_details_clone = Arc::clone(&validating_webhook_details);
+            let validating_webhooks_clone = Arc::clone(&validating_webhooks);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            show_webhook_configuration_details_window(&ctx, &mut validating_webhook_details_window, validating_webhook_details_clone, validating_webhooks_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+        }
+
+        // ValidatingAdmissionPolicy details window
+        if admission_policy_details_window.show {
+            let admission_policy_details_clone = Arc::clone(&admission_policy_details);
+            let admission_policies_clone = Arc::clone(&admission_policies);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            show_validating_admission_policy_details_window(&ctx, &mut admission_policy_details_window, admission_policy_details_clone, admission_policies_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+        }
+
         // Endpoint details window
         if endpoint_details_window.show {
             let endpoint_details_clone = Arc::clone(&endpoint_details);
-            let endpoints_clone = Arc::clone(&endpoints);
+            let service_endpoints = service_endpoints_cache.get(&endpoint_slices, &endpoint_slices_generation);
             let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
             let client_clone = Arc::clone(&client);
-            show_endpoint_details_window(&ctx, &mut endpoint_details_window, endpoint_details_clone, endpoints_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+            show_endpoint_details_window(&ctx, &mut endpoint_details_window, endpoint_details_clone, &service_endpoints, yaml_editor_window_clone, client_clone);
         }
 
         // Ingress details window
@@ -5060,13 +6002,41 @@ async fn main() {
             show_ingress_details_window(&ctx, &mut ingress_details_window, ingress_details_clone, ingresses_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
         }
 
+        // Gateway API details window
+        if gateway_api_details_window.show {
+            let gateway_api_objects_clone = Arc::clone(&gateway_api_objects);
+            let services_clone = Arc::clone(&services);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            if let Some(action) = show_gateway_api_details_window(&ctx, &mut gateway_api_details_window, gateway_api_objects_clone, services_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog) {
+                gateway_api_action = Some(action);
+            }
+        }
+        match gateway_api_action {
+            Some(GatewayApiAction::OpenDetails(kind, name, namespace)) => {
+                gateway_api_details_window.selected = Some((kind, name, namespace));
+                gateway_api_details_window.show = true;
+            }
+            Some(GatewayApiAction::OpenService(name, namespace)) => {
+                service_details_window.show = true;
+                let client_clone = Arc::clone(&client);
+                let details = Arc::clone(&service_details);
+                tokio::spawn(async move {
+                    if let Err(e) = get_service_details(client_clone, &name, Some(namespace), details).await {
+                        log::error!("Service details fetch failed: {:?}", e);
+                    }
+                });
+            }
+            None => {}
+        }
+
         // Service account details window
         if service_account_details_window.show {
             let service_account_details_clone = Arc::clone(&service_account_details);
             let service_accounts_clone = Arc::clone(&service_accounts);
             let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
             let client_clone = Arc::clone(&client);
-            show_service_account_details_window(&ctx, &mut service_account_details_window, service_account_details_clone, service_accounts_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+            show_service_account_details_window(&ctx, &mut service_account_details_window, service_account_details_clone, service_accounts_clone, yaml_editor_window_clone, ui_kubeconfig.as_deref(), ui_context.as_deref(), client_clone, &mut confirmation_dialog);
         }
 
         // Role details window
@@ -5123,6 +6093,15 @@ async fn main() {
             show_crd_details_window(&ctx, &mut crd_details_window, crd_details_clone, crds_clone, &mut confirmation_dialog, client_clone, yaml_editor_window_clone);
         }
 
+        // CR instance details window
+        if cr_instance_details_window.show {
+            let cr_instances_clone = Arc::clone(&cr_instances);
+            let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
+            let client_clone = Arc::clone(&client);
+            let cr_instance_details_clone = Arc::clone(&cr_instance_details);
+            show_cr_instance_details_window(&ctx, &mut cr_instance_details_window, cr_instance_details_clone, cr_instances_clone, &cr_views, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+        }
+
         // DaemonSet details window
         if daemonset_details_window.show {
             let daemonset_details_clone = Arc::clone(&daemonset_details);
@@ -5156,9 +6135,11 @@ async fn main() {
         if cronjob_details_window.show {
             let cronjob_details_clone = Arc::clone(&cronjob_details);
             let cronjobs_clone = Arc::clone(&cronjobs);
+            let pods_clone = Arc::clone(&pods);
+            let log_window_clone = Arc::clone(&log_window);
             let yaml_editor_window_clone = Arc::clone(&yaml_editor_window);
             let client_clone = Arc::clone(&client);
-            show_cronjob_details_window(&ctx, &mut cronjob_details_window, cronjob_details_clone, cronjobs_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
+            show_cronjob_details_window(&ctx, &mut cronjob_details_window, cronjob_details_clone, cronjobs_clone, pods_clone, log_window_clone, yaml_editor_window_clone, client_clone, &mut confirmation_dialog);
         }
 
         // StatefulSet details window
@@ -5190,8 +6171,14 @@ async fn main() {
 
         // Scale window
         if scale_window.show {
+            // Needed to warn about workloads an HPA controls
+            if !hpas_started.load(Ordering::Relaxed) {
+                hpas_started.store(true, Ordering::Relaxed);
+                hpas_loading.store(true, Ordering::Relaxed);
+                spawn_watcher(Arc::clone(&client), Arc::clone(&hpas), Arc::clone(&hpas_loading), |c, s, l| Box::pin(watch_hpas(c, s, l)));
+            }
             let client_clone = Arc::clone(&client);
-            show_scale_window(&ctx, &mut scale_window, client_clone);
+            show_scale_window(&ctx, &mut scale_window, Arc::clone(&hpas), client_clone);
         }
 
         // Confirmation dialog
@@ -5219,6 +6206,7 @@ async fn main() {
                 app_config.ai_settings.amazon_bedrock_model_id.clone(),
                 app_config.ai_settings.amazon_bedrock_region.clone(),
                 app_config.ai_settings.mcp_server_url.clone(),
+                app_config.saved_queries.clone(),
             );
             config_should_be_saved = false;
         }
@@ -5226,17 +6214,80 @@ async fn main() {
         ctx.request_repaint();
     })
     .unwrap();
+
+    if let Err(e) = delete_session_debug_pods(debug_cleanup_client).await {
+        log::error!("Failed to delete node debug pods: {}", e);
+    }
 }
 
 struct CliArgs {
     kubeconfig: Option<String>,
     context: Option<String>,
+    list: Option<String>,
+    query: Option<String>,
+    impersonate_user: Option<String>,
+    impersonate_groups: Vec<String>,
     show_help: bool,
 }
 
+/// Watcher kind whose denied state is shown above a category, the same names the watchers record
+fn watched_kind(category: &Category) -> Option<&'static str> {
+    match category {
+        Category::Nodes => Some("Nodes"),
+        Category::Secrets => Some("Secret"),
+        Category::Namespaces => Some("Namespace"),
+        Category::Pods => Some("Pod"),
+        Category::Deployments => Some("Deployment"),
+        Category::Events => Some("Event"),
+        Category::ConfigMaps => Some("ConfigMap"),
+        Category::StatefulSets => Some("StatefulSet"),
+        Category::ReplicaSets => Some("ReplicaSet"),
+        Category::Jobs => Some("Job"),
+        Category::CronJobs => Some("CronJob"),
+        Category::HorizontalPodAutoscalers => Some("HPA"),
+        Category::Services => Some("Service"),
+        Category::Leases => Some("Lease"),
+        Category::ResourceQuotas => Some("ResourceQuota"),
+        Category::LimitRanges => Some("LimitRange"),
+        Category::Endpoints => Some("EndpointSlice"),
+        Category::Ingresses => Some("Ingress"),
+        Category::GatewayApi => Some("Gateway"),
+        Category::PersistentVolumeClaims => Some("PVC"),
+        Category::PersistentVolumes => Some("PV"),
+        Category::StorageClasses => Some("StorageClass"),
+        Category::CSIDrivers => Some("CSIDriver"),
+        Category::DaemonSets => Some("DaemonSet"),
+        Category::PodDisruptionBudgets => Some("PDB"),
+        Category::NetworkPolicies => Some("NetworkPolicy"),
+        Category::CustomResourcesDefinitions => Some("CRDs"),
+        Category::Roles => Some("Role"),
+        Category::ServiceAccounts => Some("ServiceAccount"),
+        Category::ClusterRoles => Some("ClusterRole"),
+        Category::ClusterRoleBindings => Some("ClusterRoleBinding"),
+        Category::RoleBindings => Some("RoleBinding"),
+        Category::MutatingWebhooks => Some("MutatingWebhookConfiguration"),
+        Category::ValidatingWebhooks => Some("ValidatingWebhookConfiguration"),
+        Category::ValidatingAdmissionPolicies => Some("ValidatingAdmissionPolicy"),
+        _ => None,
+    }
+}
+
+impl CliArgs {
+    fn impersonation(&self) -> Option<Impersonation> {
+        self.impersonate_user.as_ref().map(|user| Impersonation {
+            user: user.clone(),
+            groups: self.impersonate_groups.clone(),
+        })
+    }
+}
+
 fn parse_cli_args(args: &[String]) -> CliArgs {
     let mut kubeconfig = None;
     let mut context = None;
+    let mut list = None;
+    let mut query = None;
+    let mut impersonate_user = None;
+    let mut impersonate_groups = Vec::new();
     let mut show_help = false;
 
     let mut i = 1;
@@ -5250,6 +6301,24 @@ fn parse_cli_args(args: &[String]) -> CliArgs {
                 i += 1;
                 context = args.get(i).cloned();
             }
+            "--list" | "-l" => {
+                i += 1;
+                list = args.get(i).cloned();
+            }
+            "--query" | "-q" => {
+                i += 1;
+                query = args.get(i).cloned();
+            }
+            "--as" => {
+                i += 1;
+                impersonate_user = args.get(i).cloned();
+            }
+            "--as-group" => {
+                i += 1;
+                if let Some(group) = args.get(i) {
+                    impersonate_groups.push(group.clone());
+                }
+            }
             "--help" | "-h" => {
                 show_help = true;
             }
@@ -5258,7 +6327,7 @@ fn parse_cli_args(args: &[String]) -> CliArgs {
         i += 1;
     }
 
-    CliArgs { kubeconfig, context, show_help }
+    CliArgs { kubeconfig, context, list, query, impersonate_user, impersonate_groups, show_help }
 }
 
 fn print_help() {
@@ -5266,9 +6335,16 @@ fn print_help() {
     println!();
     println!("USAGE:");
     println!("    rustlens [OPTIONS]");
+    println!("    rustlens [OPTIONS] --list <KIND> [--query <QUERY>]");
     println!();
     println!("OPTIONS:");
     println!("    -k, --kubeconfig <PATH>    Path to kubeconfig file (default: ~/.kube/config)");
     println!("    -c, --context <NAME>       Kubernetes context to use (default: current-context)");
+    println!("    -l, --list <KIND>          Print matching resources and exit (pods, deployments, statefulsets,");
+    println!("                               daemonsets, replicasets, jobs, cronjobs, services, configmaps,");
+    println!("                               secrets, ingresses, pvcs, nodes, namespaces)");
+    println!("    -q, --query <QUERY>        Label/field query used with --list, e.g. \"app=web,restarts>5\"");
+    println!("        --as <USER>            Impersonate a user or service account (system:serviceaccount:<ns>:<name>)");
+    println!("        --as-group <GROUP>     Impersonate a group, can be repeated");
     println!("    -h, --help                 Print this help message");
 }
@@ -27,6 +27,20 @@ pub const POD_NAME_COLUMN_COLOR: Color32 = Color32::from_rgb(197, 120, 255);
 pub const PODS_HEAD_GRID_COLOR: Color32 = Color32::GRAY;
 pub const NAMESPACE_COLUMN_COLOR: Color32 = Color32::LIGHT_BLUE;
 
+// Events timeline
+pub const TIMELINE_AXIS_COLOR: Color32 = Color32::DARK_GRAY;
+pub const TIMELINE_NORMAL_COLOR: Color32 = Color32::from_rgb(0x4C, 0xAF, 0x50); // green
+pub const TIMELINE_WARNING_COLOR: Color32 = Color32::from_rgb(0xF4, 0x43, 0x36); // red
+pub const TIMELINE_RESTART_COLOR: Color32 = Color32::ORANGE;
+pub const TIMELINE_NODE_CONDITION_COLOR: Color32 = Color32::from_rgb(0x21, 0x96, 0xF3); // blue
+
+// Impersonation
+pub const IMPERSONATION_BANNER_COLOR: Color32 = Color32::from_rgb(0x8E, 0x24, 0x1A); // dark red
+
+// Revision diffs
+pub const DIFF_ADDED_COLOR: Color32 = Color32::from_rgb(0x81, 0xC7, 0x84); // light green
+pub const DIFF_REMOVED_COLOR: Color32 = Color32::from_rgb(0xE5, 0x73, 0x73); // light red
+
 pub const GREEN_BUTTON: Color32 = Color32::from_rgb(0x4C, 0xAF, 0x50); // green
 pub const RED_BUTTON: Color32 = Color32::from_rgb(0xF4, 0x43, 0x36); // red
 pub const ORANGE_BUTTON: Color32 = Color32::ORANGE; // orange
@@ -0,0 +1,379 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+use egui::{Context, CursorIcon, Key};
+use kube::api::ApiResource;
+use kube::Client;
+use crate::theme::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BulkKind {
+    Node,
+    Pod,
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Service,
+    ConfigMap,
+    Secret,
+    Job,
+    CronJob,
+}
+
+impl BulkKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BulkKind::Node => "node",
+            BulkKind::Pod => "pod",
+            BulkKind::Deployment => "deployment",
+            BulkKind::StatefulSet => "statefulset",
+            BulkKind::DaemonSet => "daemonset",
+            BulkKind::ReplicaSet => "replicaset",
+            BulkKind::Service => "service",
+            BulkKind::ConfigMap => "configmap",
+            BulkKind::Secret => "secret",
+            BulkKind::Job => "job",
+            BulkKind::CronJob => "cronjob",
+        }
+    }
+
+    pub fn api_resource(&self) -> ApiResource {
+        use k8s_openapi::api::{apps::v1 as apps, batch::v1 as batch, core::v1 as core};
+        match self {
+            BulkKind::Node => ApiResource::erase::<core::Node>(&()),
+            BulkKind::Pod => ApiResource::erase::<core::Pod>(&()),
+            BulkKind::Deployment => ApiResource::erase::<apps::Deployment>(&()),
+            BulkKind::StatefulSet => ApiResource::erase::<apps::StatefulSet>(&()),
+            BulkKind::DaemonSet => ApiResource::erase::<apps::DaemonSet>(&()),
+            BulkKind::ReplicaSet => ApiResource::erase::<apps::ReplicaSet>(&()),
+            BulkKind::Service => ApiResource::erase::<core::Service>(&()),
+            BulkKind::ConfigMap => ApiResource::erase::<core::ConfigMap>(&()),
+            BulkKind::Secret => ApiResource::erase::<core::Secret>(&()),
+            BulkKind::Job => ApiResource::erase::<batch::Job>(&()),
+            BulkKind::CronJob => ApiResource::erase::<batch::CronJob>(&()),
+        }
+    }
+
+    fn scale_target(&self) -> Option<crate::ScaleTarget> {
+        match self {
+            BulkKind::Deployment => Some(crate::ScaleTarget::Deployment),
+            BulkKind::StatefulSet => Some(crate::ScaleTarget::StatefulSet),
+            BulkKind::ReplicaSet => Some(crate::ScaleTarget::ReplicaSet),
+            BulkKind::DaemonSet => Some(crate::ScaleTarget::DaemonSet),
+            _ => None,
+        }
+    }
+
+    fn can_restart(&self) -> bool {
+        matches!(self, BulkKind::Deployment | BulkKind::StatefulSet | BulkKind::DaemonSet)
+    }
+
+    fn can_scale(&self) -> bool {
+        matches!(self, BulkKind::Deployment | BulkKind::StatefulSet | BulkKind::ReplicaSet)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct BulkItem {
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+impl BulkItem {
+    pub fn display(&self) -> String {
+        match &self.namespace {
+            Some(ns) => format!("{}/{}", ns, self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum BulkAction {
+    Delete,
+    Restart,
+    Scale(i32),
+    Cordon(bool),
+    Metadata(&'static str, BTreeMap<String, Option<String>>),
+}
+
+pub struct BulkSelection {
+    pub kind: Option<BulkKind>,
+    pub items: BTreeSet<BulkItem>,
+    pub replicas: i32,
+    pub metadata_input: String,
+    anchor: Option<BulkItem>,
+    last_rows: Vec<BulkItem>,
+    frame_rows: Vec<BulkItem>,
+}
+
+impl BulkSelection {
+    pub fn new() -> Self {
+        Self {
+            kind: None,
+            items: BTreeSet::new(),
+            replicas: 1,
+            metadata_input: String::new(),
+            anchor: None,
+            last_rows: Vec::new(),
+            frame_rows: Vec::new(),
+        }
+    }
+
+    /// Called once per frame before the rows of the table are drawn
+    fn begin_table(&mut self, kind: BulkKind) {
+        if self.kind != Some(kind) {
+            self.kind = Some(kind);
+            self.items.clear();
+            self.anchor = None;
+            self.frame_rows.clear();
+        }
+        self.last_rows = std::mem::take(&mut self.frame_rows);
+        // Rows hidden by a filter or removed by the watcher are not acted on
+        let visible: BTreeSet<&BulkItem> = self.last_rows.iter().collect();
+        self.items.retain(|i| visible.contains(i));
+    }
+
+    fn click(&mut self, item: BulkItem, modifiers: egui::Modifiers) {
+        if modifiers.shift
+            && let Some(anchor) = &self.anchor
+            && let (Some(a), Some(b)) = (self.last_rows.iter().position(|r| r == anchor), self.last_rows.iter().position(|r| r == &item)) {
+                let (from, to) = if a <= b { (a, b) } else { (b, a) };
+                if !modifiers.command {
+                    self.items.clear();
+                }
+                self.items.extend(self.last_rows[from..=to].iter().cloned());
+                return;
+            }
+        if !self.items.remove(&item) {
+            self.items.insert(item.clone());
+        }
+        self.anchor = Some(item);
+    }
+}
+
+/// Row name label with ctrl/shift click selection. Returns true on a plain click (open details).
+pub fn bulk_name_label(ui: &mut egui::Ui, selection: &mut BulkSelection, name: &str, namespace: Option<&str>) -> bool {
+    bulk_name_label_response(ui, selection, name, namespace).0
+}
+
+/// Same as `bulk_name_label`, also returning the label response for row hover highlighting
+pub fn bulk_name_label_response(ui: &mut egui::Ui, selection: &mut BulkSelection, name: &str, namespace: Option<&str>) -> (bool, egui::Response) {
+    let item = BulkItem { namespace: namespace.map(|ns| ns.to_string()), name: name.to_string() };
+    let selected = selection.items.contains(&item);
+    selection.frame_rows.push(item.clone());
+
+    let text = if selected {
+        egui::RichText::new(format!("☑ {}", name)).color(SELECTED)
+    } else {
+        egui::RichText::new(name).color(ITEM_NAME_COLOR)
+    };
+    let response = ui.label(text).on_hover_cursor(CursorIcon::PointingHand);
+    if !response.clicked() {
+        return (false, response);
+    }
+
+    let modifiers = ui.input(|i| i.modifiers);
+    if modifiers.command || modifiers.shift {
+        selection.click(item, modifiers);
+        (false, response)
+    } else {
+        (true, response)
+    }
+}
+
+#[derive(Default)]
+pub struct BulkReport {
+    pub show: bool,
+    pub title: String,
+    pub total: usize,
+    pub results: Vec<(String, Result<(), String>)>,
+}
+
+fn parse_metadata_changes(input: &str) -> BTreeMap<String, Option<String>> {
+    input.split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| {
+            if let Some((k, v)) = s.split_once('=') {
+                Some((k.trim().to_string(), Some(v.trim().to_string())))
+            } else {
+                s.strip_suffix('-').map(|k| (k.trim().to_string(), None))
+            }
+        })
+        .collect()
+}
+
+async fn run_bulk_item(client: Arc<Client>, kind: BulkKind, item: &BulkItem, action: &BulkAction) -> Result<(), anyhow::Error> {
+    let ns = item.namespace.as_deref();
+    match action {
+        BulkAction::Delete => crate::delete_dynamic(client, &kind.api_resource(), &item.name, ns).await?,
+        BulkAction::Restart => {
+            let target = kind.scale_target().ok_or_else(|| anyhow::anyhow!("restart is not supported"))?;
+            crate::restart_workload(client, &item.name, ns.unwrap_or("default"), target).await?
+        }
+        BulkAction::Scale(replicas) => {
+            let target = kind.scale_target().ok_or_else(|| anyhow::anyhow!("scale is not supported"))?;
+            crate::scale_workload(client, &item.name, ns.unwrap_or("default"), *replicas, target).await?
+        }
+        BulkAction::Cordon(cordoned) => crate::cordon_node(client, &item.name, *cordoned).await?,
+        BulkAction::Metadata(field, changes) => crate::patch_metadata_dynamic(client, &kind.api_resource(), &item.name, ns, field, changes).await?,
+    }
+    Ok(())
+}
+
+fn spawn_bulk(client: Arc<Client>, kind: BulkKind, items: Vec<BulkItem>, action: BulkAction, title: String, report: Arc<Mutex<BulkReport>>) {
+    {
+        let mut r = report.lock().unwrap();
+        r.show = true;
+        r.title = title;
+        r.total = items.len();
+        r.results.clear();
+    }
+    tokio::spawn(async move {
+        for item in items {
+            let result = run_bulk_item(Arc::clone(&client), kind, &item, &action).await.map_err(|e| e.to_string());
+            report.lock().unwrap().results.push((item.display(), result));
+        }
+    });
+}
+
+fn request_bulk(confirm: &mut super::DeleteConfirmation, selection: &BulkSelection, kind: BulkKind, action: BulkAction, verb: &str, client: &Arc<Client>, report: &Arc<Mutex<BulkReport>>) {
+    let items: Vec<BulkItem> = selection.items.iter().cloned().collect();
+    let summary: Vec<String> = items.iter().map(|i| format!("{} {}", kind.label(), i.display())).collect();
+    let title = format!("{} {} {}(s)", verb, items.len(), kind.label());
+    let client = Arc::clone(client);
+    let report = Arc::clone(report);
+    confirm.request_bulk(verb.to_string(), summary, move || {
+        spawn_bulk(client, kind, items, action, title, report);
+    });
+}
+
+fn export_yaml(selection: &BulkSelection, kind: BulkKind, client: &Arc<Client>, report: &Arc<Mutex<BulkReport>>) {
+    let Some(path) = rfd::FileDialog::new().set_file_name(format!("{}s.yaml", kind.label())).save_file() else {
+        return;
+    };
+    let items: Vec<BulkItem> = selection.items.iter().cloned().collect();
+    {
+        let mut r = report.lock().unwrap();
+        r.show = true;
+        r.title = format!("export {} {}(s) to {}", items.len(), kind.label(), path.display());
+        r.total = items.len();
+        r.results.clear();
+    }
+    let client = Arc::clone(client);
+    let report = Arc::clone(report);
+    tokio::spawn(async move {
+        let ar = kind.api_resource();
+        let mut documents = Vec::new();
+        for item in items {
+            let result = crate::get_dynamic_yaml(Arc::clone(&client), &ar, &item.name, item.namespace.as_deref()).await;
+            let result = result.map(|yaml| documents.push(yaml)).map_err(|e| e.to_string());
+            report.lock().unwrap().results.push((item.display(), result));
+        }
+        if let Err(e) = std::fs::write(&path, documents.join("---\n")) {
+            report.lock().unwrap().results.push((path.display().to_string(), Err(e.to_string())));
+        }
+    });
+}
+
+/// Selection summary and bulk actions menu, placed in the table header row
+pub fn show_bulk_toolbar(ui: &mut egui::Ui, selection: &mut BulkSelection, kind: BulkKind, client: &Arc<Client>, confirm: &mut super::DeleteConfirmation, report: &Arc<Mutex<BulkReport>>) {
+    selection.begin_table(kind);
+
+    if ui.button(egui::RichText::new("☑ Select all").size(16.0).color(BLUE_BUTTON)).on_hover_text("Select all filtered rows, ctrl/shift click on names to select").clicked() {
+        selection.items = selection.last_rows.iter().cloned().collect();
+    }
+    if selection.items.is_empty() {
+        return;
+    }
+
+    ui.label(egui::RichText::new(format!("{} selected", selection.items.len())).color(SELECTED));
+    if ui.button(egui::RichText::new("ｘ").size(16.0).color(RED_BUTTON)).on_hover_text("Clear selection").clicked() {
+        selection.items.clear();
+        return;
+    }
+
+    ui.menu_button(egui::RichText::new("Bulk actions").size(16.0).color(MENU_BUTTON), |ui| {
+        ui.set_width(260.0);
+        if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
+            request_bulk(confirm, selection, kind, BulkAction::Delete, "delete", client, report);
+            ui.close_kind(egui::UiKind::Menu);
+        }
+        if kind.can_restart() && ui.button(egui::RichText::new("🔄 Restart").size(16.0).color(BLUE_BUTTON)).clicked() {
+            request_bulk(confirm, selection, kind, BulkAction::Restart, "restart", client, report);
+            ui.close_kind(egui::UiKind::Menu);
+        }
+        if kind.can_scale() {
+            ui.horizontal(|ui| {
+                ui.add(egui::DragValue::new(&mut selection.replicas).range(0..=1000));
+                if ui.button(egui::RichText::new("⬍ Scale").size(16.0).color(ORANGE_BUTTON)).clicked() {
+                    let verb = format!("scale to {} replicas", selection.replicas);
+                    request_bulk(confirm, selection, kind, BulkAction::Scale(selection.replicas), &verb, client, report);
+                    ui.close_kind(egui::UiKind::Menu);
+                }
+            });
+        }
+        if kind == BulkKind::Node {
+            if ui.button(egui::RichText::new("⛔ Cordon").size(16.0).color(ORANGE_BUTTON)).clicked() {
+                request_bulk(confirm, selection, kind, BulkAction::Cordon(true), "cordon", client, report);
+                ui.close_kind(egui::UiKind::Menu);
+            }
+            if ui.button(egui::RichText::new("✅ Uncordon").size(16.0).color(GREEN_BUTTON)).clicked() {
+                request_bulk(confirm, selection, kind, BulkAction::Cordon(false), "uncordon", client, report);
+                ui.close_kind(egui::UiKind::Menu);
+            }
+        }
+        ui.separator();
+        ui.add(egui::TextEdit::singleline(&mut selection.metadata_input).hint_text("key=value,other-").desired_width(240.0));
+        let changes = parse_metadata_changes(&selection.metadata_input);
+        ui.horizontal(|ui| {
+            if ui.add_enabled(!changes.is_empty(), egui::Button::new(egui::RichText::new("🏷 Label").size(16.0).color(YELLOW_BUTTON))).clicked() {
+                let verb = format!("label ({})", selection.metadata_input);
+                request_bulk(confirm, selection, kind, BulkAction::Metadata("labels", changes.clone()), &verb, client, report);
+                ui.close_kind(egui::UiKind::Menu);
+            }
+            if ui.add_enabled(!changes.is_empty(), egui::Button::new(egui::RichText::new("📝 Annotate").size(16.0).color(YELLOW_BUTTON))).clicked() {
+                let verb = format!("annotate ({})", selection.metadata_input);
+                request_bulk(confirm, selection, kind, BulkAction::Metadata("annotations", changes.clone()), &verb, client, report);
+                ui.close_kind(egui::UiKind::Menu);
+            }
+        });
+        ui.separator();
+        if ui.button(egui::RichText::new("💾 Export YAML").size(16.0).color(COPY_BUTTON)).clicked() {
+            export_yaml(selection, kind, client, report);
+            ui.close_kind(egui::UiKind::Menu);
+        }
+    });
+}
+
+pub fn show_bulk_report_window(ctx: &Context, report: Arc<Mutex<BulkReport>>) {
+    let mut report = report.lock().unwrap();
+    let mut show = report.show;
+    let response = egui::Window::new("Bulk operation results").min_width(500.0).collapsible(false).resizable(true).open(&mut show).show(ctx, |ui| {
+        let failed = report.results.iter().filter(|(_, r)| r.is_err()).count();
+        ui.heading(egui::RichText::new(&report.title).color(ROW_NAME_COLOR));
+        ui.label(format!("{}/{} done, {} failed", report.results.len(), report.total, failed));
+        if report.results.len() < report.total {
+            ui.spinner();
+        }
+        ui.separator();
+        egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+            egui::Grid::new("bulk_report_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                for (item, result) in &report.results {
+                    ui.label(egui::RichText::new(item).color(DETAIL_COLOR));
+                    match result {
+                        Ok(_) => ui.label(egui::RichText::new("✅ OK").color(GREEN_BUTTON)),
+                        Err(e) => ui.label(egui::RichText::new(format!("❌ {}", e)).color(ERROR_MESSAGE_COLOR)),
+                    };
+                    ui.end_row();
+                }
+            });
+        });
+    });
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            show = false;
+        }
+    report.show = show;
+}
@@ -30,6 +30,18 @@ pub fn show_cluster_role_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Cluster role details", &mut cluster_role_details_window.show, &name, error) {
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_cluster_role_details(client, &name, details).await {
+                    log::error!("Cluster role details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let cluster_role_item = guard_cluster_roles.iter().find(|item| item.name == guard_details.name.clone().unwrap());
     if cluster_role_item.is_none() {
         return;
@@ -0,0 +1,299 @@
+use egui::{Align2, Context, Key, Modifiers, RichText};
+
+const MAX_RESULTS: usize = 50;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum PaletteAction {
+    Base64Decoder,
+    IpCalculator,
+    YamlJsonConverter,
+    ResourcesConverter,
+    UuidGenerator,
+    JwtDecoder,
+    AiConsultant,
+    NewResource,
+}
+
+#[derive(Clone)]
+pub enum PaletteTarget {
+    Resource {
+        category: crate::Category,
+        name: String,
+        namespace: Option<String>,
+    },
+    /// Gateway API objects of different kinds may share a name, the kind picks the right one
+    GatewayApi {
+        kind: crate::GatewayApiKind,
+        name: String,
+        namespace: Option<String>,
+    },
+    Category(crate::Category),
+    Namespace(Option<String>),
+    Action(PaletteAction),
+}
+
+#[derive(Clone)]
+pub struct PaletteEntry {
+    pub kind: &'static str,
+    pub label: String,
+    pub namespace: Option<String>,
+    pub target: PaletteTarget,
+    haystack: String,
+}
+
+pub struct CommandPalette {
+    pub show: bool,
+    pub query: String,
+    pub selected: usize,
+    entries: Vec<PaletteEntry>,
+    /// Kinds whose watcher hasn't been started, so nothing of them is searchable yet
+    not_loaded: Vec<&'static str>,
+    focus_requested: bool,
+    /// The click that opened the palette (Search button) must not count as a click outside it
+    just_opened: bool,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            query: String::new(),
+            selected: 0,
+            entries: Vec::new(),
+            not_loaded: Vec::new(),
+            focus_requested: false,
+            just_opened: false,
+        }
+    }
+
+    /// Resets the palette, the caller refills it with the resources it currently watches
+    pub fn open(&mut self) {
+        self.show = true;
+        self.query.clear();
+        self.selected = 0;
+        self.focus_requested = true;
+        self.just_opened = true;
+        self.entries.clear();
+        self.not_loaded.clear();
+
+        for (label, action) in [
+            ("Base64 decoder", PaletteAction::Base64Decoder),
+            ("IP calculator", PaletteAction::IpCalculator),
+            ("YAML/JSON converter", PaletteAction::YamlJsonConverter),
+            ("Resources converter", PaletteAction::ResourcesConverter),
+            ("UUID generator", PaletteAction::UuidGenerator),
+            ("JWT decoder", PaletteAction::JwtDecoder),
+            ("AI consultant", PaletteAction::AiConsultant),
+            ("Create new resource", PaletteAction::NewResource),
+        ] {
+            self.add_entry("Action", label.to_string(), None, PaletteTarget::Action(action));
+        }
+
+        for (label, category) in [
+            ("Overview", crate::Category::ClusterOverview),
+            ("Nodes", crate::Category::Nodes),
+            ("Namespaces", crate::Category::Namespaces),
+            ("Events", crate::Category::Events),
+            ("Pods", crate::Category::Pods),
+            ("Deployments", crate::Category::Deployments),
+            ("StatefulSets", crate::Category::StatefulSets),
+            ("DaemonSets", crate::Category::DaemonSets),
+            ("ReplicaSets", crate::Category::ReplicaSets),
+            ("Jobs", crate::Category::Jobs),
+            ("CronJobs", crate::Category::CronJobs),
+            ("HorizontalPodAutoscalers", crate::Category::HorizontalPodAutoscalers),
+            ("ConfigMaps", crate::Category::ConfigMaps),
+            ("Secrets", crate::Category::Secrets),
+            ("Pod Disruption Budgets", crate::Category::PodDisruptionBudgets),
+            ("Leases", crate::Category::Leases),
+            ("ResourceQuotas", crate::Category::ResourceQuotas),
+            ("LimitRanges", crate::Category::LimitRanges),
+            ("Namespace capacity", crate::Category::NamespaceCapacity),
+            ("Services", crate::Category::Services),
+            ("Endpoints", crate::Category::Endpoints),
+            ("Ingresses", crate::Category::Ingresses),
+            ("Gateway API", crate::Category::GatewayApi),
+            ("Network Policies", crate::Category::NetworkPolicies),
+            ("Network policy analyzer", crate::Category::NetworkPolicyAnalyzer),
+            ("Proxy", crate::Category::ProxyProcess),
+            ("PersistentVolumeClaims", crate::Category::PersistentVolumeClaims),
+            ("PersistentVolumes", crate::Category::PersistentVolumes),
+            ("StorageClasses", crate::Category::StorageClasses),
+            ("CSI Drivers", crate::Category::CSIDrivers),
+            ("Service accounts", crate::Category::ServiceAccounts),
+            ("Roles", crate::Category::Roles),
+            ("Cluster roles", crate::Category::ClusterRoles),
+            ("Role bindings", crate::Category::RoleBindings),
+            ("Cluster role bindings", crate::Category::ClusterRoleBindings),
+            ("RBAC analyzer", crate::Category::RbacAnalyzer),
+            ("Mutating webhooks", crate::Category::MutatingWebhooks),
+            ("Validating webhooks", crate::Category::ValidatingWebhooks),
+            ("Admission policies", crate::Category::ValidatingAdmissionPolicies),
+            ("Custom resource definitions", crate::Category::CustomResourcesDefinitions),
+            ("Helm releases", crate::Category::HelmReleases),
+            ("Configuration", crate::Category::Configuration),
+            ("App logs", crate::Category::AppLogs),
+            ("About", crate::Category::About),
+        ] {
+            self.add_entry("Go to", label.to_string(), None, PaletteTarget::Category(category));
+        }
+
+        self.add_entry("Namespace", "all".to_string(), None, PaletteTarget::Namespace(None));
+    }
+
+    pub fn add_entry(&mut self, kind: &'static str, label: String, namespace: Option<String>, target: PaletteTarget) {
+        let haystack = format!("{} {} {}", label, namespace.as_deref().unwrap_or_default(), kind).to_lowercase();
+        self.entries.push(PaletteEntry { kind, label, namespace, target, haystack });
+    }
+
+    pub fn add_resources<'a>(&mut self, kind: &'static str, category: crate::Category, items: impl Iterator<Item = (&'a str, Option<&'a str>)>) {
+        for (name, namespace) in items {
+            self.add_entry(kind, name.to_string(), namespace.map(|n| n.to_string()), PaletteTarget::Resource {
+                category: category.clone(),
+                name: name.to_string(),
+                namespace: namespace.map(|n| n.to_string()),
+            });
+        }
+    }
+
+    pub fn add_not_loaded(&mut self, kind: &'static str) {
+        self.not_loaded.push(kind);
+    }
+
+    fn results(&self) -> Vec<&PaletteEntry> {
+        let tokens: Vec<String> = self.query.split_whitespace().map(|t| t.to_lowercase()).collect();
+        if tokens.is_empty() {
+            return self.entries.iter().take(MAX_RESULTS).collect();
+        }
+        let mut scored: Vec<(i32, &PaletteEntry)> = self.entries.iter()
+            .filter_map(|entry| {
+                tokens.iter()
+                    .map(|token| fuzzy_score(token, &entry.haystack))
+                    .sum::<Option<i32>>()
+                    .map(|score| (score, entry))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.label.len().cmp(&b.1.label.len())));
+        scored.into_iter().take(MAX_RESULTS).map(|(_, entry)| entry).collect()
+    }
+}
+
+/// Subsequence match, consecutive characters and word starts score higher.
+/// `pattern` and `candidate` are expected in lowercase.
+pub fn fuzzy_score(pattern: &str, candidate: &str) -> Option<i32> {
+    let mut score = 0;
+    let mut chars = candidate.char_indices().peekable();
+    let mut prev_match: Option<usize> = None;
+    let mut prev_char: Option<char> = None;
+    for p in pattern.chars() {
+        loop {
+            let (i, c) = chars.next()?;
+            let boundary = prev_char.is_none_or(|pc| matches!(pc, ' ' | '-' | '_' | '.' | '/'));
+            prev_char = Some(c);
+            if c == p {
+                score += 1;
+                if boundary {
+                    score += 8;
+                }
+                if prev_match.is_some_and(|m| m + 1 == i) {
+                    score += 5;
+                }
+                prev_match = Some(i);
+                break;
+            }
+        }
+    }
+    if candidate.starts_with(pattern) {
+        score += 10;
+    }
+    Some(score)
+}
+
+pub fn show_command_palette(ctx: &Context, palette: &mut CommandPalette) -> Option<PaletteTarget> {
+    let results_len = palette.results().len();
+    let mut chosen: Option<PaletteTarget> = None;
+    let mut moved = false;
+
+    // Keys steering the list must be taken before the text edit sees them
+    let mut enter = false;
+    ctx.input_mut(|i| {
+        if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+            palette.selected = (palette.selected + 1) % results_len.max(1);
+            moved = true;
+        }
+        if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+            palette.selected = palette.selected.checked_sub(1).unwrap_or(results_len.saturating_sub(1));
+            moved = true;
+        }
+        if i.consume_key(Modifiers::NONE, Key::Enter) {
+            enter = true;
+        }
+        if i.consume_key(Modifiers::NONE, Key::Escape) {
+            palette.show = false;
+        }
+    });
+    if !palette.show {
+        return None;
+    }
+
+    let response = egui::Window::new("Command palette")
+        .title_bar(false)
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size([640.0, 0.0])
+        .anchor(Align2::CENTER_TOP, [0.0, 80.0])
+        .show(ctx, |ui| {
+            let text = ui.add(egui::TextEdit::singleline(&mut palette.query)
+                .hint_text("Search resources, categories and actions...")
+                .text_color(crate::FILTER_TEXT_COLOR)
+                .desired_width(f32::INFINITY));
+            if palette.focus_requested {
+                text.request_focus();
+                palette.focus_requested = false;
+            }
+            if text.changed() {
+                palette.selected = 0;
+            }
+
+            if !palette.not_loaded.is_empty() {
+                ui.label(RichText::new(format!("Not loaded yet, open their category to search them: {}", palette.not_loaded.join(", "))).color(crate::WARNING_COLOR).small());
+            }
+
+            ui.separator();
+            let results = palette.results();
+            if results.is_empty() {
+                ui.label(RichText::new("Nothing found").color(crate::SECOND_DETAIL_COLOR));
+            }
+            egui::ScrollArea::vertical().max_height(420.0).auto_shrink([false, true]).show(ui, |ui| {
+                for (i, entry) in results.iter().enumerate() {
+                    let selected = i == palette.selected;
+                    let row = ui.horizontal(|ui| {
+                        ui.add_sized([90.0, 18.0], egui::Label::new(RichText::new(entry.kind).color(crate::ROW_NAME_COLOR)));
+                        let label = ui.selectable_label(selected, RichText::new(&entry.label).color(crate::ITEM_NAME_COLOR));
+                        if let Some(ns) = &entry.namespace {
+                            ui.label(RichText::new(ns).color(crate::NAMESPACE_COLUMN_COLOR));
+                        }
+                        label
+                    }).inner;
+                    if selected && moved {
+                        row.scroll_to_me(None);
+                    }
+                    if row.clicked() || (selected && enter) {
+                        chosen = Some(entry.target.clone());
+                    }
+                }
+            });
+            ui.separator();
+            ui.label(RichText::new("↑↓ select   ⏎ open   Esc close").color(crate::SECOND_DETAIL_COLOR).small());
+        });
+
+    let just_opened = std::mem::take(&mut palette.just_opened);
+    if let Some(inner_response) = response
+        && !just_opened && inner_response.response.clicked_elsewhere() {
+            palette.show = false;
+        }
+    if chosen.is_some() {
+        palette.show = false;
+    }
+    chosen
+}
@@ -184,6 +184,7 @@ pub fn show_configuration(ui: &mut Ui, app_config: &mut AppConfig) {
             app_config.ai_settings.amazon_bedrock_model_id.clone(),
             app_config.ai_settings.amazon_bedrock_region.clone(),
             app_config.ai_settings.mcp_server_url.clone(),
+            app_config.saved_queries.clone(),
         );
     }
 }
@@ -5,6 +5,9 @@ pub struct DeleteConfirmation {
     pub resource_name: Option<String>,
     pub namespace: Option<String>,
     pub on_confirm: Option<Box<dyn FnOnce() + Send>>,
+    pub action: String,
+    pub summary: Vec<String>,
+    pub question: Option<String>,
 }
 
 impl DeleteConfirmation {
@@ -14,6 +17,9 @@ impl DeleteConfirmation {
             resource_name: None,
             namespace: None,
             on_confirm: None,
+            action: "delete".to_string(),
+            summary: Vec::new(),
+            question: None,
         }
     }
 
@@ -25,21 +31,71 @@ impl DeleteConfirmation {
         self.resource_name = Some(resource_name);
         self.namespace = namespace;
         self.on_confirm = Some(Box::new(on_confirm));
+        self.action = "delete".to_string();
+        self.summary.clear();
+        self.question = None;
+    }
+
+    /// Confirmation for a non-delete action on one resource, `action` labels the confirm button
+    pub fn request_action<F>(&mut self, action: String, question: String, on_confirm: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.show = true;
+        self.resource_name = None;
+        self.namespace = None;
+        self.on_confirm = Some(Box::new(on_confirm));
+        self.action = action;
+        self.summary.clear();
+        self.question = Some(question);
+    }
+
+    /// Confirmation for an action on several resources, every affected one is listed
+    pub fn request_bulk<F>(&mut self, action: String, summary: Vec<String>, on_confirm: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.show = true;
+        self.resource_name = None;
+        self.namespace = None;
+        self.on_confirm = Some(Box::new(on_confirm));
+        self.action = action;
+        self.summary = summary;
+        self.question = None;
     }
 }
 
 pub fn show_delete_confirmation(ctx: &Context, delete_confirm: &mut DeleteConfirmation) {
     if delete_confirm.show {
         let resource_name = delete_confirm.resource_name.clone().unwrap_or_default();
-        let response = egui::Window::new("Confirm deletion")
+        let title = if !delete_confirm.summary.is_empty() {
+            "Confirm bulk action"
+        } else if delete_confirm.question.is_some() {
+            "Confirm action"
+        } else {
+            "Confirm deletion"
+        };
+        let response = egui::Window::new(title)
+            .id(egui::Id::new("delete_confirmation_window"))
             .collapsible(false)
             .resizable(false)
             .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
             .show(ctx, |ui| {
-                ui.label(format!("Are you sure you want to delete \"{}\"?", resource_name));
+                if let Some(question) = &delete_confirm.question {
+                    ui.label(question);
+                } else if delete_confirm.summary.is_empty() {
+                    ui.label(format!("Are you sure you want to delete \"{}\"?", resource_name));
+                } else {
+                    ui.label(format!("Are you sure you want to {} {} resource(s)?", delete_confirm.action, delete_confirm.summary.len()));
+                    egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                        for item in &delete_confirm.summary {
+                            ui.label(egui::RichText::new(item).color(crate::DETAIL_COLOR));
+                        }
+                    });
+                }
 
                 ui.horizontal(|ui| {
-                    if ui.button(egui::RichText::new("Yes, delete").color(crate::RED_BUTTON)).clicked() {
+                    if ui.button(egui::RichText::new(format!("Yes, {}", delete_confirm.action)).color(crate::RED_BUTTON)).clicked() {
                         if let Some(callback) = delete_confirm.on_confirm.take() {
                             callback();
                         }
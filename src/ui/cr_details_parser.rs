@@ -1,251 +1,476 @@
+use std::{collections::BTreeMap, fs::File};
+use anyhow::Context;
+use egui::Color32;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
 use k8s_openapi::jiff::Timestamp;
+use serde::Deserialize;
 use serde_json::Value;
-use crate::{functions::item_color, theme::ITEM_NAME_COLOR};
+use walkdir::WalkDir;
+use crate::{functions::item_color, theme::*};
+
+// -------------------------
+// View example (views/external_secret.yaml)
+// -------------------------
+// name: "ExternalSecret"
+// match:
+//   group: "external-secrets.io"
+//   kind: "ExternalSecret"
+// columns:
+//   - name: "Status"
+//     path: ".status.conditions[?(@.type=='Ready')].status"
+//     colors:
+//       "True": "green"
+//       "False": "red"
+//   - name: "Age"
+//     path: ".metadata.creationTimestamp"
+//     format: "age"
+// details:
+//   - title: "Store"
+//     fields:
+//       - name: "Kind"
+//         path: ".spec.secretStoreRef.kind"
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CrView {
+    pub name: String,
+    #[serde(rename = "match")]
+    pub match_rule: CrViewMatch,
+    pub columns: Vec<CrViewColumn>,
+    #[serde(default)]
+    pub details: Vec<CrViewSection>,
+}
 
-// Cluster secret store
-/////////////////////////////////////////////
-pub fn show_cluster_secret_store_header(ui: &mut egui::Ui) {
-    ui.label("Name");
-    ui.label("Message");
-    ui.label("Reason");
-    ui.label("Type");
-    ui.label("Status");
-    ui.label("Capabilities");
-    ui.label("Age");
-}
-
-pub fn show_cluster_secret_store_details(name: &String, data: &Value, ui: &mut egui::Ui) {
-    let status_obj = data.get("status");
-    let metadata_obj = data.get("metadata");
-
-    let message = status_obj
-        .and_then(|s| s.get("conditions"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|cond| cond.get("message"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let reason = status_obj
-        .and_then(|s| s.get("conditions"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|cond| cond.get("reason"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let ctype = status_obj
-        .and_then(|s| s.get("conditions"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|cond| cond.get("type"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let cstatus = status_obj
-        .and_then(|s| s.get("conditions"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|cond| cond.get("status"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let capabilities = status_obj
-        .and_then(|s| s.get("capabilities"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let creation_timestamp = metadata_obj
-        .and_then(|s| s.get("creationTimestamp"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-    let ts: Timestamp = creation_timestamp.parse().unwrap_or(Timestamp::UNIX_EPOCH);
-    let creation_timestamp_k8s_time = Time(ts);
-
-    ui.label(egui::RichText::new(name).color(ITEM_NAME_COLOR));
-    ui.label(message);
-    ui.label(reason);
-    ui.label(egui::RichText::new(ctype).color(item_color(ctype)));
-    ui.label(cstatus);
-    ui.label(capabilities);
-    ui.label(crate::format_age(&creation_timestamp_k8s_time));
-}
-
-// External secret
-/////////////////////////////////////////////
-pub fn show_external_secret_header(ui: &mut egui::Ui) {
-    ui.label("Name");
-    ui.label("Namespace");
-    ui.label("StoreType");
-    ui.label("Refresh interval");
-    ui.label("Status");
-    ui.label("Reason");
-    ui.label("Age");
-}
-
-pub fn show_external_secret_details(name: &String, data: &Value, ui: &mut egui::Ui) {
-    let metadata_obj = data.get("metadata");
-    let spec_obj = data.get("spec");
-    let status_obj = data.get("status");
-
-    let ctype = status_obj
-        .and_then(|s| s.get("conditions"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|cond| cond.get("type"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let namespace = metadata_obj
-        .and_then(|s| s.get("namespace"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let refresh_interval = spec_obj
-        .and_then(|s| s.get("refreshInterval"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let store_type = spec_obj
-        .and_then(|s| s.get("secretStoreRef"))
-        .and_then(|cond| cond.get("kind"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let creation_timestamp = metadata_obj
-        .and_then(|s| s.get("creationTimestamp"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-    let ts: Timestamp = creation_timestamp.parse().unwrap_or(Timestamp::UNIX_EPOCH);
-    let creation_timestamp_k8s_time = Time(ts);
-
-    let reason = status_obj
-        .and_then(|s| s.get("conditions"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|cond| cond.get("reason"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    ui.label(egui::RichText::new(name).color(ITEM_NAME_COLOR));
-    ui.label(namespace);
-    ui.label(store_type);
-    ui.label(refresh_interval);
-    ui.label(egui::RichText::new(ctype).color(item_color(ctype)));
-    ui.label(reason);
-    ui.label(crate::format_age(&creation_timestamp_k8s_time));
-}
-
-// Virtual service
-/////////////////////////////////////////////
-pub fn show_virtual_service_header(ui: &mut egui::Ui) {
-    ui.label("Name");
-    ui.label("Namespace");
-    ui.label("Gateway");
-    ui.label("Host");
-    ui.label("Age");
-}
-
-pub fn show_virtual_service_details(name: &String, data: &Value, ui: &mut egui::Ui) {
-    let metadata_obj = data.get("metadata");
-    let spec_obj = data.get("spec");
-
-    let namespace = metadata_obj
-        .and_then(|s| s.get("namespace"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let creation_timestamp = metadata_obj
-        .and_then(|s| s.get("creationTimestamp"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-    let ts: Timestamp = creation_timestamp.parse().unwrap_or(Timestamp::UNIX_EPOCH);
-    let creation_timestamp_k8s_time = Time(ts);
-
-    let gateways = spec_obj
-        .and_then(|s| s.get("gateways"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let hosts = spec_obj
-        .and_then(|s| s.get("hosts"))
-        .and_then(|c| c.as_array())
-        .and_then(|a| a.first())
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    ui.label(egui::RichText::new(name).color(ITEM_NAME_COLOR));
-    ui.label(namespace);
-    ui.label(gateways);
-    ui.label(hosts);
-    ui.label(crate::format_age(&creation_timestamp_k8s_time));
-}
-
-
-// CiliumLoadBalancerIPPool
-/////////////////////////////////////////////
-pub fn show_cilium_load_balancer_ip_pool_header(ui: &mut egui::Ui) {
-    ui.label("Name");
-    ui.label("Age");
-    ui.label("allowFirstLastIPs");
-    ui.label("IP Pools");
-    ui.label("Disabled");
-}
-
-pub fn show_cilium_load_balancer_ip_pool_details(name: &String, data: &Value, ui: &mut egui::Ui) {
-    let metadata_obj = data.get("metadata");
-    let spec_obj = data.get("spec");
-
-    let creation_timestamp = metadata_obj
-        .and_then(|s| s.get("creationTimestamp"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-    let ts: Timestamp = creation_timestamp.parse().unwrap_or(Timestamp::UNIX_EPOCH);
-    let creation_timestamp_k8s_time = Time(ts);
-
-    let allow_first_last_ips = spec_obj
-        .and_then(|s| s.get("allowFirstLastIPs"))
-        .and_then(|v| v.as_str())
-        .unwrap_or("-");
-
-    let disabled = spec_obj
-        .and_then(|s| s.get("disabled"))
-        .and_then(|v| v.as_bool()).unwrap_or_default();
-
-    let blocks_str = spec_obj
-        .and_then(|s| s.get("blocks"))
-        .and_then(|b| b.as_array())
-        .map(|blocks_array| {
-            blocks_array
-                .iter()
-                .map(|block_obj| {
-                    if let Some(cidr) = block_obj.get("cidr").and_then(|v| v.as_str()) {
-                        cidr.to_string()
-                    } else if let (Some(start), Some(stop)) = (
-                        block_obj.get("start").and_then(|v| v.as_str()),
-                        block_obj.get("stop").and_then(|v| v.as_str())
-                    ) {
-                        format!("{} - {}", start, stop)
-                    } else {
-                        "?".to_string()
+#[derive(Debug, Deserialize, Clone)]
+pub struct CrViewMatch {
+    pub group: Option<String>,
+    pub version: Option<String>,
+    pub kind: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CrViewColumn {
+    pub name: String,
+    pub path: String,
+    pub format: Option<String>,
+    #[serde(default)]
+    pub colors: BTreeMap<String, String>,
+    #[serde(default)]
+    pub status_colors: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CrViewSection {
+    pub title: String,
+    pub fields: Vec<CrViewColumn>,
+}
+
+impl CrViewMatch {
+    pub fn matches(&self, group: &str, version: &str, kind: &str) -> bool {
+        self.kind == kind
+            && self.group.as_deref().is_none_or(|g| g == group)
+            && self.version.as_deref().is_none_or(|v| v == version)
+    }
+}
+
+const CLUSTER_SECRET_STORE_VIEW: &str = r#"name: "ClusterSecretStore"
+match:
+  group: "external-secrets.io"
+  kind: "ClusterSecretStore"
+columns:
+  - name: "Message"
+    path: ".status.conditions[0].message"
+  - name: "Reason"
+    path: ".status.conditions[0].reason"
+  - name: "Type"
+    path: ".status.conditions[0].type"
+    status_colors: true
+  - name: "Status"
+    path: ".status.conditions[0].status"
+  - name: "Capabilities"
+    path: ".status.capabilities"
+  - name: "Age"
+    path: ".metadata.creationTimestamp"
+    format: "age"
+details:
+  - title: "Provider"
+    fields:
+      - name: "Provider"
+        path: ".spec.provider"
+      - name: "Refresh interval"
+        path: ".spec.refreshInterval"
+"#;
+
+const EXTERNAL_SECRET_VIEW: &str = r#"name: "ExternalSecret"
+match:
+  group: "external-secrets.io"
+  kind: "ExternalSecret"
+columns:
+  - name: "Namespace"
+    path: ".metadata.namespace"
+  - name: "StoreType"
+    path: ".spec.secretStoreRef.kind"
+  - name: "Refresh interval"
+    path: ".spec.refreshInterval"
+  - name: "Status"
+    path: ".status.conditions[0].type"
+    status_colors: true
+  - name: "Reason"
+    path: ".status.conditions[0].reason"
+  - name: "Age"
+    path: ".metadata.creationTimestamp"
+    format: "age"
+details:
+  - title: "Store"
+    fields:
+      - name: "Store name"
+        path: ".spec.secretStoreRef.name"
+      - name: "Store kind"
+        path: ".spec.secretStoreRef.kind"
+  - title: "Target"
+    fields:
+      - name: "Secret name"
+        path: ".spec.target.name"
+      - name: "Creation policy"
+        path: ".spec.target.creationPolicy"
+      - name: "Remote keys"
+        path: ".spec.data[*].remoteRef.key"
+  - title: "Sync"
+    fields:
+      - name: "Refresh time"
+        path: ".status.refreshTime"
+      - name: "Synced version"
+        path: ".status.syncedResourceVersion"
+      - name: "Message"
+        path: ".status.conditions[0].message"
+"#;
+
+const VIRTUAL_SERVICE_VIEW: &str = r#"name: "VirtualService"
+match:
+  group: "networking.istio.io"
+  kind: "VirtualService"
+columns:
+  - name: "Namespace"
+    path: ".metadata.namespace"
+  - name: "Gateway"
+    path: ".spec.gateways[0]"
+  - name: "Host"
+    path: ".spec.hosts[0]"
+  - name: "Age"
+    path: ".metadata.creationTimestamp"
+    format: "age"
+details:
+  - title: "Routing"
+    fields:
+      - name: "Gateways"
+        path: ".spec.gateways[*]"
+      - name: "Hosts"
+        path: ".spec.hosts[*]"
+      - name: "Destinations"
+        path: ".spec.http[*].route[*].destination.host"
+"#;
+
+const CILIUM_LB_IP_POOL_VIEW: &str = r#"name: "CiliumLoadBalancerIPPool"
+match:
+  group: "cilium.io"
+  kind: "CiliumLoadBalancerIPPool"
+columns:
+  - name: "Age"
+    path: ".metadata.creationTimestamp"
+    format: "age"
+  - name: "allowFirstLastIPs"
+    path: ".spec.allowFirstLastIPs"
+  - name: "IP Pools"
+    path: ".spec.blocks[*].cidr"
+  - name: "Ranges start"
+    path: ".spec.blocks[*].start"
+  - name: "Ranges stop"
+    path: ".spec.blocks[*].stop"
+  - name: "Disabled"
+    path: ".spec.disabled"
+    format: "bool"
+details:
+  - title: "Selector"
+    fields:
+      - name: "Service selector"
+        path: ".spec.serviceSelector.matchLabels"
+"#;
+
+const BUILTIN_VIEWS: [&str; 4] = [
+    CLUSTER_SECRET_STORE_VIEW,
+    EXTERNAL_SECRET_VIEW,
+    VIRTUAL_SERVICE_VIEW,
+    CILIUM_LB_IP_POOL_VIEW,
+];
+
+/// Built-in views first, then user views from `~/.local/share/rustlens/views`.
+/// A user view with the same match rule replaces the built-in one.
+pub fn load_cr_views() -> Vec<CrView> {
+    let mut views: Vec<CrView> = Vec::new();
+
+    for raw in BUILTIN_VIEWS {
+        match yaml_serde::from_str::<CrView>(raw) {
+            Ok(view) => views.push(view),
+            Err(e) => log::error!("Failed to parse built-in CR view: {}", e),
+        }
+    }
+
+    let mut views_dir = crate::app_root_path();
+    views_dir.push("views");
+
+    if !views_dir.exists() {
+        return views;
+    }
+
+    for entry in WalkDir::new(views_dir).into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path();
+
+        if !path.is_file() {
+            continue;
+        }
+
+        match path.extension().and_then(|s| s.to_str()) {
+            Some("yaml") | Some("yml") => {
+                let parsed = File::open(path)
+                    .with_context(|| format!("opening view file {}", path.display()))
+                    .and_then(|f| {
+                        yaml_serde::from_reader::<_, CrView>(f)
+                            .with_context(|| format!("parsing YAML {}", path.display()))
+                    });
+                match parsed {
+                    Ok(view) => {
+                        views.retain(|v| {
+                            v.match_rule.kind != view.match_rule.kind
+                                || v.match_rule.group != view.match_rule.group
+                                || v.match_rule.version != view.match_rule.version
+                        });
+                        log::info!("Loaded CR view {} from {}", view.name, path.display());
+                        views.push(view);
+                    }
+                    Err(e) => log::error!("Failed to load CR view: {:?}", e),
+                }
+            }
+            _ => {
+                // ignore all other files
+            }
+        }
+    }
+
+    views
+}
+
+pub fn find_cr_view<'a>(views: &'a [CrView], group: &str, version: &str, kind: &str) -> Option<&'a CrView> {
+    views.iter().rev().find(|v| v.match_rule.matches(group, version, kind))
+}
+
+enum PathSegment {
+    Key(String),
+    Index(usize),
+    All,
+    Filter(String, String),
+}
+
+fn parse_path(path: &str) -> Vec<PathSegment> {
+    let trimmed = path.trim().trim_start_matches('{').trim_end_matches('}');
+    let trimmed = trimmed.strip_prefix('$').unwrap_or(trimmed);
+
+    let mut segments = Vec::new();
+    let chars: Vec<char> = trimmed.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '.' => i += 1,
+            '[' => {
+                let end = match chars[i..].iter().position(|c| *c == ']') {
+                    Some(p) => i + p,
+                    None => break,
+                };
+                let inner: String = chars[i + 1..end].iter().collect();
+                let inner = inner.trim();
+                if inner == "*" {
+                    segments.push(PathSegment::All);
+                } else if let Ok(idx) = inner.parse::<usize>() {
+                    segments.push(PathSegment::Index(idx));
+                } else if let Some(expr) = inner.strip_prefix("?(@.").and_then(|e| e.strip_suffix(')')) {
+                    if let Some((key, value)) = expr.split_once("==") {
+                        let value = value.trim().trim_matches(|c| c == '\'' || c == '"');
+                        segments.push(PathSegment::Filter(key.trim().to_string(), value.to_string()));
+                    }
+                } else {
+                    segments.push(PathSegment::Key(inner.trim_matches(|c| c == '\'' || c == '"').to_string()));
+                }
+                i = end + 1;
+            }
+            _ => {
+                let start = i;
+                while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                    i += 1;
+                }
+                segments.push(PathSegment::Key(chars[start..i].iter().collect()));
+            }
+        }
+    }
+
+    segments
+}
+
+/// Minimal JSONPath evaluator: `.a.b`, `['dotted.key']`, `[0]`, `[*]` and `[?(@.key=='value')]`.
+pub fn json_path<'a>(data: &'a Value, path: &str) -> Vec<&'a Value> {
+    let mut current: Vec<&Value> = vec![data];
+
+    for segment in parse_path(path) {
+        let mut next = Vec::new();
+        for value in current {
+            match &segment {
+                PathSegment::Key(key) => {
+                    if let Some(v) = value.get(key.as_str()) {
+                        next.push(v);
+                    }
+                }
+                PathSegment::Index(idx) => {
+                    if let Some(v) = value.get(*idx) {
+                        next.push(v);
+                    }
+                }
+                PathSegment::All => {
+                    match value {
+                        Value::Array(arr) => next.extend(arr.iter()),
+                        Value::Object(obj) => next.extend(obj.values()),
+                        _ => {}
                     }
-                })
-                .collect::<Vec<String>>()
-                .join(", ")
-        })
-        .filter(|s| !s.is_empty())
-        .unwrap_or_else(|| "-".to_string());
-
-    ui.label(egui::RichText::new(name).color(ITEM_NAME_COLOR));
-    ui.label(crate::format_age(&creation_timestamp_k8s_time));
-    ui.label(allow_first_last_ips);
-    ui.label(blocks_str);
-    if disabled {
-        ui.label("Yes");
-    } else {
-        ui.label("No");
+                }
+                PathSegment::Filter(key, expected) => {
+                    if let Some(arr) = value.as_array() {
+                        for v in arr {
+                            let matched = match v.get(key.as_str()) {
+                                Some(Value::String(s)) => s == expected,
+                                Some(Value::Bool(b)) => b.to_string() == *expected,
+                                Some(Value::Number(n)) => n.to_string() == *expected,
+                                _ => false,
+                            };
+                            if matched {
+                                next.push(v);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        current = next;
+    }
+
+    current
+}
+
+fn value_to_string(v: &Value) -> Option<String> {
+    match v {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Object(obj) => {
+            if let Some(Value::String(s)) = obj.get("name") {
+                return Some(s.clone());
+            }
+            serde_json::to_string(v).ok()
+        }
+        Value::Array(_) => serde_json::to_string(v).ok(),
+        Value::Null => None,
+    }
+}
+
+fn format_value(v: &Value, format: Option<&str>) -> Option<String> {
+    match format {
+        Some("age") => {
+            let ts: Timestamp = v.as_str()?.parse().ok()?;
+            Some(crate::format_age(&Time(ts)))
+        }
+        Some("bool") => {
+            let b = match v {
+                Value::Bool(b) => *b,
+                Value::String(s) => s.eq_ignore_ascii_case("true"),
+                _ => return None,
+            };
+            Some(if b { "Yes".to_string() } else { "No".to_string() })
+        }
+        _ => value_to_string(v),
     }
 }
+
+pub fn extract_view_value(data: &Value, column: &CrViewColumn) -> Option<String> {
+    let values: Vec<String> = json_path(data, &column.path)
+        .into_iter()
+        .filter_map(|v| format_value(v, column.format.as_deref()))
+        .collect();
+
+    if values.is_empty() {
+        if column.format.as_deref() == Some("bool") {
+            return Some("No".to_string());
+        }
+        return None;
+    }
+    Some(values.join(", "))
+}
+
+fn named_color(name: &str) -> Option<Color32> {
+    // Exactly six ASCII hex digits, anything else falls through to the names below
+    if let Some(hex) = name.strip_prefix('#')
+        && hex.len() == 6 && hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            let rgb = u32::from_str_radix(hex, 16).ok()?;
+            return Some(Color32::from_rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8));
+        }
+
+    match name.to_lowercase().as_str() {
+        "green" => Some(Color32::GREEN),
+        "red" => Some(Color32::RED),
+        "orange" => Some(Color32::ORANGE),
+        "yellow" => Some(Color32::YELLOW),
+        "blue" => Some(Color32::LIGHT_BLUE),
+        "gray" | "grey" => Some(Color32::GRAY),
+        "white" => Some(Color32::WHITE),
+        "violet" | "purple" => Some(Color32::from_rgb(198, 98, 247)),
+        _ => None,
+    }
+}
+
+pub fn view_value_color(column: &CrViewColumn, value: &str) -> Color32 {
+    if let Some(color) = column.colors.get(value).and_then(|c| named_color(c)) {
+        return color;
+    }
+    if let Some(color) = column.colors.get("*").and_then(|c| named_color(c)) {
+        return color;
+    }
+    if column.status_colors {
+        return item_color(value);
+    }
+    DETAIL_COLOR
+}
+
+pub fn show_cr_view_header(view: &CrView, ui: &mut egui::Ui) {
+    ui.label("Name");
+    for column in &view.columns {
+        ui.label(&column.name);
+    }
+}
+
+/// Draws one row of the view, returns true when the name was clicked
+pub fn show_cr_view_row(view: &CrView, name: &str, data: &Value, ui: &mut egui::Ui) -> bool {
+    let clicked = ui.label(egui::RichText::new(name).color(ITEM_NAME_COLOR)).on_hover_cursor(egui::CursorIcon::PointingHand).clicked();
+
+    for column in &view.columns {
+        match extract_view_value(data, column) {
+            Some(val) => {
+                let color = view_value_color(column, &val);
+                if color == DETAIL_COLOR {
+                    ui.label(val);
+                } else {
+                    ui.label(egui::RichText::new(val).color(color));
+                }
+            }
+            None => {
+                ui.label("-");
+            }
+        }
+    }
+
+    clicked
+}
@@ -0,0 +1,195 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use egui::{Context, Key};
+use crate::functions::item_color;
+use crate::theme::*;
+use crate::ui::YamlEditorWindow;
+
+pub struct CrInstanceDetailsWindow {
+    pub show: bool,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub last_refresh: Option<Instant>,
+}
+
+const EVENTS_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+impl CrInstanceDetailsWindow {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            name: None,
+            namespace: None,
+            last_refresh: None,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn show_cr_instance_details_window(
+        ctx: &Context,
+        cr_instance_details_window: &mut CrInstanceDetailsWindow,
+        details: Arc<Mutex<crate::CrInstanceDetails>>,
+        cr_instances: Arc<Mutex<Vec<crate::CrdInstance>>>,
+        views: &[super::CrView],
+        yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        client: Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) {
+    let guard_instances = cr_instances.lock().unwrap();
+    if cr_instance_details_window.name.is_none() {
+        return;
+    }
+    let instance = guard_instances.iter().find(|item| Some(&item.name) == cr_instance_details_window.name.as_ref() && item.namespace == cr_instance_details_window.namespace);
+    let Some(instance) = instance else {
+        return;
+    };
+    let view = super::find_cr_view(views, &instance.group, &instance.version, &instance.kind);
+
+    // Events are not watched, refresh them periodically while the window is open
+    if cr_instance_details_window.last_refresh.is_none_or(|t| t.elapsed() > EVENTS_REFRESH_INTERVAL) {
+        cr_instance_details_window.last_refresh = Some(Instant::now());
+        let name = instance.name.clone();
+        let ns = instance.namespace.clone();
+        let kind = instance.kind.clone();
+        let client_clone = Arc::clone(&client);
+        let details_clone = Arc::clone(&details);
+        tokio::spawn(async move {
+            if let Err(e) = crate::get_cr_instance_details(client_clone, &name, ns, &kind, details_clone).await {
+                log::error!("CR instance events fetch failed: {:?}", e);
+            }
+        });
+    }
+    let guard_details = details.lock().unwrap();
+    let events_match = guard_details.name.as_ref() == Some(&instance.name) && guard_details.namespace == instance.namespace;
+
+    let title = format!("{} details", instance.kind);
+    let mut retry_events = false;
+    let response = egui::Window::new(title).id(egui::Id::new("cr_instance_details_window")).min_width(800.0).collapsible(false).resizable(true).open(&mut cr_instance_details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
+                let name = instance.name.clone();
+                let ns = instance.namespace.clone();
+                let group = instance.group.clone();
+                let version = instance.version.clone();
+                let plural = instance.plural.clone();
+                let kind = instance.kind.clone();
+                let client_clone = Arc::clone(&client);
+                let yaml_editor_clone = Arc::clone(&yaml_editor_window);
+                tokio::spawn(async move {
+                    match crate::get_cr_instance_yaml(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
+                        Ok(yaml) => {
+                            let mut editor = yaml_editor_clone.lock().unwrap();
+                            editor.content = yaml;
+                            editor.show = true;
+                        }
+                        Err(e) => log::error!("Failed to get CR YAML: {}", e),
+                    }
+                });
+            }
+
+            if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
+                let name = instance.name.clone();
+                let ns = instance.namespace.clone();
+                let group = instance.group.clone();
+                let version = instance.version.clone();
+                let plural = instance.plural.clone();
+                let kind = instance.kind.clone();
+                let client_clone = Arc::clone(&client);
+                delete_confirm.request(name.clone(), ns.clone(), move || {
+                    tokio::spawn(async move {
+                        if let Err(e) = crate::delete_cr_instance(client_clone, &name, ns.as_deref(), &group, &version, &kind, &plural).await {
+                            log::error!("Failed to delete CR instance: {}", e);
+                        }
+                    });
+                });
+            }
+        });
+        ui.separator();
+        egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
+            egui::Grid::new("cr_instance_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                ui.label(egui::RichText::new("Name:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&instance.name).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(ns) = &instance.namespace {
+                    ui.label(egui::RichText::new("Namespace:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(ns).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.label(egui::RichText::new("Api version:").color(ROW_NAME_COLOR));
+                let api_version = if instance.group.is_empty() {
+                    instance.version.clone()
+                } else {
+                    format!("{}/{}", instance.group, instance.version)
+                };
+                ui.label(egui::RichText::new(api_version).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(view) = view {
+                    for (idx, section) in view.details.iter().enumerate() {
+                        ui.separator(); ui.separator(); ui.end_row();
+                        ui.label(egui::RichText::new(format!("{}:", section.title)).color(ROW_NAME_COLOR));
+                        egui::Grid::new(format!("cr_instance_details_section_grid_{}", idx)).striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                            for field in &section.fields {
+                                ui.label(egui::RichText::new(&field.name).color(DETAIL_COLOR));
+                                match super::extract_view_value(&instance.data, field) {
+                                    Some(val) => {
+                                        let color = super::view_value_color(field, &val);
+                                        let color = if color == DETAIL_COLOR { SECOND_DETAIL_COLOR } else { color };
+                                        ui.label(egui::RichText::new(val).color(color));
+                                    }
+                                    None => {
+                                        ui.label(egui::RichText::new("-").color(SECOND_DETAIL_COLOR));
+                                    }
+                                }
+                                ui.end_row();
+                            }
+                        });
+                        ui.end_row();
+                    }
+                }
+
+                if let Some(labels) = instance.data.get("metadata").and_then(|m| m.get("labels")).and_then(|l| l.as_object()) {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(egui::RichText::new("Labels:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("cr_instance_details_labels_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                        for (j, y) in labels.iter() {
+                            ui.label(egui::RichText::new(j).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(y.as_str().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
+            if events_match && let Some(error) = &guard_details.error {
+                retry_events = crate::show_details_error(ui, &instance.name, error);
+            } else if events_match && !guard_details.events.is_empty() {
+                egui::Grid::new("cr_instance_details_events_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for event in &guard_details.events {
+                        ui.label(egui::RichText::new(event.timestamp.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.message.clone().unwrap_or_default()).color(item_color(&event.event_type.clone().unwrap_or_default())));
+                        ui.end_row();
+                    }
+                });
+            }
+        });
+    });
+
+    crate::show_delete_confirmation(ctx, delete_confirm);
+
+    if retry_events {
+        cr_instance_details_window.last_refresh = None;
+    }
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            cr_instance_details_window.show = false;
+        }
+}
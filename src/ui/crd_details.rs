@@ -4,12 +4,14 @@ use crate::theme::*;
 
 pub struct CrdDetailsWindow {
     pub show: bool,
+    pub schema_version: Option<String>,
 }
 
 impl CrdDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            schema_version: None,
         }
     }
 }
@@ -28,11 +30,32 @@ pub fn show_crd_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Crd details", &mut crd_details_window.show, &name, error) {
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_crd_details(client, &name, details).await {
+                    log::error!("CRD details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let crd_item = guard_crds.iter().find(|item| item.name == guard_details.name.clone().unwrap());
     if crd_item.is_none() {
         return;
     }
 
+    // Default to the storage version when the CRD changes or nothing is selected yet
+    if !guard_details.versions.iter().any(|v| Some(&v.name) == crd_details_window.schema_version.as_ref()) {
+        crd_details_window.schema_version = guard_details.versions.iter()
+            .find(|v| v.storage)
+            .or(guard_details.versions.first())
+            .map(|v| v.name.clone());
+    }
+    let schema_version = &mut crd_details_window.schema_version;
+
     let response = egui::Window::new("Crd details").min_width(800.0).collapsible(false).resizable(true).open(&mut crd_details_window.show).show(ctx, |ui| {
         if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
             crate::edit_cluster_yaml_for::<k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition>(
@@ -96,6 +119,46 @@ pub fn show_crd_details_window(
                     ui.end_row();
                 }
             });
+
+            if !guard_details.versions.is_empty() {
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.heading(egui::RichText::new("Schema:").color(ROW_NAME_COLOR));
+                    egui::ComboBox::from_id_salt("crd_schema_version_combo")
+                        .selected_text(schema_version.clone().unwrap_or_default())
+                        .show_ui(ui, |ui| {
+                            for version in &guard_details.versions {
+                                let mut label = version.name.clone();
+                                if version.storage {
+                                    label.push_str(" (storage)");
+                                }
+                                if !version.served {
+                                    label.push_str(" (not served)");
+                                }
+                                ui.selectable_value(schema_version, Some(version.name.clone()), label);
+                            }
+                        });
+                });
+
+                let selected = guard_details.versions.iter().find(|v| Some(&v.name) == schema_version.as_ref());
+                match selected.and_then(|v| v.schema.as_ref()) {
+                    Some(schema) => {
+                        let required: Vec<&str> = schema.get("required")
+                            .and_then(|r| r.as_array())
+                            .map(|r| r.iter().filter_map(|v| v.as_str()).collect())
+                            .unwrap_or_default();
+                        if let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) {
+                            let id_path = format!("crd_schema.{}", schema_version.clone().unwrap_or_default());
+                            for (name, property) in properties {
+                                super::show_schema_tree(ui, name, property, required.contains(&name.as_str()), &id_path);
+                            }
+                        }
+                    }
+                    None => {
+                        ui.label(egui::RichText::new("No schema defined for this version").color(WARNING_COLOR));
+                    }
+                }
+            }
         });
     });
     crate::show_delete_confirmation(ctx, delete_confirm);
@@ -0,0 +1,246 @@
+use serde_json::Value;
+use crate::theme::*;
+
+#[derive(Debug, Clone)]
+pub struct SchemaError {
+    pub path: String,
+    pub message: String,
+}
+
+pub fn schema_type_name(schema: &Value) -> String {
+    if schema.get("x-kubernetes-int-or-string").and_then(|v| v.as_bool()).unwrap_or(false) {
+        return "int-or-string".to_string();
+    }
+
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("array") => {
+            let item_type = schema.get("items").map(schema_type_name).unwrap_or_else(|| "any".to_string());
+            format!("[]{}", item_type)
+        }
+        Some("object") => {
+            if schema.get("properties").is_none() {
+                if let Some(additional) = schema.get("additionalProperties").filter(|a| a.is_object()) {
+                    return format!("map[string]{}", schema_type_name(additional));
+                }
+                if schema.get("x-kubernetes-preserve-unknown-fields").and_then(|v| v.as_bool()).unwrap_or(false) {
+                    return "object (free-form)".to_string();
+                }
+            }
+            "object".to_string()
+        }
+        Some(t) => t.to_string(),
+        None => {
+            if schema.get("properties").is_some() {
+                "object".to_string()
+            } else {
+                "any".to_string()
+            }
+        }
+    }
+}
+
+fn required_fields(schema: &Value) -> Vec<&str> {
+    schema.get("required")
+        .and_then(|r| r.as_array())
+        .map(|r| r.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default()
+}
+
+fn enum_values(schema: &Value) -> Option<String> {
+    let values = schema.get("enum")?.as_array()?;
+    let values: Vec<String> = values.iter().map(|v| match v {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }).collect();
+    Some(values.join(" | "))
+}
+
+fn child_schemas(schema: &Value) -> Option<&serde_json::Map<String, Value>> {
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("array") => schema.get("items").and_then(|i| i.get("properties")).and_then(|p| p.as_object()),
+        _ => schema.get("properties").and_then(|p| p.as_object()),
+    }
+}
+
+fn show_schema_field_label(ui: &mut egui::Ui, name: &str, schema: &Value, required: bool) {
+    ui.label(egui::RichText::new(name).color(DETAIL_COLOR));
+    ui.label(egui::RichText::new(schema_type_name(schema)).color(SECOND_DETAIL_COLOR));
+    if required {
+        ui.label(egui::RichText::new("required").color(WARNING_COLOR));
+    }
+    if let Some(values) = enum_values(schema) {
+        ui.label(egui::RichText::new(format!("enum: {}", values)).color(SEARCH_MATCH_COLOR));
+    }
+}
+
+/// Recursive tree of an OpenAPI v3 schema, nested objects are collapsible
+pub fn show_schema_tree(ui: &mut egui::Ui, name: &str, schema: &Value, required: bool, id_path: &str) {
+    let description = schema.get("description").and_then(|d| d.as_str());
+    let id = format!("{}.{}", id_path, name);
+
+    match child_schemas(schema) {
+        Some(children) if !children.is_empty() => {
+            let header = egui::CollapsingHeader::new(egui::RichText::new(format!("{} ({})", name, schema_type_name(schema))).color(DETAIL_COLOR))
+                .id_salt(&id)
+                .default_open(false)
+                .show(ui, |ui| {
+                    if let Some(desc) = description {
+                        ui.label(egui::RichText::new(desc).italics().color(egui::Color32::GRAY));
+                    }
+                    let item_schema = if schema.get("type").and_then(|t| t.as_str()) == Some("array") {
+                        schema.get("items").unwrap_or(schema)
+                    } else {
+                        schema
+                    };
+                    let required_children = required_fields(item_schema);
+                    for (child_name, child_schema) in children {
+                        show_schema_tree(ui, child_name, child_schema, required_children.contains(&child_name.as_str()), &id);
+                    }
+                });
+            if required {
+                header.header_response.on_hover_text("required");
+            }
+        }
+        _ => {
+            let response = ui.horizontal(|ui| {
+                show_schema_field_label(ui, name, schema, required);
+            }).response;
+            if let Some(desc) = description {
+                response.on_hover_text(desc);
+            }
+        }
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn value_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Validates value against an OpenAPI v3 schema.
+/// `resolve` is used for `$ref` lookups of the cluster OpenAPI document, CRD schemas never contain them.
+pub fn validate_value(value: &Value, schema: &Value, path: &str, resolve: &dyn Fn(&str) -> Option<Value>, errors: &mut Vec<SchemaError>) {
+    if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+        // Both accept plain numbers in manifests although published as strings
+        if reference.ends_with("intstr.IntOrString") || reference.ends_with("resource.Quantity") {
+            if !(value.is_null() || value.is_string() || value.is_number()) {
+                errors.push(SchemaError { path: path.to_string(), message: format!("expected number or string, got {}", value_type_name(value)) });
+            }
+            return;
+        }
+        if let Some(resolved) = resolve(reference) {
+            validate_value(value, &resolved, path, resolve, errors);
+        }
+        return;
+    }
+
+    if let Some(all_of) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for sub in all_of {
+            validate_value(value, sub, path, resolve, errors);
+        }
+    }
+
+    if value.is_null() {
+        return;
+    }
+
+    if schema.get("x-kubernetes-int-or-string").and_then(|v| v.as_bool()).unwrap_or(false)
+        || schema.get("format").and_then(|f| f.as_str()) == Some("int-or-string") {
+        if !(value.is_string() || value.is_i64() || value.is_u64()) {
+            errors.push(SchemaError { path: path.to_string(), message: format!("expected integer or string, got {}", value_type_name(value)) });
+        }
+        return;
+    }
+
+    if let Some(expected) = schema.get("type").and_then(|t| t.as_str()) {
+        let actual = value_type_name(value);
+        let type_ok = match expected {
+            "number" => value.is_number(),
+            other => other == actual,
+        };
+        if !type_ok {
+            errors.push(SchemaError { path: path.to_string(), message: format!("expected {}, got {}", expected, actual) });
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|e| e.as_array())
+        && !allowed.contains(value) {
+            let values = enum_values(schema).unwrap_or_default();
+            errors.push(SchemaError { path: path.to_string(), message: format!("value must be one of: {}", values) });
+        }
+
+    match value {
+        Value::Object(obj) => {
+            for req in required_fields(schema) {
+                if !obj.contains_key(req) {
+                    errors.push(SchemaError { path: join_path(path, req), message: "required field is missing".to_string() });
+                }
+            }
+
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            let additional = schema.get("additionalProperties");
+            let preserve_unknown = schema.get("x-kubernetes-preserve-unknown-fields").and_then(|v| v.as_bool()).unwrap_or(false);
+            // Embedded objects get apiVersion, kind and metadata without listing them
+            let embedded = schema.get("x-kubernetes-embedded-resource").and_then(|v| v.as_bool()).unwrap_or(false);
+
+            for (key, child) in obj {
+                let child_path = join_path(path, key);
+                if embedded && ["apiVersion", "kind", "metadata"].contains(&key.as_str()) {
+                    continue;
+                }
+                if let Some(child_schema) = properties.and_then(|p| p.get(key)) {
+                    validate_value(child, child_schema, &child_path, resolve, errors);
+                } else if let Some(additional_schema) = additional.filter(|a| a.is_object()) {
+                    validate_value(child, additional_schema, &child_path, resolve, errors);
+                } else if properties.is_some() && !preserve_unknown && additional.and_then(|a| a.as_bool()) != Some(true) {
+                    errors.push(SchemaError { path: child_path, message: "unknown field, pruned by the API server".to_string() });
+                }
+            }
+        }
+        Value::Array(arr) => {
+            if let Some(items) = schema.get("items") {
+                for (idx, item) in arr.iter().enumerate() {
+                    validate_value(item, items, &format!("{}[{}]", path, idx), resolve, errors);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Validates a whole resource, server managed parts (metadata, status) are skipped
+pub fn validate_resource(value: &Value, schema: &Value, resolve: &dyn Fn(&str) -> Option<Value>) -> Vec<SchemaError> {
+    let mut errors = Vec::new();
+
+    let mut resource = value.clone();
+    let mut root_schema = schema.clone();
+    for skipped in ["metadata", "status", "apiVersion", "kind"] {
+        if let Some(obj) = resource.as_object_mut() {
+            obj.remove(skipped);
+        }
+        if let Some(props) = root_schema.get_mut("properties").and_then(|p| p.as_object_mut()) {
+            props.remove(skipped);
+        }
+        if let Some(req) = root_schema.get_mut("required").and_then(|r| r.as_array_mut()) {
+            req.retain(|r| r.as_str() != Some(skipped));
+        }
+    }
+    validate_value(&resource, &root_schema, "", resolve, &mut errors);
+    errors
+}
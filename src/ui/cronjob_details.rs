@@ -1,26 +1,101 @@
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use egui::{Context, Key};
 use crate::functions::item_color;
-use crate::{ui::YamlEditorWindow};
+use crate::{ui::YamlEditorWindow, JobOutcome};
 use crate::theme::*;
 
 pub struct CronJobDetailsWindow {
     pub show: bool,
+    pub runs_to_show: usize,
+    pub shown_cronjob: Option<(String, Option<String>)>,
+    pub last_refresh: Option<Instant>,
+    /// Result of the last trigger/suspend action, true when it is an error
+    pub action_message: Arc<Mutex<Option<(String, bool)>>>,
 }
 
+/// Job history is refreshed while one of the jobs is still running
+const JOBS_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
 impl CronJobDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            runs_to_show: 5,
+            shown_cronjob: None,
+            last_refresh: None,
+            action_message: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+fn outcome_color(outcome: JobOutcome) -> egui::Color32 {
+    match outcome {
+        JobOutcome::Running => BLUE_BUTTON,
+        JobOutcome::Succeeded => GREEN_BUTTON,
+        JobOutcome::Failed => ERROR_MESSAGE_COLOR,
+    }
+}
+
+fn format_seconds(total_seconds: i64) -> String {
+    let total_seconds = total_seconds.max(0);
+    if total_seconds < 60 {
+        format!("{}s", total_seconds)
+    } else if total_seconds < 3600 {
+        format!("{}m {}s", total_seconds / 60, total_seconds % 60)
+    } else if total_seconds < 86400 {
+        format!("{}h {}m", total_seconds / 3600, (total_seconds % 3600) / 60)
+    } else {
+        format!("{}d {}h", total_seconds / 86400, (total_seconds % 86400) / 3600)
+    }
+}
+
+fn refresh_cronjob_details(client: &Arc<crate::Client>, details: &Arc<Mutex<crate::CronJobDetails>>, name: String, ns: Option<String>) {
+    let client = Arc::clone(client);
+    let details = Arc::clone(details);
+    tokio::spawn(async move {
+        if let Err(e) = crate::get_cronjob_details(client, &name, ns, details).await {
+            log::error!("Cronjob details fetch failed: {:?}", e);
+        }
+    });
+}
+
+/// Job pods are named `<job>-<suffix>`, the newest one is the most interesting
+fn open_job_logs(
+        job_name: &str,
+        ns: Option<String>,
+        pods: &Arc<Mutex<Vec<crate::PodItem>>>,
+        log_window: &Arc<Mutex<crate::LogWindow>>,
+        client: &Arc<crate::Client>,
+) -> bool {
+    let prefix = format!("{}-", job_name);
+    let pods_guard = pods.lock().unwrap();
+    let job_pod = pods_guard.iter()
+        .filter(|p| p.name.starts_with(&prefix) && p.namespace == ns)
+        .max_by_key(|p| p.creation_timestamp.as_ref().map(|t| t.0));
+    match job_pod {
+        Some(pod) => {
+            crate::open_logs_for_pod(
+                pod.name.clone(),
+                ns.unwrap_or_default(),
+                pod.containers.clone(),
+                Arc::clone(log_window),
+                Arc::clone(client),
+            );
+            true
         }
+        None => false,
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn show_cronjob_details_window(
     ctx: &Context,
     cronjob_details_window: &mut CronJobDetailsWindow,
     details: Arc<Mutex<crate::CronJobDetails>>,
     cronjobs: Arc<Mutex<Vec<crate::CronJobItem>>>,
+    pods: Arc<Mutex<Vec<crate::PodItem>>>,
+    log_window: Arc<Mutex<crate::LogWindow>>,
     yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
     client: Arc<crate::Client>,
     delete_confirm: &mut super::DeleteConfirmation,
@@ -30,18 +105,71 @@ pub fn show_cronjob_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "CronJob details", &mut cronjob_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_cronjob_details(client, &name, ns, details).await {
+                    log::error!("Cronjob details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let cronjob_item = guard_cronjobs.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if cronjob_item.is_none() {
         return;
     }
     let cur_ns = &cronjob_item.unwrap().namespace;
+    let cur_name = guard_details.name.clone().unwrap();
+
+    let current = (cur_name.clone(), cur_ns.clone());
+    if cronjob_details_window.shown_cronjob.as_ref() != Some(&current) {
+        cronjob_details_window.shown_cronjob = Some(current);
+        cronjob_details_window.last_refresh = Some(Instant::now());
+        *cronjob_details_window.action_message.lock().unwrap() = None;
+    }
+    if guard_details.jobs.iter().any(|j| j.outcome == JobOutcome::Running)
+        && cronjob_details_window.last_refresh.is_none_or(|t| t.elapsed() > JOBS_REFRESH_INTERVAL) {
+            cronjob_details_window.last_refresh = Some(Instant::now());
+            refresh_cronjob_details(&client, &details, cur_name.clone(), cur_ns.clone());
+        }
+    let action_message = Arc::clone(&cronjob_details_window.action_message);
+    let runs_to_show = &mut cronjob_details_window.runs_to_show;
 
     let response = egui::Window::new("CronJob details").min_width(800.0).collapsible(false).resizable(true).open(&mut cronjob_details_window.show).show(ctx, |ui| {
         ui.horizontal(|ui| {
-            if ui.button(egui::RichText::new("📃 Logs").size(16.0).color(crate::GRAY_BUTTON)).clicked() {
-                // TODO
-                log::warn!("TODO! Not implemented yet for cronjob");
-            }
+            let latest_job = guard_details.jobs.first().map(|j| j.name.clone());
+            if ui.add_enabled(latest_job.is_some(), egui::Button::new(egui::RichText::new("📃 Logs").size(16.0).color(crate::GRAY_BUTTON)))
+                .on_hover_text("Logs of the most recent job")
+                .clicked()
+                && let Some(job_name) = latest_job
+                && !open_job_logs(&job_name, cur_ns.clone(), &pods, &log_window, &client) {
+                    *action_message.lock().unwrap() = Some((format!("No pods left for job {}", job_name), true));
+                }
+
+            if ui.button(egui::RichText::new("▶ Trigger now").size(16.0).color(crate::BLUE_BUTTON))
+                .on_hover_text("Create a Job from the jobTemplate, like kubectl create job --from=cronjob/...")
+                .clicked() {
+                    let name = cur_name.clone();
+                    let ns = cur_ns.clone();
+                    let client = Arc::clone(&client);
+                    let details = Arc::clone(&details);
+                    let action_message = Arc::clone(&action_message);
+                    tokio::spawn(async move {
+                        match crate::trigger_cronjob(Arc::clone(&client), &name, ns.as_deref().unwrap_or("default")).await {
+                            Ok(job_name) => {
+                                *action_message.lock().unwrap() = Some((format!("Created job {}", job_name), false));
+                                refresh_cronjob_details(&client, &details, name, ns);
+                            }
+                            Err(e) => {
+                                *action_message.lock().unwrap() = Some((format!("Failed to trigger cronJob: {}", e), true));
+                            }
+                        }
+                    });
+                }
 
             if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
                 crate::edit_yaml_for::<k8s_openapi::api::batch::v1::CronJob>(
@@ -80,6 +208,8 @@ pub fn show_cronjob_details_window(
                 let name = guard_details.name.clone().unwrap();
                 let ns = cur_ns.clone();
 
+                let client = Arc::clone(&client);
+
                 delete_confirm.request(name.clone(), ns.clone(), move || {
                     tokio::spawn(async move {
                         if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::batch::v1::CronJob>(
@@ -93,6 +223,9 @@ pub fn show_cronjob_details_window(
                 });
             }
         });
+        if let Some((message, is_error)) = action_message.lock().unwrap().clone() {
+            ui.label(egui::RichText::new(message).color(if is_error { ERROR_MESSAGE_COLOR } else { GREEN_BUTTON }));
+        }
         ui.separator();
         egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
             egui::Grid::new("cronjob_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
@@ -143,6 +276,91 @@ pub fn show_cronjob_details_window(
                 }
             });
 
+            ui.separator();
+            ui.heading(egui::RichText::new("Schedule:").color(ROW_NAME_COLOR));
+            egui::Grid::new("cronjob_details_schedule_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                ui.label(egui::RichText::new("Expression:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&guard_details.schedule).monospace().color(DETAIL_COLOR));
+                ui.end_row();
+
+                ui.label(egui::RichText::new("Time zone:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(guard_details.time_zone.clone().unwrap_or_else(|| "controller local time (assumed UTC)".to_string())).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(policy) = &guard_details.concurrency_policy {
+                    ui.label(egui::RichText::new("Concurrency policy:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(policy).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.label(egui::RichText::new("History limits:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(format!(
+                    "{} successful, {} failed",
+                    guard_details.successful_jobs_history_limit.unwrap_or(3),
+                    guard_details.failed_jobs_history_limit.unwrap_or(1),
+                )).color(DETAIL_COLOR));
+                ui.end_row();
+            });
+            if let Some(error) = &guard_details.zone_error {
+                ui.label(egui::RichText::new(error).color(WARNING_COLOR));
+            }
+            if let Some(error) = &guard_details.schedule_error {
+                ui.label(egui::RichText::new(format!("Cannot parse schedule: {}", error)).color(ERROR_MESSAGE_COLOR));
+            }
+            if let (Some(cron), Some(zone)) = (&guard_details.cron, &guard_details.zone) {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Next runs:").color(ROW_NAME_COLOR));
+                    ui.add(egui::DragValue::new(runs_to_show).range(1..=50));
+                    if cronjob_item.unwrap().suspend == "true" {
+                        ui.label(egui::RichText::new("(suspended, these runs will be skipped)").color(WARNING_COLOR));
+                    }
+                });
+                let now = k8s_openapi::jiff::Timestamp::now();
+                let runs = cron.next_runs(zone, now, *runs_to_show);
+                if runs.is_empty() {
+                    ui.label(egui::RichText::new("The schedule never matches").color(WARNING_COLOR));
+                }
+                egui::Grid::new("cronjob_details_next_runs_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                    for run in runs {
+                        ui.label(egui::RichText::new(run.strftime("%Y-%m-%d %H:%M %Z").to_string()).monospace().color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(format!("in {}", format_seconds(run.timestamp().as_second() - now.as_second()))).color(SECOND_DETAIL_COLOR));
+                        ui.end_row();
+                    }
+                });
+            }
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Job history:").color(ROW_NAME_COLOR));
+            if guard_details.jobs.is_empty() {
+                ui.label(egui::RichText::new("No jobs owned by this CronJob").color(SECOND_DETAIL_COLOR));
+            } else {
+                egui::Grid::new("cronjob_details_jobs_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                    ui.label(egui::RichText::new("Job").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Started").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Duration").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Outcome").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Logs").color(PODS_HEAD_GRID_COLOR));
+                    ui.end_row();
+
+                    for job in &guard_details.jobs {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(&job.name).color(POD_NAME_COLUMN_COLOR));
+                            if job.manual {
+                                ui.label(egui::RichText::new("manual").color(ORANGE_BUTTON)).on_hover_text("Created with Trigger now or kubectl create job --from");
+                            }
+                        });
+                        ui.label(job.start.as_ref().map(|t| format!("{} ago", crate::format_age(t))).unwrap_or_else(|| "-".to_string()));
+                        ui.label(job.duration_seconds().map(format_seconds).unwrap_or_else(|| "-".to_string()));
+                        ui.label(egui::RichText::new(format!("{:?}", job.outcome)).color(outcome_color(job.outcome)));
+                        if ui.button(egui::RichText::new("📃").color(crate::GRAY_BUTTON)).on_hover_text("Open logs").clicked()
+                            && !open_job_logs(&job.name, cur_ns.clone(), &pods, &log_window, &client) {
+                                *action_message.lock().unwrap() = Some((format!("No pods left for job {}", job.name), true));
+                            }
+                        ui.end_row();
+                    }
+                });
+            }
+
             ui.separator();
             ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
             if !guard_details.events.is_empty() {
@@ -6,12 +6,18 @@ use crate::theme::*;
 
 pub struct DaemonSetDetailsWindow {
     pub show: bool,
+    pub diff_revision: Option<i64>,
+    pub missing_only: bool,
+    pub action_error: Arc<Mutex<Option<String>>>,
 }
 
 impl DaemonSetDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            diff_revision: None,
+            missing_only: false,
+            action_error: Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -30,11 +36,29 @@ pub fn show_daemonset_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "DaemonSet details", &mut daemonset_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_daemonset_details(client, &name, ns, details).await {
+                    log::error!("Daemonset details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let daemonset_item = guard_daemonsets.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if daemonset_item.is_none() {
         return;
     }
     let cur_ns = &daemonset_item.unwrap().namespace;
+    let cur_name = guard_details.name.clone().unwrap();
+    // The DaemonSet controller always runs the newest ControllerRevision
+    let current_revision = guard_details.revisions.first().map(|r| r.revision);
+    let mut rollback_to = None;
+    let action_error = Arc::clone(&daemonset_details_window.action_error);
 
     let response = egui::Window::new("DaemonSet details").min_width(800.0).collapsible(false).resizable(true).open(&mut daemonset_details_window.show).show(ctx, |ui| {
         ui.horizontal(|ui| {
@@ -55,6 +79,7 @@ pub fn show_daemonset_details_window(
             if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
                 let name = guard_details.name.clone().unwrap();
                 let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
 
                 delete_confirm.request(name.clone(), ns.clone(), move || {
                     tokio::spawn(async move {
@@ -69,6 +94,9 @@ pub fn show_daemonset_details_window(
                 });
             }
         });
+        if let Some(error) = action_error.lock().unwrap().clone() {
+            ui.label(egui::RichText::new(error).color(ERROR_MESSAGE_COLOR));
+        }
         ui.separator();
         egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
             egui::Grid::new("daemonset_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
@@ -124,6 +152,38 @@ pub fn show_daemonset_details_window(
                 }
             });
 
+            ui.separator();
+            let covered = guard_details.coverage.iter().filter(|c| c.pod.is_some()).count();
+            ui.horizontal(|ui| {
+                ui.heading(egui::RichText::new("Node coverage:").color(ROW_NAME_COLOR));
+                let color = if covered == guard_details.coverage.len() { GREEN_BUTTON } else { WARNING_COLOR };
+                ui.label(egui::RichText::new(format!("{} of {} nodes run a pod", covered, guard_details.coverage.len())).color(color));
+                ui.checkbox(&mut daemonset_details_window.missing_only, "Only nodes without a pod");
+            });
+            egui::Grid::new("daemonset_details_coverage_grid").striped(true).min_col_width(20.0).max_col_width(500.0).show(ui, |ui| {
+                ui.label(egui::RichText::new("Node").color(PODS_HEAD_GRID_COLOR));
+                ui.label(egui::RichText::new("Pod").color(PODS_HEAD_GRID_COLOR));
+                ui.label(egui::RichText::new("Reason").color(PODS_HEAD_GRID_COLOR));
+                ui.end_row();
+                for item in guard_details.coverage.iter().filter(|c| !daemonset_details_window.missing_only || c.pod.is_none()) {
+                    ui.label(egui::RichText::new(&item.node).color(ITEM_NAME_COLOR));
+                    match &item.pod {
+                        Some(pod) => {
+                            ui.label(egui::RichText::new(pod).color(if item.pod_ready { GREEN_BUTTON } else { WARNING_COLOR }));
+                        }
+                        None => {
+                            ui.label(egui::RichText::new("-").color(GRAY_BUTTON));
+                        }
+                    }
+                    ui.label(egui::RichText::new(item.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Revision history:").color(ROW_NAME_COLOR));
+            rollback_to = crate::show_revision_history(ui, "daemonset", &guard_details.revisions, current_revision, &mut daemonset_details_window.diff_revision);
+
             ui.separator();
             ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
             if !guard_details.events.is_empty() {
@@ -138,6 +198,19 @@ pub fn show_daemonset_details_window(
             }
         });
     });
+    if let Some(revision) = rollback_to
+        && let Some(target) = guard_details.revisions.iter().find(|r| r.revision == revision) {
+            let client_clone = Arc::clone(&client);
+            let details_clone = Arc::clone(&details);
+            let (name, ns) = (cur_name.clone(), cur_ns.clone());
+            crate::request_controller_rollback(delete_confirm, &client, crate::ScaleTarget::DaemonSet, cur_name.clone(), cur_ns.clone(), target, action_error, move || {
+                tokio::spawn(async move {
+                    if let Err(e) = crate::get_daemonset_details(client_clone, &name, ns, details_clone).await {
+                        log::error!("Daemonset details fetch failed: {:?}", e);
+                    }
+                });
+            });
+        }
     crate::show_delete_confirmation(ctx, delete_confirm);
 
     if let Some(inner_response) = response
@@ -0,0 +1,219 @@
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
+use egui::{Context, Key, RichText, ScrollArea, TextStyle};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use crate::theme::*;
+
+pub enum DebugTarget {
+    /// Ephemeral container added to a running pod, optionally sharing the process namespace of `target_container`
+    Pod { pod: String, namespace: String, image: String, target_container: Option<String> },
+    /// Privileged hostPID pod pinned to the node
+    Node { node: String, image: String },
+}
+
+#[derive(Clone, PartialEq)]
+pub enum DebugStatus {
+    Starting(String),
+    Attached,
+    Closed(String),
+    Failed(String),
+}
+
+/// Container the session is attached to, known once the debug container/pod is created
+#[derive(Clone)]
+pub struct DebugContainer {
+    pub pod: String,
+    pub namespace: String,
+    pub container: String,
+    /// Node debug pods are deleted when the session closes, ephemeral containers cannot be removed from a pod
+    pub delete_pod_on_close: bool,
+}
+
+pub struct DebugSessionWindow {
+    pub show: bool,
+    pub title: String,
+    pub status: Arc<Mutex<DebugStatus>>,
+    pub container: Arc<Mutex<Option<DebugContainer>>>,
+    pub output: Arc<Mutex<String>>,
+    pub input: String,
+    pub stdin: Option<UnboundedSender<Vec<u8>>>,
+    /// Set when the session is closed before the debug container/pod exists
+    pub cancelled: Arc<AtomicBool>,
+}
+
+impl DebugSessionWindow {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            title: String::new(),
+            status: Arc::new(Mutex::new(DebugStatus::Closed(String::new()))),
+            container: Arc::new(Mutex::new(None)),
+            output: Arc::new(Mutex::new(String::new())),
+            input: String::new(),
+            stdin: None,
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+/// Creates the debug container or pod, waits for it to run and attaches to it. A previous session is cleaned up first.
+pub fn start_debug_session(window: &mut DebugSessionWindow, target: DebugTarget, client: Arc<crate::Client>) {
+    close_debug_session(window, &client);
+
+    let (tx, rx) = unbounded_channel();
+    window.stdin = Some(tx);
+    window.status = Arc::new(Mutex::new(DebugStatus::Starting("Creating debug container...".to_string())));
+    window.container = Arc::new(Mutex::new(None));
+    window.cancelled = Arc::new(AtomicBool::new(false));
+    window.output = Arc::new(Mutex::new(String::new()));
+    window.input.clear();
+    window.title = match &target {
+        DebugTarget::Pod { pod, .. } => format!("Debug pod {}", pod),
+        DebugTarget::Node { node, .. } => format!("Debug node {}", node),
+    };
+    window.show = true;
+
+    let status = Arc::clone(&window.status);
+    let container = Arc::clone(&window.container);
+    let output = Arc::clone(&window.output);
+    let cancelled = Arc::clone(&window.cancelled);
+    tokio::spawn(async move {
+        let created = match target {
+            DebugTarget::Pod { pod, namespace, image, target_container } => {
+                crate::add_ephemeral_container(Arc::clone(&client), &pod, &namespace, &image, target_container.as_deref()).await
+                    .map(|name| DebugContainer { pod, namespace, container: name, delete_pod_on_close: false })
+            }
+            DebugTarget::Node { node, image } => {
+                crate::create_node_debug_pod(Arc::clone(&client), &node, &image).await
+                    .map(|(pod, namespace)| DebugContainer { pod, namespace, container: "debugger".to_string(), delete_pod_on_close: true })
+            }
+        };
+        let debug = match created {
+            Ok(debug) => debug,
+            Err(e) => {
+                *status.lock().unwrap() = DebugStatus::Failed(format!("Failed to create debug container: {}", e));
+                return;
+            }
+        };
+        {
+            // Checked under the container lock, close either sees the container or we see the cancel
+            let mut container = container.lock().unwrap();
+            if cancelled.load(Ordering::SeqCst) {
+                drop(container);
+                if debug.delete_pod_on_close
+                    && let Err(e) = crate::delete_pod(client, debug.pod.clone(), Some(&debug.namespace), true).await {
+                        log::error!("Failed to delete debug pod {}: {}", debug.pod, e);
+                    }
+                return;
+            }
+            *container = Some(debug.clone());
+        }
+
+        *status.lock().unwrap() = DebugStatus::Starting(format!("Waiting for {} in pod {} to start...", debug.container, debug.pod));
+        if let Err(e) = crate::wait_for_container_running(Arc::clone(&client), &debug.pod, &debug.namespace, &debug.container).await {
+            *status.lock().unwrap() = DebugStatus::Failed(e);
+            return;
+        }
+
+        *status.lock().unwrap() = DebugStatus::Attached;
+        let result = crate::attach_container(client, &debug.pod, &debug.namespace, &debug.container, output, rx).await;
+        *status.lock().unwrap() = match result {
+            Ok(_) => DebugStatus::Closed("Session ended".to_string()),
+            Err(e) => DebugStatus::Failed(format!("Attach failed: {}", e)),
+        };
+    });
+}
+
+/// Exits the shell and deletes the node debug pod, if any
+pub fn close_debug_session(window: &mut DebugSessionWindow, client: &Arc<crate::Client>) {
+    window.cancelled.store(true, Ordering::SeqCst);
+    if let Some(stdin) = window.stdin.take() {
+        // Dropping the sender afterwards detaches; the exit stops the process so the container does not linger
+        let _ = stdin.send(b"exit\n".to_vec());
+    }
+    if let Some(debug) = window.container.lock().unwrap().take()
+        && debug.delete_pod_on_close {
+            let client = Arc::clone(client);
+            tokio::spawn(async move {
+                if let Err(e) = crate::delete_pod(client, debug.pod.clone(), Some(&debug.namespace), true).await {
+                    log::error!("Failed to delete debug pod {}: {}", debug.pod, e);
+                }
+            });
+        }
+}
+
+pub fn show_debug_session_window(ctx: &Context, window: &mut DebugSessionWindow, client: Arc<crate::Client>) {
+    let status = window.status.lock().unwrap().clone();
+    let debug = window.container.lock().unwrap().clone();
+    let mut open = window.show;
+
+    let response = egui::Window::new(&window.title).id(egui::Id::new("debug_session_window")).min_width(800.0).collapsible(false).resizable(true).open(&mut open).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            match &status {
+                DebugStatus::Starting(message) => {
+                    ui.spinner();
+                    ui.label(RichText::new(message).color(SECOND_DETAIL_COLOR));
+                }
+                DebugStatus::Attached => {
+                    ui.label(RichText::new("● Attached").color(GREEN_BUTTON));
+                }
+                DebugStatus::Closed(message) => {
+                    ui.label(RichText::new(message).color(WARNING_COLOR));
+                }
+                DebugStatus::Failed(message) => {
+                    ui.label(RichText::new(message).color(ERROR_MESSAGE_COLOR));
+                }
+            }
+            if let Some(debug) = &debug {
+                ui.separator();
+                ui.label(RichText::new(format!("{}/{} container {}", debug.namespace, debug.pod, debug.container)).color(DETAIL_COLOR));
+            }
+        });
+        if let Some(debug) = &debug {
+            let cleanup = if debug.delete_pod_on_close {
+                "The debug pod is deleted when this window is closed"
+            } else {
+                "Ephemeral containers stay in the pod spec, the shell is exited when this window is closed"
+            };
+            ui.label(RichText::new(cleanup).color(TOLERATIONS_HEAD_GRID_COLOR));
+        }
+        ui.separator();
+
+        let output = window.output.lock().unwrap().clone();
+        ScrollArea::vertical().max_height(450.0).auto_shrink(false).stick_to_bottom(true).show(ui, |ui| {
+            ui.add(egui::Label::new(RichText::new(output).text_style(TextStyle::Monospace).color(DETAIL_COLOR)).wrap());
+        });
+        ui.separator();
+
+        let attached = status == DebugStatus::Attached;
+        ui.horizontal(|ui| {
+            let edit = ui.add_enabled(attached, egui::TextEdit::singleline(&mut window.input)
+                .font(TextStyle::Monospace)
+                .desired_width(600.0)
+                .hint_text("Command, Enter to send"));
+            let send = edit.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+            if (send || ui.add_enabled(attached, egui::Button::new("⏎ Send")).clicked())
+                && let Some(stdin) = &window.stdin {
+                    let mut line = std::mem::take(&mut window.input);
+                    line.push('\n');
+                    let _ = stdin.send(line.into_bytes());
+                    edit.request_focus();
+                }
+            if ui.add_enabled(attached, egui::Button::new("Ctrl+C")).on_hover_text("Interrupt the running command").clicked()
+                && let Some(stdin) = &window.stdin {
+                    let _ = stdin.send(vec![0x03]);
+                }
+            if ui.button("🗑 Clear").clicked() {
+                window.output.lock().unwrap().clear();
+            }
+        });
+    });
+
+    if let Some(inner_response) = &response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            open = false;
+        }
+    if !open {
+        close_debug_session(window, &client);
+    }
+    window.show = open;
+}
@@ -1,19 +1,50 @@
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use egui::{Context, Key};
-use crate::{functions::item_color, theme::*, ui::YamlEditorWindow};
+use crate::{functions::item_color, theme::*, ui::YamlEditorWindow, RolloutState};
 
 pub struct DeploymentDetailsWindow {
     pub show: bool,
+    pub diff_revision: Option<i64>,
+    pub shown_deployment: Option<(String, Option<String>)>,
+    pub last_refresh: Option<Instant>,
+    pub action_error: Arc<Mutex<Option<String>>>,
 }
 
+/// Details are fetched once, while a rollout is in progress they are refreshed to follow it
+const ROLLOUT_REFRESH_INTERVAL: Duration = Duration::from_secs(3);
+
 impl DeploymentDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            diff_revision: None,
+            shown_deployment: None,
+            last_refresh: None,
+            action_error: Arc::new(Mutex::new(None)),
         }
     }
 }
 
+fn rollout_color(state: RolloutState) -> egui::Color32 {
+    match state {
+        RolloutState::Complete => GREEN_BUTTON,
+        RolloutState::Progressing => BLUE_BUTTON,
+        RolloutState::Paused => WARNING_COLOR,
+        RolloutState::Failed => ERROR_MESSAGE_COLOR,
+    }
+}
+
+fn refresh_deployment_details(client: &Arc<crate::Client>, details: &Arc<Mutex<crate::DeploymentDetails>>, name: String, ns: Option<String>) {
+    let client = Arc::clone(client);
+    let details = Arc::clone(details);
+    tokio::spawn(async move {
+        if let Err(e) = crate::get_deployment_details(client, &name, ns, details).await {
+            log::error!("Deployment details fetch failed: {:?}", e);
+        }
+    });
+}
+
 pub fn show_deployment_details_window(
     ctx: &Context,
     deployment_details_window: &mut DeploymentDetailsWindow,
@@ -28,11 +59,42 @@ pub fn show_deployment_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Deployment details", &mut deployment_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_deployment_details(client, &name, ns, details).await {
+                    log::error!("Deployment details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let deployment_item = guard_deployments.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if deployment_item.is_none() {
         return;
     }
     let cur_ns = &deployment_item.unwrap().namespace;
+    let cur_name = guard_details.name.clone().unwrap();
+
+    let current = (cur_name.clone(), cur_ns.clone());
+    if deployment_details_window.shown_deployment.as_ref() != Some(&current) {
+        deployment_details_window.shown_deployment = Some(current);
+        deployment_details_window.diff_revision = None;
+        deployment_details_window.last_refresh = Some(Instant::now());
+        *deployment_details_window.action_error.lock().unwrap() = None;
+    }
+
+    let (rollout_state, rollout_message) = guard_details.rollout.state(&guard_details.conditions);
+    if rollout_state == RolloutState::Progressing
+        && deployment_details_window.last_refresh.is_none_or(|t| t.elapsed() > ROLLOUT_REFRESH_INTERVAL) {
+            deployment_details_window.last_refresh = Some(Instant::now());
+            refresh_deployment_details(&client, &details, cur_name.clone(), cur_ns.clone());
+        }
+    let mut rollback_to = None;
+    let action_error = Arc::clone(&deployment_details_window.action_error);
 
     let response = egui::Window::new("Deployment details").min_width(800.0).collapsible(false).resizable(true).open(&mut deployment_details_window.show).show(ctx, |ui| {
         ui.horizontal(|ui| {
@@ -49,9 +111,31 @@ pub fn show_deployment_details_window(
                 );
             }
 
+            let paused = guard_details.rollout.paused;
+            let (pause_label, pause_color) = if paused { ("▶ Resume", crate::GREEN_BUTTON) } else { ("⏸ Pause", crate::ORANGE_BUTTON) };
+            if ui.button(egui::RichText::new(pause_label).size(16.0).color(pause_color)).on_hover_text("Pause or resume the rollout of template changes").clicked() {
+                let name = cur_name.clone();
+                let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
+                let details = Arc::clone(&details);
+                let action_error = Arc::clone(&action_error);
+                tokio::spawn(async move {
+                    match crate::set_deployment_paused(Arc::clone(&client), &name, ns.as_deref().unwrap_or("default"), !paused).await {
+                        Ok(_) => {
+                            *action_error.lock().unwrap() = None;
+                            refresh_deployment_details(&client, &details, name, ns);
+                        }
+                        Err(e) => {
+                            *action_error.lock().unwrap() = Some(format!("Failed to {} rollout: {}", if paused { "resume" } else { "pause" }, e));
+                        }
+                    }
+                });
+            }
+
             if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
                 let name = guard_details.name.clone().unwrap();
                 let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
 
                 delete_confirm.request(name.clone(), ns.clone(), move || {
                     tokio::spawn(async move {
@@ -66,6 +150,9 @@ pub fn show_deployment_details_window(
                 });
             }
         });
+        if let Some(error) = action_error.lock().unwrap().clone() {
+            ui.label(egui::RichText::new(error).color(ERROR_MESSAGE_COLOR));
+        }
         ui.separator();
         egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
             egui::Grid::new("deployment_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
@@ -94,12 +181,37 @@ pub fn show_deployment_details_window(
                     ui.end_row();
                 }
 
+                ui.label(egui::RichText::new("Rollout:").color(ROW_NAME_COLOR));
+                ui.horizontal(|ui| {
+                    if rollout_state == RolloutState::Progressing {
+                        ui.spinner();
+                    }
+                    ui.label(egui::RichText::new(&rollout_message).color(rollout_color(rollout_state)));
+                });
+                ui.end_row();
+
+                let rollout = &guard_details.rollout;
+                ui.label(egui::RichText::new("Generation:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(format!("{} (observed {})", rollout.generation, rollout.observed_generation)).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(deadline) = rollout.progress_deadline_seconds {
+                    ui.label(egui::RichText::new("Progress deadline:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(format!("{}s", deadline)).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
                 if !guard_details.conditions.is_empty() {
                     ui.separator(); ui.separator(); ui.end_row();
                     ui.label(egui::RichText::new("Conditions:").color(ROW_NAME_COLOR));
                     egui::Grid::new("deployment_details_conditions_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
                         for item in guard_details.conditions.iter() {
-                            ui.label(egui::RichText::new(&item.type_).color(item_color(&item.type_)));
+                            let color = if item.status == "True" { item_color(&item.type_) } else { WARNING_COLOR };
+                            ui.label(egui::RichText::new(&item.type_).color(color));
+                            ui.label(egui::RichText::new(&item.status).color(color));
+                            ui.label(egui::RichText::new(item.reason.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(item.message.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                            ui.label(egui::RichText::new(item.last_transition_time.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
                             ui.end_row();
                         }
                     });
@@ -156,6 +268,10 @@ pub fn show_deployment_details_window(
                 }
             });
 
+            ui.separator();
+            ui.heading(egui::RichText::new("Revision history:").color(ROW_NAME_COLOR));
+            rollback_to = crate::show_revision_history(ui, "deployment", &guard_details.revisions, guard_details.current_revision, &mut deployment_details_window.diff_revision);
+
             ui.separator();
             ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
             if !guard_details.events.is_empty() {
@@ -170,6 +286,31 @@ pub fn show_deployment_details_window(
             }
         });
     });
+    if let Some(revision) = rollback_to
+        && let Some(target) = guard_details.revisions.iter().find(|r| r.revision == revision) {
+            let name = cur_name.clone();
+            let ns = cur_ns.clone();
+            let replicaset = target.source.clone();
+            let client = Arc::clone(&client);
+            let details = Arc::clone(&details);
+            delete_confirm.request_action(
+                "roll back".to_string(),
+                format!("Roll back deployment \"{}\" to revision {} ({})?", name, revision, replicaset),
+                move || {
+                    tokio::spawn(async move {
+                        match crate::rollback_deployment(Arc::clone(&client), &name, ns.as_deref().unwrap_or("default"), &replicaset).await {
+                            Ok(_) => {
+                                *action_error.lock().unwrap() = None;
+                                refresh_deployment_details(&client, &details, name, ns);
+                            }
+                            Err(e) => {
+                                *action_error.lock().unwrap() = Some(format!("Rollback to revision {} failed: {}", revision, e));
+                            }
+                        }
+                    });
+                },
+            );
+        }
     crate::show_delete_confirmation(ctx, delete_confirm);
 
     if let Some(inner_response) = response
@@ -1,5 +1,5 @@
 use std::sync::{Arc, Mutex};
-use egui::{Context, Key};
+use egui::{Color32, Context, Key};
 use crate::functions::item_color;
 use crate::{ui::YamlEditorWindow};
 use crate::theme::*;
@@ -16,56 +16,72 @@ impl EndpointDetailsWindow {
     }
 }
 
+fn condition_label(ui: &mut egui::Ui, value: bool, good: bool) {
+    let color = if value == good { GREEN_BUTTON } else { ERROR_MESSAGE_COLOR };
+    ui.label(egui::RichText::new(if value { "✔" } else { "✖" }).color(color));
+}
+
+fn optional_label(ui: &mut egui::Ui, value: Option<&str>) {
+    match value {
+        Some(value) if !value.is_empty() => ui.label(egui::RichText::new(value).color(DETAIL_COLOR)),
+        _ => ui.label(egui::RichText::new("-").color(Color32::GRAY)),
+    };
+}
+
 pub fn show_endpoint_details_window(
     ctx: &Context,
     endpoint_details_window: &mut EndpointDetailsWindow,
     details: Arc<Mutex<crate::EndpointDetails>>,
-    endpoints: Arc<Mutex<Vec<crate::EndpointItem>>>,
+    service_endpoints: &[crate::ServiceEndpoints],
     yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
     client: Arc<crate::Client>,
-    delete_confirm: &mut super::DeleteConfirmation,
 ) {
     let guard_details = details.lock().unwrap(); // More detailed info
-    let guard_endpoints = endpoints.lock().unwrap(); // Endpoints with base details already we have
     if guard_details.name.is_none() {
         return;
     }
-    let endpoint_item = guard_endpoints.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
-    if endpoint_item.is_none() {
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Endpoint details", &mut endpoint_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_endpoint_details(client, &name, ns, details).await {
+                    log::error!("Endpoint details fetch failed: {:?}", e);
+                }
+            });
+        }
         return;
     }
-    let cur_ns = &endpoint_item.unwrap().namespace;
+    // Taken from the watched slices so endpoint conditions stay live
+    let name = guard_details.name.clone().unwrap();
+    let service_endpoints = service_endpoints.iter()
+        .find(|item| item.name == name && item.namespace == guard_details.namespace);
+    let cur_ns = guard_details.namespace.clone().unwrap_or_else(|| "default".to_string());
 
     let response = egui::Window::new("Endpoint details").min_width(800.0).collapsible(false).resizable(true).open(&mut endpoint_details_window.show).show(ctx, |ui| {
         ui.horizontal(|ui| {
-            if ui.button(egui::RichText::new("📃 Logs").size(16.0).color(crate::GRAY_BUTTON)).clicked() {
-                // TODO
-                log::warn!("TODO! Not implemented yet");
-            }
-
-            if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
-                crate::edit_yaml_for::<k8s_openapi::api::core::v1::Endpoints>(
-                    guard_details.name.clone().unwrap(),
-                    cur_ns.to_owned().unwrap(),
+            if guard_details.service_found && ui.button(egui::RichText::new("✏ Edit Service").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
+                crate::edit_yaml_for::<k8s_openapi::api::core::v1::Service>(
+                    name.clone(),
+                    cur_ns.clone(),
                     Arc::clone(&yaml_editor_window),
                     Arc::clone(&client),
                 );
             }
-
-            if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
-                let name = guard_details.name.clone().unwrap();
-                let ns = cur_ns.clone();
-
-                delete_confirm.request(name.clone(), ns.clone(), move || {
-                    tokio::spawn(async move {
-                        if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::Endpoints>(
-                            name,
-                            ns.as_deref(),
-                            Arc::clone(&client),
-                        ).await {
-                            log::error!("Failed to delete endpoint: {}", err);
+            if let Some(item) = &service_endpoints {
+                ui.menu_button(egui::RichText::new("✏ Edit slice").size(16.0).color(crate::GREEN_BUTTON), |ui| {
+                    for slice in &item.slices {
+                        if ui.button(slice).clicked() {
+                            crate::edit_yaml_for::<k8s_openapi::api::discovery::v1::EndpointSlice>(
+                                slice.clone(),
+                                cur_ns.clone(),
+                                Arc::clone(&yaml_editor_window),
+                                Arc::clone(&client),
+                            );
+                            ui.close_kind(egui::UiKind::Menu);
                         }
-                    });
+                    }
                 });
             }
         });
@@ -73,11 +89,19 @@ pub fn show_endpoint_details_window(
         egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
             egui::Grid::new("endpoint_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
 
-                ui.label(egui::RichText::new("Endpoint name:").color(ROW_NAME_COLOR));
-                ui.label(egui::RichText::new(guard_details.name.clone().unwrap()).color(DETAIL_COLOR));
+                ui.label(egui::RichText::new("Service:").color(ROW_NAME_COLOR));
+                if guard_details.service_found {
+                    ui.label(egui::RichText::new(&name).color(DETAIL_COLOR));
+                } else {
+                    ui.label(egui::RichText::new(format!("{} (no Service, slices are managed elsewhere)", name)).color(WARNING_COLOR));
+                }
+                ui.end_row();
+
+                ui.label(egui::RichText::new("Namespace:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&cur_ns).color(DETAIL_COLOR));
                 ui.end_row();
 
-                if let Some(item) = endpoint_item {
+                if let Some(item) = &service_endpoints {
                     if let Some(creation_timestamp) = &item.creation_timestamp {
                         ui.label(egui::RichText::new("Creation time:").color(ROW_NAME_COLOR));
                         let timestamp_text = format!("{}, {} ago", creation_timestamp.0, crate::format_age(creation_timestamp));
@@ -85,11 +109,40 @@ pub fn show_endpoint_details_window(
                         ui.end_row();
                     }
 
-                    if let Some(namespace) = &item.namespace {
-                        ui.label(egui::RichText::new("Namespace:").color(ROW_NAME_COLOR));
-                        ui.label(egui::RichText::new(namespace).color(DETAIL_COLOR));
+                    ui.label(egui::RichText::new("Endpoints:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(format!("{} ready, {} not ready, {} terminating", item.ready(), item.not_ready(), item.terminating())).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Address families:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(item.address_types.iter().cloned().collect::<Vec<_>>().join(", ")).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Ports:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(item.ports.iter().map(|p| p.describe()).collect::<Vec<_>>().join(", ")).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    let zones = item.zones();
+                    if !zones.is_empty() {
+                        ui.label(egui::RichText::new("Zones:").color(ROW_NAME_COLOR));
+                        ui.label(egui::RichText::new(zones.into_iter().collect::<Vec<_>>().join(", ")).color(DETAIL_COLOR));
                         ui.end_row();
                     }
+
+                    ui.label(egui::RichText::new("Topology hints:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(if item.has_hints() { "enabled" } else { "none" }).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                if !guard_details.ip_families.is_empty() {
+                    ui.label(egui::RichText::new("Service IP families:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(guard_details.ip_families.join(", ")).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                if let Some(selector) = &guard_details.service_selector {
+                    ui.label(egui::RichText::new("Selector:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(selector.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(", ")).color(DETAIL_COLOR));
+                    ui.end_row();
                 }
 
                 if let Some(labels) = guard_details.labels.clone() {
@@ -119,6 +172,50 @@ pub fn show_endpoint_details_window(
                 }
             });
 
+            ui.separator();
+            ui.heading(egui::RichText::new("Endpoints:").color(ROW_NAME_COLOR));
+            match &service_endpoints {
+                Some(item) if !item.endpoints.is_empty() => {
+                    egui::Grid::new("endpoint_details_endpoints_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+                        ui.label("Addresses");
+                        ui.label("Family");
+                        ui.label("Ready");
+                        ui.label("Serving");
+                        ui.label("Terminating");
+                        ui.label("Target");
+                        ui.label("Node");
+                        ui.label("Zone");
+                        ui.label("Hints");
+                        ui.label("Slice");
+                        ui.end_row();
+                        for endpoint in &item.endpoints {
+                            let addresses = endpoint.addresses.join(", ");
+                            let addresses = match &endpoint.hostname {
+                                Some(hostname) => format!("{} ({})", addresses, hostname),
+                                None => addresses,
+                            };
+                            ui.label(egui::RichText::new(addresses).color(DETAIL_COLOR));
+                            ui.label(&endpoint.address_type);
+                            condition_label(ui, endpoint.ready, true);
+                            condition_label(ui, endpoint.serving, true);
+                            condition_label(ui, endpoint.terminating, false);
+                            optional_label(ui, endpoint.target.as_deref());
+                            optional_label(ui, endpoint.node_name.as_deref());
+                            optional_label(ui, endpoint.zone.as_deref());
+                            let hints: Vec<String> = endpoint.hint_zones.iter().map(|z| format!("zone {}", z))
+                                .chain(endpoint.hint_nodes.iter().map(|n| format!("node {}", n)))
+                                .collect();
+                            optional_label(ui, Some(hints.join(", ").as_str()));
+                            ui.label(egui::RichText::new(&endpoint.slice).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                }
+                _ => {
+                    ui.label(egui::RichText::new("No endpoints, check the Service selector and pod readiness").color(WARNING_COLOR));
+                }
+            }
+
             ui.separator();
             ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
             if !guard_details.events.is_empty() {
@@ -133,7 +230,6 @@ pub fn show_endpoint_details_window(
             }
         });
     });
-    crate::show_delete_confirmation(ctx, delete_confirm);
 
     if let Some(inner_response) = response
         && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use egui::{Align2, FontId, RichText, Sense, Stroke, Ui, pos2, vec2};
+use k8s_openapi::{apimachinery::pkg::apis::meta::v1::Time, jiff::Timestamp};
+
+const TIMELINE_WIDTH: f32 = 600.0;
+const TIMELINE_ROW_HEIGHT: f32 = 18.0;
+const HOVER_DISTANCE: f32 = 6.0;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EventsView {
+    List,
+    Timeline,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EventTimeRange {
+    Minutes15,
+    Hour1,
+    Hours6,
+    Hours24,
+    Days7,
+}
+
+impl EventTimeRange {
+    pub const ALL: [EventTimeRange; 5] = [
+        EventTimeRange::Minutes15,
+        EventTimeRange::Hour1,
+        EventTimeRange::Hours6,
+        EventTimeRange::Hours24,
+        EventTimeRange::Days7,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EventTimeRange::Minutes15 => "Last 15 minutes",
+            EventTimeRange::Hour1 => "Last hour",
+            EventTimeRange::Hours6 => "Last 6 hours",
+            EventTimeRange::Hours24 => "Last 24 hours",
+            EventTimeRange::Days7 => "Last 7 days",
+        }
+    }
+
+    pub fn seconds(&self) -> i64 {
+        match self {
+            EventTimeRange::Minutes15 => 900,
+            EventTimeRange::Hour1 => 3600,
+            EventTimeRange::Hours6 => 6 * 3600,
+            EventTimeRange::Hours24 => 86400,
+            EventTimeRange::Days7 => 7 * 86400,
+        }
+    }
+
+    pub fn contains(&self, now: Timestamp, at: Option<Timestamp>) -> bool {
+        at.is_some_and(|at| now.as_second() - at.as_second() <= self.seconds())
+    }
+}
+
+pub struct EventTimelineState {
+    pub view: EventsView,
+    pub range: EventTimeRange,
+    pub expanded: HashSet<String>,
+}
+
+impl EventTimelineState {
+    pub fn new() -> Self {
+        Self {
+            view: EventsView::List,
+            range: EventTimeRange::Hour1,
+            expanded: HashSet::new(),
+        }
+    }
+}
+
+pub struct EventAggregate {
+    pub reason: String,
+    pub event_type: String,
+    pub count: i64,
+    pub first: Timestamp,
+    pub last: Timestamp,
+    pub message: String,
+    pub controllers: BTreeSet<String>,
+}
+
+pub struct EventPoint {
+    pub at: Timestamp,
+    pub warning: bool,
+    pub count: i32,
+    pub reason: String,
+    pub message: String,
+}
+
+/// Events of one involved object inside the selected time range
+pub struct EventGroup {
+    pub key: String,
+    pub object_kind: String,
+    pub object_name: String,
+    pub namespace: Option<String>,
+    pub aggregates: Vec<EventAggregate>,
+    pub points: Vec<EventPoint>,
+    pub warnings: i64,
+    pub total: i64,
+    pub last: Timestamp,
+}
+
+pub enum CorrelationMarker {
+    PodRestart {
+        at: Timestamp,
+        pod: String,
+    },
+    NodeCondition {
+        at: Timestamp,
+        node: String,
+        condition: String,
+        status: String,
+        reason: Option<String>,
+    },
+}
+
+impl CorrelationMarker {
+    pub fn at(&self) -> Timestamp {
+        match self {
+            CorrelationMarker::PodRestart { at, .. } => *at,
+            CorrelationMarker::NodeCondition { at, .. } => *at,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            CorrelationMarker::PodRestart { pod, .. } => format!("Container restart in pod {}", pod),
+            CorrelationMarker::NodeCondition { node, condition, status, reason, .. } => {
+                format!("Node {} {}={} {}", node, condition, status, reason.as_deref().unwrap_or_default())
+            }
+        }
+    }
+}
+
+pub fn group_events(events: &[crate::EventItem], range: EventTimeRange, now: Timestamp, filter: &str, warnings_only: bool) -> Vec<EventGroup> {
+    let mut groups: BTreeMap<String, EventGroup> = BTreeMap::new();
+    for event in events {
+        if !range.contains(now, event.last_seen) {
+            continue;
+        }
+        if warnings_only && event.event_type != "Warning" {
+            continue;
+        }
+        if !filter.is_empty() && !event.involved_object.to_lowercase().contains(filter) && !event.message.to_lowercase().contains(filter) {
+            continue;
+        }
+        let Some(last) = event.last_seen else {
+            continue;
+        };
+        let first = event.first_seen.unwrap_or(last);
+        let count = event.count.unwrap_or(1).max(1);
+
+        let key = format!("{}/{}", event.namespace.as_deref().unwrap_or_default(), event.involved_object);
+        let group = groups.entry(key.clone()).or_insert_with(|| EventGroup {
+            key,
+            object_kind: event.object_kind.clone(),
+            object_name: event.object_name.clone(),
+            namespace: event.namespace.clone(),
+            aggregates: Vec::new(),
+            points: Vec::new(),
+            warnings: 0,
+            total: 0,
+            last,
+        });
+
+        let warning = event.event_type == "Warning";
+        group.total += count as i64;
+        if warning {
+            group.warnings += count as i64;
+        }
+        group.last = group.last.max(last);
+        group.points.push(EventPoint { at: last, warning, count, reason: event.reason.clone(), message: event.message.clone() });
+
+        match group.aggregates.iter_mut().find(|a| a.reason == event.reason && a.event_type == event.event_type) {
+            Some(aggregate) => {
+                aggregate.count += count as i64;
+                aggregate.first = aggregate.first.min(first);
+                if last >= aggregate.last {
+                    aggregate.last = last;
+                    aggregate.message = event.message.clone();
+                }
+                if !event.reporting_controller.is_empty() {
+                    aggregate.controllers.insert(event.reporting_controller.clone());
+                }
+            }
+            None => group.aggregates.push(EventAggregate {
+                reason: event.reason.clone(),
+                event_type: event.event_type.clone(),
+                count: count as i64,
+                first,
+                last,
+                message: event.message.clone(),
+                controllers: [event.reporting_controller.clone()].into_iter().filter(|c| !c.is_empty()).collect(),
+            }),
+        }
+    }
+
+    let mut groups: Vec<EventGroup> = groups.into_values().collect();
+    for group in groups.iter_mut() {
+        group.aggregates.sort_by_key(|a| std::cmp::Reverse(a.count));
+    }
+    // Objects with warnings first, then the most recently active
+    groups.sort_by(|a, b| (b.warnings > 0).cmp(&(a.warnings > 0)).then(b.last.cmp(&a.last)));
+    groups
+}
+
+fn node_markers(node: &crate::NodeItem, range: EventTimeRange, now: Timestamp, markers: &mut Vec<CorrelationMarker>) {
+    for condition in &node.conditions {
+        let at = condition.last_transition.as_ref().map(|t| t.0);
+        if let Some(at) = at.filter(|at| range.contains(now, Some(*at))) {
+            markers.push(CorrelationMarker::NodeCondition {
+                at,
+                node: node.name.clone(),
+                condition: condition.type_.clone(),
+                status: condition.status.clone(),
+                reason: condition.reason.clone(),
+            });
+        }
+    }
+}
+
+fn pod_marker(pod: &crate::PodItem, range: EventTimeRange, now: Timestamp, markers: &mut Vec<CorrelationMarker>) {
+    let at = pod.last_restart.as_ref().map(|t| t.0);
+    if let Some(at) = at.filter(|at| range.contains(now, Some(*at))) {
+        markers.push(CorrelationMarker::PodRestart { at, pod: pod.name.clone() });
+    }
+}
+
+/// Pod rows get their restarts and the condition changes of their node,
+/// node rows get their condition changes and restarts of the pods running there
+pub fn correlation_markers(group: &EventGroup, pods: &[crate::PodItem], nodes: &[crate::NodeItem], range: EventTimeRange, now: Timestamp) -> Vec<CorrelationMarker> {
+    let mut markers = Vec::new();
+    match group.object_kind.as_str() {
+        "Pod" => {
+            if let Some(pod) = pods.iter().find(|p| p.name == group.object_name && p.namespace == group.namespace) {
+                pod_marker(pod, range, now, &mut markers);
+                if let Some(node) = pod.node_name.as_ref().and_then(|n| nodes.iter().find(|node| &node.name == n)) {
+                    node_markers(node, range, now, &mut markers);
+                }
+            }
+        }
+        "Node" => {
+            if let Some(node) = nodes.iter().find(|n| n.name == group.object_name) {
+                node_markers(node, range, now, &mut markers);
+            }
+            for pod in pods.iter().filter(|p| p.node_name.as_deref() == Some(group.object_name.as_str())) {
+                pod_marker(pod, range, now, &mut markers);
+            }
+        }
+        _ => {}
+    }
+    markers.sort_by_key(|m| m.at());
+    markers
+}
+
+fn format_offset(seconds: i64) -> String {
+    if seconds == 0 {
+        "now".to_string()
+    } else if seconds < 3600 {
+        format!("-{}m", seconds / 60)
+    } else if seconds < 86400 {
+        format!("-{}h", seconds / 3600)
+    } else {
+        format!("-{}d", seconds / 86400)
+    }
+}
+
+fn time_to_x(rect: egui::Rect, range: EventTimeRange, now: Timestamp, at: Timestamp) -> f32 {
+    let offset = (now.as_second() - at.as_second()).clamp(0, range.seconds()) as f32;
+    rect.right() - offset / range.seconds() as f32 * rect.width()
+}
+
+fn show_axis(ui: &mut Ui, range: EventTimeRange) {
+    let (rect, _) = ui.allocate_exact_size(vec2(TIMELINE_WIDTH, TIMELINE_ROW_HEIGHT), Sense::hover());
+    let painter = ui.painter_at(rect.expand(2.0));
+    let ticks = 4;
+    for i in 0..=ticks {
+        let x = rect.left() + rect.width() * i as f32 / ticks as f32;
+        let offset = range.seconds() * (ticks - i) as i64 / ticks as i64;
+        let align = match i {
+            0 => Align2::LEFT_CENTER,
+            i if i == ticks => Align2::RIGHT_CENTER,
+            _ => Align2::CENTER_CENTER,
+        };
+        painter.text(pos2(x, rect.center().y), align, format_offset(offset), FontId::proportional(12.0), crate::SECOND_DETAIL_COLOR);
+    }
+}
+
+fn show_strip(ui: &mut Ui, group: &EventGroup, markers: &[CorrelationMarker], range: EventTimeRange, now: Timestamp) {
+    let (rect, response) = ui.allocate_exact_size(vec2(TIMELINE_WIDTH, TIMELINE_ROW_HEIGHT), Sense::hover());
+    let painter = ui.painter_at(rect.expand(4.0));
+    painter.line_segment([pos2(rect.left(), rect.center().y), pos2(rect.right(), rect.center().y)], Stroke::new(1.0, crate::TIMELINE_AXIS_COLOR));
+
+    for marker in markers {
+        let x = time_to_x(rect, range, now, marker.at());
+        let color = match marker {
+            CorrelationMarker::PodRestart { .. } => crate::TIMELINE_RESTART_COLOR,
+            CorrelationMarker::NodeCondition { .. } => crate::TIMELINE_NODE_CONDITION_COLOR,
+        };
+        painter.line_segment([pos2(x, rect.top()), pos2(x, rect.bottom())], Stroke::new(2.0, color));
+    }
+
+    for point in &group.points {
+        let x = time_to_x(rect, range, now, point.at);
+        let radius = (3.0 + (point.count as f32).ln()).min(7.0);
+        let color = if point.warning { crate::TIMELINE_WARNING_COLOR } else { crate::TIMELINE_NORMAL_COLOR };
+        painter.circle_filled(pos2(x, rect.center().y), radius, color);
+    }
+
+    if let Some(pointer) = response.hover_pos() {
+        let mut hovered: Vec<String> = group.points.iter()
+            .filter(|p| (time_to_x(rect, range, now, p.at) - pointer.x).abs() <= HOVER_DISTANCE)
+            .map(|p| format!("{} ({}x) {} ago: {}", p.reason, p.count, crate::format_age(&Time(p.at)), p.message))
+            .collect();
+        hovered.extend(markers.iter()
+            .filter(|m| (time_to_x(rect, range, now, m.at()) - pointer.x).abs() <= HOVER_DISTANCE)
+            .map(|m| format!("{} ago: {}", crate::format_age(&Time(m.at())), m.describe())));
+        if !hovered.is_empty() {
+            response.on_hover_ui_at_pointer(|ui| {
+                ui.set_max_width(500.0);
+                for line in hovered {
+                    ui.label(line);
+                }
+            });
+        }
+    }
+}
+
+pub fn show_event_timeline(ui: &mut Ui, state: &mut EventTimelineState, groups: &[EventGroup], pods: &[crate::PodItem], nodes: &[crate::NodeItem], now: Timestamp) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("●").color(crate::TIMELINE_WARNING_COLOR));
+        ui.label("Warning");
+        ui.label(RichText::new("●").color(crate::TIMELINE_NORMAL_COLOR));
+        ui.label("Normal");
+        ui.label(RichText::new("|").color(crate::TIMELINE_RESTART_COLOR).strong());
+        ui.label("Container restart");
+        ui.label(RichText::new("|").color(crate::TIMELINE_NODE_CONDITION_COLOR).strong());
+        ui.label("Node condition change");
+    });
+    ui.separator();
+
+    egui::ScrollArea::vertical().auto_shrink(false).id_salt("events_timeline_scroll").show(ui, |ui| {
+        egui::Grid::new("events_timeline_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+            ui.label("Object");
+            ui.label("Namespace");
+            ui.label("Warnings");
+            ui.label("Total");
+            show_axis(ui, state.range);
+            ui.end_row();
+
+            for group in groups {
+                let markers = correlation_markers(group, pods, nodes, state.range, now);
+                let expanded = state.expanded.contains(&group.key);
+                let arrow = if expanded { "⏷" } else { "⏵" };
+                let label = format!("{} {}/{}", arrow, group.object_kind, group.object_name);
+                if ui.label(RichText::new(label).color(crate::ITEM_NAME_COLOR)).on_hover_cursor(egui::CursorIcon::PointingHand).clicked() {
+                    if expanded {
+                        state.expanded.remove(&group.key);
+                    } else {
+                        state.expanded.insert(group.key.clone());
+                    }
+                }
+                ui.label(RichText::new(group.namespace.as_deref().unwrap_or_default()).color(crate::NAMESPACE_COLUMN_COLOR));
+                let warnings_color = if group.warnings > 0 { crate::WARNING_COLOR } else { crate::SECOND_DETAIL_COLOR };
+                ui.label(RichText::new(group.warnings.to_string()).color(warnings_color));
+                ui.label(group.total.to_string());
+                show_strip(ui, group, &markers, state.range, now);
+                ui.end_row();
+
+                if expanded {
+                    ui.label("");
+                    ui.label("");
+                    ui.label("");
+                    ui.label("");
+                    ui.vertical(|ui| {
+                        egui::Grid::new(format!("events_aggregate_{}", group.key)).striped(true).min_col_width(20.0).max_col_width(300.0).show(ui, |ui| {
+                            ui.label(RichText::new("Reason").color(crate::ROW_NAME_COLOR));
+                            ui.label(RichText::new("Type").color(crate::ROW_NAME_COLOR));
+                            ui.label(RichText::new("Count").color(crate::ROW_NAME_COLOR));
+                            ui.label(RichText::new("First seen").color(crate::ROW_NAME_COLOR));
+                            ui.label(RichText::new("Last seen").color(crate::ROW_NAME_COLOR));
+                            ui.label(RichText::new("Source").color(crate::ROW_NAME_COLOR));
+                            ui.label(RichText::new("Latest message").color(crate::ROW_NAME_COLOR));
+                            ui.end_row();
+                            for aggregate in &group.aggregates {
+                                ui.label(RichText::new(&aggregate.reason).color(crate::DETAIL_COLOR));
+                                ui.label(RichText::new(&aggregate.event_type).color(crate::item_color(&aggregate.event_type)));
+                                ui.label(aggregate.count.to_string());
+                                ui.label(crate::format_age(&Time(aggregate.first)));
+                                ui.label(crate::format_age(&Time(aggregate.last)));
+                                ui.label(RichText::new(aggregate.controllers.iter().cloned().collect::<Vec<_>>().join(", ")).color(crate::SECOND_DETAIL_COLOR));
+                                ui.label(&aggregate.message);
+                                ui.end_row();
+                            }
+                        });
+                        if !markers.is_empty() {
+                            ui.label(RichText::new("Correlated").color(crate::ROW_NAME_COLOR));
+                            for marker in &markers {
+                                let color = match marker {
+                                    CorrelationMarker::PodRestart { .. } => crate::TIMELINE_RESTART_COLOR,
+                                    CorrelationMarker::NodeCondition { .. } => crate::TIMELINE_NODE_CONDITION_COLOR,
+                                };
+                                ui.label(RichText::new(format!("{} ago: {}", crate::format_age(&Time(marker.at())), marker.describe())).color(color));
+                            }
+                        }
+                    });
+                    ui.end_row();
+                }
+            }
+        });
+    });
+}
@@ -0,0 +1,37 @@
+use egui::{Context, Key, RichText, Ui};
+use crate::theme::*;
+
+fn error_color(error: &crate::DetailsError) -> egui::Color32 {
+    match error {
+        crate::DetailsError::NotFound(_) | crate::DetailsError::TimedOut => WARNING_COLOR,
+        crate::DetailsError::Forbidden(_) | crate::DetailsError::Other(_) => ERROR_MESSAGE_COLOR,
+    }
+}
+
+/// Renders a failed details fetch, returns true when the user asked to retry
+pub fn show_details_error(ui: &mut Ui, name: &str, error: &crate::DetailsError) -> bool {
+    let color = error_color(error);
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(error.icon()).size(28.0).color(color));
+        ui.vertical(|ui| {
+            ui.label(RichText::new(format!("{}: {}", error.title(), name)).size(16.0).color(color));
+            ui.label(RichText::new(error.to_string()).color(SECOND_DETAIL_COLOR));
+        });
+    });
+    ui.add_space(8.0);
+    ui.button(RichText::new("⟳ Retry").size(16.0).color(BLUE_BUTTON)).clicked()
+}
+
+/// Takes the place of a details window while its fetch is failing, keeping the same title and position
+pub fn show_details_error_window(ctx: &Context, title: &str, open: &mut bool, name: &str, error: &crate::DetailsError) -> bool {
+    let mut retry = false;
+    let response = egui::Window::new(title).min_width(800.0).collapsible(false).resizable(true).open(open).show(ctx, |ui| {
+        retry = show_details_error(ui, name, error);
+    });
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            *open = false;
+        }
+    retry
+}
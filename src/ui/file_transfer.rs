@@ -0,0 +1,269 @@
+use std::sync::{Arc, Mutex};
+use egui::{RichText, Ui};
+use crate::{theme::*, RemoteEntry, TransferProgress, TransferState};
+
+#[derive(Default)]
+pub struct DirListing {
+    pub loading: bool,
+    pub entries: Vec<RemoteEntry>,
+    pub error: Option<String>,
+}
+
+/// Browses a container filesystem and copies files in and out of it, like `kubectl cp`
+pub struct FileTransferPanel {
+    /// Pod and namespace being browsed, the panel resets when another pod is shown
+    pub pod: Option<(String, String)>,
+    pub container: String,
+    pub path: String,
+    pub path_input: String,
+    pub listing: Arc<Mutex<DirListing>>,
+    pub transfers: Vec<Arc<Mutex<TransferProgress>>>,
+}
+
+impl FileTransferPanel {
+    pub fn new() -> Self {
+        Self {
+            pod: None,
+            container: String::new(),
+            path: "/".to_string(),
+            path_input: "/".to_string(),
+            listing: Arc::new(Mutex::new(DirListing::default())),
+            transfers: Vec::new(),
+        }
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+fn join_remote_path(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+fn parent_remote_path(path: &str) -> String {
+    match path.trim_end_matches('/').rsplit_once('/') {
+        Some(("", _)) | None => "/".to_string(),
+        Some((parent, _)) => parent.to_string(),
+    }
+}
+
+fn load_listing(panel: &mut FileTransferPanel, path: String, client: &Arc<crate::Client>) {
+    let Some((pod, ns)) = panel.pod.clone() else {
+        return;
+    };
+    panel.path = path.clone();
+    panel.path_input = path.clone();
+    // A fresh listing so a slow answer for the previous directory cannot overwrite this one
+    panel.listing = Arc::new(Mutex::new(DirListing { loading: true, ..Default::default() }));
+    let listing = Arc::clone(&panel.listing);
+    let container = panel.container.clone();
+    let client = Arc::clone(client);
+    tokio::spawn(async move {
+        let result = crate::list_container_dir(client, &pod, &ns, &container, &path).await;
+        let mut listing = listing.lock().unwrap();
+        listing.loading = false;
+        match result {
+            Ok(entries) => listing.entries = entries,
+            Err(e) => listing.error = Some(e),
+        }
+    });
+}
+
+fn start_download(panel: &mut FileTransferPanel, entry: &RemoteEntry, client: &Arc<crate::Client>) {
+    let Some((pod, ns)) = panel.pod.clone() else {
+        return;
+    };
+    let Some(local_dir) = rfd::FileDialog::new().set_title("Download to folder").pick_folder() else {
+        return;
+    };
+    let remote_path = join_remote_path(&panel.path, &entry.name);
+    let total = if entry.is_dir { None } else { Some(entry.size) };
+    let progress = Arc::new(Mutex::new(TransferProgress::new(format!("⬇ {}", remote_path), total)));
+    panel.transfers.push(Arc::clone(&progress));
+
+    let container = panel.container.clone();
+    let client = Arc::clone(client);
+    tokio::spawn(async move {
+        let result = crate::download_from_container(client, &pod, &ns, &container, &remote_path, local_dir, Arc::clone(&progress)).await;
+        progress.lock().unwrap().state = match result {
+            Ok(path) => TransferState::Done(format!("Saved to {}", path.display())),
+            Err(e) => TransferState::Failed(e),
+        };
+    });
+}
+
+fn start_upload(panel: &mut FileTransferPanel, folder: bool, client: &Arc<crate::Client>) {
+    let Some((pod, ns)) = panel.pod.clone() else {
+        return;
+    };
+    let dialog = rfd::FileDialog::new().set_title("Upload to container");
+    let picked = if folder { dialog.pick_folder() } else { dialog.pick_file() };
+    let Some(local_path) = picked else {
+        return;
+    };
+    let remote_dir = panel.path.clone();
+    let progress = Arc::new(Mutex::new(TransferProgress::new(
+        format!("⬆ {} → {}", local_path.display(), remote_dir),
+        None,
+    )));
+    panel.transfers.push(Arc::clone(&progress));
+
+    let container = panel.container.clone();
+    let listing = Arc::clone(&panel.listing);
+    let client = Arc::clone(client);
+    tokio::spawn(async move {
+        let result = crate::upload_to_container(Arc::clone(&client), &pod, &ns, &container, local_path, &remote_dir, Arc::clone(&progress)).await;
+        let uploaded = result.is_ok();
+        progress.lock().unwrap().state = match result {
+            Ok(_) => TransferState::Done("Uploaded".to_string()),
+            Err(e) => TransferState::Failed(e),
+        };
+        // Show the new file if the same directory is still listed
+        if uploaded && let Ok(entries) = crate::list_container_dir(client, &pod, &ns, &container, &remote_dir).await {
+            listing.lock().unwrap().entries = entries;
+        }
+    });
+}
+
+fn show_transfers(ui: &mut Ui, panel: &mut FileTransferPanel) {
+    if panel.transfers.is_empty() {
+        return;
+    }
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Transfers:").color(ROW_NAME_COLOR));
+        if ui.button("Clear finished").clicked() {
+            panel.transfers.retain(|t| t.lock().unwrap().state == TransferState::Running);
+        }
+    });
+    for transfer in &panel.transfers {
+        let transfer = transfer.lock().unwrap().clone();
+        ui.label(RichText::new(&transfer.label).color(DETAIL_COLOR));
+        match &transfer.state {
+            TransferState::Running => {
+                let text = match transfer.total {
+                    Some(total) => format!("{} / {}", format_size(transfer.transferred), format_size(total)),
+                    None => format_size(transfer.transferred),
+                };
+                let fraction = transfer.total
+                    .filter(|total| *total > 0)
+                    .map(|total| (transfer.transferred as f32 / total as f32).min(1.0))
+                    .unwrap_or(0.0);
+                ui.add(egui::ProgressBar::new(fraction).text(text).animate(transfer.total.is_none()));
+            }
+            TransferState::Done(message) => {
+                ui.label(RichText::new(format!("✔ {} ({})", message, format_size(transfer.transferred))).color(GREEN_BUTTON));
+            }
+            TransferState::Failed(error) => {
+                ui.label(RichText::new(format!("✖ {}", error)).color(ERROR_MESSAGE_COLOR));
+            }
+        }
+    }
+    if panel.transfers.iter().any(|t| t.lock().unwrap().state == TransferState::Running) {
+        ui.ctx().request_repaint_after(std::time::Duration::from_millis(200));
+    }
+}
+
+pub fn show_file_transfer_panel(ui: &mut Ui, panel: &mut FileTransferPanel, pod: &str, namespace: &str, containers: &[String], client: &Arc<crate::Client>) {
+    let current = (pod.to_string(), namespace.to_string());
+    if panel.pod.as_ref() != Some(&current) {
+        panel.pod = Some(current);
+        panel.container = containers.first().cloned().unwrap_or_default();
+        panel.transfers.clear();
+        load_listing(panel, "/".to_string(), client);
+    }
+
+    let mut navigate_to = None;
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Container:").color(ROW_NAME_COLOR));
+        let before = panel.container.clone();
+        egui::ComboBox::from_id_salt("file_transfer_container_combo").selected_text(panel.container.clone()).show_ui(ui, |ui| {
+            for container in containers {
+                ui.selectable_value(&mut panel.container, container.clone(), container);
+            }
+        });
+        if panel.container != before {
+            navigate_to = Some(panel.path.clone());
+        }
+
+        if ui.button("⬆ Up").clicked() {
+            navigate_to = Some(parent_remote_path(&panel.path));
+        }
+        let path_edit = ui.add(egui::TextEdit::singleline(&mut panel.path_input).desired_width(300.0));
+        if (path_edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))) || ui.button("Go").clicked() {
+            navigate_to = Some(panel.path_input.trim().to_string());
+        }
+        if ui.button("⟳").on_hover_text("Refresh").clicked() {
+            navigate_to = Some(panel.path.clone());
+        }
+        ui.separator();
+        if ui.button(RichText::new("⬆ Upload file").color(GREEN_BUTTON)).clicked() {
+            start_upload(panel, false, client);
+        }
+        if ui.button(RichText::new("⬆ Upload folder").color(GREEN_BUTTON)).clicked() {
+            start_upload(panel, true, client);
+        }
+    });
+
+    let mut download = None;
+    let listing = Arc::clone(&panel.listing);
+    let listing = listing.lock().unwrap();
+    if listing.loading {
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.label(RichText::new(format!("Listing {}...", panel.path)).color(SECOND_DETAIL_COLOR));
+        });
+    } else if let Some(error) = &listing.error {
+        ui.label(RichText::new(format!("Cannot list {}: {}", panel.path, error)).color(ERROR_MESSAGE_COLOR));
+        ui.label(RichText::new("Browsing needs sh and stat, copying needs tar in the container").color(SECOND_DETAIL_COLOR));
+    } else {
+        egui::ScrollArea::vertical().id_salt("file_transfer_scroll").max_height(250.0).show(ui, |ui| {
+            egui::Grid::new("file_transfer_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                ui.label(RichText::new("Name").color(PODS_HEAD_GRID_COLOR));
+                ui.label(RichText::new("Size").color(PODS_HEAD_GRID_COLOR));
+                ui.label(RichText::new("Modified").color(PODS_HEAD_GRID_COLOR));
+                ui.label(RichText::new("").color(PODS_HEAD_GRID_COLOR));
+                ui.end_row();
+
+                for entry in &listing.entries {
+                    if entry.is_dir {
+                        if ui.link(RichText::new(format!("📁 {}", entry.name)).color(ITEM_NAME_COLOR)).clicked() {
+                            navigate_to = Some(join_remote_path(&panel.path, &entry.name));
+                        }
+                        ui.label("-");
+                    } else {
+                        let icon = if entry.is_link { "🔗" } else { "📄" };
+                        ui.label(RichText::new(format!("{} {}", icon, entry.name)).color(DETAIL_COLOR));
+                        ui.label(format_size(entry.size));
+                    }
+                    ui.label(RichText::new(entry.modified.map(|t| t.strftime("%Y-%m-%d %H:%M").to_string()).unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                    if ui.button("⬇ Download").clicked() {
+                        download = Some(entry.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+    }
+    drop(listing);
+
+    if let Some(entry) = download {
+        start_download(panel, &entry, client);
+    }
+    if let Some(path) = navigate_to.filter(|p| !p.is_empty()) {
+        load_listing(panel, path, client);
+    }
+    show_transfers(ui, panel);
+}
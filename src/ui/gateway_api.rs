@@ -0,0 +1,600 @@
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use egui::{Color32, Context, Key, RichText, Ui};
+use crate::{BackendRef, GatewayApiDiscovery, GatewayApiKind, GatewayApiObject, ParentRef, ServiceItem};
+use crate::ui::YamlEditorWindow;
+use crate::theme::*;
+
+/// Follow-up the main loop has to do, these open windows owned by it
+pub enum GatewayApiAction {
+    OpenDetails(GatewayApiKind, String, Option<String>),
+    OpenService(String, String),
+}
+
+pub struct GatewayApiState {
+    pub view: GatewayApiKind,
+    /// Empty for all namespaces
+    pub namespace: String,
+    pub filter: String,
+}
+
+impl GatewayApiState {
+    pub fn new() -> Self {
+        Self {
+            view: GatewayApiKind::Gateway,
+            namespace: String::new(),
+            filter: String::new(),
+        }
+    }
+}
+
+pub struct GatewayApiDetailsWindow {
+    pub show: bool,
+    pub selected: Option<(GatewayApiKind, String, Option<String>)>,
+}
+
+impl GatewayApiDetailsWindow {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            selected: None,
+        }
+    }
+}
+
+/// Conflicted and OverlappingTLSConfig are the only Gateway API conditions where True is bad
+fn condition_healthy(condition: &crate::ConditionDetails) -> bool {
+    match condition.type_.as_str() {
+        "Conflicted" | "OverlappingTLSConfig" => condition.status != "True",
+        _ => condition.status == "True",
+    }
+}
+
+pub fn gateway_conditions_summary(conditions: &[crate::ConditionDetails]) -> (String, Color32) {
+    if conditions.is_empty() {
+        return ("Pending".to_string(), WARNING_COLOR);
+    }
+    let failing: Vec<&str> = conditions.iter().filter(|c| !condition_healthy(c)).map(|c| c.type_.as_str()).collect();
+    if failing.is_empty() {
+        let healthy: Vec<&str> = conditions.iter().map(|c| c.type_.as_str()).collect();
+        (healthy.join(", "), GREEN_BUTTON)
+    } else {
+        (format!("Not {}", failing.join(", ")), ERROR_MESSAGE_COLOR)
+    }
+}
+
+/// Route status over all parents: accepted everywhere, rejected somewhere or not yet processed
+pub fn route_status(route: &GatewayApiObject) -> (String, Color32) {
+    if route.parents.is_empty() {
+        return ("Pending".to_string(), WARNING_COLOR);
+    }
+    let failing = |type_: &str| route.parents.iter()
+        .any(|p| p.conditions.iter().any(|c| c.type_ == type_ && c.status != "True"));
+    if failing("Accepted") {
+        ("Not accepted".to_string(), ERROR_MESSAGE_COLOR)
+    } else if failing("ResolvedRefs") {
+        ("Unresolved refs".to_string(), WARNING_COLOR)
+    } else {
+        ("Accepted".to_string(), GREEN_BUTTON)
+    }
+}
+
+/// Checks a route parentRef against the watched Gateways, the same way a controller would reject it
+pub fn resolve_parent_ref(parent: &ParentRef, route: &GatewayApiObject, objects: &[GatewayApiObject]) -> Result<(), String> {
+    if parent.kind != "Gateway" {
+        return Ok(());
+    }
+    let namespace = parent.namespace.as_ref().or(route.namespace.as_ref());
+    let Some(gateway) = objects.iter().find(|o| o.kind == GatewayApiKind::Gateway && o.name == parent.name && o.namespace.as_ref() == namespace) else {
+        return Err("Gateway not found".to_string());
+    };
+    let listeners: Vec<&crate::GatewayListener> = gateway.listeners.iter()
+        .filter(|l| parent.section_name.as_ref().is_none_or(|s| s == &l.name))
+        .filter(|l| parent.port.is_none_or(|p| p == l.port))
+        .collect();
+    if listeners.is_empty() {
+        return Err(match &parent.section_name {
+            Some(section) => format!("no listener {}", section),
+            None => "no matching listener".to_string(),
+        });
+    }
+    let same_namespace = route.namespace.as_ref() == gateway.namespace.as_ref();
+    if !listeners.iter().any(|l| l.allowed_namespaces != "Same" || same_namespace) {
+        return Err("listeners only allow routes from their own namespace".to_string());
+    }
+    Ok(())
+}
+
+fn backend_service<'a>(backend: &BackendRef, route: &GatewayApiObject, services: &'a [ServiceItem]) -> Option<&'a ServiceItem> {
+    let namespace = backend.namespace.as_ref().or(route.namespace.as_ref());
+    services.iter().find(|s| s.name == backend.name && s.namespace.as_ref() == namespace)
+}
+
+fn describe_backend(backend: &BackendRef) -> String {
+    let mut text = backend.name.clone();
+    if let Some(namespace) = &backend.namespace {
+        text = format!("{}/{}", namespace, text);
+    }
+    if let Some(port) = backend.port {
+        text.push_str(&format!(":{}", port));
+    }
+    if let Some(weight) = backend.weight {
+        text.push_str(&format!(" (weight {})", weight));
+    }
+    text
+}
+
+fn show_backends(ui: &mut Ui, route: &GatewayApiObject, services: &[ServiceItem], action: &mut Option<GatewayApiAction>) {
+    ui.vertical(|ui| {
+        if route.backends.is_empty() {
+            ui.label(RichText::new("-").color(SECOND_DETAIL_COLOR));
+        }
+        for backend in &route.backends {
+            if backend.kind != "Service" {
+                ui.label(RichText::new(format!("{} {}", backend.kind, describe_backend(backend))).color(SECOND_DETAIL_COLOR));
+                continue;
+            }
+            match backend_service(backend, route, services) {
+                Some(service) => {
+                    if ui.link(RichText::new(describe_backend(backend)).color(POD_NAME_COLUMN_COLOR)).on_hover_text("Open Service details").clicked() {
+                        *action = Some(GatewayApiAction::OpenService(service.name.clone(), service.namespace.clone().unwrap_or_default()));
+                    }
+                }
+                None => {
+                    ui.label(RichText::new(format!("{} (Service not found)", describe_backend(backend))).color(ERROR_MESSAGE_COLOR));
+                }
+            }
+        }
+    });
+}
+
+fn show_parents(ui: &mut Ui, route: &GatewayApiObject, objects: &[GatewayApiObject], action: &mut Option<GatewayApiAction>) {
+    ui.vertical(|ui| {
+        for parent in &route.parent_refs {
+            let text = parent.describe(route.namespace.as_deref());
+            match resolve_parent_ref(parent, route, objects) {
+                Ok(()) => {
+                    let accepted = route.parent_status(parent)
+                        .and_then(|s| s.conditions.iter().find(|c| c.type_ == "Accepted"));
+                    let color = match accepted {
+                        Some(c) if c.status == "True" => GREEN_BUTTON,
+                        Some(_) => ERROR_MESSAGE_COLOR,
+                        None => WARNING_COLOR,
+                    };
+                    let response = ui.link(RichText::new(text).color(color));
+                    let response = match accepted.and_then(|c| c.message.as_deref()) {
+                        Some(message) => response.on_hover_text(message),
+                        None => response,
+                    };
+                    if response.clicked() && parent.kind == "Gateway" {
+                        let namespace = parent.namespace.clone().or(route.namespace.clone());
+                        *action = Some(GatewayApiAction::OpenDetails(GatewayApiKind::Gateway, parent.name.clone(), namespace));
+                    }
+                }
+                Err(reason) => {
+                    ui.label(RichText::new(format!("{} ({})", text, reason)).color(ERROR_MESSAGE_COLOR));
+                }
+            }
+        }
+    });
+}
+
+fn show_conditions(ui: &mut Ui, id: impl std::hash::Hash + std::fmt::Debug, conditions: &[crate::ConditionDetails]) {
+    egui::Grid::new(id).striped(true).min_col_width(20.0).max_col_width(500.0).show(ui, |ui| {
+        for condition in conditions {
+            let color = if condition_healthy(condition) { GREEN_BUTTON } else { WARNING_COLOR };
+            ui.label(RichText::new(&condition.type_).color(color));
+            ui.label(RichText::new(&condition.status).color(color));
+            ui.label(RichText::new(condition.reason.clone().unwrap_or_default()).color(DETAIL_COLOR));
+            ui.label(RichText::new(condition.message.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+            ui.end_row();
+        }
+    });
+}
+
+fn edit_object(object: &GatewayApiObject, yaml_editor_window: &Arc<Mutex<YamlEditorWindow>>, client: &Arc<crate::Client>) {
+    let ar = object.api_resource.clone();
+    let name = object.name.clone();
+    let ns = object.namespace.clone();
+    let client_clone = Arc::clone(client);
+    let yaml_editor_clone = Arc::clone(yaml_editor_window);
+    tokio::spawn(async move {
+        match crate::get_dynamic_yaml(client_clone, &ar, &name, ns.as_deref()).await {
+            Ok(yaml) => {
+                let mut editor = yaml_editor_clone.lock().unwrap();
+                editor.content = yaml;
+                editor.show = true;
+            }
+            Err(e) => log::error!("Failed to get {} YAML: {}", ar.kind, e),
+        }
+    });
+}
+
+fn delete_object(object: &GatewayApiObject, client: &Arc<crate::Client>, delete_confirm: &mut super::DeleteConfirmation) {
+    let ar = object.api_resource.clone();
+    let name = object.name.clone();
+    let ns = object.namespace.clone();
+    let client_clone = Arc::clone(client);
+    delete_confirm.request(name.clone(), ns.clone(), move || {
+        tokio::spawn(async move {
+            if let Err(e) = crate::delete_dynamic(client_clone, &ar, &name, ns.as_deref()).await {
+                log::error!("Failed to delete {}: {}", ar.kind, e);
+            }
+        });
+    });
+}
+
+fn show_actions_menu(ui: &mut Ui, object: &GatewayApiObject, yaml_editor_window: &Arc<Mutex<YamlEditorWindow>>, client: &Arc<crate::Client>, delete_confirm: &mut super::DeleteConfirmation) {
+    ui.menu_button(RichText::new(crate::ACTIONS_MENU_LABEL).size(crate::ACTIONS_MENU_BUTTON_SIZE).color(MENU_BUTTON), |ui| {
+        ui.set_width(200.0);
+        if ui.button(RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
+            edit_object(object, yaml_editor_window, client);
+            ui.close_kind(egui::UiKind::Menu);
+        }
+        if ui.button(RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
+            delete_object(object, client, delete_confirm);
+            ui.close_kind(egui::UiKind::Menu);
+        }
+    });
+}
+
+fn name_link(ui: &mut Ui, object: &GatewayApiObject, action: &mut Option<GatewayApiAction>) {
+    if ui.link(RichText::new(&object.name).color(ITEM_NAME_COLOR)).clicked() {
+        *action = Some(GatewayApiAction::OpenDetails(object.kind, object.name.clone(), object.namespace.clone()));
+    }
+}
+
+fn age(object: &GatewayApiObject) -> String {
+    object.creation_timestamp.as_ref().map(crate::format_age).unwrap_or_default()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn show_gateway_api(
+        ui: &mut Ui,
+        state: &mut GatewayApiState,
+        objects: &[GatewayApiObject],
+        discovered: &GatewayApiDiscovery,
+        services: &[ServiceItem],
+        yaml_editor_window: &Arc<Mutex<YamlEditorWindow>>,
+        client: &Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) -> Option<GatewayApiAction> {
+    ui.horizontal(|ui| {
+        ui.heading("Gateway API");
+        ui.separator();
+        for (kind, _) in &discovered.served {
+            let count = objects.iter().filter(|o| o.kind == *kind).count();
+            ui.selectable_value(&mut state.view, *kind, format!("{} ({})", kind.kind(), count));
+        }
+    });
+    if let Some(error) = &discovered.error {
+        ui.separator();
+        ui.label(RichText::new(error).color(WARNING_COLOR));
+        ui.label(RichText::new("Install the Gateway API CRDs and a controller to manage Gateways and routes, Ingresses keep working without them.").color(SECOND_DETAIL_COLOR));
+        return None;
+    }
+    let versions: BTreeSet<&str> = discovered.served.iter().map(|(_, version)| version.as_str()).collect();
+    let missing: Vec<&str> = GatewayApiKind::ALL.iter()
+        .filter(|k| !discovered.served.iter().any(|(served, _)| served == *k))
+        .map(|k| k.kind())
+        .collect();
+    let mut summary = format!("Served as {}", versions.into_iter().collect::<Vec<_>>().join(", "));
+    if !missing.is_empty() {
+        summary.push_str(&format!(", not installed: {}", missing.join(", ")));
+    }
+    ui.label(RichText::new(summary).color(SECOND_DETAIL_COLOR));
+    if !discovered.served.iter().any(|(kind, _)| *kind == state.view) {
+        if let Some((kind, _)) = discovered.served.first() {
+            state.view = *kind;
+        } else {
+            return None;
+        }
+    }
+
+    ui.horizontal(|ui| {
+        if state.view != GatewayApiKind::GatewayClass {
+            ui.label(RichText::new("Namespace:").color(ROW_NAME_COLOR));
+            let selected = if state.namespace.is_empty() { "all".to_string() } else { state.namespace.clone() };
+            egui::ComboBox::from_id_salt("gateway_api_namespace").selected_text(selected).width(150.0).show_ui(ui, |ui| {
+                ui.selectable_value(&mut state.namespace, String::new(), "all");
+                let namespaces: BTreeSet<String> = objects.iter().filter_map(|o| o.namespace.clone()).collect();
+                for ns in namespaces {
+                    ui.selectable_value(&mut state.namespace, ns.clone(), ns);
+                }
+            });
+        }
+        ui.add(egui::TextEdit::singleline(&mut state.filter).hint_text("Filter by name...").text_color(FILTER_TEXT_COLOR));
+        if ui.button("ｘ").clicked() {
+            state.filter.clear();
+        }
+    });
+    ui.separator();
+
+    let filter = state.filter.to_lowercase();
+    let visible: Vec<&GatewayApiObject> = objects.iter()
+        .filter(|o| o.kind == state.view)
+        .filter(|o| state.namespace.is_empty() || o.namespace.is_none() || o.namespace.as_deref() == Some(state.namespace.as_str()))
+        .filter(|o| filter.is_empty() || o.name.to_lowercase().contains(&filter))
+        .collect();
+    if visible.is_empty() {
+        super::show_empty(ui);
+        return None;
+    }
+
+    let mut action = None;
+    egui::ScrollArea::vertical().auto_shrink(false).id_salt("gateway_api_scroll").show(ui, |ui| {
+        egui::Grid::new(("gateway_api_grid", state.view)).striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+            match state.view {
+                GatewayApiKind::GatewayClass => {
+                    for header in ["Name", "Controller", "Status", "Gateways", "Age", "Actions"] {
+                        ui.label(RichText::new(header).color(PODS_HEAD_GRID_COLOR));
+                    }
+                    ui.end_row();
+                    for class in visible {
+                        name_link(ui, class, &mut action);
+                        ui.label(RichText::new(class.controller_name.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        let (status, color) = gateway_conditions_summary(&class.conditions);
+                        ui.label(RichText::new(status).color(color));
+                        let gateways = objects.iter().filter(|o| o.kind == GatewayApiKind::Gateway && o.class_name.as_ref() == Some(&class.name)).count();
+                        ui.label(gateways.to_string());
+                        ui.label(age(class));
+                        show_actions_menu(ui, class, yaml_editor_window, client, delete_confirm);
+                        ui.end_row();
+                    }
+                }
+                GatewayApiKind::Gateway => {
+                    for header in ["Name", "Namespace", "Class", "Addresses", "Listeners", "Routes", "Status", "Age", "Actions"] {
+                        ui.label(RichText::new(header).color(PODS_HEAD_GRID_COLOR));
+                    }
+                    ui.end_row();
+                    for gateway in visible {
+                        name_link(ui, gateway, &mut action);
+                        ui.label(RichText::new(gateway.namespace.clone().unwrap_or_default()).color(NAMESPACE_COLUMN_COLOR));
+                        let class_known = objects.iter().any(|o| o.kind == GatewayApiKind::GatewayClass && Some(&o.name) == gateway.class_name.as_ref());
+                        let class_color = if class_known { DETAIL_COLOR } else { ERROR_MESSAGE_COLOR };
+                        ui.label(RichText::new(gateway.class_name.clone().unwrap_or_default()).color(class_color));
+                        ui.label(if gateway.addresses.is_empty() { "-".to_string() } else { gateway.addresses.join(", ") });
+                        let listeners: Vec<String> = gateway.listeners.iter().map(|l| format!("{}/{}", l.protocol, l.port)).collect();
+                        ui.label(listeners.join(", "));
+                        let routes = objects.iter().filter(|o| o.kind.is_route() && o.attaches_to(gateway, None)).count();
+                        ui.label(routes.to_string());
+                        let (status, color) = gateway_conditions_summary(&gateway.conditions);
+                        ui.label(RichText::new(status).color(color));
+                        ui.label(age(gateway));
+                        show_actions_menu(ui, gateway, yaml_editor_window, client, delete_confirm);
+                        ui.end_row();
+                    }
+                }
+                GatewayApiKind::HTTPRoute | GatewayApiKind::GRPCRoute | GatewayApiKind::TCPRoute => {
+                    for header in ["Name", "Namespace", "Hostnames", "Parents", "Backends", "Status", "Age", "Actions"] {
+                        ui.label(RichText::new(header).color(PODS_HEAD_GRID_COLOR));
+                    }
+                    ui.end_row();
+                    for route in visible {
+                        name_link(ui, route, &mut action);
+                        ui.label(RichText::new(route.namespace.clone().unwrap_or_default()).color(NAMESPACE_COLUMN_COLOR));
+                        ui.label(if route.hostnames.is_empty() { "*".to_string() } else { route.hostnames.join("\n") });
+                        show_parents(ui, route, objects, &mut action);
+                        show_backends(ui, route, services, &mut action);
+                        let (status, color) = route_status(route);
+                        ui.label(RichText::new(status).color(color));
+                        ui.label(age(route));
+                        show_actions_menu(ui, route, yaml_editor_window, client, delete_confirm);
+                        ui.end_row();
+                    }
+                }
+            }
+        });
+    });
+    action
+}
+
+fn show_gateway_details(ui: &mut Ui, gateway: &GatewayApiObject, objects: &[GatewayApiObject], action: &mut Option<GatewayApiAction>) {
+    ui.label(RichText::new("Class:").color(ROW_NAME_COLOR));
+    match objects.iter().find(|o| o.kind == GatewayApiKind::GatewayClass && Some(&o.name) == gateway.class_name.as_ref()) {
+        Some(class) => {
+            if ui.link(RichText::new(&class.name).color(DETAIL_COLOR)).on_hover_text(class.controller_name.clone().unwrap_or_default()).clicked() {
+                *action = Some(GatewayApiAction::OpenDetails(GatewayApiKind::GatewayClass, class.name.clone(), None));
+            }
+        }
+        None => {
+            ui.label(RichText::new(format!("{} (GatewayClass not found)", gateway.class_name.clone().unwrap_or_default())).color(ERROR_MESSAGE_COLOR));
+        }
+    }
+    ui.end_row();
+
+    ui.label(RichText::new("Addresses:").color(ROW_NAME_COLOR));
+    ui.label(RichText::new(if gateway.addresses.is_empty() { "none assigned".to_string() } else { gateway.addresses.join(", ") }).color(DETAIL_COLOR));
+    ui.end_row();
+
+    ui.separator(); ui.separator(); ui.end_row();
+    ui.label(RichText::new("Listeners:").color(ROW_NAME_COLOR));
+    egui::Grid::new("gateway_details_listeners_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+        for header in ["Name", "Protocol", "Port", "Hostname", "Allowed from", "Attached", "Status"] {
+            ui.label(RichText::new(header).color(PODS_HEAD_GRID_COLOR));
+        }
+        ui.end_row();
+        for listener in &gateway.listeners {
+            ui.label(RichText::new(&listener.name).color(DETAIL_COLOR));
+            ui.label(&listener.protocol);
+            ui.label(listener.port.to_string());
+            ui.label(listener.hostname.clone().unwrap_or("*".to_string()));
+            ui.label(&listener.allowed_namespaces);
+            // The controller count only includes accepted routes, the local one every route referencing the listener
+            let referencing = objects.iter().filter(|o| o.kind.is_route() && o.attaches_to(gateway, Some(&listener.name))).count();
+            let attached = listener.attached_routes.map(|a| a.to_string()).unwrap_or("-".to_string());
+            let attached_color = if listener.attached_routes.is_some_and(|a| a as usize != referencing) { WARNING_COLOR } else { DETAIL_COLOR };
+            ui.label(RichText::new(format!("{} of {}", attached, referencing)).color(attached_color))
+                .on_hover_text("Routes the controller attached, of routes referencing this listener");
+            let (status, color) = gateway_conditions_summary(&listener.conditions);
+            let response = ui.label(RichText::new(status).color(color));
+            let messages: Vec<String> = listener.conditions.iter()
+                .filter(|c| !condition_healthy(c))
+                .filter_map(|c| c.message.clone())
+                .collect();
+            if !messages.is_empty() {
+                response.on_hover_text(messages.join("\n"));
+            }
+            ui.end_row();
+        }
+    });
+    ui.end_row();
+
+    let routes: Vec<&GatewayApiObject> = objects.iter().filter(|o| o.kind.is_route() && o.attaches_to(gateway, None)).collect();
+    ui.label(RichText::new("Routes:").color(ROW_NAME_COLOR));
+    if routes.is_empty() {
+        ui.label(RichText::new("none").color(SECOND_DETAIL_COLOR));
+    } else {
+        egui::Grid::new("gateway_details_routes_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+            for route in routes {
+                ui.label(RichText::new(route.kind.kind()).color(SECOND_DETAIL_COLOR));
+                if ui.link(RichText::new(format!("{}/{}", route.namespace.clone().unwrap_or_default(), route.name)).color(ITEM_NAME_COLOR)).clicked() {
+                    *action = Some(GatewayApiAction::OpenDetails(route.kind, route.name.clone(), route.namespace.clone()));
+                }
+                let (status, color) = route_status(route);
+                ui.label(RichText::new(status).color(color));
+                ui.end_row();
+            }
+        });
+    }
+    ui.end_row();
+}
+
+fn show_route_details(ui: &mut Ui, route: &GatewayApiObject, objects: &[GatewayApiObject], services: &[ServiceItem], action: &mut Option<GatewayApiAction>) {
+    if route.kind != GatewayApiKind::TCPRoute {
+        ui.label(RichText::new("Hostnames:").color(ROW_NAME_COLOR));
+        ui.label(RichText::new(if route.hostnames.is_empty() { "* (from listeners)".to_string() } else { route.hostnames.join(", ") }).color(DETAIL_COLOR));
+        ui.end_row();
+    }
+
+    ui.label(RichText::new("Rules:").color(ROW_NAME_COLOR));
+    ui.label(RichText::new(route.rules.to_string()).color(DETAIL_COLOR));
+    ui.end_row();
+
+    ui.label(RichText::new("Backends:").color(ROW_NAME_COLOR));
+    show_backends(ui, route, services, action);
+    ui.end_row();
+
+    ui.separator(); ui.separator(); ui.end_row();
+    ui.label(RichText::new("Parents:").color(ROW_NAME_COLOR));
+    ui.vertical(|ui| {
+        for (i, parent) in route.parent_refs.iter().enumerate() {
+            let text = parent.describe(route.namespace.as_deref());
+            match resolve_parent_ref(parent, route, objects) {
+                Ok(()) => {
+                    if ui.link(RichText::new(&text).color(ITEM_NAME_COLOR)).clicked() && parent.kind == "Gateway" {
+                        let namespace = parent.namespace.clone().or(route.namespace.clone());
+                        *action = Some(GatewayApiAction::OpenDetails(GatewayApiKind::Gateway, parent.name.clone(), namespace));
+                    }
+                }
+                Err(reason) => {
+                    ui.label(RichText::new(format!("{} ({})", text, reason)).color(ERROR_MESSAGE_COLOR));
+                }
+            }
+            match route.parent_status(parent) {
+                Some(status) => {
+                    ui.label(RichText::new(format!("controller: {}", status.controller_name)).color(SECOND_DETAIL_COLOR));
+                    show_conditions(ui, ("gateway_route_parent_conditions", i), &status.conditions);
+                }
+                None => {
+                    ui.label(RichText::new("not processed by any controller").color(WARNING_COLOR));
+                }
+            }
+            ui.add_space(4.0);
+        }
+    });
+    ui.end_row();
+}
+
+pub fn show_gateway_api_details_window(
+        ctx: &Context,
+        details_window: &mut GatewayApiDetailsWindow,
+        objects: Arc<Mutex<Vec<GatewayApiObject>>>,
+        services: Arc<Mutex<Vec<ServiceItem>>>,
+        yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        client: Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) -> Option<GatewayApiAction> {
+    let (kind, name, namespace) = details_window.selected.clone()?;
+    let guard_objects = objects.lock().unwrap();
+    let guard_services = services.lock().unwrap();
+    let item = guard_objects.iter().find(|o| o.kind == kind && o.name == name && o.namespace == namespace)?;
+
+    let mut action = None;
+    let title = format!("{} details", kind.kind());
+    let response = egui::Window::new(title).id(egui::Id::new("gateway_api_details")).min_width(800.0).collapsible(false).resizable(true).open(&mut details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button(RichText::new("✏ Edit").size(16.0).color(GREEN_BUTTON)).clicked() {
+                edit_object(item, &yaml_editor_window, &client);
+            }
+            if ui.button(RichText::new("🗑 Delete").size(16.0).color(RED_BUTTON)).clicked() {
+                delete_object(item, &client, delete_confirm);
+            }
+        });
+        ui.separator();
+        egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
+            egui::Grid::new("gateway_api_details_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                ui.label(RichText::new(format!("{} name:", kind.kind())).color(ROW_NAME_COLOR));
+                ui.label(RichText::new(&item.name).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(ns) = &item.namespace {
+                    ui.label(RichText::new("Namespace:").color(ROW_NAME_COLOR));
+                    ui.label(RichText::new(ns).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.label(RichText::new("API version:").color(ROW_NAME_COLOR));
+                ui.label(RichText::new(&item.api_resource.api_version).color(SECOND_DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(creation_timestamp) = &item.creation_timestamp {
+                    ui.label(RichText::new("Creation time:").color(ROW_NAME_COLOR));
+                    let timestamp_text = format!("{}, {} ago", creation_timestamp.0, crate::format_age(creation_timestamp));
+                    ui.label(RichText::new(timestamp_text).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                match kind {
+                    GatewayApiKind::GatewayClass => {
+                        ui.label(RichText::new("Controller:").color(ROW_NAME_COLOR));
+                        ui.label(RichText::new(item.controller_name.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.end_row();
+                        if let Some(description) = &item.description {
+                            ui.label(RichText::new("Description:").color(ROW_NAME_COLOR));
+                            ui.label(RichText::new(description).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                        ui.label(RichText::new("Gateways:").color(ROW_NAME_COLOR));
+                        ui.vertical(|ui| {
+                            for gateway in guard_objects.iter().filter(|o| o.kind == GatewayApiKind::Gateway && o.class_name.as_ref() == Some(&item.name)) {
+                                if ui.link(RichText::new(format!("{}/{}", gateway.namespace.clone().unwrap_or_default(), gateway.name)).color(ITEM_NAME_COLOR)).clicked() {
+                                    action = Some(GatewayApiAction::OpenDetails(GatewayApiKind::Gateway, gateway.name.clone(), gateway.namespace.clone()));
+                                }
+                            }
+                        });
+                        ui.end_row();
+                    }
+                    GatewayApiKind::Gateway => show_gateway_details(ui, item, &guard_objects, &mut action),
+                    GatewayApiKind::HTTPRoute | GatewayApiKind::GRPCRoute | GatewayApiKind::TCPRoute => {
+                        show_route_details(ui, item, &guard_objects, &guard_services, &mut action);
+                    }
+                }
+
+                if !item.conditions.is_empty() {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(RichText::new("Conditions:").color(ROW_NAME_COLOR));
+                    show_conditions(ui, "gateway_api_details_conditions_grid", &item.conditions);
+                    ui.end_row();
+                }
+            });
+        });
+    });
+    crate::show_delete_confirmation(ctx, delete_confirm);
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            details_window.show = false;
+        }
+    action
+}
@@ -0,0 +1,260 @@
+use std::sync::{Arc, Mutex};
+use egui::{Context, Key};
+use crate::functions::item_color;
+use crate::{ui::YamlEditorWindow};
+use crate::theme::*;
+
+pub struct HpaDetailsWindow {
+    pub show: bool,
+    /// HPA the min/max fields were loaded from, they reset when another one is shown
+    pub editing: Option<(String, Option<String>)>,
+    pub min_replicas: i32,
+    pub max_replicas: i32,
+    pub action_message: Arc<Mutex<Option<(String, bool)>>>,
+}
+
+impl HpaDetailsWindow {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+            editing: None,
+            min_replicas: 1,
+            max_replicas: 1,
+            action_message: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+fn refresh_hpa_details(client: &Arc<crate::Client>, details: &Arc<Mutex<crate::HpaDetails>>, name: String, ns: Option<String>) {
+    let client = Arc::clone(client);
+    let details = Arc::clone(details);
+    tokio::spawn(async move {
+        if let Err(e) = crate::get_hpa_details(client, &name, ns, details).await {
+            log::error!("HPA details fetch failed: {:?}", e);
+        }
+    });
+}
+
+pub fn show_hpa_details_window(
+        ctx: &Context,
+        hpa_details_window: &mut HpaDetailsWindow,
+        details: Arc<Mutex<crate::HpaDetails>>,
+        hpas: Arc<Mutex<Vec<crate::HpaItem>>>,
+        yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        client: Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) {
+    let guard_details = details.lock().unwrap(); // More detailed info
+    let guard_hpas = hpas.lock().unwrap(); // HPAs with base details already we have
+    if guard_details.name.is_none() {
+        return;
+    }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "HPA details", &mut hpa_details_window.show, &name, error) {
+            refresh_hpa_details(&client, &details, name, guard_details.namespace.clone());
+        }
+        return;
+    }
+    let hpa_item = guard_hpas.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
+    let Some(item) = hpa_item else {
+        return;
+    };
+    let cur_ns = &item.namespace;
+    let cur_name = item.name.clone();
+
+    let current = (cur_name.clone(), cur_ns.clone());
+    if hpa_details_window.editing.as_ref() != Some(&current) {
+        hpa_details_window.editing = Some(current);
+        hpa_details_window.min_replicas = item.min_replicas;
+        hpa_details_window.max_replicas = item.max_replicas;
+        *hpa_details_window.action_message.lock().unwrap() = None;
+    }
+    let action_message = Arc::clone(&hpa_details_window.action_message);
+
+    let response = egui::Window::new("HPA details").min_width(800.0).collapsible(false).resizable(true).open(&mut hpa_details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
+                crate::edit_yaml_for::<k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler>(
+                    cur_name.clone(),
+                    cur_ns.to_owned().unwrap(),
+                    Arc::clone(&yaml_editor_window),
+                    Arc::clone(&client),
+                );
+            }
+
+            if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
+                let name = cur_name.clone();
+                let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
+
+                delete_confirm.request(name.clone(), ns.clone(), move || {
+                    tokio::spawn(async move {
+                        if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler>(
+                            name,
+                            ns.as_deref(),
+                            client,
+                        ).await {
+                            log::error!("Failed to delete HPA: {}", err);
+                        }
+                    });
+                });
+            }
+
+            ui.separator();
+            ui.label(egui::RichText::new("Min:").color(ROW_NAME_COLOR));
+            // minReplicas 0 is valid behind the HPAScaleToZero gate, values read from the cluster are shown as they are
+            ui.add(egui::DragValue::new(&mut hpa_details_window.min_replicas).range(1..=hpa_details_window.max_replicas).clamp_existing_to_range(false));
+            ui.label(egui::RichText::new("Max:").color(ROW_NAME_COLOR));
+            ui.add(egui::DragValue::new(&mut hpa_details_window.max_replicas).range(hpa_details_window.min_replicas.max(1)..=i32::MAX).clamp_existing_to_range(false));
+            let changed = hpa_details_window.min_replicas != item.min_replicas || hpa_details_window.max_replicas != item.max_replicas;
+            if ui.add_enabled(changed, egui::Button::new(egui::RichText::new("✔ Apply").size(16.0).color(crate::BLUE_BUTTON))).clicked() {
+                let name = cur_name.clone();
+                let ns = cur_ns.clone();
+                let (min, max) = (hpa_details_window.min_replicas, hpa_details_window.max_replicas);
+                let client = Arc::clone(&client);
+                let details = Arc::clone(&details);
+                let action_message = Arc::clone(&action_message);
+                tokio::spawn(async move {
+                    match crate::update_hpa_replicas(Arc::clone(&client), &name, ns.as_deref().unwrap_or("default"), min, max).await {
+                        Ok(_) => {
+                            *action_message.lock().unwrap() = Some((format!("Replicas range set to {}-{}", min, max), false));
+                            refresh_hpa_details(&client, &details, name, ns);
+                        }
+                        Err(e) => {
+                            *action_message.lock().unwrap() = Some((format!("Failed to update HPA: {}", e), true));
+                        }
+                    }
+                });
+            }
+        });
+        if let Some((message, is_error)) = action_message.lock().unwrap().clone() {
+            ui.label(egui::RichText::new(message).color(if is_error { ERROR_MESSAGE_COLOR } else { GREEN_BUTTON }));
+        }
+        ui.separator();
+        egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
+            egui::Grid::new("hpa_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+
+                ui.label(egui::RichText::new("HPA name:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&cur_name).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(creation_timestamp) = &item.creation_timestamp {
+                    ui.label(egui::RichText::new("Creation time:").color(ROW_NAME_COLOR));
+                    let timestamp_text = format!("{}, {} ago", creation_timestamp.0, crate::format_age(creation_timestamp));
+                    ui.label(egui::RichText::new(timestamp_text).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                if let Some(namespace) = &item.namespace {
+                    ui.label(egui::RichText::new("Namespace:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(namespace).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.label(egui::RichText::new("Scale target:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(format!("{}/{}", item.target_kind, item.target_name)).color(DETAIL_COLOR));
+                ui.end_row();
+
+                ui.label(egui::RichText::new("Replicas:").color(ROW_NAME_COLOR));
+                let replicas_color = if item.current_replicas >= item.max_replicas { WARNING_COLOR } else { DETAIL_COLOR };
+                ui.label(egui::RichText::new(format!("{} current, {} desired (min {}, max {})", item.current_replicas, item.desired_replicas, item.min_replicas, item.max_replicas)).color(replicas_color));
+                ui.end_row();
+
+                if let Some(last_scale_time) = &item.last_scale_time {
+                    ui.label(egui::RichText::new("Last scaled:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(format!("{} ago", crate::format_age(last_scale_time))).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.separator(); ui.separator(); ui.end_row();
+                ui.label(egui::RichText::new("Metrics:").color(ROW_NAME_COLOR));
+                if guard_details.metrics.is_empty() {
+                    ui.label(egui::RichText::new("No metrics, the controller defaults to 80% CPU").color(SECOND_DETAIL_COLOR));
+                } else {
+                    egui::Grid::new("hpa_details_metrics_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                        ui.label(egui::RichText::new("Type").color(PODS_HEAD_GRID_COLOR));
+                        ui.label(egui::RichText::new("Metric").color(PODS_HEAD_GRID_COLOR));
+                        ui.label(egui::RichText::new("Current").color(PODS_HEAD_GRID_COLOR));
+                        ui.label(egui::RichText::new("Target").color(PODS_HEAD_GRID_COLOR));
+                        ui.end_row();
+                        for metric in &guard_details.metrics {
+                            ui.label(egui::RichText::new(&metric.kind).color(SECOND_DETAIL_COLOR));
+                            ui.label(egui::RichText::new(&metric.name).color(DETAIL_COLOR));
+                            match &metric.current {
+                                Some(current) => ui.label(egui::RichText::new(current).color(DETAIL_COLOR)),
+                                None => ui.label(egui::RichText::new("<unknown>").color(WARNING_COLOR)),
+                            };
+                            ui.label(egui::RichText::new(&metric.target).color(DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                }
+                ui.end_row();
+
+                ui.label(egui::RichText::new("Behavior:").color(ROW_NAME_COLOR));
+                egui::Grid::new("hpa_details_behavior_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                    for (direction, rules) in &guard_details.behavior {
+                        ui.label(egui::RichText::new(direction).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(rules).color(SECOND_DETAIL_COLOR));
+                        ui.end_row();
+                    }
+                });
+                ui.end_row();
+
+                if !guard_details.conditions.is_empty() {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(egui::RichText::new("Conditions:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("hpa_details_conditions_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                        for condition in guard_details.conditions.iter() {
+                            // ScalingLimited is the one condition that is bad when true
+                            let healthy = (condition.status == "True") != (condition.type_ == "ScalingLimited");
+                            let color = if healthy { GREEN_BUTTON } else { WARNING_COLOR };
+                            ui.label(egui::RichText::new(&condition.type_).color(color));
+                            ui.label(egui::RichText::new(&condition.status).color(color));
+                            ui.label(egui::RichText::new(condition.reason.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(condition.message.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Scaling history:").color(ROW_NAME_COLOR));
+            let rescales: Vec<_> = guard_details.events.iter().filter(|e| e.reason.as_deref() == Some("SuccessfulRescale")).collect();
+            if rescales.is_empty() {
+                ui.label(egui::RichText::new("No recent rescale events").color(SECOND_DETAIL_COLOR));
+            } else {
+                egui::Grid::new("hpa_details_rescale_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for event in rescales {
+                        ui.label(egui::RichText::new(event.timestamp.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.message.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.end_row();
+                    }
+                });
+            }
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
+            let others: Vec<_> = guard_details.events.iter().filter(|e| e.reason.as_deref() != Some("SuccessfulRescale")).collect();
+            if !others.is_empty() {
+                egui::Grid::new("hpa_details_events_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for event in others {
+                        ui.label(egui::RichText::new(event.timestamp.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.message.clone().unwrap_or_default()).color(item_color(&event.event_type.clone().unwrap_or_default())));
+                        ui.end_row();
+                    }
+                });
+            }
+        });
+    });
+    crate::show_delete_confirmation(ctx, delete_confirm);
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            hpa_details_window.show = false;
+        }
+}
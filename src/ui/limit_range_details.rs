@@ -0,0 +1,184 @@
+use std::sync::{Arc, Mutex};
+use egui::{Context, Key};
+use crate::functions::item_color;
+use crate::{ui::YamlEditorWindow};
+use crate::theme::*;
+
+pub struct LimitRangeDetailsWindow {
+    pub show: bool,
+}
+
+impl LimitRangeDetailsWindow {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+        }
+    }
+}
+
+pub fn show_limit_range_details_window(
+        ctx: &Context,
+        limit_range_details_window: &mut LimitRangeDetailsWindow,
+        details: Arc<Mutex<crate::LimitRangeDetails>>,
+        limit_ranges: Arc<Mutex<Vec<crate::LimitRangeItem>>>,
+        yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        client: Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) {
+    let guard_details = details.lock().unwrap(); // More detailed info
+    let guard_limit_ranges = limit_ranges.lock().unwrap(); // LimitRanges with base details already we have
+    if guard_details.name.is_none() {
+        return;
+    }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "LimitRange details", &mut limit_range_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_limit_range_details(client, &name, ns, details).await {
+                    log::error!("LimitRange details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
+    let limit_range_item = guard_limit_ranges.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
+    let Some(item) = limit_range_item else {
+        return;
+    };
+    let cur_ns = &item.namespace;
+
+    let response = egui::Window::new("LimitRange details").min_width(800.0).collapsible(false).resizable(true).open(&mut limit_range_details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
+                crate::edit_yaml_for::<k8s_openapi::api::core::v1::LimitRange>(
+                    item.name.clone(),
+                    cur_ns.to_owned().unwrap(),
+                    Arc::clone(&yaml_editor_window),
+                    Arc::clone(&client),
+                );
+            }
+
+            if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
+                let name = item.name.clone();
+                let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
+
+                delete_confirm.request(name.clone(), ns.clone(), move || {
+                    tokio::spawn(async move {
+                        if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::LimitRange>(
+                            name,
+                            ns.as_deref(),
+                            client,
+                        ).await {
+                            log::error!("Failed to delete limit range: {}", err);
+                        }
+                    });
+                });
+            }
+        });
+        ui.separator();
+        egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
+            egui::Grid::new("limit_range_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+
+                ui.label(egui::RichText::new("LimitRange name:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&item.name).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(creation_timestamp) = &item.creation_timestamp {
+                    ui.label(egui::RichText::new("Creation time:").color(ROW_NAME_COLOR));
+                    let timestamp_text = format!("{}, {} ago", creation_timestamp.0, crate::format_age(creation_timestamp));
+                    ui.label(egui::RichText::new(timestamp_text).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                if let Some(namespace) = &item.namespace {
+                    ui.label(egui::RichText::new("Namespace:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(namespace).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.separator(); ui.separator(); ui.end_row();
+                ui.label(egui::RichText::new("Limits:").color(ROW_NAME_COLOR));
+                egui::Grid::new("limit_range_details_limits_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                    ui.label(egui::RichText::new("Type").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Resource").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Min").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Max").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Default request").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Default limit").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Max limit/request ratio").color(PODS_HEAD_GRID_COLOR));
+                    ui.end_row();
+                    for limit in &item.limits {
+                        let mut resources: Vec<&String> = limit.min.keys()
+                            .chain(limit.max.keys())
+                            .chain(limit.default_request.keys())
+                            .chain(limit.default.keys())
+                            .chain(limit.max_limit_request_ratio.keys())
+                            .collect();
+                        resources.sort();
+                        resources.dedup();
+                        for resource in resources {
+                            let value = |values: &std::collections::BTreeMap<String, String>| values.get(resource).cloned().unwrap_or("-".to_string());
+                            ui.label(egui::RichText::new(&limit.type_).color(SECOND_DETAIL_COLOR));
+                            ui.label(egui::RichText::new(resource).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(value(&limit.min)).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(value(&limit.max)).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(value(&limit.default_request)).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(value(&limit.default)).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(value(&limit.max_limit_request_ratio)).color(DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    }
+                });
+                ui.end_row();
+
+                if let Some(labels) = guard_details.labels.clone() {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(egui::RichText::new("Labels:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("limit_range_details_labels_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                        for (j, y) in labels.iter() {
+                            ui.label(egui::RichText::new(j).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(y).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+
+                if let Some(annotations) = guard_details.annotations.clone() {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(egui::RichText::new("Annotations:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("limit_range_details_annotations_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                        for (j, y) in annotations.iter() {
+                            ui.label(egui::RichText::new(j).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(y).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
+            if !guard_details.events.is_empty() {
+                egui::Grid::new("limit_range_details_events_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for event in &guard_details.events {
+                        ui.label(egui::RichText::new(event.timestamp.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.message.clone().unwrap_or_default()).color(item_color(&event.event_type.clone().unwrap_or_default())));
+                        ui.end_row();
+                    }
+                });
+            }
+        });
+    });
+    crate::show_delete_confirmation(ctx, delete_confirm);
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            limit_range_details_window.show = false;
+        }
+}
@@ -2,6 +2,8 @@ pub mod logs;
 pub mod new_resource;
 pub mod scale;
 pub mod yaml_editor;
+pub mod openapi;
+pub mod query;
 pub mod templates;
 pub mod other;
 pub mod node_details;
@@ -23,11 +25,16 @@ pub mod cluster_role_details;
 pub mod overview;
 pub mod about;
 pub mod confirmation_dialog;
+pub mod command_palette;
+pub mod bulk_actions;
+pub mod event_timeline;
 pub mod decoder;
 pub mod pvc_details;
 pub mod pv_details;
 pub mod crd_details;
+pub mod crd_schema;
 pub mod cr_details_parser;
+pub mod cr_instance_details;
 pub mod cluster_rb_details;
 pub mod rb_details;
 pub mod log_parser;
@@ -41,11 +48,26 @@ pub mod ai_consultant;
 pub mod configuration;
 pub mod lease_details;
 pub mod sc_details;
+pub mod fetch_error;
+pub mod rollout_history;
+pub mod debug_session;
+pub mod file_transfer;
+pub mod rbac_analyzer;
+pub mod network_policy_analyzer;
+pub mod hpa_details;
+pub mod resource_quota_details;
+pub mod limit_range_details;
+pub mod namespace_capacity;
+pub mod webhook_configuration_details;
+pub mod validating_admission_policy_details;
+pub mod gateway_api;
 
 pub use logs::*;
 pub use new_resource::*;
 pub use scale::*;
 pub use yaml_editor::*;
+pub use openapi::*;
+pub use query::*;
 pub use templates::*;
 pub use other::*;
 pub use node_details::*;
@@ -67,11 +89,16 @@ pub use cluster_role_details::*;
 pub use overview::*;
 pub use about::*;
 pub use confirmation_dialog::*;
+pub use command_palette::*;
+pub use bulk_actions::*;
+pub use event_timeline::*;
 pub use decoder::*;
 pub use pvc_details::*;
 pub use pv_details::*;
 pub use crd_details::*;
+pub use crd_schema::*;
 pub use cr_details_parser::*;
+pub use cr_instance_details::*;
 pub use cluster_rb_details::*;
 pub use rb_details::*;
 pub use log_parser::*;
@@ -85,3 +112,16 @@ pub use ai_consultant::*;
 pub use configuration::*;
 pub use lease_details::*;
 pub use sc_details::*;
+pub use fetch_error::*;
+pub use rollout_history::*;
+pub use debug_session::*;
+pub use file_transfer::*;
+pub use rbac_analyzer::*;
+pub use network_policy_analyzer::*;
+pub use hpa_details::*;
+pub use resource_quota_details::*;
+pub use limit_range_details::*;
+pub use namespace_capacity::*;
+pub use webhook_configuration_details::*;
+pub use validating_admission_policy_details::*;
+pub use gateway_api::*;
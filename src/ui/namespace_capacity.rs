@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use egui::{Color32, RichText, Ui};
+use crate::{LimitRangeItem, NamespaceItem, PodItem, ResourceQuotaItem};
+use crate::theme::*;
+
+/// Quota usage from this ratio on is highlighted as close to the limit
+pub const QUOTA_WARNING_RATIO: f64 = 0.8;
+
+pub fn usage_color(ratio: f64) -> Color32 {
+    if ratio >= 1.0 {
+        ERROR_MESSAGE_COLOR
+    } else if ratio >= QUOTA_WARNING_RATIO {
+        WARNING_COLOR
+    } else {
+        GREEN_BUTTON
+    }
+}
+
+pub struct NamespaceCapacityState {
+    pub filter: String,
+    pub only_with_quotas: bool,
+    pub sort_by_usage: bool,
+}
+
+impl NamespaceCapacityState {
+    pub fn new() -> Self {
+        Self {
+            filter: String::new(),
+            only_with_quotas: false,
+            sort_by_usage: true,
+        }
+    }
+}
+
+pub struct NamespaceCapacityRow<'a> {
+    pub namespace: String,
+    /// Pods still holding resources, Succeeded and Failed ones do not count against quotas
+    pub pods: usize,
+    pub requests: BTreeMap<String, f64>,
+    pub limits: BTreeMap<String, f64>,
+    pub quotas: Vec<&'a ResourceQuotaItem>,
+    /// Highest used/hard ratio over all quotas of the namespace, with the quota and resource
+    pub max_usage: Option<(String, String, f64)>,
+    pub limit_ranges: Vec<&'a LimitRangeItem>,
+}
+
+pub fn build_capacity_rows<'a>(namespaces: &[NamespaceItem], pods: &[PodItem], quotas: &'a [ResourceQuotaItem], limit_ranges: &'a [LimitRangeItem]) -> Vec<NamespaceCapacityRow<'a>> {
+    let mut rows: BTreeMap<String, NamespaceCapacityRow<'a>> = namespaces.iter().map(|ns| (ns.name.clone(), NamespaceCapacityRow {
+        namespace: ns.name.clone(),
+        pods: 0,
+        requests: BTreeMap::new(),
+        limits: BTreeMap::new(),
+        quotas: Vec::new(),
+        max_usage: None,
+        limit_ranges: Vec::new(),
+    })).collect();
+
+    for pod in pods {
+        if matches!(pod.phase.as_deref(), Some("Succeeded") | Some("Failed")) {
+            continue;
+        }
+        let Some(row) = pod.namespace.as_ref().and_then(|ns| rows.get_mut(ns)) else {
+            continue;
+        };
+        row.pods += 1;
+        for (resource, value) in &pod.requests {
+            *row.requests.entry(resource.clone()).or_default() += value;
+        }
+        for (resource, value) in &pod.limits {
+            *row.limits.entry(resource.clone()).or_default() += value;
+        }
+    }
+
+    for quota in quotas {
+        let Some(row) = quota.namespace.as_ref().and_then(|ns| rows.get_mut(ns)) else {
+            continue;
+        };
+        if let Some((resource, ratio)) = quota.max_usage()
+            && row.max_usage.as_ref().is_none_or(|(_, _, max)| ratio > *max) {
+                row.max_usage = Some((quota.name.clone(), resource, ratio));
+            }
+        row.quotas.push(quota);
+    }
+
+    for limit_range in limit_ranges {
+        if let Some(row) = limit_range.namespace.as_ref().and_then(|ns| rows.get_mut(ns)) {
+            row.limit_ranges.push(limit_range);
+        }
+    }
+
+    rows.into_values().collect()
+}
+
+fn format_resource_pair(row: &NamespaceCapacityRow, resource: &str) -> String {
+    let format = |values: &BTreeMap<String, f64>| values.get(resource).map(|v| crate::format_quantity(resource, *v)).unwrap_or("-".to_string());
+    format!("{} / {}", format(&row.requests), format(&row.limits))
+}
+
+fn quota_summary(quota: &ResourceQuotaItem) -> String {
+    quota.hard.iter()
+        .map(|(resource, hard)| format!("{}: {}/{}", resource, quota.used.get(resource).map(String::as_str).unwrap_or("0"), hard))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Container defaults of the namespace LimitRanges, "cpu 100m/500m" is default request/limit
+fn container_defaults(limit_ranges: &[&LimitRangeItem]) -> String {
+    let mut parts = Vec::new();
+    for limit in limit_ranges.iter().filter_map(|l| l.container_defaults()) {
+        let mut resources: Vec<&String> = limit.default_request.keys().chain(limit.default.keys()).collect();
+        resources.sort();
+        resources.dedup();
+        for resource in resources {
+            parts.push(format!("{} {}/{}",
+                resource,
+                limit.default_request.get(resource).map(String::as_str).unwrap_or("-"),
+                limit.default.get(resource).map(String::as_str).unwrap_or("-"),
+            ));
+        }
+    }
+    if parts.is_empty() { "-".to_string() } else { parts.join(", ") }
+}
+
+pub fn show_namespace_capacity(ui: &mut Ui, state: &mut NamespaceCapacityState, mut rows: Vec<NamespaceCapacityRow>) {
+    rows.retain(|row| (!state.only_with_quotas || !row.quotas.is_empty())
+        && (state.filter.is_empty() || row.namespace.to_lowercase().contains(&state.filter.to_lowercase())));
+    if state.sort_by_usage {
+        rows.sort_by(|a, b| {
+            let ratio = |row: &NamespaceCapacityRow| row.max_usage.as_ref().map(|(_, _, r)| *r).unwrap_or(-1.0);
+            ratio(b).total_cmp(&ratio(a))
+        });
+    }
+
+    ui.horizontal(|ui| {
+        ui.heading(format!("Namespace capacity - {}", rows.len()));
+        ui.separator();
+        ui.add(egui::TextEdit::singleline(&mut state.filter).hint_text("Filter namespaces...").desired_width(200.0));
+        if ui.button("ｘ").clicked() {
+            state.filter.clear();
+        }
+        ui.separator();
+        ui.checkbox(&mut state.only_with_quotas, "Only with quotas");
+        ui.checkbox(&mut state.sort_by_usage, "Sort by usage");
+    });
+    ui.label(RichText::new(format!(
+        "Requests / limits are summed over running and pending pods. Usage is highlighted from {:.0}% of a quota.",
+        QUOTA_WARNING_RATIO * 100.0,
+    )).color(SECOND_DETAIL_COLOR));
+    ui.separator();
+
+    egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| {
+        egui::Grid::new("namespace_capacity_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+            ui.label(RichText::new("Namespace").color(PODS_HEAD_GRID_COLOR));
+            ui.label(RichText::new("Pods").color(PODS_HEAD_GRID_COLOR));
+            ui.label(RichText::new("CPU req / lim").color(PODS_HEAD_GRID_COLOR));
+            ui.label(RichText::new("Memory req / lim").color(PODS_HEAD_GRID_COLOR));
+            ui.label(RichText::new("Quotas").color(PODS_HEAD_GRID_COLOR));
+            ui.label(RichText::new("Highest usage").color(PODS_HEAD_GRID_COLOR));
+            ui.label(RichText::new("Container defaults").color(PODS_HEAD_GRID_COLOR));
+            ui.end_row();
+
+            for row in &rows {
+                ui.label(RichText::new(&row.namespace).color(NAMESPACE_COLUMN_COLOR));
+                ui.label(RichText::new(row.pods.to_string()).color(DETAIL_COLOR));
+                ui.label(RichText::new(format_resource_pair(row, "cpu")).color(DETAIL_COLOR));
+                ui.label(RichText::new(format_resource_pair(row, "memory")).color(DETAIL_COLOR));
+
+                if row.quotas.is_empty() {
+                    ui.label(RichText::new("-").color(SECOND_DETAIL_COLOR));
+                } else {
+                    ui.vertical(|ui| {
+                        for quota in &row.quotas {
+                            ui.label(RichText::new(&quota.name).color(DETAIL_COLOR)).on_hover_text(quota_summary(quota));
+                        }
+                    });
+                }
+
+                match &row.max_usage {
+                    Some((quota, resource, ratio)) => {
+                        let percent = if ratio.is_finite() { format!("{:.0}%", ratio * 100.0) } else { "over".to_string() };
+                        let used = row.quotas.iter()
+                            .find(|q| &q.name == quota)
+                            .map(|q| format!("{}/{}", q.used.get(resource).map(String::as_str).unwrap_or("0"), q.hard[resource]))
+                            .unwrap_or_default();
+                        ui.label(RichText::new(format!("{} {} ({})", resource, used, percent)).color(usage_color(*ratio)))
+                            .on_hover_text(format!("ResourceQuota {}", quota));
+                    }
+                    None => {
+                        ui.label(RichText::new("-").color(SECOND_DETAIL_COLOR));
+                    }
+                }
+
+                ui.label(RichText::new(container_defaults(&row.limit_ranges)).color(SECOND_DETAIL_COLOR));
+                ui.end_row();
+            }
+        });
+    });
+}
@@ -0,0 +1,482 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::IpAddr;
+use egui::{RichText, Ui};
+use ipnetwork::IpNetwork;
+use k8s_openapi::api::networking::v1::{IPBlock, NetworkPolicyPeer, NetworkPolicyPort};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use crate::{NamespaceItem, NetworkPolicyItem, PodItem};
+use crate::theme::*;
+
+/// Pods shown in the matrix, beyond this it is unreadable anyway
+const MATRIX_MAX_PODS: usize = 30;
+
+/// Label every namespace gets from the API server, policies commonly select namespaces by it
+const NAMESPACE_NAME_LABEL: &str = "kubernetes.io/metadata.name";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum NetpolView {
+    Policies,
+    Simulator,
+    Matrix,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortQuery {
+    pub port: i32,
+    pub protocol: String,
+}
+
+impl PortQuery {
+    pub fn describe(&self) -> String {
+        format!("{}/{}", self.port, self.protocol)
+    }
+}
+
+/// Outcome of one side of a connection: the source egress or the destination ingress
+#[derive(Clone, Debug, Default)]
+pub struct DirectionVerdict {
+    /// Policies selecting the pod for this direction, none means the pod is not isolated
+    pub isolating: Vec<String>,
+    /// Isolating policies with a rule admitting the traffic
+    pub allowed_by: Vec<String>,
+}
+
+impl DirectionVerdict {
+    pub fn allowed(&self) -> bool {
+        self.isolating.is_empty() || !self.allowed_by.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TrafficVerdict {
+    pub egress: DirectionVerdict,
+    pub ingress: DirectionVerdict,
+}
+
+impl TrafficVerdict {
+    pub fn allowed(&self) -> bool {
+        self.egress.allowed() && self.ingress.allowed()
+    }
+}
+
+pub struct NetpolSnapshot<'a> {
+    pub policies: &'a [NetworkPolicyItem],
+    pub pods: &'a [PodItem],
+    namespace_labels: HashMap<String, BTreeMap<String, String>>,
+}
+
+fn pod_key(pod: &PodItem) -> (String, String) {
+    (pod.namespace.clone().unwrap_or_default(), pod.name.clone())
+}
+
+fn ip_block_matches(block: &IPBlock, ip: Option<&str>) -> bool {
+    let Some(ip) = ip.and_then(|ip| ip.parse::<IpAddr>().ok()) else {
+        return false;
+    };
+    let contains = |cidr: &str| cidr.parse::<IpNetwork>().is_ok_and(|net| net.contains(ip));
+    contains(&block.cidr) && !block.except.iter().flatten().any(|cidr| contains(cidr.as_str()))
+}
+
+/// Named ports are resolved against the destination pod, an empty list allows every port
+fn ports_match(ports: Option<&Vec<NetworkPolicyPort>>, destination: &PodItem, query: &PortQuery) -> bool {
+    let Some(ports) = ports.filter(|p| !p.is_empty()) else {
+        return true;
+    };
+    ports.iter().any(|p| {
+        let protocol = p.protocol.as_deref().unwrap_or("TCP");
+        if protocol != query.protocol {
+            return false;
+        }
+        match &p.port {
+            None => true,
+            Some(IntOrString::Int(port)) => match p.end_port {
+                Some(end) => (*port..=end).contains(&query.port),
+                None => *port == query.port,
+            },
+            Some(IntOrString::String(name)) => destination.named_ports.get(name)
+                .is_some_and(|(port, protocol)| *port == query.port && *protocol == query.protocol),
+        }
+    })
+}
+
+impl<'a> NetpolSnapshot<'a> {
+    pub fn new(policies: &'a [NetworkPolicyItem], pods: &'a [PodItem], namespaces: &[NamespaceItem]) -> Self {
+        let namespace_labels = namespaces.iter().map(|ns| {
+            let mut labels = ns.labels.clone().unwrap_or_default();
+            labels.entry(NAMESPACE_NAME_LABEL.to_string()).or_insert_with(|| ns.name.clone());
+            (ns.name.clone(), labels)
+        }).collect();
+        Self { policies, pods, namespace_labels }
+    }
+
+    fn namespace_matches(&self, selector: &LabelSelector, namespace: &str) -> bool {
+        match self.namespace_labels.get(namespace) {
+            Some(labels) => crate::label_selector_matches(selector, labels),
+            None => crate::label_selector_matches(selector, &BTreeMap::from([(NAMESPACE_NAME_LABEL.to_string(), namespace.to_string())])),
+        }
+    }
+
+    pub fn selects(&self, policy: &NetworkPolicyItem, pod: &PodItem) -> bool {
+        policy.namespace == pod.namespace && crate::label_selector_matches(&policy.selector, &pod.labels)
+    }
+
+    pub fn selected_pods(&self, policy: &NetworkPolicyItem) -> Vec<&'a PodItem> {
+        self.pods.iter().filter(|pod| self.selects(policy, pod)).collect()
+    }
+
+    /// A peer without namespaceSelector is limited to the policy namespace, both selectors together are AND-ed
+    fn peer_matches(&self, peer: &NetworkPolicyPeer, policy_namespace: &str, pod: &PodItem) -> bool {
+        if let Some(block) = &peer.ip_block {
+            return ip_block_matches(block, pod.pod_ip.as_deref());
+        }
+        let pod_namespace = pod.namespace.as_deref().unwrap_or_default();
+        let namespace_ok = match &peer.namespace_selector {
+            Some(selector) => self.namespace_matches(selector, pod_namespace),
+            None => pod_namespace == policy_namespace,
+        };
+        namespace_ok && peer.pod_selector.as_ref().is_none_or(|selector| crate::label_selector_matches(selector, &pod.labels))
+    }
+
+    fn peers_match(&self, peers: Option<&Vec<NetworkPolicyPeer>>, policy_namespace: &str, pod: &PodItem) -> bool {
+        match peers {
+            Some(peers) if !peers.is_empty() => peers.iter().any(|peer| self.peer_matches(peer, policy_namespace, pod)),
+            _ => true,
+        }
+    }
+
+    /// Evaluates every policy like the API defines it: traffic passes when the source egress and the destination ingress both allow it
+    pub fn evaluate(&self, source: &PodItem, destination: &PodItem, query: &PortQuery) -> TrafficVerdict {
+        let mut egress = DirectionVerdict::default();
+        let mut ingress = DirectionVerdict::default();
+        for policy in self.policies {
+            let policy_namespace = policy.namespace.as_deref().unwrap_or_default();
+            if policy.isolates_egress && self.selects(policy, source) {
+                egress.isolating.push(policy.name.clone());
+                if policy.egress.iter().any(|rule| self.peers_match(rule.to.as_ref(), policy_namespace, destination) && ports_match(rule.ports.as_ref(), destination, query)) {
+                    egress.allowed_by.push(policy.name.clone());
+                }
+            }
+            if policy.isolates_ingress && self.selects(policy, destination) {
+                ingress.isolating.push(policy.name.clone());
+                if policy.ingress.iter().any(|rule| self.peers_match(rule.from.as_ref(), policy_namespace, source) && ports_match(rule.ports.as_ref(), destination, query)) {
+                    ingress.allowed_by.push(policy.name.clone());
+                }
+            }
+        }
+        TrafficVerdict { egress, ingress }
+    }
+
+    fn find_pod(&self, key: &Option<(String, String)>) -> Option<&'a PodItem> {
+        let key = key.as_ref()?;
+        self.pods.iter().find(|pod| pod_key(pod) == *key)
+    }
+
+    fn namespaces(&self) -> BTreeSet<String> {
+        self.pods.iter().filter_map(|pod| pod.namespace.clone()).collect()
+    }
+}
+
+fn describe_peer(peer: &NetworkPolicyPeer) -> String {
+    if let Some(block) = &peer.ip_block {
+        let except = block.except.as_ref().filter(|e| !e.is_empty()).map(|e| format!(" except {}", e.join(", "))).unwrap_or_default();
+        return format!("ipBlock {}{}", block.cidr, except);
+    }
+    let pods = match &peer.pod_selector {
+        Some(selector) if !crate::format_label_selector(selector).is_empty() => format!("pods {}", crate::format_label_selector(selector)),
+        _ => "all pods".to_string(),
+    };
+    let namespaces = match &peer.namespace_selector {
+        None => "in the policy namespace".to_string(),
+        Some(selector) if crate::format_label_selector(selector).is_empty() => "in all namespaces".to_string(),
+        Some(selector) => format!("in namespaces {}", crate::format_label_selector(selector)),
+    };
+    format!("{} {}", pods, namespaces)
+}
+
+fn describe_peers(peers: Option<&Vec<NetworkPolicyPeer>>) -> String {
+    match peers {
+        Some(peers) if !peers.is_empty() => peers.iter().map(describe_peer).collect::<Vec<_>>().join("\nor "),
+        _ => "anywhere".to_string(),
+    }
+}
+
+fn describe_ports(ports: Option<&Vec<NetworkPolicyPort>>) -> String {
+    match ports {
+        Some(ports) if !ports.is_empty() => ports.iter().map(|p| {
+            let protocol = p.protocol.as_deref().unwrap_or("TCP");
+            match (&p.port, p.end_port) {
+                (None, _) => format!("all/{}", protocol),
+                (Some(IntOrString::Int(port)), Some(end)) => format!("{}-{}/{}", port, end, protocol),
+                (Some(IntOrString::Int(port)), None) => format!("{}/{}", port, protocol),
+                (Some(IntOrString::String(name)), _) => format!("{}/{}", name, protocol),
+            }
+        }).collect::<Vec<_>>().join(", "),
+        _ => "all ports".to_string(),
+    }
+}
+
+pub struct NetpolAnalyzerState {
+    pub view: NetpolView,
+    /// Empty for all namespaces in the policies view
+    pub namespace: String,
+    pub source: Option<(String, String)>,
+    pub destination: Option<(String, String)>,
+    pub query: PortQuery,
+}
+
+impl NetpolAnalyzerState {
+    pub fn new() -> Self {
+        Self {
+            view: NetpolView::Policies,
+            namespace: String::new(),
+            source: None,
+            destination: None,
+            query: PortQuery { port: 80, protocol: "TCP".to_string() },
+        }
+    }
+}
+
+fn show_port_inputs(ui: &mut Ui, query: &mut PortQuery) {
+    ui.label(RichText::new("Port:").color(ROW_NAME_COLOR));
+    ui.add(egui::DragValue::new(&mut query.port).range(1..=65535));
+    egui::ComboBox::from_id_salt("netpol_protocol").selected_text(query.protocol.clone()).width(70.0).show_ui(ui, |ui| {
+        for protocol in ["TCP", "UDP", "SCTP"] {
+            ui.selectable_value(&mut query.protocol, protocol.to_string(), protocol);
+        }
+    });
+}
+
+fn show_pod_picker(ui: &mut Ui, id: &str, label: &str, selected: &mut Option<(String, String)>, snapshot: &NetpolSnapshot) {
+    ui.label(RichText::new(label).color(ROW_NAME_COLOR));
+    let mut namespace = selected.as_ref().map(|(ns, _)| ns.clone()).unwrap_or_default();
+    egui::ComboBox::from_id_salt(format!("{}_namespace", id)).selected_text(namespace.clone()).width(150.0).show_ui(ui, |ui| {
+        for ns in snapshot.namespaces() {
+            ui.selectable_value(&mut namespace, ns.clone(), ns);
+        }
+    });
+    if selected.as_ref().is_none_or(|(ns, _)| *ns != namespace) {
+        *selected = snapshot.pods.iter().find(|pod| pod.namespace.as_deref() == Some(namespace.as_str())).map(pod_key);
+    }
+    let pod_name = selected.as_ref().map(|(_, name)| name.clone()).unwrap_or_default();
+    egui::ComboBox::from_id_salt(format!("{}_pod", id)).selected_text(pod_name).width(250.0).show_ui(ui, |ui| {
+        for pod in snapshot.pods.iter().filter(|pod| pod.namespace.as_deref() == Some(namespace.as_str())) {
+            let key = pod_key(pod);
+            ui.selectable_value(selected, Some(key), &pod.name);
+        }
+    });
+}
+
+fn show_direction(ui: &mut Ui, title: &str, pod: &PodItem, verdict: &DirectionVerdict) {
+    let (status, color) = if verdict.allowed() { ("✔ allowed", GREEN_BUTTON) } else { ("✖ blocked", ERROR_MESSAGE_COLOR) };
+    ui.label(RichText::new(title).color(ROW_NAME_COLOR));
+    ui.label(RichText::new(status).color(color));
+    let detail = if verdict.isolating.is_empty() {
+        format!("{} is not selected by any policy for this direction", pod.name)
+    } else if verdict.allowed_by.is_empty() {
+        format!("Isolated by {}, none of their rules matches", verdict.isolating.join(", "))
+    } else {
+        format!("Isolated by {}, allowed by {}", verdict.isolating.join(", "), verdict.allowed_by.join(", "))
+    };
+    ui.label(RichText::new(detail).color(SECOND_DETAIL_COLOR));
+    ui.end_row();
+}
+
+fn show_simulator_view(ui: &mut Ui, state: &mut NetpolAnalyzerState, snapshot: &NetpolSnapshot) {
+    ui.horizontal(|ui| {
+        show_pod_picker(ui, "netpol_source", "From:", &mut state.source, snapshot);
+    });
+    ui.horizontal(|ui| {
+        show_pod_picker(ui, "netpol_destination", "To:", &mut state.destination, snapshot);
+    });
+    ui.horizontal(|ui| {
+        show_port_inputs(ui, &mut state.query);
+    });
+    ui.separator();
+
+    let (Some(source), Some(destination)) = (snapshot.find_pod(&state.source), snapshot.find_pod(&state.destination)) else {
+        ui.label(RichText::new("Pick a source and a destination pod").color(SECOND_DETAIL_COLOR));
+        return;
+    };
+    let verdict = snapshot.evaluate(source, destination, &state.query);
+    let (text, color) = if verdict.allowed() {
+        (format!("✔ {} can reach {} on {}", source.name, destination.name, state.query.describe()), GREEN_BUTTON)
+    } else {
+        (format!("✖ {} cannot reach {} on {}", source.name, destination.name, state.query.describe()), ERROR_MESSAGE_COLOR)
+    };
+    ui.heading(RichText::new(text).color(color));
+    egui::Grid::new("netpol_verdict_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+        show_direction(ui, "Egress from source:", source, &verdict.egress);
+        show_direction(ui, "Ingress to destination:", destination, &verdict.ingress);
+    });
+    if source.pod_ip.is_none() || destination.pod_ip.is_none() {
+        ui.label(RichText::new("⚠ A pod has no IP yet, ipBlock rules cannot match it").color(WARNING_COLOR));
+    }
+    ui.label(RichText::new("Policies are only enforced when the CNI plugin supports them, and ipBlock handling of pod IPs depends on the plugin").color(TOLERATIONS_HEAD_GRID_COLOR));
+}
+
+fn show_matrix_view(ui: &mut Ui, state: &mut NetpolAnalyzerState, snapshot: &NetpolSnapshot) {
+    let namespaces = snapshot.namespaces();
+    if !namespaces.contains(&state.namespace) {
+        state.namespace = namespaces.iter().next().cloned().unwrap_or_default();
+    }
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Namespace:").color(ROW_NAME_COLOR));
+        egui::ComboBox::from_id_salt("netpol_matrix_namespace").selected_text(state.namespace.clone()).width(150.0).show_ui(ui, |ui| {
+            for ns in &namespaces {
+                ui.selectable_value(&mut state.namespace, ns.clone(), ns);
+            }
+        });
+        show_port_inputs(ui, &mut state.query);
+    });
+
+    let pods: Vec<&PodItem> = snapshot.pods.iter()
+        .filter(|pod| pod.namespace.as_deref() == Some(state.namespace.as_str()))
+        .filter(|pod| !pod.terminating && matches!(pod.phase.as_deref(), Some("Running") | Some("Pending")))
+        .collect();
+    if pods.is_empty() {
+        ui.label(RichText::new("No running pods in this namespace").color(SECOND_DETAIL_COLOR));
+        return;
+    }
+    if pods.len() > MATRIX_MAX_PODS {
+        ui.label(RichText::new(format!("Showing the first {} of {} pods", MATRIX_MAX_PODS, pods.len())).color(WARNING_COLOR));
+    }
+    let pods = &pods[..pods.len().min(MATRIX_MAX_PODS)];
+    ui.label(RichText::new("Rows are sources, columns destinations. Click a cell for the details.").color(SECOND_DETAIL_COLOR));
+    ui.separator();
+
+    let mut open = None;
+    egui::ScrollArea::both().auto_shrink(false).id_salt("netpol_matrix_scroll").show(ui, |ui| {
+        egui::Grid::new("netpol_matrix_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+            ui.label("");
+            for (i, pod) in pods.iter().enumerate() {
+                ui.label(RichText::new(format!("{}", i + 1)).color(PODS_HEAD_GRID_COLOR)).on_hover_text(&pod.name);
+            }
+            ui.end_row();
+            for (i, source) in pods.iter().enumerate() {
+                ui.label(RichText::new(format!("{}. {}", i + 1, source.name)).color(POD_NAME_COLUMN_COLOR));
+                for destination in pods {
+                    let verdict = snapshot.evaluate(source, destination, &state.query);
+                    let (text, color) = if verdict.allowed() { ("✔", GREEN_BUTTON) } else { ("✖", ERROR_MESSAGE_COLOR) };
+                    let cell = ui.add(egui::Button::new(RichText::new(text).color(color)).frame(false))
+                        .on_hover_text(format!("{} → {}", source.name, destination.name));
+                    if cell.clicked() {
+                        open = Some((pod_key(source), pod_key(destination)));
+                    }
+                }
+                ui.end_row();
+            }
+        });
+    });
+    if let Some((source, destination)) = open {
+        state.source = Some(source);
+        state.destination = Some(destination);
+        state.view = NetpolView::Simulator;
+    }
+}
+
+fn show_policies_view(ui: &mut Ui, state: &mut NetpolAnalyzerState, snapshot: &NetpolSnapshot) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Namespace:").color(ROW_NAME_COLOR));
+        let selected = if state.namespace.is_empty() { "all".to_string() } else { state.namespace.clone() };
+        egui::ComboBox::from_id_salt("netpol_policies_namespace").selected_text(selected).width(150.0).show_ui(ui, |ui| {
+            ui.selectable_value(&mut state.namespace, String::new(), "all");
+            let namespaces: BTreeSet<String> = snapshot.policies.iter().filter_map(|p| p.namespace.clone()).collect();
+            for ns in namespaces {
+                ui.selectable_value(&mut state.namespace, ns.clone(), ns);
+            }
+        });
+    });
+    ui.separator();
+
+    let policies: Vec<&NetworkPolicyItem> = snapshot.policies.iter()
+        .filter(|p| state.namespace.is_empty() || p.namespace.as_deref() == Some(state.namespace.as_str()))
+        .collect();
+    if policies.is_empty() {
+        ui.label(RichText::new("No network policies, all traffic is allowed").color(SECOND_DETAIL_COLOR));
+        return;
+    }
+    let mut simulate = None;
+    egui::ScrollArea::vertical().auto_shrink(false).id_salt("netpol_policies_scroll").show(ui, |ui| {
+        for policy in policies {
+            let namespace = policy.namespace.clone().unwrap_or_default();
+            let selected = snapshot.selected_pods(policy);
+            let title = format!("{}/{} - {} pods", namespace, policy.name, selected.len());
+            egui::CollapsingHeader::new(RichText::new(title).color(ITEM_NAME_COLOR)).id_salt(("netpol", &namespace, &policy.name)).show(ui, |ui| {
+                egui::Grid::new(("netpol_policy_grid", &namespace, &policy.name)).striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    ui.label(RichText::new("Pod selector:").color(ROW_NAME_COLOR));
+                    ui.label(RichText::new(&policy.pod_selector).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(RichText::new("Isolates:").color(ROW_NAME_COLOR));
+                    let isolates: Vec<&str> = [(policy.isolates_ingress, "Ingress"), (policy.isolates_egress, "Egress")]
+                        .into_iter().filter(|(on, _)| *on).map(|(_, name)| name).collect();
+                    ui.label(RichText::new(isolates.join(", ")).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(RichText::new("Selected pods:").color(ROW_NAME_COLOR));
+                    ui.vertical(|ui| {
+                        if selected.is_empty() {
+                            ui.label(RichText::new("none").color(WARNING_COLOR));
+                        }
+                        for pod in &selected {
+                            if ui.link(RichText::new(&pod.name).color(POD_NAME_COLUMN_COLOR)).on_hover_text("Simulate traffic to this pod").clicked() {
+                                simulate = Some(pod_key(pod));
+                            }
+                        }
+                    });
+                    ui.end_row();
+
+                    if policy.isolates_ingress {
+                        ui.label(RichText::new("Ingress rules:").color(ROW_NAME_COLOR));
+                        ui.vertical(|ui| {
+                            if policy.ingress.is_empty() {
+                                ui.label(RichText::new("none, all ingress is denied").color(ERROR_MESSAGE_COLOR));
+                            }
+                            for rule in &policy.ingress {
+                                ui.label(RichText::new(format!("from {}", describe_peers(rule.from.as_ref()))).color(DETAIL_COLOR));
+                                ui.label(RichText::new(format!("on {}", describe_ports(rule.ports.as_ref()))).color(SECOND_DETAIL_COLOR));
+                            }
+                        });
+                        ui.end_row();
+                    }
+
+                    if policy.isolates_egress {
+                        ui.label(RichText::new("Egress rules:").color(ROW_NAME_COLOR));
+                        ui.vertical(|ui| {
+                            if policy.egress.is_empty() {
+                                ui.label(RichText::new("none, all egress is denied").color(ERROR_MESSAGE_COLOR));
+                            }
+                            for rule in &policy.egress {
+                                ui.label(RichText::new(format!("to {}", describe_peers(rule.to.as_ref()))).color(DETAIL_COLOR));
+                                ui.label(RichText::new(format!("on {}", describe_ports(rule.ports.as_ref()))).color(SECOND_DETAIL_COLOR));
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+            });
+        }
+    });
+    if let Some(destination) = simulate {
+        state.destination = Some(destination);
+        state.view = NetpolView::Simulator;
+    }
+}
+
+pub fn show_network_policy_analyzer(ui: &mut Ui, state: &mut NetpolAnalyzerState, snapshot: &NetpolSnapshot) {
+    ui.horizontal(|ui| {
+        ui.heading("Network policy analyzer");
+        ui.separator();
+        ui.selectable_value(&mut state.view, NetpolView::Policies, "📋 Policies");
+        ui.selectable_value(&mut state.view, NetpolView::Simulator, "🚦 Traffic simulator");
+        ui.selectable_value(&mut state.view, NetpolView::Matrix, "▦ Namespace matrix");
+    });
+    ui.label(RichText::new(format!("{} network policies, {} pods", snapshot.policies.len(), snapshot.pods.len())).color(SECOND_DETAIL_COLOR));
+    ui.separator();
+    match state.view {
+        NetpolView::Policies => show_policies_view(ui, state, snapshot),
+        NetpolView::Simulator => show_simulator_view(ui, state, snapshot),
+        NetpolView::Matrix => show_matrix_view(ui, state, snapshot),
+    }
+}
@@ -1,11 +1,17 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::{Arc, Mutex}};
 use egui::{Context, Key};
 use kube::Client;
+use crate::ui::{ResourceTemplate, TemplateParamType};
 
 pub struct NewResourceWindow {
     pub resource_type: crate::ResourceType,
     pub content: String,
     pub show: bool,
+    pub templates: Vec<ResourceTemplate>,
+    pub template_name: String,
+    pub params: BTreeMap<String, String>,
+    /// Last template output, content differing from it has been edited by hand
+    pub rendered: String,
 }
 
 impl NewResourceWindow {
@@ -14,119 +20,143 @@ impl NewResourceWindow {
             resource_type: crate::ResourceType::Blank,
             content: String::new(),
             show: false,
+            templates: crate::load_templates(),
+            template_name: String::new(),
+            params: BTreeMap::new(),
+            rendered: String::new(),
         }
     }
+
+    fn select_template(&mut self, name: &str) {
+        let Some(template) = self.templates.iter().find(|t| t.name == name) else {
+            return;
+        };
+        self.template_name = template.name.clone();
+        if let Some(resource_type) = &template.resource_type {
+            self.resource_type = resource_type.clone();
+        }
+        self.params = template.params.iter().map(|p| (p.name.clone(), p.default.clone())).collect();
+        self.content = crate::render_template(template, &self.params);
+        self.rendered = self.content.clone();
+    }
+
+    fn render(&mut self) {
+        if let Some(template) = self.current_template() {
+            self.content = crate::render_template(template, &self.params);
+            self.rendered = self.content.clone();
+        }
+    }
+
+    fn current_template(&self) -> Option<&ResourceTemplate> {
+        self.templates.iter().find(|t| t.name == self.template_name)
+    }
 }
 
-pub fn show_new_resource_window(ctx: &Context, new_resource_window: &mut NewResourceWindow, client: Arc<Client>) {
+pub fn show_new_resource_window(
+        ctx: &Context,
+        new_resource_window: &mut NewResourceWindow,
+        client: Arc<Client>,
+        namespaces: Arc<Mutex<Vec<crate::NamespaceItem>>>,
+        storage_classes: Arc<Mutex<Vec<crate::StorageClassItem>>>,
+) {
     let response = egui::Window::new("Create New Resource").collapsible(false).resizable(true).default_width(600.0).show(ctx, |ui| {
+        // Opened from an "Add new" button, pick the built-in template of that resource type
         if new_resource_window.content.is_empty() {
-            new_resource_window.content = match new_resource_window.resource_type {
-                crate::ResourceType::NameSpace => crate::NAMESPACE_TEMPLATE.to_string(),
-                crate::ResourceType::Pod => crate::POD_TEMPLATE.to_string(),
-                crate::ResourceType::StorageClass => crate::STORAGE_CLASS_TEMPLATE.to_string(),
-                crate::ResourceType::Deployment => crate::DEPLOYMENT_TEMPLATE.to_string(),
-                crate::ResourceType::Service => crate::SERVICE_TEMPLATE.to_string(),
-                crate::ResourceType::DaemonSet => crate::DAEMONSET_TEMPLATE.to_string(),
-                crate::ResourceType::Ingress => crate::INGRESS_TEMPLATE.to_string(),
-                crate::ResourceType::ReplicaSet => crate::REPLICASET_TEMPLATE.to_string(),
-                crate::ResourceType::PodWithPvc => crate::POD_WITH_PVC_TEMPLATE.to_string(),
-                crate::ResourceType::ConfigMap => crate::CONFIGMAP_TEMPLATE.to_string(),
-                crate::ResourceType::Secret => crate::SECRET_TEMPLATE.to_string(),
-                crate::ResourceType::ExternalSecret => crate::EXTERNAL_SECRET_TEMPLATE.to_string(),
-                crate::ResourceType::Role => crate::ROLE_TEMPLATE.to_string(),
-                crate::ResourceType::RoleBinding => crate::CLUSTER_ROLE_BINDING_TEMPLATE.to_string(),
-                crate::ResourceType::ClusterRole => crate::CLUSTER_ROLE_TEMPLATE.to_string(),
-                crate::ResourceType::ClusterRoleBinding => crate::CLUSTER_ROLE_BINDING_TEMPLATE.to_string(),
-                crate::ResourceType::ServiceAccount => crate::SERVICE_ACCOUNT_TEMPLATE.to_string(),
-                crate::ResourceType::PersistenceVolumeClaim => crate::PVC_TEMPLATE.to_string(),
-                crate::ResourceType::Blank => "".to_string(),
-            };
+            let name = new_resource_window.templates.iter()
+                .find(|t| t.resource_type.as_ref() == Some(&new_resource_window.resource_type))
+                .map(|t| t.name.clone())
+                .unwrap_or_else(|| "Blank".to_string());
+            new_resource_window.select_template(&name);
         }
 
         ui.horizontal(|ui| {
             ui.label("YAML Template:");
-            egui::ComboBox::from_id_salt("templates_combo").width(150.0)
-                .selected_text(match new_resource_window.resource_type {
-                    crate::ResourceType::NameSpace => "NameSpace",
-                    crate::ResourceType::Secret => "Secret",
-                    crate::ResourceType::Deployment => "Deployment",
-                    crate::ResourceType::StorageClass => "StorageClass",
-                    crate::ResourceType::Role => "Role",
-                    crate::ResourceType::RoleBinding => "RoleBinding",
-                    crate::ResourceType::Service => "Service",
-                    crate::ResourceType::Ingress => "Ingress",
-                    crate::ResourceType::DaemonSet => "DaemonSet",
-                    crate::ResourceType::ReplicaSet => "ReplicaSet",
-                    crate::ResourceType::ConfigMap => "Configmap",
-                    crate::ResourceType::ClusterRole => "Cluster role",
-                    crate::ResourceType::ClusterRoleBinding => "Cluster role binding",
-                    crate::ResourceType::ExternalSecret => "External secret",
-                    crate::ResourceType::Pod => "Pod",
-                    crate::ResourceType::PodWithPvc => "Pod with PVC",
-                    crate::ResourceType::ServiceAccount => "Service account",
-                    crate::ResourceType::PersistenceVolumeClaim => "PersistenceVolumeClaim",
-                    crate::ResourceType::Blank => "Blank",
-                }).show_ui(ui, |ui| {
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::NameSpace, "NameSpace",).clicked() {
-                        new_resource_window.content = crate::NAMESPACE_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::Secret, "Secret",).clicked() {
-                        new_resource_window.content = crate::SECRET_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::Deployment, "Deployment",).clicked() {
-                        new_resource_window.content = crate::DEPLOYMENT_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::Service, "Service",).clicked() {
-                        new_resource_window.content = crate::SERVICE_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::StorageClass, "StorageClass",).clicked() {
-                        new_resource_window.content = crate::STORAGE_CLASS_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::DaemonSet, "DaemonSet",).clicked() {
-                        new_resource_window.content = crate::DAEMONSET_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::Ingress, "Ingress",).clicked() {
-                        new_resource_window.content = crate::INGRESS_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::ReplicaSet, "ReplicaSet",).clicked() {
-                        new_resource_window.content = crate::REPLICASET_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::Pod, "Pod",).clicked() {
-                        new_resource_window.content = crate::POD_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::PodWithPvc, "Pod with PVC",).clicked() {
-                        new_resource_window.content = crate::POD_WITH_PVC_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::ConfigMap, "Configmap",).clicked() {
-                        new_resource_window.content = crate::CONFIGMAP_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::ServiceAccount, "Service account",).clicked() {
-                        new_resource_window.content = crate::SERVICE_ACCOUNT_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::Role, "Role",).clicked() {
-                        new_resource_window.content = crate::ROLE_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::RoleBinding, "RoleBinding",).clicked() {
-                        new_resource_window.content = crate::ROLE_BINDING_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::ClusterRole, "Cluster role",).clicked() {
-                        new_resource_window.content = crate::CLUSTER_ROLE_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::ClusterRoleBinding, "Cluster role binding",).clicked() {
-                        new_resource_window.content = crate::CLUSTER_ROLE_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::ExternalSecret, "External secret",).clicked() {
-                        new_resource_window.content = crate::EXTERNAL_SECRET_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::PersistenceVolumeClaim,"PersistenceVolumeClaim",).clicked() {
-                        new_resource_window.content = crate::PVC_TEMPLATE.to_string();
-                    };
-                    if ui.selectable_value(&mut new_resource_window.resource_type, crate::ResourceType::Blank,"Blank",).clicked() {
-                        new_resource_window.content = "".to_string();
-                    };
+            let mut selected: Option<String> = None;
+            egui::ComboBox::from_id_salt("templates_combo").width(200.0)
+                .selected_text(new_resource_window.template_name.clone())
+                .show_ui(ui, |ui| {
+                    for template in &new_resource_window.templates {
+                        let label = if template.resource_type.is_some() {
+                            template.name.clone()
+                        } else {
+                            format!("{} (user)", template.name)
+                        };
+                        if ui.selectable_label(template.name == new_resource_window.template_name, label).clicked() {
+                            selected = Some(template.name.clone());
+                        }
+                    }
                 });
+            if let Some(name) = selected {
+                new_resource_window.select_template(&name);
+            }
+
+            if ui.button("⟳ Reload templates").clicked() {
+                new_resource_window.templates = crate::load_templates();
+                let name = new_resource_window.template_name.clone();
+                new_resource_window.select_template(&name);
+            }
         });
+
+        let params = new_resource_window.current_template().map(|t| t.params.clone()).unwrap_or_default();
+        if !params.is_empty() {
+            ui.separator();
+            let mut changed = false;
+            egui::Grid::new("template_params_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                for param in &params {
+                    ui.label(egui::RichText::new(format!("{}:", param.name)).color(crate::ROW_NAME_COLOR));
+                    let value = new_resource_window.params.entry(param.name.clone()).or_insert_with(|| param.default.clone());
+                    match param.param_type {
+                        TemplateParamType::Namespace => {
+                            egui::ComboBox::from_id_salt(format!("template_param_{}", param.name)).width(200.0)
+                                .selected_text(value.clone())
+                                .show_ui(ui, |ui| {
+                                    for ns in namespaces.lock().unwrap().iter() {
+                                        changed |= ui.selectable_value(value, ns.name.clone(), &ns.name).changed();
+                                    }
+                                });
+                        }
+                        TemplateParamType::StorageClass => {
+                            egui::ComboBox::from_id_salt(format!("template_param_{}", param.name)).width(200.0)
+                                .selected_text(value.clone())
+                                .show_ui(ui, |ui| {
+                                    for sc in storage_classes.lock().unwrap().iter() {
+                                        let label = if sc.is_default == "Yes" {
+                                            format!("{} (default)", sc.name)
+                                        } else {
+                                            sc.name.clone()
+                                        };
+                                        changed |= ui.selectable_value(value, sc.name.clone(), label).changed();
+                                    }
+                                });
+                        }
+                        TemplateParamType::Replicas => {
+                            let mut replicas: u32 = value.parse().unwrap_or(1);
+                            if ui.add(egui::DragValue::new(&mut replicas).range(0..=1000)).changed() {
+                                *value = replicas.to_string();
+                                changed = true;
+                            }
+                        }
+                        TemplateParamType::Name | TemplateParamType::Image | TemplateParamType::String => {
+                            changed |= ui.add(egui::TextEdit::singleline(value).desired_width(300.0)).changed();
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+            let edited = new_resource_window.content != new_resource_window.rendered;
+            if changed && !edited {
+                new_resource_window.render();
+            }
+            if edited {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("⚠ YAML edited by hand, parameter changes are not applied").color(crate::WARNING_COLOR));
+                    if ui.button("⟳ Re-render (discard edits)").clicked() {
+                        new_resource_window.render();
+                    }
+                });
+            }
+        }
+
         ui.separator();
         egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
             ui.add(egui::TextEdit::multiline(&mut new_resource_window.content)
@@ -143,9 +173,16 @@ pub fn show_new_resource_window(ctx: &Context, new_resource_window: &mut NewReso
             if ui.button(egui::RichText::new("✔ Apply").size(16.0).color(egui::Color32::GREEN)).clicked() {
                 let yaml = new_resource_window.content.clone();
                 let client_clone = Arc::clone(&client);
-                let resource_type = new_resource_window.resource_type.clone();
+                let resource_type = new_resource_window.current_template()
+                    .and_then(|t| t.resource_type.clone())
+                    .filter(|t| *t != crate::ResourceType::Blank);
                 tokio::spawn(async move {
-                    if let Err(e) = crate::apply_yaml(client_clone, &yaml, resource_type).await {
+                    // User templates and blank YAML may describe any kind, apply them server side
+                    let result = match resource_type {
+                        Some(resource_type) => crate::apply_yaml(client_clone, &yaml, resource_type).await,
+                        None => crate::patch_resource(client_clone, &yaml).await,
+                    };
+                    if let Err(e) = result {
                         log::error!("Error applying YAML: {:?}", e);
                     }
                 });
@@ -1,16 +1,20 @@
 use std::sync::{Arc, Mutex};
 use egui::{Context, CursorIcon, Key};
 use kube::Client;
-use crate::{functions::item_color, get_details::get_pod_details, theme::*, ui::PodDetailsWindow};
+use crate::{functions::item_color, get_details::get_pod_details, theme::*, ui::{DebugSessionWindow, DebugTarget, PodDetailsWindow}};
 
 pub struct NodeDetailsWindow {
     pub show: bool,
+    pub show_debug_form: bool,
+    pub debug_image: String,
 }
 
 impl NodeDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            show_debug_form: false,
+            debug_image: crate::DEFAULT_DEBUG_IMAGE.to_string(),
         }
     }
 }
@@ -23,6 +27,7 @@ pub fn show_node_details_window(
         pods: Arc<Mutex<Vec<crate::PodItem>>>,
         pod_details_window: &mut PodDetailsWindow,
         pod_details: Arc<Mutex<crate::PodDetails>>,
+        debug_session_window: &mut DebugSessionWindow,
         client: Arc<Client>
 ){
     let guard_details = details.lock().unwrap(); // More detailed info
@@ -32,12 +37,47 @@ pub fn show_node_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Node details", &mut node_details_window.show, &name, error) {
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_node_details(client, &name, details).await {
+                    log::error!("Node details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
 
     let node_item = guard_nodes.iter().find(|item| item.name == guard_details.name.clone().unwrap());
 
     let pods: Vec<_> = guard_pods.iter().filter(|pod| pod.node_name.as_deref() == Some(guard_details.name.clone().unwrap().as_str())).cloned().collect();
 
     let response = egui::Window::new("Node details").min_width(800.0).collapsible(false).resizable(true).open(&mut node_details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.selectable_label(node_details_window.show_debug_form, egui::RichText::new("🐞 Debug node").size(16.0).color(crate::ORANGE_BUTTON))
+                .on_hover_text("Start a privileged pod in the host namespaces of this node, with its filesystem under /host")
+                .clicked() {
+                    node_details_window.show_debug_form = !node_details_window.show_debug_form;
+                }
+        });
+        if node_details_window.show_debug_form {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("Image:").color(ROW_NAME_COLOR));
+                ui.add(egui::TextEdit::singleline(&mut node_details_window.debug_image).desired_width(200.0));
+                let image = node_details_window.debug_image.trim().to_string();
+                if ui.add_enabled(!image.is_empty(), egui::Button::new(egui::RichText::new("▶ Start").color(crate::GREEN_BUTTON))).clicked() {
+                    crate::start_debug_session(debug_session_window, DebugTarget::Node {
+                        node: guard_details.name.clone().unwrap(),
+                        image,
+                    }, Arc::clone(&client));
+                    node_details_window.show_debug_form = false;
+                }
+            });
+            ui.label(egui::RichText::new("The pod runs privileged in the default namespace and is deleted when the debug session is closed").color(WARNING_COLOR));
+        }
+        ui.separator();
         egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
             egui::Grid::new("node_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
 
@@ -0,0 +1,227 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use kube::Client;
+use serde_json::Value;
+
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Lazily populated copy of the cluster's /openapi/v3 documents, one per group-version
+#[derive(Default)]
+pub struct OpenApiCache {
+    pub index: Option<BTreeMap<String, String>>,
+    pub schemas: HashMap<String, Arc<HashMap<String, Value>>>,
+    pub pending: HashSet<String>,
+    pub failed: HashSet<String>,
+    pub generation: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompletionItem {
+    pub key: String,
+    pub type_name: String,
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+/// "v1" -> "api/v1", "apps/v1" -> "apis/apps/v1"
+pub fn openapi_gv_path(api_version: &str) -> String {
+    if api_version.contains('/') {
+        format!("apis/{}", api_version)
+    } else {
+        format!("api/{}", api_version)
+    }
+}
+
+async fn openapi_request(client: &Client, path: &str) -> Result<Value, anyhow::Error> {
+    let req = http::Request::get(path).body(Vec::new())?;
+    let raw_json = client.request_text(req).await?;
+    Ok(serde_json::from_str(&raw_json)?)
+}
+
+pub async fn load_openapi_schemas(client: Arc<Client>, cache: Arc<Mutex<OpenApiCache>>, gv_path: String) {
+    let result: Result<HashMap<String, Value>, anyhow::Error> = async {
+        let index = cache.lock().unwrap().index.clone();
+        let index = match index {
+            Some(index) => index,
+            None => {
+                let root = openapi_request(&client, "/openapi/v3").await?;
+                let index: BTreeMap<String, String> = root.get("paths")
+                    .and_then(|p| p.as_object())
+                    .map(|paths| paths.iter().filter_map(|(k, v)| {
+                        v.get("serverRelativeURL").and_then(|u| u.as_str()).map(|u| (k.clone(), u.to_string()))
+                    }).collect())
+                    .unwrap_or_default();
+                cache.lock().unwrap().index = Some(index.clone());
+                index
+            }
+        };
+
+        let url = index.get(&gv_path).ok_or_else(|| anyhow::anyhow!("{} is not published in /openapi/v3", gv_path))?;
+        let document = openapi_request(&client, url).await?;
+        let schemas = document.get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(|s| s.as_object())
+            .map(|s| s.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+            .unwrap_or_default();
+        Ok(schemas)
+    }.await;
+
+    let mut guard = cache.lock().unwrap();
+    guard.pending.remove(&gv_path);
+    match result {
+        Ok(schemas) => {
+            guard.schemas.insert(gv_path, Arc::new(schemas));
+        }
+        Err(e) => {
+            log::warn!("OpenAPI schema for {} not loaded: {}", gv_path, e);
+            guard.failed.insert(gv_path);
+        }
+    }
+    guard.generation += 1;
+}
+
+pub fn resolve_schema_ref(schemas: &HashMap<String, Value>, reference: &str) -> Option<Value> {
+    schemas.get(reference.strip_prefix(SCHEMA_REF_PREFIX)?).cloned()
+}
+
+pub fn find_kind_schema<'a>(schemas: &'a HashMap<String, Value>, group: &str, version: &str, kind: &str) -> Option<&'a Value> {
+    schemas.values().find(|schema| {
+        schema.get("x-kubernetes-group-version-kind")
+            .and_then(|g| g.as_array())
+            .is_some_and(|gvks| gvks.iter().any(|gvk| {
+                gvk.get("group").and_then(|g| g.as_str()).unwrap_or_default() == group
+                    && gvk.get("version").and_then(|v| v.as_str()) == Some(version)
+                    && gvk.get("kind").and_then(|k| k.as_str()) == Some(kind)
+            }))
+    })
+}
+
+/// Follows `$ref` and single element `allOf` wrappers used by the apiserver for nested types
+fn unwrap_schema(schemas: &HashMap<String, Value>, schema: &Value) -> Value {
+    let mut current = schema.clone();
+    for _ in 0..16 {
+        if let Some(reference) = current.get("$ref").and_then(|r| r.as_str()) {
+            match resolve_schema_ref(schemas, reference) {
+                Some(resolved) => current = resolved,
+                None => break,
+            }
+        } else if let Some(inner) = current.get("allOf").and_then(|a| a.as_array()).filter(|a| a.len() == 1) {
+            current = inner[0].clone();
+        } else {
+            break;
+        }
+    }
+    current
+}
+
+/// Schema of the object located at `path`, arrays are stepped through transparently
+pub fn schema_at_path(schemas: &HashMap<String, Value>, root: &Value, path: &[String]) -> Option<Value> {
+    let mut current = unwrap_schema(schemas, root);
+    for key in path {
+        if current.get("type").and_then(|t| t.as_str()) == Some("array") {
+            current = unwrap_schema(schemas, current.get("items")?);
+        }
+        let next = current.get("properties").and_then(|p| p.get(key))
+            .or_else(|| current.get("additionalProperties").filter(|a| a.is_object()))?;
+        current = unwrap_schema(schemas, next);
+    }
+    if current.get("type").and_then(|t| t.as_str()) == Some("array") {
+        current = unwrap_schema(schemas, current.get("items")?);
+    }
+    Some(current)
+}
+
+pub fn completion_items(schemas: &HashMap<String, Value>, schema: &Value) -> Vec<CompletionItem> {
+    let required: Vec<&str> = schema.get("required")
+        .and_then(|r| r.as_array())
+        .map(|r| r.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+
+    let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) else {
+        return vec![];
+    };
+
+    let mut items: Vec<CompletionItem> = properties.iter().map(|(key, prop)| {
+        let resolved = unwrap_schema(schemas, prop);
+        CompletionItem {
+            key: key.clone(),
+            type_name: super::schema_type_name(&resolved),
+            required: required.contains(&key.as_str()),
+            description: prop.get("description").or(resolved.get("description")).and_then(|d| d.as_str()).map(|d| d.to_string()),
+        }
+    }).collect();
+    items.sort_by(|a, b| b.required.cmp(&a.required).then(a.key.cmp(&b.key)));
+    items
+}
+
+fn key_indent(line: &str) -> (usize, &str) {
+    let indent = line.len() - line.trim_start().len();
+    let rest = line.trim_start();
+    match rest.strip_prefix("- ") {
+        Some(item) => (indent + 2 + item.len() - item.trim_start().len(), item.trim_start()),
+        None => (indent, rest),
+    }
+}
+
+pub struct YamlCursorContext {
+    pub path: Vec<String>,
+    pub prefix: String,
+    pub indent: usize,
+}
+
+/// Key path of the mapping the cursor is in and the partially typed key before the cursor.
+/// Returns None when the cursor is not at a key position (e.g. after the colon).
+pub fn yaml_cursor_context(content: &str, cursor_byte: usize) -> Option<YamlCursorContext> {
+    let cursor_byte = cursor_byte.min(content.len());
+    let line_start = content[..cursor_byte].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let before_cursor = &content[line_start..cursor_byte];
+    let (key_column, prefix) = key_indent(before_cursor);
+    if prefix.contains(':') || prefix.contains(' ') || prefix.starts_with('#') {
+        return None;
+    }
+
+    let mut indent = key_column;
+    let mut path = Vec::new();
+    for line in content[..line_start].lines().rev() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.starts_with("---") {
+            break;
+        }
+        let (line_indent, rest) = key_indent(line);
+        if line_indent >= indent {
+            continue;
+        }
+        let key = rest.split(':').next().unwrap_or_default().trim();
+        let has_value = rest.split_once(':').map(|(_, v)| !v.trim().is_empty() && !v.trim().starts_with('#')).unwrap_or(true);
+        if key.is_empty() || has_value {
+            return None;
+        }
+        path.push(key.trim_matches('"').to_string());
+        indent = line_indent;
+        if indent == 0 {
+            break;
+        }
+    }
+    path.reverse();
+    Some(YamlCursorContext { path, prefix: prefix.to_string(), indent: key_column })
+}
+
+/// apiVersion and kind taken line by line, works while the buffer is not valid YAML
+pub fn yaml_type_meta(content: &str) -> Option<(String, String)> {
+    let mut api_version = None;
+    let mut kind = None;
+    for line in content.lines() {
+        if line.trim_start() == "---" && (api_version.is_some() || kind.is_some()) {
+            break;
+        }
+        if let Some(v) = line.strip_prefix("apiVersion:") {
+            api_version = Some(v.trim().trim_matches('"').to_string());
+        } else if let Some(k) = line.strip_prefix("kind:") {
+            kind = Some(k.trim().trim_matches('"').to_string());
+        }
+    }
+    Some((api_version?, kind?))
+}
@@ -22,3 +22,17 @@ pub fn show_empty(ui: &mut Ui) {
         },
     );
 }
+
+/// Shown above a category whose watcher was refused by RBAC, so an empty list is not mistaken for no objects
+pub fn show_access_denied(ui: &mut Ui, kind: &str, reason: &str) {
+    egui::Frame::new()
+        .fill(crate::IMPERSONATION_BANNER_COLOR)
+        .inner_margin(egui::Margin::symmetric(10, 6))
+        .corner_radius(egui::CornerRadius::same(4))
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.label(RichText::new(format!("🔒 Access denied: cannot list {}", kind)).strong().color(egui::Color32::WHITE));
+            ui.label(RichText::new(reason).color(egui::Color32::LIGHT_GRAY));
+        });
+    ui.add_space(4.0);
+}
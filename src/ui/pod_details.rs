@@ -1,15 +1,26 @@
 use std::sync::{Arc, Mutex};
 use egui::{Context, Key};
-use crate::{functions::item_color, ui::{LogWindow, YamlEditorWindow}, theme::*};
+use crate::{functions::item_color, ui::{DebugSessionWindow, DebugTarget, FileTransferPanel, LogWindow, YamlEditorWindow}, theme::*};
 
 pub struct PodDetailsWindow {
     pub show: bool,
+    pub show_debug_form: bool,
+    pub debug_image: String,
+    /// Container whose process namespace the debug container joins, empty for none
+    pub debug_target: String,
+    pub show_files: bool,
+    pub file_transfer: FileTransferPanel,
 }
 
 impl PodDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            show_debug_form: false,
+            debug_image: crate::DEFAULT_DEBUG_IMAGE.to_string(),
+            debug_target: String::new(),
+            show_files: false,
+            file_transfer: FileTransferPanel::new(),
         }
     }
 }
@@ -21,6 +32,7 @@ pub fn show_pod_details_window(
         pods: Arc<Mutex<Vec<crate::PodItem>>>,
         log_window: Arc<Mutex<LogWindow>>,
         yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        debug_session_window: &mut DebugSessionWindow,
         client: Arc<crate::Client>,
         delete_confirm: &mut super::DeleteConfirmation,
 ) {
@@ -30,6 +42,19 @@ pub fn show_pod_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Pod details", &mut pod_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_pod_details(client, &name, ns, details).await {
+                    log::error!("Pod details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
 
     let pod_item = guard_pods.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if pod_item.is_none() {
@@ -65,9 +90,22 @@ pub fn show_pod_details_window(
                 );
             }
 
+            if ui.selectable_label(pod_details_window.show_debug_form, egui::RichText::new("🐞 Debug").size(16.0).color(crate::ORANGE_BUTTON))
+                .on_hover_text("Attach an ephemeral debug container to this pod")
+                .clicked() {
+                    pod_details_window.show_debug_form = !pod_details_window.show_debug_form;
+                }
+
+            if ui.selectable_label(pod_details_window.show_files, egui::RichText::new("📁 Files").size(16.0).color(crate::BLUE_BUTTON))
+                .on_hover_text("Browse container files, download and upload them")
+                .clicked() {
+                    pod_details_window.show_files = !pod_details_window.show_files;
+                }
+
             if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
                 let name = guard_details.name.clone().unwrap();
                 let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
 
                 delete_confirm.request(name.clone(), Some("dd".to_string()), move || {
                     tokio::spawn(async move {
@@ -78,6 +116,43 @@ pub fn show_pod_details_window(
                 });
             }
         });
+        if pod_details_window.show_debug_form {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("Image:").color(ROW_NAME_COLOR));
+                ui.add(egui::TextEdit::singleline(&mut pod_details_window.debug_image).desired_width(200.0));
+                ui.label(egui::RichText::new("Target container:").color(ROW_NAME_COLOR));
+                let target_text = if pod_details_window.debug_target.is_empty() { "none" } else { pod_details_window.debug_target.as_str() };
+                egui::ComboBox::from_id_salt("pod_debug_target_combo").selected_text(target_text.to_string()).show_ui(ui, |ui| {
+                    ui.selectable_value(&mut pod_details_window.debug_target, String::new(), "none");
+                    for container in &guard_details.containers {
+                        ui.selectable_value(&mut pod_details_window.debug_target, container.name.clone(), &container.name);
+                    }
+                }).response.on_hover_text("Share the process namespace of this container, to see its processes and filesystem under /proc/1/root");
+                let image = pod_details_window.debug_image.trim().to_string();
+                if ui.add_enabled(!image.is_empty(), egui::Button::new(egui::RichText::new("▶ Start").color(crate::GREEN_BUTTON))).clicked() {
+                    let target_container = Some(pod_details_window.debug_target.clone()).filter(|t| !t.is_empty());
+                    crate::start_debug_session(debug_session_window, DebugTarget::Pod {
+                        pod: guard_details.name.clone().unwrap(),
+                        namespace: cur_ns.clone().unwrap_or_else(|| "default".to_string()),
+                        image,
+                        target_container,
+                    }, Arc::clone(&client));
+                    pod_details_window.show_debug_form = false;
+                }
+            });
+        }
+        if pod_details_window.show_files {
+            ui.separator();
+            let containers: Vec<String> = guard_details.containers.iter().map(|c| c.name.clone()).collect();
+            crate::show_file_transfer_panel(
+                ui,
+                &mut pod_details_window.file_transfer,
+                guard_details.name.as_deref().unwrap_or_default(),
+                cur_ns.as_deref().unwrap_or("default"),
+                &containers,
+                &client,
+            );
+        }
         ui.separator();
         egui::ScrollArea::vertical().max_height(600.0).auto_shrink(false).show(ui, |ui| {
             egui::Grid::new("pod_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
@@ -30,6 +30,18 @@ pub fn show_pv_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Pv details", &mut pv_details_window.show, &name, error) {
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_pv_details(client, &name, details).await {
+                    log::error!("PV details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let pv_item = guard_pvs.iter().find(|item| item.name == guard_details.name.clone().unwrap());
     if pv_item.is_none() {
         return;
@@ -30,6 +30,19 @@ pub fn show_pvc_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Pvc details", &mut pvc_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_pvc_details(client, &name, ns, details).await {
+                    log::error!("PVC details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let pvc_item = guard_pvcs.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if pvc_item.is_none() {
         return;
@@ -0,0 +1,590 @@
+use egui::{RichText, Ui};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+pub const QUERY_SYNTAX_HELP: &str = "Plain text filters by name.\n\
+Selectors are comma separated:\n  app=web,tier!=db\n  env in (prod,stage)  env notin (dev)\n  team  !team\n\
+Fields take precedence over labels:\n  status=CrashLoopBackOff  restarts>5\n  node=worker-3  age<1h  namespace=kube-system";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryValue {
+    Text(String),
+    Number(f64),
+    List(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryOp {
+    Eq,
+    NotEq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    In,
+    NotIn,
+    Exists,
+    NotExists,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryTerm {
+    pub key: String,
+    pub op: QueryOp,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ResourceQuery {
+    #[default]
+    Empty,
+    Text(String),
+    Selector(Vec<QueryTerm>),
+}
+
+/// Rows of the resource tables that can be matched by a label/field query
+pub trait Queryable {
+    fn query_name(&self) -> &str;
+    fn query_namespace(&self) -> Option<&str> {
+        None
+    }
+    fn query_created(&self) -> Option<&Time>;
+    fn query_label(&self, key: &str) -> Option<String>;
+    fn query_field(&self, _field: &str) -> Option<QueryValue> {
+        None
+    }
+    /// Columns matched by plain text queries
+    fn query_text(&self) -> Vec<&str> {
+        vec![self.query_name()]
+    }
+}
+
+fn text(value: &str) -> Option<QueryValue> {
+    Some(QueryValue::Text(value.to_string()))
+}
+
+fn number(value: impl Into<f64>) -> Option<QueryValue> {
+    Some(QueryValue::Number(value.into()))
+}
+
+fn common_field<T: Queryable + ?Sized>(item: &T, key: &str) -> Option<QueryValue> {
+    match key {
+        "name" => text(item.query_name()),
+        "namespace" | "ns" => text(item.query_namespace().unwrap_or_default()),
+        "age" => item.query_created().map(|ts| {
+            QueryValue::Number((k8s_openapi::jiff::Timestamp::now().as_second() - ts.0.as_second()) as f64)
+        }),
+        _ => item.query_field(key),
+    }
+}
+
+/// "5", "1.5", "90s", "15m", "1h30m", "2d", "1w"
+pub fn parse_query_number(value: &str) -> Option<f64> {
+    let value = value.trim();
+    // An empty field is not zero, "replicas<1" must not match objects that have no replicas
+    if value.is_empty() {
+        return None;
+    }
+    if let Ok(n) = value.parse::<f64>() {
+        return Some(n);
+    }
+    let mut total = 0.0;
+    let mut digits = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() || c == '.' {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1.0,
+            'm' => 60.0,
+            'h' => 3600.0,
+            'd' => 86400.0,
+            'w' => 604800.0,
+            _ => return None,
+        };
+        total += digits.parse::<f64>().ok()? * unit;
+        digits.clear();
+    }
+    if !digits.is_empty() {
+        return None;
+    }
+    Some(total)
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '/'))
+}
+
+/// Splits on commas that are not inside `in (...)` value lists
+fn split_terms(input: &str) -> Result<Vec<&str>, String> {
+    let mut terms = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                if depth == 0 {
+                    return Err("Unbalanced ')'".to_string());
+                }
+                depth -= 1;
+            }
+            ',' if depth == 0 => {
+                terms.push(input[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err("Missing ')'".to_string());
+    }
+    terms.push(input[start..].trim());
+    Ok(terms)
+}
+
+fn parse_set_term(term: &str) -> Option<Result<QueryTerm, String>> {
+    let open = term.find('(')?;
+    let mut head = term[..open].split_whitespace();
+    let key = head.next()?;
+    let op = match head.next()? {
+        "in" => QueryOp::In,
+        "notin" => QueryOp::NotIn,
+        _ => return None,
+    };
+    if head.next().is_some() || !term.ends_with(')') {
+        return Some(Err(format!("Invalid set expression '{}'", term)));
+    }
+    if !is_key(key) {
+        return Some(Err(format!("Invalid key '{}'", key)));
+    }
+    let values: Vec<String> = term[open + 1..term.len() - 1]
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+    if values.is_empty() {
+        return Some(Err(format!("Empty value list for '{}'", key)));
+    }
+    Some(Ok(QueryTerm { key: key.to_string(), op, values }))
+}
+
+fn parse_term(term: &str) -> Result<QueryTerm, String> {
+    if term.is_empty() {
+        return Err("Empty expression".to_string());
+    }
+    if let Some(result) = parse_set_term(term) {
+        return result;
+    }
+    if let Some(key) = term.strip_prefix('!') {
+        let key = key.trim();
+        if is_key(key) {
+            return Ok(QueryTerm { key: key.to_string(), op: QueryOp::NotExists, values: vec![] });
+        }
+    }
+
+    let Some(pos) = term.find(['!', '=', '<', '>']) else {
+        if is_key(term) {
+            return Ok(QueryTerm { key: term.to_string(), op: QueryOp::Exists, values: vec![] });
+        }
+        return Err(format!("Invalid key '{}'", term));
+    };
+    let rest = &term[pos..];
+    let (op, op_len) = if rest.starts_with("!=") {
+        (QueryOp::NotEq, 2)
+    } else if rest.starts_with("==") {
+        (QueryOp::Eq, 2)
+    } else if rest.starts_with(">=") {
+        (QueryOp::Ge, 2)
+    } else if rest.starts_with("<=") {
+        (QueryOp::Le, 2)
+    } else if rest.starts_with('=') {
+        (QueryOp::Eq, 1)
+    } else if rest.starts_with('>') {
+        (QueryOp::Gt, 1)
+    } else if rest.starts_with('<') {
+        (QueryOp::Lt, 1)
+    } else {
+        return Err(format!("Unknown operator in '{}'", term));
+    };
+
+    let key = term[..pos].trim();
+    let value = term[pos + op_len..].trim();
+    if !is_key(key) {
+        return Err(format!("Invalid key '{}'", key));
+    }
+    if matches!(op, QueryOp::Gt | QueryOp::Ge | QueryOp::Lt | QueryOp::Le) && parse_query_number(value).is_none() {
+        return Err(format!("'{}' is not a number or duration", value));
+    }
+    Ok(QueryTerm { key: key.to_string(), op, values: vec![value.to_string()] })
+}
+
+impl ResourceQuery {
+    pub fn try_parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(ResourceQuery::Empty);
+        }
+        let is_selector = input.contains(['=', '!', '<', '>'])
+            || split_terms(input).is_ok_and(|terms| terms.iter().any(|t| parse_set_term(t).is_some()));
+        if !is_selector {
+            return Ok(ResourceQuery::Text(input.to_lowercase()));
+        }
+        let terms = split_terms(input)?
+            .into_iter()
+            .map(parse_term)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ResourceQuery::Selector(terms))
+    }
+
+    /// Invalid queries do not filter anything, the bar shows the error instead
+    pub fn parse(input: &str) -> Self {
+        Self::try_parse(input).unwrap_or_default()
+    }
+
+    pub fn matches<T: Queryable + ?Sized>(&self, item: &T) -> bool {
+        match self {
+            ResourceQuery::Empty => true,
+            ResourceQuery::Text(text) => item.query_text().iter().any(|t| t.to_lowercase().contains(text)),
+            ResourceQuery::Selector(terms) => terms.iter().all(|term| term.matches(item)),
+        }
+    }
+}
+
+impl QueryTerm {
+    fn matches<T: Queryable + ?Sized>(&self, item: &T) -> bool {
+        // Label values are compared exactly, as the apiserver does
+        let value = common_field(item, &self.key).or_else(|| item.query_label(&self.key).map(|l| QueryValue::List(vec![l])));
+        let expected = self.values.first().map(|v| v.as_str()).unwrap_or_default();
+        match self.op {
+            QueryOp::Exists => value.is_some_and(|v| !v.is_empty()),
+            QueryOp::NotExists => value.is_none_or(|v| v.is_empty()),
+            QueryOp::Eq => value.is_some_and(|v| v.equals(expected)),
+            QueryOp::NotEq => !value.is_some_and(|v| v.equals(expected)),
+            QueryOp::In => value.is_some_and(|v| self.values.iter().any(|e| v.equals(e))),
+            QueryOp::NotIn => !value.is_some_and(|v| self.values.iter().any(|e| v.equals(e))),
+            QueryOp::Gt | QueryOp::Ge | QueryOp::Lt | QueryOp::Le => {
+                let (Some(actual), Some(expected)) = (value.and_then(|v| v.as_number()), parse_query_number(expected)) else {
+                    return false;
+                };
+                match self.op {
+                    QueryOp::Gt => actual > expected,
+                    QueryOp::Ge => actual >= expected,
+                    QueryOp::Lt => actual < expected,
+                    _ => actual <= expected,
+                }
+            }
+        }
+    }
+}
+
+impl QueryValue {
+    fn is_empty(&self) -> bool {
+        match self {
+            QueryValue::Text(t) => t.is_empty(),
+            QueryValue::Number(_) => false,
+            QueryValue::List(l) => l.is_empty(),
+        }
+    }
+
+    fn equals(&self, expected: &str) -> bool {
+        match self {
+            QueryValue::Text(t) => t.eq_ignore_ascii_case(expected),
+            QueryValue::Number(n) => parse_query_number(expected).is_some_and(|e| *n == e),
+            QueryValue::List(l) => l.iter().any(|v| v == expected),
+        }
+    }
+
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            QueryValue::Text(t) => parse_query_number(t),
+            QueryValue::Number(n) => Some(*n),
+            QueryValue::List(l) => l.first().and_then(|v| parse_query_number(v)),
+        }
+    }
+}
+
+/// Filter text field with the clear button, saved queries menu and the parse error
+pub fn query_filter_bar(ui: &mut Ui, filter: &mut String, hint: &str, saved_queries: &mut Vec<String>, config_changed: &mut bool) {
+    ui.add(egui::TextEdit::singleline(filter).hint_text(hint).text_color(crate::FILTER_TEXT_COLOR).desired_width(200.0))
+        .on_hover_text(QUERY_SYNTAX_HELP);
+    if ui.button(RichText::new("ｘ").size(16.0).color(crate::RED_BUTTON)).on_hover_text("Clean filter").clicked() {
+        filter.clear();
+    }
+    ui.menu_button(RichText::new("⭐").size(16.0).color(crate::YELLOW_BUTTON), |ui| {
+        let current = filter.trim().to_string();
+        let already_saved = saved_queries.contains(&current);
+        if ui.add_enabled(!current.is_empty() && !already_saved, egui::Button::new("💾 Save current query")).clicked() {
+            saved_queries.push(current);
+            *config_changed = true;
+            ui.close();
+        }
+        if !saved_queries.is_empty() {
+            ui.separator();
+        }
+        let mut remove: Option<usize> = None;
+        for (i, query) in saved_queries.iter().enumerate() {
+            ui.horizontal(|ui| {
+                if ui.button(RichText::new("🗑").color(crate::RED_BUTTON)).on_hover_text("Delete saved query").clicked() {
+                    remove = Some(i);
+                }
+                if ui.selectable_label(*filter == *query, RichText::new(query).color(crate::FILTER_TEXT_COLOR)).clicked() {
+                    *filter = query.clone();
+                    ui.close();
+                }
+            });
+        }
+        if let Some(i) = remove {
+            saved_queries.remove(i);
+            *config_changed = true;
+        }
+    }).response.on_hover_text("Saved queries");
+    if let Err(e) = ResourceQuery::try_parse(filter) {
+        ui.label(RichText::new(e).color(crate::ERROR_COLOR));
+    }
+}
+
+macro_rules! queryable_labels {
+    () => {
+        fn query_name(&self) -> &str {
+            &self.name
+        }
+        fn query_namespace(&self) -> Option<&str> {
+            self.namespace.as_deref()
+        }
+        fn query_created(&self) -> Option<&Time> {
+            self.creation_timestamp.as_ref()
+        }
+        fn query_label(&self, key: &str) -> Option<String> {
+            self.labels.get(key).cloned()
+        }
+    };
+}
+
+impl Queryable for crate::PodItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "status" | "phase" => {
+                if self.terminating {
+                    text("Terminating")
+                } else if self.pod_has_crashloop {
+                    text("CrashLoopBackOff")
+                } else {
+                    text(self.phase.as_deref().unwrap_or_default())
+                }
+            }
+            "restarts" => number(self.restart_count),
+            "node" => text(self.node_name.as_deref().unwrap_or_default()),
+            "qos" => text(self.qos_class.as_deref().unwrap_or_default()),
+            "controller" => text(self.controller.as_deref().unwrap_or_default()),
+            "ready" => number(self.ready_containers),
+            "containers" => number(self.total_containers),
+            _ => None,
+        }
+    }
+
+    fn query_text(&self) -> Vec<&str> {
+        vec![&self.name, self.node_name.as_deref().unwrap_or_default()]
+    }
+}
+
+impl Queryable for crate::DeploymentItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "replicas" => number(self.replicas),
+            "ready" => number(self.ready_replicas),
+            "available" => number(self.available_replicas),
+            "unavailable" => number(self.unavailable_replicas),
+            "updated" => number(self.updated_replicas),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::StatefulSetItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "replicas" => number(self.replicas),
+            "ready" => number(self.ready_replicas),
+            "service" => text(&self.service_name),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::DaemonSetItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "desired" => number(self.desired),
+            "current" => number(self.current),
+            "ready" => number(self.ready),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::ReplicaSetItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "desired" => number(self.desired),
+            "current" => number(self.current),
+            "ready" => number(self.ready),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::JobItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "status" => text(&self.condition),
+            "completions" => number(self.completions),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::CronJobItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "schedule" => text(&self.schedule),
+            "suspend" => text(&self.suspend),
+            "active" => number(self.active as f64),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::ServiceItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "type" => text(&self.svc_type),
+            "cluster_ip" | "ip" => text(&self.cluster_ip),
+            "external_ip" => text(&self.external_ip),
+            "status" => text(&self.status),
+            _ => None,
+        }
+    }
+
+    fn query_text(&self) -> Vec<&str> {
+        vec![&self.name, &self.svc_type]
+    }
+}
+
+impl Queryable for crate::ConfigMapItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "keys" => number(self.keys.len() as f64),
+            "key" => Some(QueryValue::List(self.keys.clone())),
+            "type" => text(&self.type_),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::SecretItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "type" => text(&self.secret_type),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::IngressItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "host" => text(&self.host),
+            "service" => text(&self.service),
+            "tls" => text(&self.tls),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::PvcItem {
+    queryable_labels!();
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "status" => text(&self.status),
+            "storage_class" | "sc" => text(&self.storage_class),
+            "volume" => text(&self.volume_name),
+            "size" => text(&self.size),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::NodeItem {
+    fn query_name(&self) -> &str {
+        &self.name
+    }
+
+    fn query_created(&self) -> Option<&Time> {
+        self.creation_timestamp.as_ref()
+    }
+
+    fn query_label(&self, key: &str) -> Option<String> {
+        self.labels.iter()
+            .filter_map(|l| l.split_once('='))
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.to_string())
+    }
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "status" => text(&self.status),
+            "role" | "roles" => Some(QueryValue::List(self.roles.clone())),
+            "version" => text(self.version.as_deref().unwrap_or_default()),
+            "cordoned" => text(if self.scheduling_disabled { "true" } else { "false" }),
+            "cpu" => self.cpu_percent.and_then(number),
+            "memory" | "mem" => self.mem_percent.and_then(number),
+            "disk" => self.storage_percent.and_then(number),
+            _ => None,
+        }
+    }
+}
+
+impl Queryable for crate::NamespaceItem {
+    fn query_name(&self) -> &str {
+        &self.name
+    }
+
+    fn query_created(&self) -> Option<&Time> {
+        self.creation_timestamp.as_ref()
+    }
+
+    fn query_label(&self, key: &str) -> Option<String> {
+        self.labels.as_ref().and_then(|l| l.get(key).cloned())
+    }
+
+    fn query_field(&self, field: &str) -> Option<QueryValue> {
+        match field {
+            "status" | "phase" => text(self.phase.as_deref().unwrap_or_default()),
+            _ => None,
+        }
+    }
+}
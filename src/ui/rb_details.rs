@@ -30,6 +30,19 @@ pub fn show_rb_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Role binding details", &mut rb_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_rb_details(client, &name, ns, details).await {
+                    log::error!("Role binding details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let rb_item = guard_rbs.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if rb_item.is_none() {
         return;
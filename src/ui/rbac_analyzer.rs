@@ -0,0 +1,591 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use egui::{RichText, Ui};
+use k8s_openapi::api::rbac::v1::{PolicyRule, RoleRef, Subject};
+use crate::theme::*;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum RbacView {
+    Subject,
+    WhoCan,
+    Aggregation,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RbacSubject {
+    pub kind: String,
+    pub name: String,
+    /// Only for ServiceAccounts
+    pub namespace: Option<String>,
+}
+
+impl RbacSubject {
+    /// ServiceAccount subjects without namespace belong to the binding namespace
+    fn from_subject(subject: &Subject, binding_ns: Option<&str>) -> Self {
+        let namespace = if subject.kind == "ServiceAccount" {
+            subject.namespace.clone().or_else(|| binding_ns.map(|ns| ns.to_string()))
+        } else {
+            None
+        };
+        Self { kind: subject.kind.clone(), name: subject.name.clone(), namespace }
+    }
+
+    pub fn label(&self) -> String {
+        match &self.namespace {
+            Some(ns) => format!("{} {}/{}", self.kind, ns, self.name),
+            None => format!("{} {}", self.kind, self.name),
+        }
+    }
+
+    /// Groups the authenticator adds on its own, bindings to them apply to the subject too
+    fn implicit_groups(&self) -> Vec<String> {
+        match self.kind.as_str() {
+            "ServiceAccount" => vec![
+                "system:serviceaccounts".to_string(),
+                format!("system:serviceaccounts:{}", self.namespace.as_deref().unwrap_or_default()),
+                "system:authenticated".to_string(),
+            ],
+            "User" => vec!["system:authenticated".to_string()],
+            _ => Vec::new(),
+        }
+    }
+
+    /// User and groups to put in a SubjectAccessReview
+    fn review_identity(&self) -> (Option<String>, Vec<String>) {
+        match self.kind.as_str() {
+            "ServiceAccount" => (
+                Some(format!("system:serviceaccount:{}:{}", self.namespace.as_deref().unwrap_or_default(), self.name)),
+                self.implicit_groups(),
+            ),
+            "Group" => (None, vec![self.name.clone()]),
+            _ => (Some(self.name.clone()), self.implicit_groups()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EffectiveRule {
+    pub rule: PolicyRule,
+    /// None for cluster wide grants
+    pub namespace: Option<String>,
+    pub granted_by: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct WhoCanEntry {
+    pub subject: RbacSubject,
+    pub namespace: Option<String>,
+    pub granted_by: String,
+    /// Set when the rule only covers some resourceNames
+    pub resource_names: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessQuery {
+    pub verb: String,
+    pub api_group: String,
+    pub resource: String,
+    /// Empty for cluster wide or all namespaces
+    pub namespace: String,
+    pub name: String,
+}
+
+impl AccessQuery {
+    fn describe(&self) -> String {
+        let resource = if self.api_group.is_empty() { self.resource.clone() } else { format!("{}.{}", self.resource, self.api_group) };
+        let name = if self.name.is_empty() { String::new() } else { format!("/{}", self.name) };
+        let scope = if self.namespace.is_empty() { "cluster wide".to_string() } else { format!("in {}", self.namespace) };
+        format!("{} {}{} {}", self.verb, resource, name, scope)
+    }
+
+    fn resource_attributes(&self) -> k8s_openapi::api::authorization::v1::ResourceAttributes {
+        let (resource, subresource) = match self.resource.split_once('/') {
+            Some((resource, subresource)) => (resource.to_string(), Some(subresource.to_string())),
+            None => (self.resource.clone(), None),
+        };
+        let optional = |value: &str| Some(value.to_string()).filter(|v| !v.is_empty());
+        k8s_openapi::api::authorization::v1::ResourceAttributes {
+            verb: optional(&self.verb),
+            group: Some(self.api_group.clone()),
+            resource: Some(resource),
+            subresource,
+            namespace: optional(&self.namespace),
+            name: optional(&self.name),
+            ..Default::default()
+        }
+    }
+}
+
+/// Answer of the API server next to the one computed from the watched objects
+#[derive(Clone, Debug)]
+pub struct AccessReview {
+    pub subject: RbacSubject,
+    pub query: AccessQuery,
+    pub local: bool,
+    pub result: Option<Result<(bool, String), String>>,
+}
+
+/// The watched RBAC objects, with aggregated ClusterRoles resolved once per analysis
+pub struct RbacSnapshot<'a> {
+    pub roles: &'a [crate::RoleItem],
+    pub cluster_roles: &'a [crate::ClusterRoleItem],
+    pub rbs: &'a [crate::RoleBindingItem],
+    pub cluster_rbs: &'a [crate::ClusterRoleBindingItem],
+    resolved: HashMap<String, (Vec<PolicyRule>, Vec<String>)>,
+}
+
+fn push_unique(rules: &mut Vec<PolicyRule>, new_rules: &[PolicyRule]) {
+    for rule in new_rules {
+        if !rules.contains(rule) {
+            rules.push(rule.clone());
+        }
+    }
+}
+
+impl<'a> RbacSnapshot<'a> {
+    pub fn new(
+            roles: &'a [crate::RoleItem],
+            cluster_roles: &'a [crate::ClusterRoleItem],
+            rbs: &'a [crate::RoleBindingItem],
+            cluster_rbs: &'a [crate::ClusterRoleBindingItem],
+    ) -> Self {
+        Self { roles, cluster_roles, rbs, cluster_rbs, resolved: HashMap::new() }
+    }
+
+    /// Rules of a ClusterRole. For aggregated roles they are collected again from the roles matching the
+    /// selectors (recursively), instead of trusting what the aggregation controller wrote. Also returns those roles.
+    pub fn resolve_cluster_role(&mut self, name: &str) -> (Vec<PolicyRule>, Vec<String>) {
+        if let Some(resolved) = self.resolved.get(name) {
+            return resolved.clone();
+        }
+        let mut rules = Vec::new();
+        let mut sources = Vec::new();
+        let mut visited = HashSet::new();
+        self.collect_cluster_role(name, &mut rules, &mut sources, &mut visited);
+        self.resolved.insert(name.to_string(), (rules.clone(), sources.clone()));
+        (rules, sources)
+    }
+
+    fn collect_cluster_role(&self, name: &str, rules: &mut Vec<PolicyRule>, sources: &mut Vec<String>, visited: &mut HashSet<String>) {
+        if !visited.insert(name.to_string()) {
+            return;
+        }
+        let Some(role) = self.cluster_roles.iter().find(|r| r.name == name) else {
+            return;
+        };
+        if role.aggregation_selectors.is_empty() {
+            push_unique(rules, &role.rules);
+            return;
+        }
+        for other in self.cluster_roles {
+            if other.name != name && role.aggregation_selectors.iter().any(|s| crate::label_selector_matches(s, &other.labels)) {
+                sources.push(other.name.clone());
+                self.collect_cluster_role(&other.name, rules, sources, visited);
+            }
+        }
+    }
+
+    fn role_ref_rules(&mut self, role_ref: &RoleRef, binding_ns: Option<&str>) -> Vec<PolicyRule> {
+        match role_ref.kind.as_str() {
+            "ClusterRole" => self.resolve_cluster_role(&role_ref.name).0,
+            "Role" => self.roles.iter()
+                .find(|r| r.name == role_ref.name && r.namespace.as_deref() == binding_ns)
+                .map(|r| r.rules.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Every subject referenced by a binding, to pick from
+    pub fn known_subjects(&self) -> BTreeSet<RbacSubject> {
+        let cluster = self.cluster_rbs.iter().flat_map(|b| b.subjects.iter().map(|s| RbacSubject::from_subject(s, None)));
+        let namespaced = self.rbs.iter().flat_map(|b| b.subjects.iter().map(|s| RbacSubject::from_subject(s, b.namespace.as_deref())));
+        cluster.chain(namespaced).collect()
+    }
+
+    /// Why a binding subject applies to `subject`, None when it does not
+    fn subject_match(binding_subject: &Subject, binding_ns: Option<&str>, subject: &RbacSubject, groups: &[String]) -> Option<String> {
+        let bound = RbacSubject::from_subject(binding_subject, binding_ns);
+        if &bound == subject {
+            Some(String::new())
+        } else if bound.kind == "Group" && groups.contains(&bound.name) {
+            Some(format!(" (via group {})", bound.name))
+        } else {
+            None
+        }
+    }
+
+    pub fn effective_rules(&mut self, subject: &RbacSubject, include_implicit_groups: bool) -> Vec<EffectiveRule> {
+        let groups = if include_implicit_groups { subject.implicit_groups() } else { Vec::new() };
+        let mut result = Vec::new();
+
+        for binding in self.cluster_rbs {
+            let Some(via) = binding.subjects.iter().find_map(|s| Self::subject_match(s, None, subject, &groups)) else {
+                continue;
+            };
+            let granted_by = format!("ClusterRoleBinding {} → {} {}{}", binding.name, binding.role_ref.kind, binding.role_ref.name, via);
+            for rule in self.role_ref_rules(&binding.role_ref, None) {
+                result.push(EffectiveRule { rule, namespace: None, granted_by: granted_by.clone() });
+            }
+        }
+        for binding in self.rbs {
+            let ns = binding.namespace.as_deref();
+            let Some(via) = binding.subjects.iter().find_map(|s| Self::subject_match(s, ns, subject, &groups)) else {
+                continue;
+            };
+            let granted_by = format!("RoleBinding {}/{} → {} {}{}", ns.unwrap_or_default(), binding.name, binding.role_ref.kind, binding.role_ref.name, via);
+            for rule in self.role_ref_rules(&binding.role_ref, ns) {
+                result.push(EffectiveRule { rule, namespace: binding.namespace.clone(), granted_by: granted_by.clone() });
+            }
+        }
+        result
+    }
+
+    /// Answers "who can <verb> <resource> in <namespace>" from the bindings
+    pub fn who_can(&mut self, query: &AccessQuery) -> Vec<WhoCanEntry> {
+        let mut result = Vec::new();
+        for binding in self.cluster_rbs {
+            let rules = self.role_ref_rules(&binding.role_ref, None);
+            let Some(resource_names) = rules.iter().filter_map(|r| rule_allows(r, query)).min_by_key(|names| names.is_some()) else {
+                continue;
+            };
+            for subject in &binding.subjects {
+                result.push(WhoCanEntry {
+                    subject: RbacSubject::from_subject(subject, None),
+                    namespace: None,
+                    granted_by: format!("ClusterRoleBinding {} → {} {}", binding.name, binding.role_ref.kind, binding.role_ref.name),
+                    resource_names: resource_names.clone(),
+                });
+            }
+        }
+        for binding in self.rbs {
+            let ns = binding.namespace.as_deref();
+            if !query.namespace.is_empty() && ns != Some(query.namespace.as_str()) {
+                continue;
+            }
+            let rules = self.role_ref_rules(&binding.role_ref, ns);
+            let Some(resource_names) = rules.iter().filter_map(|r| rule_allows(r, query)).min_by_key(|names| names.is_some()) else {
+                continue;
+            };
+            for subject in &binding.subjects {
+                result.push(WhoCanEntry {
+                    subject: RbacSubject::from_subject(subject, ns),
+                    namespace: binding.namespace.clone(),
+                    granted_by: format!("RoleBinding {}/{} → {} {}", ns.unwrap_or_default(), binding.name, binding.role_ref.kind, binding.role_ref.name),
+                    resource_names: resource_names.clone(),
+                });
+            }
+        }
+        result.sort_by(|a, b| a.subject.cmp(&b.subject).then_with(|| a.namespace.cmp(&b.namespace)));
+        result
+    }
+}
+
+fn list_contains(values: Option<&Vec<String>>, value: &str) -> bool {
+    values.is_some_and(|v| v.iter().any(|v| v == "*" || v == value))
+}
+
+/// Same matching as the RBAC authorizer. Some(None) when the rule allows the request,
+/// Some(Some(names)) when it does only for some resourceNames and the query has no name.
+pub fn rule_allows(rule: &PolicyRule, query: &AccessQuery) -> Option<Option<Vec<String>>> {
+    if !list_contains(Some(&rule.verbs), &query.verb) || !list_contains(rule.api_groups.as_ref(), &query.api_group) {
+        return None;
+    }
+    let resources = rule.resources.as_ref()?;
+    let subresource_wildcard = query.resource.split_once('/').map(|(_, sub)| format!("*/{}", sub));
+    let resource_matches = resources.iter().any(|r| r == "*" || *r == query.resource || Some(r) == subresource_wildcard.as_ref());
+    if !resource_matches {
+        return None;
+    }
+    match rule.resource_names.as_ref().filter(|n| !n.is_empty()) {
+        None => Some(None),
+        Some(names) if query.name.is_empty() => Some(Some(names.clone())),
+        Some(names) if names.contains(&query.name) => Some(None),
+        Some(_) => None,
+    }
+}
+
+/// Whether the effective rules allow the query, only cluster wide rules count when no namespace is given
+fn rules_allow(rules: &[EffectiveRule], query: &AccessQuery) -> bool {
+    rules.iter()
+        .filter(|r| r.namespace.is_none() || r.namespace.as_deref() == Some(query.namespace.as_str()))
+        .any(|r| rule_allows(&r.rule, query) == Some(None))
+}
+
+fn join_or_dash(values: Option<&Vec<String>>) -> String {
+    match values {
+        Some(values) if !values.is_empty() => values.join(", "),
+        _ => "-".to_string(),
+    }
+}
+
+pub struct RbacAnalyzerState {
+    pub view: RbacView,
+    pub subject: RbacSubject,
+    pub include_implicit_groups: bool,
+    pub rules_filter: String,
+    pub query: AccessQuery,
+    pub review: Arc<Mutex<Option<AccessReview>>>,
+}
+
+impl RbacAnalyzerState {
+    pub fn new() -> Self {
+        Self {
+            view: RbacView::Subject,
+            subject: RbacSubject { kind: "ServiceAccount".to_string(), name: "default".to_string(), namespace: Some("default".to_string()) },
+            include_implicit_groups: true,
+            rules_filter: String::new(),
+            query: AccessQuery { verb: "get".to_string(), resource: "secrets".to_string(), ..Default::default() },
+            review: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+fn request_review(state: &RbacAnalyzerState, subject: RbacSubject, local: bool, client: &Arc<crate::Client>) {
+    let query = state.query.clone();
+    *state.review.lock().unwrap() = Some(AccessReview { subject: subject.clone(), query: query.clone(), local, result: None });
+    let review = Arc::clone(&state.review);
+    let client = Arc::clone(client);
+    tokio::spawn(async move {
+        let (user, groups) = subject.review_identity();
+        let result = crate::subject_access_review(client, user, groups, query.resource_attributes()).await.map_err(|e| e.to_string());
+        // Ignore the answer if another review was requested meanwhile
+        if let Some(current) = review.lock().unwrap().as_mut()
+            && current.subject == subject && current.query == query {
+                current.result = Some(result);
+            }
+    });
+}
+
+fn show_query_inputs(ui: &mut Ui, query: &mut AccessQuery) {
+    ui.label(RichText::new("Verb:").color(ROW_NAME_COLOR));
+    egui::ComboBox::from_id_salt("rbac_query_verb").selected_text(query.verb.clone()).width(90.0).show_ui(ui, |ui| {
+        for verb in ["get", "list", "watch", "create", "update", "patch", "delete", "deletecollection", "impersonate", "bind", "escalate", "*"] {
+            ui.selectable_value(&mut query.verb, verb.to_string(), verb);
+        }
+    });
+    ui.label(RichText::new("Resource:").color(ROW_NAME_COLOR));
+    ui.add(egui::TextEdit::singleline(&mut query.resource).hint_text("pods/log").desired_width(120.0));
+    ui.label(RichText::new("API group:").color(ROW_NAME_COLOR));
+    ui.add(egui::TextEdit::singleline(&mut query.api_group).hint_text("core").desired_width(100.0));
+    ui.label(RichText::new("Namespace:").color(ROW_NAME_COLOR));
+    ui.add(egui::TextEdit::singleline(&mut query.namespace).hint_text("any").desired_width(100.0));
+    ui.label(RichText::new("Name:").color(ROW_NAME_COLOR));
+    ui.add(egui::TextEdit::singleline(&mut query.name).hint_text("any").desired_width(100.0));
+}
+
+fn show_review(ui: &mut Ui, review: &Arc<Mutex<Option<AccessReview>>>) {
+    let Some(review) = review.lock().unwrap().clone() else {
+        return;
+    };
+    ui.separator();
+    ui.horizontal_wrapped(|ui| {
+        ui.label(RichText::new(format!("Can {} {}?", review.subject.label(), review.query.describe())).color(ROW_NAME_COLOR));
+        let (local_text, local_color) = if review.local { ("bindings: yes", GREEN_BUTTON) } else { ("bindings: no", ERROR_MESSAGE_COLOR) };
+        ui.label(RichText::new(local_text).color(local_color));
+        match &review.result {
+            None => {
+                ui.spinner();
+            }
+            Some(Ok((allowed, reason))) => {
+                let (text, color) = if *allowed { ("API server: allowed", GREEN_BUTTON) } else { ("API server: denied", ERROR_MESSAGE_COLOR) };
+                ui.label(RichText::new(text).color(color));
+                if !reason.is_empty() {
+                    ui.label(RichText::new(reason).color(SECOND_DETAIL_COLOR));
+                }
+                if *allowed != review.local {
+                    ui.label(RichText::new("⚠ Differs from the bindings: another authorizer (webhook, Node, ABAC) decided, or the lists are not loaded yet").color(WARNING_COLOR));
+                }
+            }
+            Some(Err(e)) => {
+                ui.label(RichText::new(format!("SubjectAccessReview failed: {}", e)).color(ERROR_MESSAGE_COLOR));
+            }
+        }
+    });
+}
+
+fn show_subject_view(ui: &mut Ui, state: &mut RbacAnalyzerState, snapshot: &mut RbacSnapshot, client: &Arc<crate::Client>) {
+    let known = snapshot.known_subjects();
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Subject:").color(ROW_NAME_COLOR));
+        egui::ComboBox::from_id_salt("rbac_subject_kind").selected_text(state.subject.kind.clone()).width(120.0).show_ui(ui, |ui| {
+            for kind in ["ServiceAccount", "User", "Group"] {
+                ui.selectable_value(&mut state.subject.kind, kind.to_string(), kind);
+            }
+        });
+        if state.subject.kind == "ServiceAccount" {
+            let namespace = state.subject.namespace.get_or_insert_with(|| "default".to_string());
+            ui.label(RichText::new("Namespace:").color(ROW_NAME_COLOR));
+            ui.add(egui::TextEdit::singleline(namespace).desired_width(120.0));
+        } else {
+            state.subject.namespace = None;
+        }
+        ui.label(RichText::new("Name:").color(ROW_NAME_COLOR));
+        ui.add(egui::TextEdit::singleline(&mut state.subject.name).desired_width(200.0));
+        egui::ComboBox::from_id_salt("rbac_known_subjects").selected_text("Known subjects").width(160.0).show_ui(ui, |ui| {
+            for subject in &known {
+                if ui.selectable_label(*subject == state.subject, subject.label()).clicked() {
+                    state.subject = subject.clone();
+                }
+            }
+        });
+        ui.checkbox(&mut state.include_implicit_groups, "Include implicit groups")
+            .on_hover_text("system:authenticated, system:serviceaccounts and system:serviceaccounts:<namespace>");
+    });
+
+    let rules = snapshot.effective_rules(&state.subject, state.include_implicit_groups);
+    ui.horizontal(|ui| {
+        show_query_inputs(ui, &mut state.query);
+        if ui.button(RichText::new("✔ Check").color(BLUE_BUTTON)).on_hover_text("Compare the bindings with a SubjectAccessReview").clicked() {
+            let local = rules_allow(&rules, &state.query);
+            request_review(state, state.subject.clone(), local, client);
+        }
+    });
+    show_review(ui, &state.review);
+    ui.separator();
+
+    ui.horizontal(|ui| {
+        ui.heading(format!("Effective rules - {}", rules.len()));
+        ui.separator();
+        ui.add(egui::TextEdit::singleline(&mut state.rules_filter).hint_text("Filter rules...").text_color(FILTER_TEXT_COLOR).desired_width(200.0));
+    });
+    if rules.is_empty() {
+        ui.label(RichText::new("No binding grants anything to this subject").color(SECOND_DETAIL_COLOR));
+        return;
+    }
+    let filter = state.rules_filter.to_lowercase();
+    egui::ScrollArea::vertical().auto_shrink(false).id_salt("rbac_rules_scroll").show(ui, |ui| {
+        egui::Grid::new("rbac_rules_grid").striped(true).min_col_width(20.0).max_col_width(350.0).show(ui, |ui| {
+            for title in ["Scope", "Verbs", "API groups", "Resources", "Resource names", "Granted by"] {
+                ui.label(RichText::new(title).color(PODS_HEAD_GRID_COLOR));
+            }
+            ui.end_row();
+            for effective in &rules {
+                let rule = &effective.rule;
+                let resources = match (&rule.resources, &rule.non_resource_urls) {
+                    (Some(resources), _) if !resources.is_empty() => resources.join(", "),
+                    (_, Some(urls)) => format!("URLs: {}", urls.join(", ")),
+                    _ => "-".to_string(),
+                };
+                let scope = effective.namespace.clone().unwrap_or_else(|| "cluster".to_string());
+                let row = format!("{} {} {} {}", scope, rule.verbs.join(","), resources, effective.granted_by).to_lowercase();
+                if !filter.is_empty() && !row.contains(&filter) {
+                    continue;
+                }
+                ui.label(RichText::new(scope).color(NAMESPACE_COLUMN_COLOR));
+                let verbs_color = if rule.verbs.iter().any(|v| v == "*") { WARNING_COLOR } else { DETAIL_COLOR };
+                ui.label(RichText::new(rule.verbs.join(", ")).color(verbs_color));
+                let api_groups: Option<Vec<String>> = rule.api_groups.as_ref()
+                    .map(|groups| groups.iter().map(|g| if g.is_empty() { "core".to_string() } else { g.clone() }).collect());
+                ui.label(join_or_dash(api_groups.as_ref()));
+                ui.label(RichText::new(resources).color(ITEM_NAME_COLOR));
+                ui.label(join_or_dash(rule.resource_names.as_ref()));
+                ui.label(RichText::new(&effective.granted_by).color(SECOND_DETAIL_COLOR));
+                ui.end_row();
+            }
+        });
+    });
+}
+
+fn show_who_can_view(ui: &mut Ui, state: &mut RbacAnalyzerState, snapshot: &mut RbacSnapshot, client: &Arc<crate::Client>) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Who can").color(ROW_NAME_COLOR));
+        show_query_inputs(ui, &mut state.query);
+    });
+    show_review(ui, &state.review);
+    ui.separator();
+
+    if state.query.verb.is_empty() || state.query.resource.is_empty() {
+        ui.label(RichText::new("Enter a verb and a resource").color(SECOND_DETAIL_COLOR));
+        return;
+    }
+    let entries = snapshot.who_can(&state.query);
+    ui.heading(format!("{} - {} grants", state.query.describe(), entries.len()));
+    if entries.is_empty() {
+        ui.label(RichText::new("No binding allows this").color(SECOND_DETAIL_COLOR));
+        return;
+    }
+    let mut verify = None;
+    egui::ScrollArea::vertical().auto_shrink(false).id_salt("rbac_who_can_scroll").show(ui, |ui| {
+        egui::Grid::new("rbac_who_can_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+            for title in ["Kind", "Subject", "Scope", "Only names", "Granted by", ""] {
+                ui.label(RichText::new(title).color(PODS_HEAD_GRID_COLOR));
+            }
+            ui.end_row();
+            for entry in &entries {
+                ui.label(RichText::new(&entry.subject.kind).color(SECOND_DETAIL_COLOR));
+                let name = match &entry.subject.namespace {
+                    Some(ns) => format!("{}/{}", ns, entry.subject.name),
+                    None => entry.subject.name.clone(),
+                };
+                let name_color = if entry.subject.name.starts_with("system:") { SECOND_DETAIL_COLOR } else { ITEM_NAME_COLOR };
+                ui.label(RichText::new(name).color(name_color));
+                ui.label(RichText::new(entry.namespace.clone().unwrap_or_else(|| "cluster".to_string())).color(NAMESPACE_COLUMN_COLOR));
+                ui.label(entry.resource_names.as_ref().map(|n| n.join(", ")).unwrap_or_else(|| "-".to_string()));
+                ui.label(RichText::new(&entry.granted_by).color(SECOND_DETAIL_COLOR));
+                if ui.button(RichText::new("✔ Verify").color(BLUE_BUTTON)).on_hover_text("Ask the API server with a SubjectAccessReview").clicked() {
+                    verify = Some(entry.clone());
+                }
+                ui.end_row();
+            }
+        });
+    });
+    if let Some(entry) = verify {
+        // The query may be cluster wide while the grant is namespaced, check where it applies
+        if state.query.namespace.is_empty() && let Some(ns) = &entry.namespace {
+            state.query.namespace = ns.clone();
+        }
+        let local = entry.resource_names.is_none();
+        request_review(state, entry.subject, local, client);
+    }
+}
+
+fn show_aggregation_view(ui: &mut Ui, snapshot: &mut RbacSnapshot) {
+    let cluster_roles = snapshot.cluster_roles;
+    let aggregated: Vec<&crate::ClusterRoleItem> = cluster_roles.iter().filter(|r| !r.aggregation_selectors.is_empty()).collect();
+    ui.heading(format!("Aggregated cluster roles - {}", aggregated.len()));
+    ui.separator();
+    egui::ScrollArea::vertical().auto_shrink(false).id_salt("rbac_aggregation_scroll").show(ui, |ui| {
+        egui::Grid::new("rbac_aggregation_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+            for title in ["Cluster role", "Selectors", "Aggregated from", "Rules", "State"] {
+                ui.label(RichText::new(title).color(PODS_HEAD_GRID_COLOR));
+            }
+            ui.end_row();
+            for role in aggregated {
+                let (rules, sources) = snapshot.resolve_cluster_role(&role.name);
+                ui.label(RichText::new(&role.name).color(ITEM_NAME_COLOR));
+                ui.label(RichText::new(role.aggregation_selectors.iter().map(crate::format_label_selector).collect::<Vec<_>>().join("\n")).color(SECOND_DETAIL_COLOR));
+                ui.label(if sources.is_empty() { "-".to_string() } else { sources.join("\n") });
+                ui.label(format!("{} resolved, {} stored", rules.len(), role.rules.len()));
+                let in_sync = rules.iter().all(|r| role.rules.contains(r)) && role.rules.iter().all(|r| rules.contains(r));
+                if in_sync {
+                    ui.label(RichText::new("In sync").color(GREEN_BUTTON));
+                } else {
+                    ui.label(RichText::new("Differs").color(WARNING_COLOR))
+                        .on_hover_text("The stored rules do not match the matching roles, the aggregation controller may not have caught up yet");
+                }
+                ui.end_row();
+            }
+        });
+    });
+}
+
+pub fn show_rbac_analyzer(ui: &mut Ui, state: &mut RbacAnalyzerState, snapshot: &mut RbacSnapshot, client: &Arc<crate::Client>) {
+    ui.horizontal(|ui| {
+        ui.heading("RBAC analyzer");
+        ui.separator();
+        ui.selectable_value(&mut state.view, RbacView::Subject, "👤 Subject permissions");
+        ui.selectable_value(&mut state.view, RbacView::WhoCan, "🔎 Who can");
+        ui.selectable_value(&mut state.view, RbacView::Aggregation, "🧩 Aggregated cluster roles");
+    });
+    ui.label(RichText::new(format!(
+        "{} roles, {} cluster roles, {} role bindings, {} cluster role bindings",
+        snapshot.roles.len(), snapshot.cluster_roles.len(), snapshot.rbs.len(), snapshot.cluster_rbs.len(),
+    )).color(SECOND_DETAIL_COLOR));
+    ui.separator();
+    match state.view {
+        RbacView::Subject => show_subject_view(ui, state, snapshot, client),
+        RbacView::WhoCan => show_who_can_view(ui, state, snapshot, client),
+        RbacView::Aggregation => show_aggregation_view(ui, snapshot),
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::{Arc, Mutex};
+use egui::{Context, Key};
+use crate::functions::item_color;
+use crate::{ui::YamlEditorWindow};
+use crate::theme::*;
+
+pub struct ResourceQuotaDetailsWindow {
+    pub show: bool,
+}
+
+impl ResourceQuotaDetailsWindow {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+        }
+    }
+}
+
+pub fn show_resource_quota_details_window(
+        ctx: &Context,
+        resource_quota_details_window: &mut ResourceQuotaDetailsWindow,
+        details: Arc<Mutex<crate::ResourceQuotaDetails>>,
+        quotas: Arc<Mutex<Vec<crate::ResourceQuotaItem>>>,
+        yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        client: Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) {
+    let guard_details = details.lock().unwrap(); // More detailed info
+    let guard_quotas = quotas.lock().unwrap(); // Quotas with base details already we have
+    if guard_details.name.is_none() {
+        return;
+    }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "ResourceQuota details", &mut resource_quota_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_resource_quota_details(client, &name, ns, details).await {
+                    log::error!("ResourceQuota details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
+    let quota_item = guard_quotas.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
+    let Some(item) = quota_item else {
+        return;
+    };
+    let cur_ns = &item.namespace;
+
+    let response = egui::Window::new("ResourceQuota details").min_width(800.0).collapsible(false).resizable(true).open(&mut resource_quota_details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
+                crate::edit_yaml_for::<k8s_openapi::api::core::v1::ResourceQuota>(
+                    item.name.clone(),
+                    cur_ns.to_owned().unwrap(),
+                    Arc::clone(&yaml_editor_window),
+                    Arc::clone(&client),
+                );
+            }
+
+            if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
+                let name = item.name.clone();
+                let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
+
+                delete_confirm.request(name.clone(), ns.clone(), move || {
+                    tokio::spawn(async move {
+                        if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::ResourceQuota>(
+                            name,
+                            ns.as_deref(),
+                            client,
+                        ).await {
+                            log::error!("Failed to delete resource quota: {}", err);
+                        }
+                    });
+                });
+            }
+        });
+        ui.separator();
+        egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
+            egui::Grid::new("resource_quota_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+
+                ui.label(egui::RichText::new("ResourceQuota name:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&item.name).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(creation_timestamp) = &item.creation_timestamp {
+                    ui.label(egui::RichText::new("Creation time:").color(ROW_NAME_COLOR));
+                    let timestamp_text = format!("{}, {} ago", creation_timestamp.0, crate::format_age(creation_timestamp));
+                    ui.label(egui::RichText::new(timestamp_text).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                if let Some(namespace) = &item.namespace {
+                    ui.label(egui::RichText::new("Namespace:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(namespace).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                if !item.scopes.is_empty() {
+                    ui.label(egui::RichText::new("Scopes:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(item.scopes.join(", ")).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.separator(); ui.separator(); ui.end_row();
+                ui.label(egui::RichText::new("Usage:").color(ROW_NAME_COLOR));
+                egui::Grid::new("resource_quota_details_usage_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                    ui.label(egui::RichText::new("Resource").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Used").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Hard").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("").color(PODS_HEAD_GRID_COLOR));
+                    ui.end_row();
+                    for (resource, ratio) in item.usage() {
+                        ui.label(egui::RichText::new(&resource).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(item.used.get(&resource).cloned().unwrap_or("0".to_string())).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(&item.hard[&resource]).color(SECOND_DETAIL_COLOR));
+                        let text = if ratio.is_finite() { format!("{:.0}%", ratio * 100.0) } else { "over".to_string() };
+                        ui.add(egui::ProgressBar::new(ratio.min(1.0) as f32).desired_width(200.0).fill(super::usage_color(ratio)).text(text));
+                        ui.end_row();
+                    }
+                });
+                ui.end_row();
+
+                if let Some(labels) = guard_details.labels.clone() {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(egui::RichText::new("Labels:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("resource_quota_details_labels_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                        for (j, y) in labels.iter() {
+                            ui.label(egui::RichText::new(j).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(y).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+
+                if let Some(annotations) = guard_details.annotations.clone() {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(egui::RichText::new("Annotations:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("resource_quota_details_annotations_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                        for (j, y) in annotations.iter() {
+                            ui.label(egui::RichText::new(j).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(y).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
+            if !guard_details.events.is_empty() {
+                egui::Grid::new("resource_quota_details_events_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for event in &guard_details.events {
+                        ui.label(egui::RichText::new(event.timestamp.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.message.clone().unwrap_or_default()).color(item_color(&event.event_type.clone().unwrap_or_default())));
+                        ui.end_row();
+                    }
+                });
+            }
+        });
+    });
+    crate::show_delete_confirmation(ctx, delete_confirm);
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            resource_quota_details_window.show = false;
+        }
+}
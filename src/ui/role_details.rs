@@ -31,6 +31,19 @@ pub fn show_role_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Role details", &mut role_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_role_details(client, &name, ns, details).await {
+                    log::error!("Role details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let role_item = guard_roles.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if role_item.is_none() {
         return;
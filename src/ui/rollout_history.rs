@@ -0,0 +1,238 @@
+use std::sync::{Arc, Mutex};
+use egui::{RichText, Ui};
+use crate::theme::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DiffOp {
+    Same,
+    Added,
+    Removed,
+}
+
+/// Line based LCS diff, pod templates are a few hundred lines at most
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<(DiffOp, &'a str)> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let (n, m) = (old.len(), new.len());
+
+    // lcs[i][j] is the common length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            result.push((DiffOp::Same, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push((DiffOp::Removed, old[i]));
+            i += 1;
+        } else {
+            result.push((DiffOp::Added, new[j]));
+            j += 1;
+        }
+    }
+    result.extend(old[i..].iter().map(|l| (DiffOp::Removed, *l)));
+    result.extend(new[j..].iter().map(|l| (DiffOp::Added, *l)));
+    result
+}
+
+/// Unchanged lines further than this from a change are folded
+const DIFF_CONTEXT: usize = 3;
+
+/// Diff rows ready to draw, `None` stands for a run of folded unchanged lines
+struct TemplateDiff {
+    identical: bool,
+    rows: Vec<Option<(DiffOp, String)>>,
+}
+
+fn template_diff(old: &str, new: &str) -> TemplateDiff {
+    let diff = diff_lines(old, new);
+    // Distance to the closest change, from both directions
+    let mut distance = vec![usize::MAX; diff.len()];
+    let mut last = None;
+    for (i, (op, _)) in diff.iter().enumerate() {
+        if *op != DiffOp::Same {
+            last = Some(i);
+        }
+        if let Some(c) = last {
+            distance[i] = i - c;
+        }
+    }
+    last = None;
+    for (i, (op, _)) in diff.iter().enumerate().rev() {
+        if *op != DiffOp::Same {
+            last = Some(i);
+        }
+        if let Some(c) = last {
+            distance[i] = distance[i].min(c - i);
+        }
+    }
+
+    let mut rows = Vec::new();
+    for (i, (op, line)) in diff.iter().enumerate() {
+        if distance[i] > DIFF_CONTEXT {
+            if rows.last().is_none_or(|r: &Option<(DiffOp, String)>| r.is_some()) {
+                rows.push(None);
+            }
+            continue;
+        }
+        rows.push(Some((*op, line.to_string())));
+    }
+    TemplateDiff { identical: diff.iter().all(|(op, _)| *op == DiffOp::Same), rows }
+}
+
+pub fn show_template_diff(ui: &mut Ui, old: &str, new: &str) {
+    // The LCS table is quadratic, computed once per template pair instead of every frame
+    let cache_id = egui::Id::new(("template_diff", old, new));
+    let diff = match ui.data(|d| d.get_temp::<Arc<TemplateDiff>>(cache_id)) {
+        Some(diff) => diff,
+        None => {
+            let diff = Arc::new(template_diff(old, new));
+            ui.data_mut(|d| d.insert_temp(cache_id, Arc::clone(&diff)));
+            diff
+        }
+    };
+    if diff.identical {
+        ui.label(RichText::new("Pod templates are identical").color(SECOND_DETAIL_COLOR));
+        return;
+    }
+
+    egui::ScrollArea::vertical().id_salt("template_diff_scroll").max_height(300.0).show(ui, |ui| {
+        for row in diff.rows.iter() {
+            let Some((op, line)) = row else {
+                ui.label(RichText::new("  ...").monospace().color(TOLERATIONS_HEAD_GRID_COLOR));
+                continue;
+            };
+            let (prefix, color) = match op {
+                DiffOp::Same => (' ', SECOND_DETAIL_COLOR),
+                DiffOp::Added => ('+', DIFF_ADDED_COLOR),
+                DiffOp::Removed => ('-', DIFF_REMOVED_COLOR),
+            };
+            ui.label(RichText::new(format!("{} {}", prefix, line)).monospace().color(color));
+        }
+    });
+}
+
+/// Revision list shared by Deployments (ReplicaSets) and StatefulSets/DaemonSets (ControllerRevisions).
+/// `revisions` is sorted newest first. Returns the revision the user asked to roll back to.
+pub fn show_revision_history(
+        ui: &mut Ui,
+        id_salt: &str,
+        revisions: &[crate::RevisionDetails],
+        current_revision: Option<i64>,
+        diff_revision: &mut Option<i64>,
+) -> Option<i64> {
+    let mut rollback_to = None;
+    if revisions.is_empty() {
+        ui.label(RichText::new("No revisions recorded").color(SECOND_DETAIL_COLOR));
+        return None;
+    }
+
+    egui::Grid::new(format!("{}_revisions_grid", id_salt)).striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+        ui.label(RichText::new("Revision").color(PODS_HEAD_GRID_COLOR));
+        ui.label(RichText::new("Source").color(PODS_HEAD_GRID_COLOR));
+        ui.label(RichText::new("Age").color(PODS_HEAD_GRID_COLOR));
+        ui.label(RichText::new("Pods").color(PODS_HEAD_GRID_COLOR));
+        ui.label(RichText::new("Images").color(PODS_HEAD_GRID_COLOR));
+        ui.label(RichText::new("Change cause").color(PODS_HEAD_GRID_COLOR));
+        ui.label(RichText::new("Actions").color(PODS_HEAD_GRID_COLOR));
+        ui.end_row();
+
+        for item in revisions {
+            let is_current = current_revision == Some(item.revision);
+            if is_current {
+                ui.label(RichText::new(format!("{} (current)", item.revision)).color(GREEN_BUTTON));
+            } else {
+                ui.label(RichText::new(item.revision.to_string()).color(DETAIL_COLOR));
+            }
+            ui.label(RichText::new(&item.source).color(ITEM_NAME_COLOR));
+            ui.label(item.created.as_ref().map(crate::format_age).unwrap_or_default());
+            ui.label(item.replicas.map(|r| r.to_string()).unwrap_or_else(|| "-".to_string()));
+            ui.label(RichText::new(item.images.join("\n")).color(SECOND_DETAIL_COLOR));
+            ui.label(item.change_cause.clone().unwrap_or_else(|| "-".to_string()));
+            ui.horizontal(|ui| {
+                let selected = *diff_revision == Some(item.revision);
+                if ui.selectable_label(selected, RichText::new("🔍 Diff").color(BLUE_BUTTON))
+                    .on_hover_text("Show what this revision changed in the pod template")
+                    .clicked() {
+                        *diff_revision = if selected { None } else { Some(item.revision) };
+                    }
+                if !is_current && ui.button(RichText::new("↶ Rollback").color(ORANGE_BUTTON)).clicked() {
+                    rollback_to = Some(item.revision);
+                }
+            });
+            ui.end_row();
+        }
+    });
+
+    if let Some(revision) = *diff_revision
+        && let Some(pos) = revisions.iter().position(|r| r.revision == revision) {
+            ui.separator();
+            let selected = &revisions[pos];
+            match revisions.get(pos + 1) {
+                Some(previous) => {
+                    ui.label(RichText::new(format!("Changes from revision {} to {}:", previous.revision, selected.revision)).color(ROW_NAME_COLOR));
+                    show_template_diff(ui, &previous.template, &selected.template);
+                }
+                None => {
+                    ui.label(RichText::new(format!("Revision {} is the oldest one kept, pod template:", selected.revision)).color(ROW_NAME_COLOR));
+                    show_template_diff(ui, "", &selected.template);
+                }
+            }
+        }
+
+    rollback_to
+}
+
+/// Asks for confirmation, then restores a StatefulSet/DaemonSet template from a ControllerRevision
+#[allow(clippy::too_many_arguments)]
+pub fn request_controller_rollback<F>(
+        delete_confirm: &mut super::DeleteConfirmation,
+        client: &Arc<crate::Client>,
+        kind: crate::ScaleTarget,
+        name: String,
+        namespace: Option<String>,
+        revision: &crate::RevisionDetails,
+        action_error: Arc<Mutex<Option<String>>>,
+        on_done: F,
+) where
+    F: FnOnce() + Send + 'static,
+{
+    let client = Arc::clone(client);
+    let revision_number = revision.revision;
+    let revision_name = revision.source.clone();
+    let kind_label = match kind {
+        crate::ScaleTarget::StatefulSet => "StatefulSet",
+        crate::ScaleTarget::DaemonSet => "DaemonSet",
+        crate::ScaleTarget::Deployment => "Deployment",
+        crate::ScaleTarget::ReplicaSet => "ReplicaSet",
+    };
+    delete_confirm.request_action(
+        "roll back".to_string(),
+        format!("Roll back {} \"{}\" to revision {} ({})?", kind_label, name, revision_number, revision_name),
+        move || {
+            tokio::spawn(async move {
+                match crate::rollback_controller_revision(client, &name, namespace.as_deref().unwrap_or("default"), &revision_name, kind).await {
+                    Ok(_) => {
+                        *action_error.lock().unwrap() = None;
+                        on_done();
+                    }
+                    Err(e) => {
+                        *action_error.lock().unwrap() = Some(format!("Rollback to revision {} failed: {}", revision_number, e));
+                    }
+                }
+            });
+        },
+    );
+}
@@ -30,6 +30,18 @@ pub fn show_sc_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "StorageClass details", &mut sc_details_window.show, &name, error) {
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_sc_details(client, &name, details).await {
+                    log::error!("StorageClass details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let sc_item = guard_scs.iter().find(|item| item.name == guard_details.name.clone().unwrap());
     if sc_item.is_none() {
         return;
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use egui::{Context, Key};
 use kube::Client;
 use log::info;
@@ -25,12 +25,22 @@ impl ScaleWindow {
     }
 }
 
-pub fn show_scale_window(ctx: &Context, scale_window: &mut ScaleWindow, client: Arc<Client>) {
+pub fn show_scale_window(ctx: &Context, scale_window: &mut ScaleWindow, hpas: Arc<Mutex<Vec<crate::HpaItem>>>, client: Arc<Client>) {
     let title = format!("Scale {}", scale_window.name.as_ref().unwrap());
+    let hpa = scale_window.resource_kind.as_ref().and_then(|kind| {
+        let hpas = hpas.lock().unwrap();
+        crate::find_hpa_for(&hpas, kind.kind(), scale_window.name.as_deref().unwrap_or_default(), scale_window.namespace.as_deref()).cloned()
+    });
     let response = egui::Window::new(title).show(ctx, |ui| {
         ui.horizontal(|ui| {
             ui.label(format!("Current replicas scale: {}", scale_window.cur_replicas));
         });
+        if let Some(hpa) = &hpa {
+            ui.label(egui::RichText::new(format!(
+                "⚠ Managed by HorizontalPodAutoscaler {} (min {}, max {}), it will override the replica count. Change its min/max in the HPA details instead.",
+                hpa.name, hpa.min_replicas, hpa.max_replicas,
+            )).color(crate::WARNING_COLOR));
+        }
         ui.separator();
 
         ui.horizontal(|ui| {
@@ -1,17 +1,85 @@
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 use egui::{Context, Key};
 use crate::functions::item_color;
 use crate::{ui::YamlEditorWindow};
 use crate::theme::*;
 
+const MASKED_VALUE: &str = "••••••••";
+pub const CERT_EXPIRY_WARNING_DAYS: i64 = 30;
+
 pub struct SecretDetailsWindow {
     pub show: bool,
+    pub shown_secret: Option<(String, Option<String>)>,
+    pub revealed: HashSet<String>,
+    pub editing: Option<(String, String)>,
+    pub save_error: Arc<Mutex<Option<String>>>,
 }
 
 impl SecretDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            shown_secret: None,
+            revealed: HashSet::new(),
+            editing: None,
+            save_error: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+fn format_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    }
+}
+
+pub fn show_certificates(ui: &mut egui::Ui, key: &str, certificates: &[crate::CertificateInfo]) {
+    let now = k8s_openapi::jiff::Timestamp::now();
+    for (i, cert) in certificates.iter().enumerate() {
+        egui::Grid::new(format!("secret_cert_{}_{}", key, i)).striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+            ui.label(egui::RichText::new("Subject:").color(ROW_NAME_COLOR));
+            ui.label(egui::RichText::new(&cert.subject).color(DETAIL_COLOR));
+            ui.end_row();
+            ui.label(egui::RichText::new("Issuer:").color(ROW_NAME_COLOR));
+            ui.label(egui::RichText::new(&cert.issuer).color(SECOND_DETAIL_COLOR));
+            ui.end_row();
+            if !cert.sans.is_empty() {
+                ui.label(egui::RichText::new("SANs:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(cert.sans.join(", ")).color(SECOND_DETAIL_COLOR));
+                ui.end_row();
+            }
+            if let Some(not_before) = cert.not_before {
+                ui.label(egui::RichText::new("Valid from:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(not_before.to_string()).color(SECOND_DETAIL_COLOR));
+                ui.end_row();
+            }
+            if let Some(not_after) = cert.not_after {
+                let days_left = (not_after.as_second() - now.as_second()) / 86400;
+                let (text, color) = if not_after < now {
+                    (format!("{} (expired)", not_after), ERROR_COLOR)
+                } else if days_left < CERT_EXPIRY_WARNING_DAYS {
+                    (format!("{} ({} days left)", not_after, days_left), WARNING_COLOR)
+                } else {
+                    (format!("{} ({} days left)", not_after, days_left), GREEN_BUTTON)
+                };
+                ui.label(egui::RichText::new("Expires:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(text).color(color));
+                ui.end_row();
+            }
+            ui.label(egui::RichText::new("Serial:").color(ROW_NAME_COLOR));
+            ui.label(egui::RichText::new(&cert.serial).color(SECOND_DETAIL_COLOR));
+            ui.end_row();
+            if cert.is_ca {
+                ui.label(egui::RichText::new("CA:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new("Yes").color(SECOND_DETAIL_COLOR));
+                ui.end_row();
+            }
+        });
+        if i + 1 < certificates.len() {
+            ui.separator();
         }
     }
 }
@@ -31,12 +99,34 @@ pub fn show_secret_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Secret details", &mut secret_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_secret_details(client, &name, ns, details).await {
+                    log::error!("Secret details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let secret_item = guard_secrets.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if secret_item.is_none() {
         return;
     }
     let cur_ns = &secret_item.unwrap().namespace;
 
+    // Revealed values and unsaved edits belong to the previously opened secret
+    let current = (guard_details.name.clone().unwrap(), guard_details.namespace.clone());
+    if secret_details_window.shown_secret.as_ref() != Some(&current) {
+        secret_details_window.shown_secret = Some(current);
+        secret_details_window.revealed.clear();
+        secret_details_window.editing = None;
+        *secret_details_window.save_error.lock().unwrap() = None;
+    }
+
     let response = egui::Window::new("Secret details").min_width(800.0).collapsible(false).resizable(true).open(&mut secret_details_window.show).show(ctx, |ui| {
         ui.horizontal(|ui| {
             if ui.button(egui::RichText::new("📃 Logs").size(16.0).color(crate::GRAY_BUTTON)).clicked() {
@@ -59,13 +149,14 @@ pub fn show_secret_details_window(
             if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
                 let name = guard_details.name.clone().unwrap();
                 let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
 
                 delete_confirm.request(name.clone(), ns.clone(), move || {
                     tokio::spawn(async move {
                         if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::Secret>(
                             name,
                             ns.as_deref(),
-                            Arc::clone(&client),
+                            client,
                         ).await {
                             log::error!("Failed to delete secret: {}", err);
                         }
@@ -109,6 +200,12 @@ pub fn show_secret_details_window(
                     ui.end_row();
                 }
 
+                if let Some(secret_type) = &guard_details.secret_type {
+                    ui.label(egui::RichText::new("Type:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(secret_type).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
                 if let Some(annotations) = guard_details.annotations.clone() {
                     ui.separator(); ui.separator(); ui.end_row();
                     ui.label(egui::RichText::new("Annotations:").color(ROW_NAME_COLOR));
@@ -123,6 +220,97 @@ pub fn show_secret_details_window(
                 }
             });
 
+            ui.separator();
+            ui.heading(egui::RichText::new("Data:").color(ROW_NAME_COLOR));
+            if let Some(error) = secret_details_window.save_error.lock().unwrap().clone() {
+                ui.label(egui::RichText::new(error).color(ERROR_MESSAGE_COLOR));
+            }
+            if !guard_details.data.is_empty() {
+                egui::Grid::new("secret_details_data_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for (key, value) in guard_details.data.iter() {
+                        let decoded = std::str::from_utf8(value).ok();
+                        ui.label(egui::RichText::new(key).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(format_size(value.len())).color(SECOND_DETAIL_COLOR));
+
+                        let editing_this = secret_details_window.editing.as_ref().is_some_and(|(k, _)| k == key);
+                        if editing_this {
+                            let mut save = false;
+                            let mut cancel = false;
+                            ui.vertical(|ui| {
+                                if let Some((_, buffer)) = secret_details_window.editing.as_mut() {
+                                    ui.add(egui::TextEdit::multiline(buffer).font(egui::TextStyle::Monospace).desired_rows(4).desired_width(500.0));
+                                }
+                                ui.horizontal(|ui| {
+                                    save = ui.button(egui::RichText::new("✔ Save").color(GREEN_BUTTON)).clicked();
+                                    cancel = ui.button(egui::RichText::new("🗙 Cancel").color(RED_BUTTON)).clicked();
+                                });
+                            });
+                            if save && let Some((key, buffer)) = secret_details_window.editing.take() {
+                                let name = guard_details.name.clone().unwrap();
+                                let ns = cur_ns.clone().unwrap_or_default();
+                                let client = Arc::clone(&client);
+                                let details = Arc::clone(&details);
+                                let save_error = Arc::clone(&secret_details_window.save_error);
+                                tokio::spawn(async move {
+                                    match crate::set_secret_key(Arc::clone(&client), &name, &ns, &key, buffer.as_bytes()).await {
+                                        Ok(_) => {
+                                            *save_error.lock().unwrap() = None;
+                                            if let Err(e) = crate::get_secret_details(client, &name, Some(ns), details).await {
+                                                log::error!("Secret details fetch failed: {:?}", e);
+                                            }
+                                        }
+                                        Err(e) => {
+                                            log::error!("Failed to update key {} of secret {}: {}", key, name, e);
+                                            *save_error.lock().unwrap() = Some(format!("Failed to update {}: {}", key, e));
+                                        }
+                                    }
+                                });
+                            }
+                            if cancel {
+                                secret_details_window.editing = None;
+                            }
+                        } else if secret_details_window.revealed.contains(key) {
+                            match decoded {
+                                Some(text) => ui.label(egui::RichText::new(text).monospace().color(SECOND_DETAIL_COLOR)),
+                                None => ui.label(egui::RichText::new(format!("<binary, {} bytes>", value.len())).color(SECOND_DETAIL_COLOR)),
+                            };
+                        } else {
+                            ui.label(egui::RichText::new(MASKED_VALUE).color(SECOND_DETAIL_COLOR));
+                        }
+
+                        ui.horizontal(|ui| {
+                            let revealed = secret_details_window.revealed.contains(key);
+                            let reveal_label = if revealed { "🙈 Hide" } else { "👁 Reveal" };
+                            if ui.button(egui::RichText::new(reveal_label).color(BLUE_BUTTON)).clicked() {
+                                if revealed {
+                                    secret_details_window.revealed.remove(key);
+                                } else {
+                                    secret_details_window.revealed.insert(key.clone());
+                                }
+                            }
+                            if ui.button(egui::RichText::new("📋 Copy").color(COPY_BUTTON)).on_hover_text("Copy decoded value").clicked() {
+                                use base64::Engine;
+                                let text = decoded.map(|t| t.to_string()).unwrap_or_else(|| base64::engine::general_purpose::STANDARD.encode(value));
+                                ui.ctx().copy_text(text);
+                            }
+                            if ui.add_enabled(decoded.is_some() && !editing_this, egui::Button::new(egui::RichText::new("✏ Edit").color(GREEN_BUTTON)))
+                                .on_disabled_hover_text("Binary values can not be edited inline")
+                                .clicked() {
+                                secret_details_window.editing = Some((key.clone(), decoded.unwrap_or_default().to_string()));
+                            }
+                        });
+                        ui.end_row();
+
+                        if let Some(certificates) = guard_details.certificates.get(key) {
+                            ui.label("");
+                            ui.label(egui::RichText::new(format!("🔏 {} certificate(s)", certificates.len())).color(ROW_NAME_COLOR));
+                            ui.vertical(|ui| show_certificates(ui, key, certificates));
+                            ui.end_row();
+                        }
+                    }
+                });
+            }
+
             ui.separator();
             ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
             if !guard_details.events.is_empty() {
@@ -6,22 +6,76 @@ use crate::theme::*;
 
 pub struct ServiceAccountDetailsWindow {
     pub show: bool,
+    pub show_kubeconfig_form: bool,
+    /// Comma separated, empty means the API server default audience
+    pub token_audiences: String,
+    pub token_hours: u32,
+    pub kubeconfig_message: Arc<Mutex<Option<(String, bool)>>>,
 }
 
 impl ServiceAccountDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            show_kubeconfig_form: false,
+            token_audiences: String::new(),
+            token_hours: 24,
+            kubeconfig_message: Arc::new(Mutex::new(None)),
         }
     }
 }
 
+/// Requests a bound token and saves a kubeconfig using it, the file is picked first so nothing is requested when cancelled
+#[allow(clippy::too_many_arguments)]
+fn generate_kubeconfig(
+        name: String,
+        namespace: String,
+        audiences: &str,
+        hours: u32,
+        kubeconfig_path: Option<&str>,
+        context: Option<&str>,
+        client: Arc<crate::Client>,
+        message: Arc<Mutex<Option<(String, bool)>>>,
+) {
+    let endpoint = match crate::get_cluster_endpoint(kubeconfig_path, context) {
+        Ok(endpoint) => endpoint,
+        Err(e) => {
+            *message.lock().unwrap() = Some((format!("Cannot read the current cluster: {}", e), true));
+            return;
+        }
+    };
+    let Some(path) = rfd::FileDialog::new().set_title("Save kubeconfig").set_file_name(format!("{}-{}.kubeconfig", namespace, name)).save_file() else {
+        return;
+    };
+    let audiences: Vec<String> = audiences.split(',').map(|a| a.trim().to_string()).filter(|a| !a.is_empty()).collect();
+    let expiration_seconds = i64::from(hours) * 3600;
+
+    tokio::spawn(async move {
+        let result = match crate::request_service_account_token(client, &name, &namespace, audiences, expiration_seconds).await {
+            Ok((token, expires)) => {
+                let config = crate::build_service_account_kubeconfig(&endpoint, &name, &namespace, &token);
+                crate::write_private_file(&path, &config)
+                    .map(|_| format!("Saved {} (token expires {})", path.display(), expires.0.strftime("%Y-%m-%d %H:%M UTC")))
+                    .map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+            }
+            Err(e) => Err(format!("Token request failed: {}", e)),
+        };
+        *message.lock().unwrap() = Some(match result {
+            Ok(text) => (text, false),
+            Err(text) => (text, true),
+        });
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn show_service_account_details_window(
         ctx: &Context,
         service_account_details_window: &mut ServiceAccountDetailsWindow,
         details: Arc<Mutex<crate::ServiceAccountDetails>>,
         service_accounts: Arc<Mutex<Vec<crate::ServiceAccountItem>>>,
         yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        kubeconfig_path: Option<&str>,
+        context: Option<&str>,
         client: Arc<crate::Client>,
         delete_confirm: &mut super::DeleteConfirmation,
 ) {
@@ -30,11 +84,25 @@ pub fn show_service_account_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "Service account details", &mut service_account_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_service_account_details(client, &name, ns, details).await {
+                    log::error!("Service account details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let service_account_item = guard_service_accounts.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if service_account_item.is_none() {
         return;
     }
     let cur_ns = &service_account_item.unwrap().namespace;
+    let kubeconfig_message = Arc::clone(&service_account_details_window.kubeconfig_message);
 
     let response = egui::Window::new("Service account details").min_width(800.0).collapsible(false).resizable(true).open(&mut service_account_details_window.show).show(ctx, |ui| {
         ui.horizontal(|ui| {
@@ -52,16 +120,23 @@ pub fn show_service_account_details_window(
                 );
             }
 
+            if ui.selectable_label(service_account_details_window.show_kubeconfig_form, egui::RichText::new("🔑 Generate kubeconfig").size(16.0).color(crate::BLUE_BUTTON))
+                .on_hover_text("Save a kubeconfig authenticating with a bound token of this service account")
+                .clicked() {
+                    service_account_details_window.show_kubeconfig_form = !service_account_details_window.show_kubeconfig_form;
+                }
+
             if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
                 let name = guard_details.name.clone().unwrap();
                 let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
 
                 delete_confirm.request(name.clone(), ns.clone(), move || {
                     tokio::spawn(async move {
                         if let Err(err) = crate::delete_namespaced_component_for::<k8s_openapi::api::core::v1::ServiceAccount>(
                             name,
                             ns.as_deref(),
-                            Arc::clone(&client),
+                            client,
                         ).await {
                             log::error!("Failed to delete service account: {}", err);
                         }
@@ -69,6 +144,31 @@ pub fn show_service_account_details_window(
                 });
             }
         });
+        if service_account_details_window.show_kubeconfig_form {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("Audiences:").color(ROW_NAME_COLOR));
+                ui.add(egui::TextEdit::singleline(&mut service_account_details_window.token_audiences).hint_text("API server default").desired_width(250.0));
+                ui.label(egui::RichText::new("Expires in:").color(ROW_NAME_COLOR));
+                // The API server refuses tokens shorter than 10 minutes and may cap long ones
+                ui.add(egui::DragValue::new(&mut service_account_details_window.token_hours).range(1..=8760).suffix(" h"));
+                if ui.button(egui::RichText::new("💾 Save as...").color(crate::GREEN_BUTTON)).clicked() {
+                    generate_kubeconfig(
+                        guard_details.name.clone().unwrap(),
+                        cur_ns.clone().unwrap_or_else(|| "default".to_string()),
+                        &service_account_details_window.token_audiences,
+                        service_account_details_window.token_hours,
+                        kubeconfig_path,
+                        context,
+                        Arc::clone(&client),
+                        Arc::clone(&kubeconfig_message),
+                    );
+                }
+            });
+            ui.label(egui::RichText::new("Server and CA are taken from the current context, the token cannot be revoked before it expires unless the service account is deleted").color(WARNING_COLOR));
+        }
+        if let Some((message, is_error)) = kubeconfig_message.lock().unwrap().clone() {
+            ui.label(egui::RichText::new(message).color(if is_error { ERROR_MESSAGE_COLOR } else { GREEN_BUTTON }));
+        }
         ui.separator();
         egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
             egui::Grid::new("service_account_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
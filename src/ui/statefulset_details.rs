@@ -1,4 +1,5 @@
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use egui::{Context, Key};
 use crate::functions::item_color;
 use crate::{ui::YamlEditorWindow};
@@ -6,16 +7,56 @@ use crate::theme::*;
 
 pub struct StatefulSetDetailsWindow {
     pub show: bool,
+    pub diff_revision: Option<i64>,
+    pub shown_statefulset: Option<(String, Option<String>)>,
+    pub partition_input: i32,
+    pub last_refresh: Option<Instant>,
+    pub action_error: Arc<Mutex<Option<String>>>,
 }
 
+/// Pods are replaced ordinal by ordinal, the window follows the rollout while it lasts
+const ROLLOUT_REFRESH_INTERVAL: Duration = Duration::from_secs(3);
+
 impl StatefulSetDetailsWindow {
     pub fn new() -> Self {
         Self {
             show: false,
+            diff_revision: None,
+            shown_statefulset: None,
+            partition_input: 0,
+            last_refresh: None,
+            action_error: Arc::new(Mutex::new(None)),
         }
     }
 }
 
+fn refresh_statefulset_details(client: &Arc<crate::Client>, details: &Arc<Mutex<crate::StatefulSetDetails>>, name: String, ns: Option<String>) {
+    let client = Arc::clone(client);
+    let details = Arc::clone(details);
+    tokio::spawn(async move {
+        if let Err(e) = crate::get_statefulset_details(client, &name, ns, details).await {
+            log::error!("Statefulset details fetch failed: {:?}", e);
+        }
+    });
+}
+
+fn apply_partition(client: &Arc<crate::Client>, details: &Arc<Mutex<crate::StatefulSetDetails>>, name: String, ns: Option<String>, partition: i32, action_error: &Arc<Mutex<Option<String>>>) {
+    let client = Arc::clone(client);
+    let details = Arc::clone(details);
+    let action_error = Arc::clone(action_error);
+    tokio::spawn(async move {
+        match crate::set_statefulset_partition(Arc::clone(&client), &name, ns.as_deref().unwrap_or("default"), partition).await {
+            Ok(_) => {
+                *action_error.lock().unwrap() = None;
+                refresh_statefulset_details(&client, &details, name, ns);
+            }
+            Err(e) => {
+                *action_error.lock().unwrap() = Some(format!("Failed to set partition: {}", e));
+            }
+        }
+    });
+}
+
 pub fn show_statefulset_details_window(
         ctx: &Context,
         statefulset_details_window: &mut StatefulSetDetailsWindow,
@@ -30,11 +71,46 @@ pub fn show_statefulset_details_window(
     if guard_details.name.is_none() {
         return;
     }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "StatefulSet details", &mut statefulset_details_window.show, &name, error) {
+            let ns = guard_details.namespace.clone();
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_statefulset_details(client, &name, ns, details).await {
+                    log::error!("Statefulset details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
     let statefulset_item = guard_statefulsets.iter().find(|item| item.name == guard_details.name.clone().unwrap() && item.namespace == guard_details.namespace.clone());
     if statefulset_item.is_none() {
         return;
     }
     let cur_ns = &statefulset_item.unwrap().namespace;
+    let cur_name = guard_details.name.clone().unwrap();
+
+    let current = (cur_name.clone(), cur_ns.clone());
+    if statefulset_details_window.shown_statefulset.as_ref() != Some(&current) {
+        statefulset_details_window.shown_statefulset = Some(current);
+        statefulset_details_window.diff_revision = None;
+        statefulset_details_window.partition_input = guard_details.partition.unwrap_or(0);
+        statefulset_details_window.last_refresh = Some(Instant::now());
+        *statefulset_details_window.action_error.lock().unwrap() = None;
+    }
+
+    let rolling = guard_details.current_revision != guard_details.update_revision
+        || guard_details.pods.iter().any(|p| !p.ready);
+    if rolling && statefulset_details_window.last_refresh.is_none_or(|t| t.elapsed() > ROLLOUT_REFRESH_INTERVAL) {
+        statefulset_details_window.last_refresh = Some(Instant::now());
+        refresh_statefulset_details(&client, &details, cur_name.clone(), cur_ns.clone());
+    }
+    let current_revision_number = guard_details.revisions.iter()
+        .find(|r| Some(&r.source) == guard_details.update_revision.as_ref())
+        .map(|r| r.revision);
+    let mut rollback_to = None;
+    let action_error = Arc::clone(&statefulset_details_window.action_error);
 
     let response = egui::Window::new("StatefulSet details").min_width(800.0).collapsible(false).resizable(true).open(&mut statefulset_details_window.show).show(ctx, |ui| {
         ui.horizontal(|ui| {
@@ -55,6 +131,7 @@ pub fn show_statefulset_details_window(
             if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
                 let name = guard_details.name.clone().unwrap();
                 let ns = cur_ns.clone();
+                let client = Arc::clone(&client);
 
                 delete_confirm.request(name.clone(), ns.clone(), move || {
                     tokio::spawn(async move {
@@ -69,6 +146,9 @@ pub fn show_statefulset_details_window(
                 });
             }
         });
+        if let Some(error) = action_error.lock().unwrap().clone() {
+            ui.label(egui::RichText::new(error).color(ERROR_MESSAGE_COLOR));
+        }
         ui.separator();
         egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
             egui::Grid::new("statefulset_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
@@ -119,6 +199,74 @@ pub fn show_statefulset_details_window(
                 }
             });
 
+            ui.separator();
+            ui.heading(egui::RichText::new("Rollout:").color(ROW_NAME_COLOR));
+            egui::Grid::new("statefulset_details_rollout_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                ui.label(egui::RichText::new("Update strategy:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(guard_details.update_strategy.clone().unwrap_or_else(|| "RollingUpdate".to_string())).color(DETAIL_COLOR));
+                ui.end_row();
+
+                ui.label(egui::RichText::new("Updated replicas:").color(ROW_NAME_COLOR));
+                ui.horizontal(|ui| {
+                    if rolling {
+                        ui.spinner();
+                    }
+                    ui.label(egui::RichText::new(format!("{} of {}", guard_details.updated_replicas, guard_details.replicas)).color(DETAIL_COLOR));
+                });
+                ui.end_row();
+
+                ui.label(egui::RichText::new("Revisions:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(format!(
+                    "current {}, update {}",
+                    guard_details.current_revision.clone().unwrap_or_default(),
+                    guard_details.update_revision.clone().unwrap_or_default(),
+                )).color(SECOND_DETAIL_COLOR));
+                ui.end_row();
+
+                if guard_details.update_strategy.as_deref() != Some("OnDelete") {
+                    ui.label(egui::RichText::new("Partition:").color(ROW_NAME_COLOR));
+                    ui.horizontal(|ui| {
+                        let max = guard_details.replicas.max(0);
+                        ui.add(egui::DragValue::new(&mut statefulset_details_window.partition_input).range(0..=max));
+                        if ui.button(egui::RichText::new("Apply").color(crate::GREEN_BUTTON)).clicked() {
+                            apply_partition(&client, &details, cur_name.clone(), cur_ns.clone(), statefulset_details_window.partition_input, &action_error);
+                        }
+                        let partition = guard_details.partition.unwrap_or(0);
+                        if partition > 0 && ui.button(egui::RichText::new("⏵ Next ordinal").color(crate::BLUE_BUTTON))
+                            .on_hover_text("Lower the partition by one so the next pod gets the update revision")
+                            .clicked() {
+                                statefulset_details_window.partition_input = partition - 1;
+                                apply_partition(&client, &details, cur_name.clone(), cur_ns.clone(), partition - 1, &action_error);
+                            }
+                        ui.label(egui::RichText::new(format!("pods with ordinal >= {} get the update revision", partition)).color(SECOND_DETAIL_COLOR));
+                    });
+                    ui.end_row();
+                }
+            });
+
+            if !guard_details.pods.is_empty() {
+                egui::Grid::new("statefulset_details_pods_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                    ui.label(egui::RichText::new("Ordinal").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Pod").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Revision").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Phase").color(PODS_HEAD_GRID_COLOR));
+                    ui.end_row();
+                    for pod in &guard_details.pods {
+                        let updated = pod.revision.is_some() && pod.revision == guard_details.update_revision;
+                        ui.label(egui::RichText::new(pod.ordinal.to_string()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(&pod.name).color(POD_NAME_COLUMN_COLOR));
+                        ui.label(egui::RichText::new(pod.revision.clone().unwrap_or_default()).color(if updated { GREEN_BUTTON } else { GRAY_BUTTON }));
+                        let phase = if pod.ready { "Ready".to_string() } else { pod.phase.clone() };
+                        ui.label(egui::RichText::new(&phase).color(if pod.ready { GREEN_BUTTON } else { WARNING_COLOR }));
+                        ui.end_row();
+                    }
+                });
+            }
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Revision history:").color(ROW_NAME_COLOR));
+            rollback_to = crate::show_revision_history(ui, "statefulset", &guard_details.revisions, current_revision_number, &mut statefulset_details_window.diff_revision);
+
             ui.separator();
             ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
             if !guard_details.events.is_empty() {
@@ -133,6 +281,15 @@ pub fn show_statefulset_details_window(
             }
         });
     });
+    if let Some(revision) = rollback_to
+        && let Some(target) = guard_details.revisions.iter().find(|r| r.revision == revision) {
+            let client_clone = Arc::clone(&client);
+            let details_clone = Arc::clone(&details);
+            let (name, ns) = (cur_name.clone(), cur_ns.clone());
+            crate::request_controller_rollback(delete_confirm, &client, crate::ScaleTarget::StatefulSet, cur_name.clone(), cur_ns.clone(), target, action_error, move || {
+                refresh_statefulset_details(&client_clone, &details_clone, name, ns);
+            });
+        }
     crate::show_delete_confirmation(ctx, delete_confirm);
 
     if let Some(inner_response) = response
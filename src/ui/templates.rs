@@ -1,14 +1,19 @@
+use std::{collections::BTreeMap, fs::File};
+use anyhow::Context;
+use serde::Deserialize;
+use walkdir::WalkDir;
+
 pub const NAMESPACE_TEMPLATE: &str = r#"apiVersion: v1
 kind: Namespace
 metadata:
-  name: namespace-name
+  name: {{name}}
 "#;
 
 pub const CONFIGMAP_TEMPLATE: &str = r#"apiVersion: v1
 kind: ConfigMap
 metadata:
-  name: configmap-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 data:
   key: "value"
 "#;
@@ -16,43 +21,43 @@ data:
 pub const STORAGE_CLASS_TEMPLATE: &str = r#"apiVersion: storage.k8s.io/v1
 kind: StorageClass
 metadata:
-  name: sc-name
+  name: {{name}}
 "#;
 
 pub const PVC_TEMPLATE: &str = r#"apiVersion: v1
 kind: PersistentVolumeClaim
 metadata:
-  name: pvc-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 spec:
   accessModes:
     - ReadWriteOnce
   resources:
     requests:
       storage: 1Gi
-  storageClassName: storage-class-name
+  storageClassName: {{storage_class}}
 "#;
 
 pub const POD_TEMPLATE: &str = r#"apiVersion: v1
 kind: Pod
 metadata:
-  name: pod-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 spec:
   containers:
     - name: pod-container
-      image: nginx
+      image: {{image}}
 "#;
 
 pub const POD_WITH_PVC_TEMPLATE: &str = r#"apiVersion: v1
 kind: Pod
 metadata:
-  name: pod-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 spec:
   containers:
     - name: pod-container
-      image: nginx
+      image: {{image}}
       volumeMounts:
         - name: volume-name
           mountPath: /testdata
@@ -65,8 +70,8 @@ spec:
 pub const SECRET_TEMPLATE: &str = r#"apiVersion: v1
 kind: Secret
 metadata:
-  name: secret-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 data:
   key: cXFx
 type: Opaque
@@ -75,41 +80,41 @@ type: Opaque
 pub const SERVICE_ACCOUNT_TEMPLATE: &str = r#"apiVersion: v1
 kind: ServiceAccount
 metadata:
-  name: service-account-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 "#;
 
 pub const ROLE_TEMPLATE: &str = r#"apiVersion: rbac.authorization.k8s.io/v1
 kind: Role
 metadata:
-  name: role-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 "#;
 
 pub const ROLE_BINDING_TEMPLATE: &str = r#"apiVersion: rbac.authorization.k8s.io/v1
 kind: RoleBinding
 metadata:
-  name: role-binding-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 "#;
 
 pub const CLUSTER_ROLE_TEMPLATE: &str = r#"apiVersion: rbac.authorization.k8s.io/v1
 kind: ClusterRole
 metadata:
-  name: cluster-role-name
+  name: {{name}}
 "#;
 
 pub const CLUSTER_ROLE_BINDING_TEMPLATE: &str = r#"apiVersion: rbac.authorization.k8s.io/v1
 kind: ClusterRoleBinding
 metadata:
-  name: cluster-role-binding-name
+  name: {{name}}
 "#;
 
 pub const INGRESS_TEMPLATE: &str = r#"apiVersion: networking.k8s.io/v1
 kind: Ingress
 metadata:
-  name: ingress-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
   annotations:
     nginx.ingress.kubernetes.io/rewrite-target: /
 spec:
@@ -129,8 +134,8 @@ spec:
 pub const SERVICE_TEMPLATE: &str = r#"apiVersion: v1
 kind: Service
 metadata:
-  name: service-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
   labels:
     app: app-name
 spec:
@@ -143,19 +148,19 @@ spec:
 pub const DAEMONSET_TEMPLATE: &str = r#"apiVersion: apps/v1
 kind: DaemonSet
 metadata:
-  name: daemonset-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 "#;
 
 pub const REPLICASET_TEMPLATE: &str = r#"apiVersion: apps/v1
 kind: ReplicaSet
 metadata:
-  name: replicaset-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
   labels:
     app: app-name
 spec:
-  replicas: 3
+  replicas: {{replicas}}
   selector:
     matchLabels:
       app: app-name
@@ -166,7 +171,7 @@ spec:
     spec:
       containers:
       - name: nginx
-        image: nginx
+        image: {{image}}
         ports:
         - containerPort: 80
 "#;
@@ -174,12 +179,12 @@ spec:
 pub const DEPLOYMENT_TEMPLATE: &str = r#"apiVersion: apps/v1
 kind: Deployment
 metadata:
-  name: deployment-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
   labels:
     app: app-name
 spec:
-  replicas: 3
+  replicas: {{replicas}}
   selector:
     matchLabels:
       app: app-name
@@ -190,7 +195,7 @@ spec:
     spec:
       containers:
       - name: nginx
-        image: nginx
+        image: {{image}}
         ports:
         - containerPort: 80
 "#;
@@ -198,8 +203,8 @@ spec:
 pub const EXTERNAL_SECRET_TEMPLATE: &str = r#"apiVersion: external-secrets.io/v1beta1
 kind: ExternalSecret
 metadata:
-  name: secret-name
-  namespace: namespace-name
+  name: {{name}}
+  namespace: {{namespace}}
 spec:
   dataFrom:
     - extract:
@@ -219,3 +224,288 @@ spec:
       mergePolicy: Replace
       type: Opaque
 "#;
+
+pub const JOB_TEMPLATE: &str = r#"apiVersion: batch/v1
+kind: Job
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  backoffLimit: 4
+  template:
+    spec:
+      restartPolicy: Never
+      containers:
+      - name: job
+        image: {{image}}
+        command: ["sh", "-c", "echo hello"]
+"#;
+
+pub const CRONJOB_TEMPLATE: &str = r#"apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  schedule: "{{schedule}}"
+  concurrencyPolicy: Forbid
+  successfulJobsHistoryLimit: 3
+  failedJobsHistoryLimit: 1
+  jobTemplate:
+    spec:
+      template:
+        spec:
+          restartPolicy: OnFailure
+          containers:
+          - name: job
+            image: {{image}}
+            command: ["sh", "-c", "date"]
+"#;
+
+pub const STATEFULSET_TEMPLATE: &str = r#"apiVersion: apps/v1
+kind: StatefulSet
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  serviceName: {{name}}
+  replicas: {{replicas}}
+  selector:
+    matchLabels:
+      app: {{name}}
+  template:
+    metadata:
+      labels:
+        app: {{name}}
+    spec:
+      containers:
+      - name: main
+        image: {{image}}
+        volumeMounts:
+        - name: data
+          mountPath: /data
+  volumeClaimTemplates:
+  - metadata:
+      name: data
+    spec:
+      accessModes: ["ReadWriteOnce"]
+      storageClassName: {{storage_class}}
+      resources:
+        requests:
+          storage: 1Gi
+"#;
+
+pub const NETWORK_POLICY_TEMPLATE: &str = r#"apiVersion: networking.k8s.io/v1
+kind: NetworkPolicy
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  podSelector:
+    matchLabels:
+      app: app-name
+  policyTypes:
+  - Ingress
+  ingress:
+  - from:
+    - podSelector:
+        matchLabels:
+          role: frontend
+    ports:
+    - protocol: TCP
+      port: 80
+"#;
+
+pub const PDB_TEMPLATE: &str = r#"apiVersion: policy/v1
+kind: PodDisruptionBudget
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  minAvailable: 1
+  selector:
+    matchLabels:
+      app: app-name
+"#;
+
+pub const HPA_TEMPLATE: &str = r#"apiVersion: autoscaling/v2
+kind: HorizontalPodAutoscaler
+metadata:
+  name: {{name}}
+  namespace: {{namespace}}
+spec:
+  scaleTargetRef:
+    apiVersion: apps/v1
+    kind: Deployment
+    name: deployment-name
+  minReplicas: {{replicas}}
+  maxReplicas: 10
+  metrics:
+  - type: Resource
+    resource:
+      name: cpu
+      target:
+        type: Utilization
+        averageUtilization: 80
+"#;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateParamType {
+    Name,
+    Namespace,
+    Image,
+    Replicas,
+    StorageClass,
+    #[default]
+    String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TemplateParam {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub param_type: TemplateParamType,
+    #[serde(default)]
+    pub default: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ResourceTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub params: Vec<TemplateParam>,
+    pub template: String,
+    #[serde(skip)]
+    pub resource_type: Option<crate::ResourceType>,
+}
+
+fn builtin_template(name: &str, resource_type: crate::ResourceType, template: &str, params: &[(&str, TemplateParamType, &str)]) -> ResourceTemplate {
+    ResourceTemplate {
+        name: name.to_string(),
+        params: params.iter().map(|(n, t, d)| TemplateParam {
+            name: n.to_string(),
+            param_type: t.clone(),
+            default: d.to_string(),
+        }).collect(),
+        template: template.to_string(),
+        resource_type: Some(resource_type),
+    }
+}
+
+pub fn builtin_templates() -> Vec<ResourceTemplate> {
+    use crate::ResourceType as R;
+    use TemplateParamType as P;
+
+    let named = |default: &'static str| vec![("name", P::Name, default), ("namespace", P::Namespace, "default")];
+    let with_image = |default: &'static str| {
+        let mut params = named(default);
+        params.push(("image", P::Image, "nginx"));
+        params
+    };
+    let with_replicas = |default: &'static str| {
+        let mut params = with_image(default);
+        params.push(("replicas", P::Replicas, "3"));
+        params
+    };
+
+    vec![
+        builtin_template("NameSpace", R::NameSpace, NAMESPACE_TEMPLATE, &[("name", P::Name, "namespace-name")]),
+        builtin_template("Secret", R::Secret, SECRET_TEMPLATE, &named("secret-name")),
+        builtin_template("Deployment", R::Deployment, DEPLOYMENT_TEMPLATE, &with_replicas("deployment-name")),
+        builtin_template("StatefulSet", R::StatefulSet, STATEFULSET_TEMPLATE, &{
+            let mut params = with_replicas("statefulset-name");
+            params.push(("storage_class", P::StorageClass, ""));
+            params
+        }),
+        builtin_template("Service", R::Service, SERVICE_TEMPLATE, &named("service-name")),
+        builtin_template("StorageClass", R::StorageClass, STORAGE_CLASS_TEMPLATE, &[("name", P::Name, "sc-name")]),
+        builtin_template("DaemonSet", R::DaemonSet, DAEMONSET_TEMPLATE, &named("daemonset-name")),
+        builtin_template("Ingress", R::Ingress, INGRESS_TEMPLATE, &named("ingress-name")),
+        builtin_template("ReplicaSet", R::ReplicaSet, REPLICASET_TEMPLATE, &with_replicas("replicaset-name")),
+        builtin_template("Job", R::Job, JOB_TEMPLATE, &with_image("job-name")),
+        builtin_template("CronJob", R::CronJob, CRONJOB_TEMPLATE, &{
+            let mut params = with_image("cronjob-name");
+            params.push(("schedule", P::String, "*/5 * * * *"));
+            params
+        }),
+        builtin_template("Pod", R::Pod, POD_TEMPLATE, &with_image("pod-name")),
+        builtin_template("Pod with PVC", R::PodWithPvc, POD_WITH_PVC_TEMPLATE, &with_image("pod-name")),
+        builtin_template("Configmap", R::ConfigMap, CONFIGMAP_TEMPLATE, &named("configmap-name")),
+        builtin_template("Service account", R::ServiceAccount, SERVICE_ACCOUNT_TEMPLATE, &named("service-account-name")),
+        builtin_template("Role", R::Role, ROLE_TEMPLATE, &named("role-name")),
+        builtin_template("RoleBinding", R::RoleBinding, ROLE_BINDING_TEMPLATE, &named("role-binding-name")),
+        builtin_template("Cluster role", R::ClusterRole, CLUSTER_ROLE_TEMPLATE, &[("name", P::Name, "cluster-role-name")]),
+        builtin_template("Cluster role binding", R::ClusterRoleBinding, CLUSTER_ROLE_BINDING_TEMPLATE, &[("name", P::Name, "cluster-role-binding-name")]),
+        builtin_template("External secret", R::ExternalSecret, EXTERNAL_SECRET_TEMPLATE, &named("secret-name")),
+        builtin_template("PersistenceVolumeClaim", R::PersistenceVolumeClaim, PVC_TEMPLATE, &{
+            let mut params = named("pvc-name");
+            params.push(("storage_class", P::StorageClass, ""));
+            params
+        }),
+        builtin_template("NetworkPolicy", R::NetworkPolicy, NETWORK_POLICY_TEMPLATE, &named("network-policy-name")),
+        builtin_template("PodDisruptionBudget", R::PodDisruptionBudget, PDB_TEMPLATE, &named("pdb-name")),
+        builtin_template("HorizontalPodAutoscaler", R::HorizontalPodAutoscaler, HPA_TEMPLATE, &{
+            let mut params = named("hpa-name");
+            params.push(("replicas", P::Replicas, "1"));
+            params
+        }),
+        builtin_template("Blank", R::Blank, "", &[]),
+    ]
+}
+
+/// Built-in templates followed by the user ones from the templates directory,
+/// a user template with the same name replaces the built-in one
+pub fn load_templates() -> Vec<ResourceTemplate> {
+    let mut templates = builtin_templates();
+
+    let mut templates_dir = crate::app_root_path();
+    templates_dir.push("templates");
+
+    if !templates_dir.exists() {
+        return templates;
+    }
+
+    for entry in WalkDir::new(templates_dir).into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path();
+
+        if !path.is_file() {
+            continue;
+        }
+
+        match path.extension().and_then(|s| s.to_str()) {
+            Some("yaml") | Some("yml") => {
+                let parsed = File::open(path)
+                    .with_context(|| format!("opening template file {}", path.display()))
+                    .and_then(|f| {
+                        yaml_serde::from_reader::<_, ResourceTemplate>(f)
+                            .with_context(|| format!("parsing YAML {}", path.display()))
+                    });
+                match parsed {
+                    Ok(template) => {
+                        templates.retain(|t| t.name != template.name);
+                        templates.push(template);
+                    }
+                    Err(e) => log::error!("Failed to load template: {:?}", e),
+                }
+            }
+            _ => {
+                // ignore all other files
+            }
+        }
+    }
+
+    templates
+}
+
+/// Substitutes `{{param}}` (spaces inside braces allowed) with the parameter values
+pub fn render_template(template: &ResourceTemplate, values: &BTreeMap<String, String>) -> String {
+    let mut rendered = template.template.clone();
+    for param in &template.params {
+        let value = values.get(&param.name).unwrap_or(&param.default);
+        rendered = rendered
+            .replace(&format!("{{{{{}}}}}", param.name), value)
+            .replace(&format!("{{{{ {} }}}}", param.name), value);
+    }
+    rendered
+}
@@ -0,0 +1,194 @@
+use std::sync::{Arc, Mutex};
+use egui::{Context, Key};
+use k8s_openapi::api::admissionregistration::v1::ValidatingAdmissionPolicy;
+use crate::functions::item_color;
+use crate::{ui::YamlEditorWindow};
+use crate::theme::*;
+
+pub struct ValidatingAdmissionPolicyDetailsWindow {
+    pub show: bool,
+}
+
+impl ValidatingAdmissionPolicyDetailsWindow {
+    pub fn new() -> Self {
+        Self {
+            show: false,
+        }
+    }
+}
+
+pub fn show_validating_admission_policy_details_window(
+        ctx: &Context,
+        policy_details_window: &mut ValidatingAdmissionPolicyDetailsWindow,
+        details: Arc<Mutex<crate::ValidatingAdmissionPolicyDetails>>,
+        policies: Arc<Mutex<Vec<crate::ValidatingAdmissionPolicyItem>>>,
+        yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        client: Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) {
+    let guard_details = details.lock().unwrap(); // More detailed info
+    let guard_policies = policies.lock().unwrap(); // Policies with base details already we have
+    if guard_details.name.is_none() {
+        return;
+    }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, "ValidatingAdmissionPolicy details", &mut policy_details_window.show, &name, error) {
+            let details = Arc::clone(&details);
+            tokio::spawn(async move {
+                if let Err(e) = crate::get_validating_admission_policy_details(client, &name, details).await {
+                    log::error!("ValidatingAdmissionPolicy details fetch failed: {:?}", e);
+                }
+            });
+        }
+        return;
+    }
+    let Some(item) = guard_policies.iter().find(|item| Some(&item.name) == guard_details.name.as_ref()) else {
+        return;
+    };
+    let cur_name = item.name.clone();
+
+    let response = egui::Window::new("ValidatingAdmissionPolicy details").min_width(800.0).collapsible(false).resizable(true).open(&mut policy_details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
+                crate::edit_cluster_yaml_for::<ValidatingAdmissionPolicy>(cur_name.clone(), Arc::clone(&yaml_editor_window), Arc::clone(&client));
+            }
+
+            if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
+                let name = cur_name.clone();
+                let client = Arc::clone(&client);
+
+                delete_confirm.request(name.clone(), None, move || {
+                    tokio::spawn(async move {
+                        if let Err(err) = crate::delete_cluster_component_for::<ValidatingAdmissionPolicy>(name, client).await {
+                            log::error!("Failed to delete ValidatingAdmissionPolicy: {}", err);
+                        }
+                    });
+                });
+            }
+        });
+        ui.separator();
+        egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
+            egui::Grid::new("vap_details_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+
+                ui.label(egui::RichText::new("Policy name:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&cur_name).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(creation_timestamp) = &item.creation_timestamp {
+                    ui.label(egui::RichText::new("Creation time:").color(ROW_NAME_COLOR));
+                    let timestamp_text = format!("{}, {} ago", creation_timestamp.0, crate::format_age(creation_timestamp));
+                    ui.label(egui::RichText::new(timestamp_text).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.label(egui::RichText::new("Failure policy:").color(ROW_NAME_COLOR));
+                let policy_color = if item.failure_policy == "Fail" { WARNING_COLOR } else { DETAIL_COLOR };
+                ui.label(egui::RichText::new(&item.failure_policy).color(policy_color));
+                ui.end_row();
+
+                if let Some(param_kind) = &item.param_kind {
+                    ui.label(egui::RichText::new("Param kind:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(param_kind).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                ui.label(egui::RichText::new("Match:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(if item.match_rules.is_empty() { "-".to_string() } else { item.match_rules.join("\n") }).color(DETAIL_COLOR));
+                ui.end_row();
+
+                ui.separator(); ui.separator(); ui.end_row();
+                ui.label(egui::RichText::new("Validations:").color(ROW_NAME_COLOR));
+                egui::Grid::new("vap_details_validations_grid").striped(true).min_col_width(20.0).max_col_width(500.0).show(ui, |ui| {
+                    ui.label(egui::RichText::new("Expression").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Message").color(PODS_HEAD_GRID_COLOR));
+                    ui.label(egui::RichText::new("Reason").color(PODS_HEAD_GRID_COLOR));
+                    ui.end_row();
+                    for validation in &item.validations {
+                        ui.label(egui::RichText::new(&validation.expression).monospace().color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(validation.message.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.label(egui::RichText::new(validation.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.end_row();
+                    }
+                });
+                ui.end_row();
+
+                if !item.type_warnings.is_empty() {
+                    ui.label(egui::RichText::new("Type checking:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("vap_details_type_warnings_grid").striped(true).min_col_width(20.0).max_col_width(500.0).show(ui, |ui| {
+                        for (field, warning) in &item.type_warnings {
+                            ui.label(egui::RichText::new(field).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(warning).color(WARNING_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+
+                ui.separator(); ui.separator(); ui.end_row();
+                ui.label(egui::RichText::new("Bindings:").color(ROW_NAME_COLOR));
+                if let Some(error) = &guard_details.bindings_error {
+                    ui.label(egui::RichText::new(format!("Failed to list bindings: {}", error)).color(ERROR_MESSAGE_COLOR));
+                } else if guard_details.bindings.is_empty() {
+                    ui.label(egui::RichText::new("No bindings, the policy is not enforced").color(WARNING_COLOR));
+                } else {
+                    egui::Grid::new("vap_details_bindings_grid").striped(true).min_col_width(20.0).max_col_width(400.0).show(ui, |ui| {
+                        ui.label(egui::RichText::new("Name").color(PODS_HEAD_GRID_COLOR));
+                        ui.label(egui::RichText::new("Actions").color(PODS_HEAD_GRID_COLOR));
+                        ui.label(egui::RichText::new("Params").color(PODS_HEAD_GRID_COLOR));
+                        ui.label(egui::RichText::new("Match").color(PODS_HEAD_GRID_COLOR));
+                        ui.end_row();
+                        for binding in &guard_details.bindings {
+                            ui.label(egui::RichText::new(&binding.name).color(DETAIL_COLOR));
+                            let actions_color = if binding.validation_actions.iter().any(|a| a == "Deny") { WARNING_COLOR } else { DETAIL_COLOR };
+                            ui.label(egui::RichText::new(binding.validation_actions.join(", ")).color(actions_color));
+                            ui.label(egui::RichText::new(binding.param_ref.clone().unwrap_or("-".to_string())).color(SECOND_DETAIL_COLOR));
+                            let mut scope = binding.match_rules.clone();
+                            if let Some(selector) = &binding.namespace_selector {
+                                scope.push(format!("namespaces: {}", selector));
+                            }
+                            ui.label(egui::RichText::new(if scope.is_empty() { "(policy match)".to_string() } else { scope.join("\n") }).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                }
+                ui.end_row();
+
+                if !guard_details.conditions.is_empty() {
+                    ui.separator(); ui.separator(); ui.end_row();
+                    ui.label(egui::RichText::new("Conditions:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("vap_details_conditions_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                        for condition in guard_details.conditions.iter() {
+                            let color = if condition.status == "True" { GREEN_BUTTON } else { WARNING_COLOR };
+                            ui.label(egui::RichText::new(&condition.type_).color(color));
+                            ui.label(egui::RichText::new(&condition.status).color(color));
+                            ui.label(egui::RichText::new(condition.reason.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(condition.message.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
+            if !guard_details.events.is_empty() {
+                egui::Grid::new("vap_details_events_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for event in &guard_details.events {
+                        ui.label(egui::RichText::new(event.timestamp.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.message.clone().unwrap_or_default()).color(item_color(&event.event_type.clone().unwrap_or_default())));
+                        ui.end_row();
+                    }
+                });
+            }
+        });
+    });
+    crate::show_delete_confirmation(ctx, delete_confirm);
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            policy_details_window.show = false;
+        }
+}
@@ -0,0 +1,248 @@
+use std::sync::{Arc, Mutex};
+use egui::{Color32, Context, Key};
+use k8s_openapi::api::admissionregistration::v1::{MutatingWebhookConfiguration, ValidatingWebhookConfiguration};
+use k8s_openapi::jiff::Timestamp;
+use crate::functions::item_color;
+use crate::{ui::YamlEditorWindow, WebhookKind};
+use crate::theme::*;
+
+pub struct WebhookConfigurationDetailsWindow {
+    pub show: bool,
+    pub kind: WebhookKind,
+}
+
+impl WebhookConfigurationDetailsWindow {
+    pub fn new(kind: WebhookKind) -> Self {
+        Self {
+            show: false,
+            kind,
+        }
+    }
+}
+
+/// "in 120 days" for the earliest caBundle expiry, colored like the secret certificates
+pub fn ca_expiry_text(expiry: Option<Timestamp>) -> (String, Color32) {
+    let Some(not_after) = expiry else {
+        return ("-".to_string(), SECOND_DETAIL_COLOR);
+    };
+    let now = Timestamp::now();
+    let days_left = (not_after.as_second() - now.as_second()) / 86400;
+    if not_after < now {
+        ("expired".to_string(), ERROR_COLOR)
+    } else if days_left < super::CERT_EXPIRY_WARNING_DAYS {
+        (format!("in {} days", days_left), WARNING_COLOR)
+    } else {
+        (format!("in {} days", days_left), GREEN_BUTTON)
+    }
+}
+
+fn refresh_webhook_details(client: &Arc<crate::Client>, details: &Arc<Mutex<crate::WebhookConfigurationDetails>>, kind: WebhookKind, name: String) {
+    let client = Arc::clone(client);
+    let details = Arc::clone(details);
+    tokio::spawn(async move {
+        let result = match kind {
+            WebhookKind::Mutating => crate::get_mutating_webhook_details(client, &name, details).await,
+            WebhookKind::Validating => crate::get_validating_webhook_details(client, &name, details).await,
+        };
+        if let Err(e) = result {
+            log::error!("{} details fetch failed: {:?}", kind.kind(), e);
+        }
+    });
+}
+
+pub fn show_webhook_configuration_details_window(
+        ctx: &Context,
+        webhook_details_window: &mut WebhookConfigurationDetailsWindow,
+        details: Arc<Mutex<crate::WebhookConfigurationDetails>>,
+        configs: Arc<Mutex<Vec<crate::WebhookConfigurationItem>>>,
+        yaml_editor_window: Arc<Mutex<YamlEditorWindow>>,
+        client: Arc<crate::Client>,
+        delete_confirm: &mut super::DeleteConfirmation,
+) {
+    let kind = webhook_details_window.kind;
+    let title = format!("{} details", kind.kind());
+    let guard_details = details.lock().unwrap(); // More detailed info
+    let guard_configs = configs.lock().unwrap(); // Configurations with base details already we have
+    if guard_details.name.is_none() {
+        return;
+    }
+    if let Some(error) = &guard_details.error {
+        let name = guard_details.name.clone().unwrap_or_default();
+        if crate::show_details_error_window(ctx, &title, &mut webhook_details_window.show, &name, error) {
+            refresh_webhook_details(&client, &details, kind, name);
+        }
+        return;
+    }
+    let Some(item) = guard_configs.iter().find(|item| Some(&item.name) == guard_details.name.as_ref()) else {
+        return;
+    };
+    let cur_name = item.name.clone();
+
+    let response = egui::Window::new(&title).min_width(800.0).collapsible(false).resizable(true).open(&mut webhook_details_window.show).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            if ui.button(egui::RichText::new("✏ Edit").size(16.0).color(crate::GREEN_BUTTON)).clicked() {
+                match kind {
+                    WebhookKind::Mutating => crate::edit_cluster_yaml_for::<MutatingWebhookConfiguration>(cur_name.clone(), Arc::clone(&yaml_editor_window), Arc::clone(&client)),
+                    WebhookKind::Validating => crate::edit_cluster_yaml_for::<ValidatingWebhookConfiguration>(cur_name.clone(), Arc::clone(&yaml_editor_window), Arc::clone(&client)),
+                }
+            }
+
+            if ui.button(egui::RichText::new("🗑 Delete").size(16.0).color(crate::RED_BUTTON)).clicked() {
+                let name = cur_name.clone();
+                let client = Arc::clone(&client);
+
+                delete_confirm.request(name.clone(), None, move || {
+                    tokio::spawn(async move {
+                        let result = match kind {
+                            WebhookKind::Mutating => crate::delete_cluster_component_for::<MutatingWebhookConfiguration>(name.clone(), client).await,
+                            WebhookKind::Validating => crate::delete_cluster_component_for::<ValidatingWebhookConfiguration>(name.clone(), client).await,
+                        };
+                        if let Err(err) = result {
+                            log::error!("Failed to delete {}: {}", kind.kind(), err);
+                        }
+                    });
+                });
+            }
+
+            if ui.button(egui::RichText::new("⟳ Recheck backends").size(16.0).color(crate::BLUE_BUTTON)).clicked() {
+                refresh_webhook_details(&client, &details, kind, cur_name.clone());
+            }
+        });
+        ui.separator();
+        egui::ScrollArea::vertical().auto_shrink(false).max_height(600.0).show(ui, |ui| {
+            egui::Grid::new("webhook_details_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+
+                ui.label(egui::RichText::new("Name:").color(ROW_NAME_COLOR));
+                ui.label(egui::RichText::new(&cur_name).color(DETAIL_COLOR));
+                ui.end_row();
+
+                if let Some(creation_timestamp) = &item.creation_timestamp {
+                    ui.label(egui::RichText::new("Creation time:").color(ROW_NAME_COLOR));
+                    let timestamp_text = format!("{}, {} ago", creation_timestamp.0, crate::format_age(creation_timestamp));
+                    ui.label(egui::RichText::new(timestamp_text).color(DETAIL_COLOR));
+                    ui.end_row();
+                }
+
+                if let Some(labels) = guard_details.labels.clone() {
+                    ui.label(egui::RichText::new("Labels:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("webhook_details_labels_grid").striped(true).min_col_width(20.0).show(ui, |ui| {
+                        for (j, y) in labels.iter() {
+                            ui.label(egui::RichText::new(j).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(y).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+
+                if let Some(annotations) = guard_details.annotations.clone() {
+                    ui.label(egui::RichText::new("Annotations:").color(ROW_NAME_COLOR));
+                    egui::Grid::new("webhook_details_annotations_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                        for (j, y) in annotations.iter() {
+                            ui.label(egui::RichText::new(j).color(DETAIL_COLOR));
+                            ui.label(egui::RichText::new(y).color(SECOND_DETAIL_COLOR));
+                            ui.end_row();
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            for (i, webhook) in item.webhooks.iter().enumerate() {
+                ui.separator();
+                ui.heading(egui::RichText::new(format!("Webhook {}", webhook.name)).color(ROW_NAME_COLOR));
+                egui::Grid::new(format!("webhook_details_hook_{}", i)).striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    ui.label(egui::RichText::new("Failure policy:").color(ROW_NAME_COLOR));
+                    let policy_color = if webhook.failure_policy == "Fail" { WARNING_COLOR } else { DETAIL_COLOR };
+                    ui.label(egui::RichText::new(&webhook.failure_policy).color(policy_color));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Target:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(webhook.target.as_ref().map(|t| t.to_string()).unwrap_or("-".to_string())).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    if let Some(backend) = guard_details.backends.iter().find(|b| b.webhook == webhook.name) {
+                        ui.label(egui::RichText::new("Backend:").color(ROW_NAME_COLOR));
+                        let (text, color) = if let Some(error) = &backend.error {
+                            (format!("Cannot check: {}", error), WARNING_COLOR)
+                        } else if !backend.service_found {
+                            ("Service not found".to_string(), ERROR_MESSAGE_COLOR)
+                        } else if !backend.port_found {
+                            ("Service has no such port".to_string(), ERROR_MESSAGE_COLOR)
+                        } else if backend.ready == 0 {
+                            (format!("No ready endpoints ({} not ready)", backend.not_ready), ERROR_MESSAGE_COLOR)
+                        } else {
+                            (format!("{} ready, {} not ready endpoints", backend.ready, backend.not_ready), GREEN_BUTTON)
+                        };
+                        ui.label(egui::RichText::new(text).color(color));
+                        ui.end_row();
+
+                        let unreachable = !backend.service_found || !backend.port_found || backend.ready == 0;
+                        if unreachable && backend.error.is_none() && webhook.failure_policy == "Fail" {
+                            ui.label(egui::RichText::new("⚠").color(ERROR_MESSAGE_COLOR));
+                            ui.label(egui::RichText::new("failurePolicy is Fail, every request matching the rules below is rejected").color(ERROR_MESSAGE_COLOR));
+                            ui.end_row();
+                        }
+                    }
+
+                    ui.label(egui::RichText::new("Rules:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(if webhook.rules.is_empty() { "-".to_string() } else { webhook.rules.join("\n") }).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Namespace selector:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(&webhook.namespace_selector).color(SECOND_DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Object selector:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(&webhook.object_selector).color(SECOND_DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Timeout:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(format!("{}s", webhook.timeout_seconds)).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Side effects:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(&webhook.side_effects).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Match policy:").color(ROW_NAME_COLOR));
+                    ui.label(egui::RichText::new(&webhook.match_policy).color(DETAIL_COLOR));
+                    ui.end_row();
+
+                    if let Some(reinvocation_policy) = &webhook.reinvocation_policy {
+                        ui.label(egui::RichText::new("Reinvocation:").color(ROW_NAME_COLOR));
+                        ui.label(egui::RichText::new(reinvocation_policy).color(DETAIL_COLOR));
+                        ui.end_row();
+                    }
+
+                    ui.label(egui::RichText::new("CA bundle:").color(ROW_NAME_COLOR));
+                    if webhook.ca_certificates.is_empty() {
+                        ui.label(egui::RichText::new("None, the system trust roots are used").color(SECOND_DETAIL_COLOR));
+                    } else {
+                        ui.vertical(|ui| super::show_certificates(ui, &format!("webhook_{}", i), &webhook.ca_certificates));
+                    }
+                    ui.end_row();
+                });
+            }
+
+            ui.separator();
+            ui.heading(egui::RichText::new("Events:").color(ROW_NAME_COLOR));
+            if !guard_details.events.is_empty() {
+                egui::Grid::new("webhook_details_events_grid").striped(true).min_col_width(20.0).max_col_width(600.0).show(ui, |ui| {
+                    for event in &guard_details.events {
+                        ui.label(egui::RichText::new(event.timestamp.clone().unwrap_or_default()).color(DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.reason.clone().unwrap_or_default()).color(SECOND_DETAIL_COLOR));
+                        ui.label(egui::RichText::new(event.message.clone().unwrap_or_default()).color(item_color(&event.event_type.clone().unwrap_or_default())));
+                        ui.end_row();
+                    }
+                });
+            }
+        });
+    });
+    crate::show_delete_confirmation(ctx, delete_confirm);
+
+    if let Some(inner_response) = response
+        && inner_response.response.contains_pointer() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+            webhook_details_window.show = false;
+        }
+}
@@ -1,8 +1,32 @@
-use egui::{text::LayoutJob, Color32, Context, FontId, Key, TextFormat, TextStyle, Ui};
+use egui::{text::LayoutJob, Color32, Context, FontId, Key, Modifiers, TextFormat, TextStyle, Ui};
 use kube::Client;
 use std::{sync::{Arc, Mutex}, time::{Duration, Instant}};
 use regex::RegexBuilder;
-use crate::ui::DecoderWindow;
+use crate::ui::{CompletionItem, DecoderWindow, OpenApiCache};
+
+const LIVE_VALIDATION_DELAY: Duration = Duration::from_millis(500);
+const MAX_COMPLETION_ITEMS: usize = 12;
+
+pub struct CompletionPopup {
+    pub path: Vec<String>,
+    pub items: Vec<CompletionItem>,
+    pub prefix: String,
+    pub indent: usize,
+    pub cursor: usize,
+    pub selected: usize,
+    pub pos: egui::Pos2,
+}
+
+impl CompletionPopup {
+    fn filtered(&self) -> Vec<CompletionItem> {
+        let prefix = self.prefix.to_lowercase();
+        self.items.iter()
+            .filter(|item| item.key.to_lowercase().starts_with(&prefix) && item.key != self.prefix)
+            .take(MAX_COMPLETION_ITEMS)
+            .cloned()
+            .collect()
+    }
+}
 
 pub struct YamlEditorWindow {
     pub content: String,
@@ -13,6 +37,12 @@ pub struct YamlEditorWindow {
     pub scroll_to_match: bool,
     pub status_message: Arc<Mutex<Option<(String, Instant)>>>,
     pub apply_flag: Arc<Mutex<bool>>,
+    pub validation_errors: Arc<Mutex<Vec<crate::SchemaError>>>,
+    pub openapi: Arc<Mutex<OpenApiCache>>,
+    pub validated_content: String,
+    pub validated_generation: u64,
+    pub last_edit: Instant,
+    pub completion: Option<CompletionPopup>,
 }
 
 impl YamlEditorWindow {
@@ -26,6 +56,12 @@ impl YamlEditorWindow {
             scroll_to_match: false,
             status_message: Arc::new(Mutex::new(None)),
             apply_flag: Arc::new(Mutex::new(true)),
+            validation_errors: Arc::new(Mutex::new(Vec::new())),
+            openapi: Arc::new(Mutex::new(OpenApiCache::default())),
+            validated_content: String::new(),
+            validated_generation: 0,
+            last_edit: Instant::now(),
+            completion: None,
         }
     }
 }
@@ -70,6 +106,116 @@ fn show_status_banner(ctx: &egui::Context, editor: &mut YamlEditorWindow) {
     }
 }
 
+/// Root schema of the edited kind, requests the group-version document on first use
+fn resource_schema(editor: &YamlEditorWindow, client: &Arc<Client>) -> Option<(Arc<std::collections::HashMap<String, serde_json::Value>>, serde_json::Value)> {
+    let (api_version, kind) = crate::yaml_type_meta(&editor.content)?;
+    let gv_path = crate::openapi_gv_path(&api_version);
+
+    let mut cache = editor.openapi.lock().unwrap();
+    let Some(schemas) = cache.schemas.get(&gv_path).cloned() else {
+        if !cache.pending.contains(&gv_path) && !cache.failed.contains(&gv_path) {
+            cache.pending.insert(gv_path.clone());
+            tokio::spawn(crate::load_openapi_schemas(Arc::clone(client), Arc::clone(&editor.openapi), gv_path));
+        }
+        return None;
+    };
+    drop(cache);
+
+    let (group, version) = api_version.split_once('/').unwrap_or(("", api_version.as_str()));
+    let root = crate::find_kind_schema(&schemas, group, version, &kind)?.clone();
+    Some((schemas, root))
+}
+
+fn live_validate(ctx: &Context, editor: &mut YamlEditorWindow, client: &Arc<Client>) {
+    let generation = editor.openapi.lock().unwrap().generation;
+    if editor.content == editor.validated_content && generation == editor.validated_generation {
+        return;
+    }
+    if editor.last_edit.elapsed() < LIVE_VALIDATION_DELAY {
+        ctx.request_repaint_after(LIVE_VALIDATION_DELAY);
+        return;
+    }
+    editor.validated_content = editor.content.clone();
+    editor.validated_generation = generation;
+
+    let Ok(value) = yaml_serde::from_str::<serde_json::Value>(&editor.content) else {
+        // Syntax errors are reported on apply
+        editor.validation_errors.lock().unwrap().clear();
+        return;
+    };
+    let Some((schemas, root)) = resource_schema(editor, client) else {
+        // Unknown kind or schema not loaded yet, old errors would point at another document
+        editor.validation_errors.lock().unwrap().clear();
+        return;
+    };
+    let errors = crate::validate_resource(&value, &root, &|reference| crate::resolve_schema_ref(&schemas, reference));
+    *editor.validation_errors.lock().unwrap() = errors;
+}
+
+fn char_to_byte(text: &str, char_idx: usize) -> usize {
+    text.char_indices().nth(char_idx).map(|(b, _)| b).unwrap_or(text.len())
+}
+
+fn update_completion(editor: &mut YamlEditorWindow, client: &Arc<Client>, cursor: usize, open: bool) {
+    if !open && editor.completion.is_none() {
+        return;
+    }
+    let Some(context) = crate::yaml_cursor_context(&editor.content, char_to_byte(&editor.content, cursor)) else {
+        editor.completion = None;
+        return;
+    };
+
+    let reuse = editor.completion.as_ref().is_some_and(|p| p.path == context.path);
+    if !reuse {
+        let Some((schemas, root)) = resource_schema(editor, client) else {
+            editor.completion = None;
+            return;
+        };
+        let items = crate::schema_at_path(&schemas, &root, &context.path)
+            .map(|schema| crate::completion_items(&schemas, &schema))
+            .unwrap_or_default();
+        editor.completion = Some(CompletionPopup { path: context.path, items, prefix: String::new(), indent: 0, cursor: 0, selected: 0, pos: egui::Pos2::ZERO });
+    }
+
+    if let Some(popup) = &mut editor.completion {
+        popup.prefix = context.prefix;
+        popup.indent = context.indent;
+        popup.cursor = cursor;
+        let count = popup.filtered().len();
+        if count == 0 {
+            editor.completion = None;
+        } else if popup.selected >= count {
+            popup.selected = 0;
+        }
+    }
+}
+
+fn accept_completion(ctx: &Context, editor: &mut YamlEditorWindow, text_id: egui::Id, item: &CompletionItem) {
+    let Some(popup) = editor.completion.take() else {
+        return;
+    };
+    let prefix_chars = popup.prefix.chars().count();
+    let start = char_to_byte(&editor.content, popup.cursor.saturating_sub(prefix_chars));
+    let end = char_to_byte(&editor.content, popup.cursor);
+
+    let insert = if item.type_name == "object" {
+        format!("{}:\n{}", item.key, " ".repeat(popup.indent + 2))
+    } else if item.type_name == "[]object" {
+        format!("{}:\n{}- ", item.key, " ".repeat(popup.indent + 2))
+    } else {
+        format!("{}: ", item.key)
+    };
+    editor.content.replace_range(start..end, &insert);
+    editor.last_edit = Instant::now();
+
+    let new_cursor = popup.cursor - prefix_chars + insert.chars().count();
+    if let Some(mut state) = egui::TextEdit::load_state(ctx, text_id) {
+        state.cursor.set_char_range(Some(egui::text::CCursorRange::one(egui::text::CCursor::new(new_cursor))));
+        state.store(ctx, text_id);
+    }
+    ctx.memory_mut(|m| m.request_focus(text_id));
+}
+
 pub fn show_yaml_editor(ctx: &Context, editor: &mut YamlEditorWindow, decoder: &mut DecoderWindow, client: Arc<Client>) {
     let response = egui::Window::new("Edit resource").max_width(1200.0).max_height(600.0).default_width(800.0).default_height(600.0).collapsible(false).resizable(true).show(ctx, |ui| {
         let matches: Vec<(usize, usize)> = if !editor.search_query.is_empty() {
@@ -140,24 +286,120 @@ pub fn show_yaml_editor(ctx: &Context, editor: &mut YamlEditorWindow, decoder: &
 
         let mut layouter = make_yaml_layouter(editor.search_query.clone());
 
+        // Keys steering the completion popup must be taken before the text edit sees them
+        let text_id = egui::Id::new("yaml_editor_text");
+        let mut accepted: Option<CompletionItem> = None;
+        if let Some(popup) = &mut editor.completion {
+            let filtered = popup.filtered();
+            ctx.input_mut(|i| {
+                if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                    popup.selected = (popup.selected + 1) % filtered.len().max(1);
+                }
+                if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                    popup.selected = popup.selected.checked_sub(1).unwrap_or(filtered.len().saturating_sub(1));
+                }
+                if i.consume_key(Modifiers::NONE, Key::Enter) || i.consume_key(Modifiers::NONE, Key::Tab) {
+                    accepted = filtered.get(popup.selected).cloned();
+                }
+            });
+            if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+                editor.completion = None;
+            }
+        }
+        if let Some(item) = accepted.take() {
+            accept_completion(ctx, editor, text_id, &item);
+        }
+        let open_completion = ctx.input_mut(|i| i.consume_key(Modifiers::CTRL, Key::Space));
+
         let mut scroll_area = egui::ScrollArea::vertical().hscroll(true);
         if let Some(offset) = scroll_offset {
             scroll_area = scroll_area.vertical_scroll_offset(offset);
         }
-        scroll_area.show(ui, |ui| {
-            ui.add(egui::TextEdit::multiline(&mut editor.content)
+        let output = scroll_area.show(ui, |ui| {
+            egui::TextEdit::multiline(&mut editor.content)
+                .id(text_id)
                 .font(TextStyle::Monospace)
                 .code_editor()
                 .desired_rows(20)
                 .desired_width(800.0)
-                .layouter(&mut layouter),
-            );
-        });
+                .layouter(&mut layouter)
+                .show(ui)
+        }).inner;
+
+        if output.response.changed() {
+            editor.last_edit = Instant::now();
+        }
+        if let Some(range) = output.cursor_range.filter(|_| output.response.has_focus()) {
+            let typing = output.response.changed();
+            update_completion(editor, &client, range.primary.index.0, open_completion || typing);
+            if let Some(popup) = &mut editor.completion {
+                let cursor_rect = output.galley.pos_from_cursor(range.primary);
+                popup.pos = output.galley_pos + cursor_rect.left_bottom().to_vec2();
+            }
+        }
+
+        if let Some(popup) = &editor.completion {
+            let area = egui::Area::new(egui::Id::new("yaml_completion_popup"))
+                .fixed_pos(popup.pos)
+                .order(egui::Order::Foreground)
+                .show(ctx, |ui| {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        for (idx, item) in popup.filtered().iter().enumerate() {
+                            let mut text = egui::RichText::new(format!("{}  {}", item.key, item.type_name)).monospace();
+                            if item.required {
+                                text = text.color(crate::WARNING_COLOR);
+                            }
+                            let mut resp = ui.selectable_label(idx == popup.selected, text);
+                            if let Some(desc) = &item.description {
+                                resp = resp.on_hover_text(desc);
+                            }
+                            if resp.clicked() {
+                                accepted = Some(item.clone());
+                            }
+                        }
+                    });
+                });
+            if accepted.is_none() && ctx.input(|i| i.pointer.any_click()) && !area.response.contains_pointer() {
+                editor.completion = None;
+            }
+        }
+        if let Some(item) = accepted {
+            accept_completion(ctx, editor, text_id, &item);
+        }
+
+        live_validate(ctx, editor, &client);
+
+        let validation_errors = editor.validation_errors.lock().unwrap().clone();
+        if !validation_errors.is_empty() {
+            ui.separator();
+            ui.label(egui::RichText::new(format!("⚠ Schema validation: {} problem(s), click to locate", validation_errors.len())).color(crate::WARNING_COLOR));
+            egui::ScrollArea::vertical().id_salt("yaml_validation_scroll").max_height(100.0).show(ui, |ui| {
+                for err in &validation_errors {
+                    let text = egui::RichText::new(format!("{}: {}", err.path, err.message)).color(crate::ERROR_MESSAGE_COLOR);
+                    if ui.add(egui::Label::new(text).sense(egui::Sense::click())).on_hover_cursor(egui::CursorIcon::PointingHand).clicked() {
+                        // Jump to the last key of the offending path
+                        let key = err.path.rsplit('.').next().unwrap_or_default();
+                        let key = key.split('[').next().unwrap_or_default();
+                        if !key.is_empty() {
+                            editor.search_query = format!("{}:", key);
+                            editor.search_index = 0;
+                            editor.scroll_to_match = true;
+                        }
+                    }
+                }
+            });
+        }
 
         ui.separator();
         ui.horizontal(|ui| {
             editor.apply_button_enabled = *editor.apply_flag.lock().unwrap();
-            if ui.add_enabled(editor.apply_button_enabled, egui::Button::new(egui::RichText::new("✅ Apply").size(16.0).color(egui::Color32::GREEN))).clicked() {
+            let apply = ui.add_enabled(editor.apply_button_enabled, egui::Button::new(egui::RichText::new("✅ Apply").size(16.0).color(egui::Color32::GREEN))).clicked();
+            // Client-side checks can be stricter than the API server (pruned fields, embedded objects)
+            let apply_anyway = !validation_errors.is_empty()
+                && ui.add_enabled(editor.apply_button_enabled, egui::Button::new(egui::RichText::new("⚠ Apply anyway").size(16.0).color(crate::WARNING_COLOR)))
+                    .on_hover_text("Skip the schema check, the API server still validates the object")
+                    .clicked();
+            if apply || apply_anyway {
                 let content = editor.content.clone();
                 editor.apply_button_enabled = false;
                 *editor.apply_flag.lock().unwrap() = false;
@@ -168,7 +410,22 @@ pub fn show_yaml_editor(ctx: &Context, editor: &mut YamlEditorWindow, decoder: &
                         let client_clone = Arc::clone(&client);
                         let msg_ptr = Arc::clone(&editor.status_message);
                         let apply_flag = Arc::clone(&editor.apply_flag);
+                        let validation_errors = Arc::clone(&editor.validation_errors);
+                        validation_errors.lock().unwrap().clear();
                         tokio::spawn(async move {
+                            let checked = if apply_anyway { Ok(vec![]) } else { crate::validate_cr_yaml(Arc::clone(&client_clone), content.as_str()).await };
+                            match checked {
+                                Ok(errors) if !errors.is_empty() => {
+                                    let first = &errors[0];
+                                    let msg = format!("⚠ Schema validation failed at {}: {}, use Apply anyway to send it", first.path, first.message);
+                                    *validation_errors.lock().unwrap() = errors;
+                                    *msg_ptr.lock().unwrap() = Some((msg, Instant::now()));
+                                    *apply_flag.lock().unwrap() = true;
+                                    return;
+                                }
+                                Ok(_) => {}
+                                Err(e) => log::warn!("Schema validation skipped: {}", e),
+                            }
                             let msg = match crate::patch_resource(client_clone, content.as_str()).await {
                                 Ok(_) => ("✅ Applied successfully".to_string(), Instant::now()),
                                 Err(e) => (format!("❌ Error applying YAML: {e}"), Instant::now()),
@@ -185,6 +442,8 @@ pub fn show_yaml_editor(ctx: &Context, editor: &mut YamlEditorWindow, decoder: &
             }
             if ui.button(egui::RichText::new("🗙 Close editor").size(16.0).color(egui::Color32::WHITE)).clicked() {
                 editor.show = false;
+                editor.completion = None;
+                editor.validation_errors.lock().unwrap().clear();
             }
         });
     });
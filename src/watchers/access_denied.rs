@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+use std::sync::{atomic::{AtomicBool, Ordering}, Mutex};
+use std::time::Duration;
+use kube::runtime::watcher;
+
+/// Lists the current identity may not read, with the API server message
+static DENIED_WATCHERS: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+/// Denied lists are retried at this pace instead of in a tight loop
+const DENIED_RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+fn forbidden_message(error: &watcher::Error) -> Option<String> {
+    let (code, message) = match error {
+        watcher::Error::InitialListFailed(e) | watcher::Error::WatchStartFailed(e) | watcher::Error::WatchFailed(e) => match e {
+            kube::Error::Api(status) => (status.code, status.message.clone()),
+            _ => return None,
+        },
+        watcher::Error::WatchError(status) => (status.code, status.message.clone()),
+        _ => return None,
+    };
+    matches!(code, 401 | 403).then_some(message)
+}
+
+/// Logs a watcher error. Forbidden lists stop loading and are reported through `watch_denied`.
+pub async fn record_watch_error(kind: &str, error: &watcher::Error, load_status: &AtomicBool) {
+    match forbidden_message(error) {
+        Some(message) => {
+            let first = DENIED_WATCHERS.lock().unwrap().insert(kind.to_string(), message).is_none();
+            if first {
+                log::warn!("{} watch denied: {:?}", kind, error);
+            }
+            load_status.store(false, Ordering::Relaxed);
+            tokio::time::sleep(DENIED_RETRY_INTERVAL).await;
+        }
+        None => log::error!("{} watch error: {:?}", kind, error),
+    }
+}
+
+/// Called once the list is readable again
+pub fn clear_watch_denied(kind: &str) {
+    DENIED_WATCHERS.lock().unwrap().remove(kind);
+}
+
+pub fn watch_denied(kind: &str) -> Option<String> {
+    DENIED_WATCHERS.lock().unwrap().get(kind).cloned()
+}
@@ -0,0 +1,120 @@
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
+use futures_util::StreamExt;
+use k8s_openapi::api::admissionregistration::v1::{MatchResources, ValidatingAdmissionPolicy};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use kube::{Client, Api, runtime::watcher, runtime::watcher::Event};
+
+#[derive(Debug, Clone)]
+pub struct PolicyValidation {
+    pub expression: String,
+    pub message: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidatingAdmissionPolicyItem {
+    pub name: String,
+    pub creation_timestamp: Option<Time>,
+    pub failure_policy: String,
+    pub param_kind: Option<String>,
+    pub match_rules: Vec<String>,
+    pub validations: Vec<PolicyValidation>,
+    /// Type checking warnings of the CEL expressions, an expression with a warning may never pass
+    pub type_warnings: Vec<(String, String)>,
+}
+
+/// Rules of a MatchResources, excluded ones prefixed with "not"
+pub fn format_match_resources(match_resources: Option<&MatchResources>) -> Vec<String> {
+    let Some(match_resources) = match_resources else {
+        return vec![];
+    };
+    let format = |prefix: &str, rules: Option<&Vec<k8s_openapi::api::admissionregistration::v1::NamedRuleWithOperations>>| -> Vec<String> {
+        rules.iter().flat_map(|rules| rules.iter()).map(|r| {
+            format!("{}{}", prefix, crate::format_admission_rule(r.operations.as_ref(), r.api_groups.as_ref(), r.resources.as_ref()))
+        }).collect()
+    };
+    let mut rules = format("", match_resources.resource_rules.as_ref());
+    rules.extend(format("not ", match_resources.exclude_resource_rules.as_ref()));
+    rules
+}
+
+pub fn convert_validating_admission_policy(policy: ValidatingAdmissionPolicy) -> Option<ValidatingAdmissionPolicyItem> {
+    let metadata = &policy.metadata;
+    let name = metadata.name.clone()?;
+    let spec = policy.spec.as_ref();
+
+    Some(ValidatingAdmissionPolicyItem {
+        name,
+        creation_timestamp: metadata.creation_timestamp.clone(),
+        failure_policy: spec.and_then(|s| s.failure_policy.clone()).unwrap_or("Fail".to_string()),
+        param_kind: spec.and_then(|s| s.param_kind.as_ref()).map(|p| {
+            format!("{}/{}", p.api_version.clone().unwrap_or_default(), p.kind.clone().unwrap_or_default())
+        }),
+        match_rules: format_match_resources(spec.and_then(|s| s.match_constraints.as_ref())),
+        validations: spec.and_then(|s| s.validations.as_ref()).map(|validations| validations.iter().map(|v| PolicyValidation {
+            expression: v.expression.clone(),
+            message: v.message.clone().or(v.message_expression.clone()),
+            reason: v.reason.clone(),
+        }).collect()).unwrap_or_default(),
+        type_warnings: policy.status.as_ref()
+            .and_then(|s| s.type_checking.as_ref())
+            .and_then(|t| t.expression_warnings.as_ref())
+            .map(|warnings| warnings.iter().map(|w| (w.field_ref.clone(), w.warning.clone())).collect())
+            .unwrap_or_default(),
+    })
+}
+
+pub async fn watch_validating_admission_policies(client: Arc<Client>, policy_list: Arc<Mutex<Vec<ValidatingAdmissionPolicyItem>>>, load_status: Arc<AtomicBool>) {
+    let api: Api<ValidatingAdmissionPolicy> = Api::all(client.as_ref().clone());
+
+    load_status.store(true, Ordering::Relaxed);
+
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(policy) => {
+                    if let Some(item) = convert_validating_admission_policy(policy) {
+                        initial.push(item);
+                    }
+                }
+                Event::InitDone => {
+                    let mut list_guard = policy_list.lock().unwrap();
+                    *list_guard = initial.clone();
+                    initialized = true;
+
+                    load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("ValidatingAdmissionPolicy");
+                }
+                Event::Apply(policy) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(item) = convert_validating_admission_policy(policy) {
+                        let mut list = policy_list.lock().unwrap();
+                        if let Some(existing) = list.iter_mut().find(|f| f.name == item.name) {
+                            *existing = item; // renew
+                        } else {
+                            list.push(item); // add new
+                        }
+                    }
+                }
+                Event::Delete(policy) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(name) = policy.metadata.name {
+                        let mut list = policy_list.lock().unwrap();
+                        list.retain(|item| item.name != name);
+                    }
+                }
+            },
+            Err(e) => crate::record_watch_error("ValidatingAdmissionPolicy", &e, &load_status).await,
+        }
+    }
+}
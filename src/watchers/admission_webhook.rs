@@ -0,0 +1,243 @@
+use std::fmt::Debug;
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
+use futures_util::StreamExt;
+use k8s_openapi::api::admissionregistration::v1::{MutatingWebhookConfiguration, RuleWithOperations, ValidatingWebhookConfiguration, WebhookClientConfig};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use kube::{Client, Api, Resource, runtime::watcher, runtime::watcher::Event};
+use serde::de::DeserializeOwned;
+use crate::{CertificateInfo, ServiceEndpoints, ServiceItem};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WebhookKind {
+    Mutating,
+    Validating,
+}
+
+impl WebhookKind {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WebhookKind::Mutating => "MutatingWebhookConfiguration",
+            WebhookKind::Validating => "ValidatingWebhookConfiguration",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookTarget {
+    Service {
+        namespace: String,
+        name: String,
+        port: i32,
+        path: Option<String>,
+    },
+    Url(String),
+}
+
+impl std::fmt::Display for WebhookTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookTarget::Service { namespace, name, port, path } => write!(f, "{}/{}:{}{}", namespace, name, port, path.as_deref().unwrap_or("")),
+            WebhookTarget::Url(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+/// Whether the API server can reach the webhook right now
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookBackend {
+    /// External URL, nothing to check in the cluster
+    Url,
+    MissingService,
+    NoReadyEndpoints,
+    Ready(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookInfo {
+    pub name: String,
+    pub failure_policy: String,
+    pub side_effects: String,
+    pub timeout_seconds: i32,
+    pub match_policy: String,
+    pub reinvocation_policy: Option<String>,
+    pub target: Option<WebhookTarget>,
+    pub ca_certificates: Vec<CertificateInfo>,
+    pub rules: Vec<String>,
+    pub namespace_selector: String,
+    pub object_selector: String,
+}
+
+impl WebhookInfo {
+    pub fn backend(&self, services: &[ServiceItem], service_endpoints: &[ServiceEndpoints]) -> WebhookBackend {
+        let Some(WebhookTarget::Service { namespace, name, .. }) = &self.target else {
+            return WebhookBackend::Url;
+        };
+        let same = |n: &str, ns: Option<&str>| n == name && ns == Some(namespace.as_str());
+        if !services.iter().any(|s| same(&s.name, s.namespace.as_deref())) {
+            return WebhookBackend::MissingService;
+        }
+        let ready = service_endpoints.iter()
+            .find(|e| same(&e.name, e.namespace.as_deref()))
+            .map(|e| e.ready())
+            .unwrap_or(0);
+        if ready == 0 { WebhookBackend::NoReadyEndpoints } else { WebhookBackend::Ready(ready) }
+    }
+
+    /// failurePolicy Fail with nothing to answer, every matching request is rejected
+    pub fn blocks_requests(&self, backend: &WebhookBackend) -> bool {
+        self.failure_policy == "Fail" && matches!(backend, WebhookBackend::MissingService | WebhookBackend::NoReadyEndpoints)
+    }
+
+    /// Earliest expiry of the caBundle certificates
+    pub fn ca_expiry(&self) -> Option<k8s_openapi::jiff::Timestamp> {
+        self.ca_certificates.iter().filter_map(|c| c.not_after).min()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookConfigurationItem {
+    pub name: String,
+    pub creation_timestamp: Option<Time>,
+    pub webhooks: Vec<WebhookInfo>,
+}
+
+impl WebhookConfigurationItem {
+    pub fn ca_expiry(&self) -> Option<k8s_openapi::jiff::Timestamp> {
+        self.webhooks.iter().filter_map(|w| w.ca_expiry()).min()
+    }
+}
+
+/// "CREATE,UPDATE apps/deployments" like kubectl describe, empty group is the core group
+pub fn format_admission_rule(operations: Option<&Vec<String>>, api_groups: Option<&Vec<String>>, resources: Option<&Vec<String>>) -> String {
+    let join = |values: Option<&Vec<String>>| values.filter(|v| !v.is_empty()).map(|v| v.join(",")).unwrap_or("*".to_string());
+    let groups = api_groups.map(|g| g.iter().map(|g| if g.is_empty() { "core" } else { g.as_str() }).collect::<Vec<_>>().join(","));
+    format!("{} {}/{}", join(operations), groups.unwrap_or("*".to_string()), join(resources))
+}
+
+fn format_rules(rules: Option<&Vec<RuleWithOperations>>) -> Vec<String> {
+    rules.map(|rules| rules.iter().map(|r| format_admission_rule(r.operations.as_ref(), r.api_groups.as_ref(), r.resources.as_ref())).collect()).unwrap_or_default()
+}
+
+fn webhook_target(config: &WebhookClientConfig) -> Option<WebhookTarget> {
+    if let Some(service) = &config.service {
+        Some(WebhookTarget::Service {
+            namespace: service.namespace.clone(),
+            name: service.name.clone(),
+            port: service.port.unwrap_or(443),
+            path: service.path.clone(),
+        })
+    } else {
+        config.url.clone().map(WebhookTarget::Url)
+    }
+}
+
+/// MutatingWebhook and ValidatingWebhook are separate types with the same fields
+macro_rules! webhook_info {
+    ($webhook:expr, $reinvocation_policy:expr) => {{
+        let webhook = $webhook;
+        let selector = |s: Option<&k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector>| {
+            s.map(crate::format_label_selector).filter(|s| !s.is_empty()).unwrap_or("(all)".to_string())
+        };
+        WebhookInfo {
+            name: webhook.name.clone(),
+            failure_policy: webhook.failure_policy.clone().unwrap_or("Fail".to_string()),
+            side_effects: webhook.side_effects.clone(),
+            timeout_seconds: webhook.timeout_seconds.unwrap_or(10),
+            match_policy: webhook.match_policy.clone().unwrap_or("Equivalent".to_string()),
+            reinvocation_policy: $reinvocation_policy,
+            target: webhook_target(&webhook.client_config),
+            ca_certificates: webhook.client_config.ca_bundle.as_ref().map(|ca| crate::parse_pem_certificates(&ca.0)).unwrap_or_default(),
+            rules: format_rules(webhook.rules.as_ref()),
+            namespace_selector: selector(webhook.namespace_selector.as_ref()),
+            object_selector: selector(webhook.object_selector.as_ref()),
+        }
+    }};
+}
+
+pub fn convert_mutating_webhook_configuration(config: MutatingWebhookConfiguration) -> Option<WebhookConfigurationItem> {
+    Some(WebhookConfigurationItem {
+        name: config.metadata.name.clone()?,
+        creation_timestamp: config.metadata.creation_timestamp.clone(),
+        webhooks: config.webhooks.iter().flatten().map(|w| webhook_info!(w, Some(w.reinvocation_policy.clone().unwrap_or("Never".to_string())))).collect(),
+    })
+}
+
+pub fn convert_validating_webhook_configuration(config: ValidatingWebhookConfiguration) -> Option<WebhookConfigurationItem> {
+    Some(WebhookConfigurationItem {
+        name: config.metadata.name.clone()?,
+        creation_timestamp: config.metadata.creation_timestamp.clone(),
+        webhooks: config.webhooks.iter().flatten().map(|w| webhook_info!(w, None)).collect(),
+    })
+}
+
+async fn watch_webhook_configurations<K>(
+        client: Arc<Client>,
+        config_list: Arc<Mutex<Vec<WebhookConfigurationItem>>>,
+        load_status: Arc<AtomicBool>,
+        kind: WebhookKind,
+        convert: fn(K) -> Option<WebhookConfigurationItem>,
+)
+where
+    K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
+{
+    let api: Api<K> = Api::all(client.as_ref().clone());
+
+    load_status.store(true, Ordering::Relaxed);
+
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(config) => {
+                    if let Some(item) = convert(config) {
+                        initial.push(item);
+                    }
+                }
+                Event::InitDone => {
+                    let mut list_guard = config_list.lock().unwrap();
+                    *list_guard = initial.clone();
+                    initialized = true;
+
+                    load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied(kind.kind());
+                }
+                Event::Apply(config) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(item) = convert(config) {
+                        let mut list = config_list.lock().unwrap();
+                        if let Some(existing) = list.iter_mut().find(|f| f.name == item.name) {
+                            *existing = item; // renew
+                        } else {
+                            list.push(item); // add new
+                        }
+                    }
+                }
+                Event::Delete(config) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(name) = config.meta().name.clone() {
+                        let mut list = config_list.lock().unwrap();
+                        list.retain(|item| item.name != name);
+                    }
+                }
+            },
+            Err(e) => crate::record_watch_error(kind.kind(), &e, &load_status).await,
+        }
+    }
+}
+
+pub async fn watch_mutating_webhook_configurations(client: Arc<Client>, config_list: Arc<Mutex<Vec<WebhookConfigurationItem>>>, load_status: Arc<AtomicBool>) {
+    watch_webhook_configurations::<MutatingWebhookConfiguration>(client, config_list, load_status, WebhookKind::Mutating, convert_mutating_webhook_configuration).await
+}
+
+pub async fn watch_validating_webhook_configurations(client: Arc<Client>, config_list: Arc<Mutex<Vec<WebhookConfigurationItem>>>, load_status: Arc<AtomicBool>) {
+    watch_webhook_configurations::<ValidatingWebhookConfiguration>(client, config_list, load_status, WebhookKind::Validating, convert_validating_webhook_configuration).await
+}
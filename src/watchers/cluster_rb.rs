@@ -1,6 +1,6 @@
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
-use k8s_openapi::{api::{rbac::v1::ClusterRoleBinding}, apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::{api::{rbac::v1::{ClusterRoleBinding, RoleRef, Subject}}, apimachinery::pkg::apis::meta::v1::Time};
 use kube::Client;
 use kube::{Api, runtime::watcher};
 use kube::runtime::reflector::Lookup;
@@ -10,6 +10,8 @@ pub struct ClusterRoleBindingItem {
     pub name: String,
     pub creation_timestamp: Option<Time>,
     pub namespace: Option<String>,
+    pub role_ref: RoleRef,
+    pub subjects: Vec<Subject>,
 }
 
 fn convert_cluster_rb(cluster_rb: ClusterRoleBinding) -> Option<ClusterRoleBindingItem> {
@@ -19,6 +21,8 @@ fn convert_cluster_rb(cluster_rb: ClusterRoleBinding) -> Option<ClusterRoleBindi
         name,
         creation_timestamp: cluster_rb.metadata.creation_timestamp,
         namespace,
+        role_ref: cluster_rb.role_ref,
+        subjects: cluster_rb.subjects.unwrap_or_default(),
     })
 }
 
@@ -48,6 +52,7 @@ pub async fn watch_cluster_rbs(client: Arc<Client>, cluster_rbs_list: Arc<Mutex<
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("ClusterRoleBinding");
                 }
                 watcher::Event::Apply(cluster_rb) => {
                     if !initialized {
@@ -69,7 +74,7 @@ pub async fn watch_cluster_rbs(client: Arc<Client>, cluster_rbs_list: Arc<Mutex<
                     }
                 }
             },
-            Err(e) => log::error!("ClusterRoleBinding watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("ClusterRoleBinding", &e, &load_status).await,
         }
     }
 }
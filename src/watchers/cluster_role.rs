@@ -1,6 +1,7 @@
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
-use k8s_openapi::{api::{rbac::v1::ClusterRole}, apimachinery::pkg::apis::meta::v1::Time};
+use std::collections::BTreeMap;
+use k8s_openapi::{api::{rbac::v1::{ClusterRole, PolicyRule}}, apimachinery::pkg::apis::meta::v1::{LabelSelector, Time}};
 use kube::Client;
 use kube::{Api, runtime::watcher};
 use kube::runtime::reflector::Lookup;
@@ -10,6 +11,10 @@ pub struct ClusterRoleItem {
     pub name: String,
     pub creation_timestamp: Option<Time>,
     pub namespace: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    pub rules: Vec<PolicyRule>,
+    /// Selectors of aggregationRule, empty for plain ClusterRoles
+    pub aggregation_selectors: Vec<LabelSelector>,
 }
 
 fn convert_cluster_role(cluster_role: ClusterRole) -> Option<ClusterRoleItem> {
@@ -19,6 +24,9 @@ fn convert_cluster_role(cluster_role: ClusterRole) -> Option<ClusterRoleItem> {
         name,
         creation_timestamp: cluster_role.metadata.creation_timestamp,
         namespace,
+        labels: cluster_role.metadata.labels.unwrap_or_default(),
+        rules: cluster_role.rules.unwrap_or_default(),
+        aggregation_selectors: cluster_role.aggregation_rule.and_then(|a| a.cluster_role_selectors).unwrap_or_default(),
     })
 }
 
@@ -48,6 +56,7 @@ pub async fn watch_cluster_roles(client: Arc<Client>, cluster_roles_list: Arc<Mu
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("ClusterRole");
                 }
                 watcher::Event::Apply(cluster_role) => {
                     if !initialized {
@@ -69,7 +78,7 @@ pub async fn watch_cluster_roles(client: Arc<Client>, cluster_roles_list: Arc<Mu
                     }
                 }
             },
-            Err(e) => log::error!("ClusterRole watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("ClusterRole", &e, &load_status).await,
         }
     }
 }
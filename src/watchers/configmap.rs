@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::{sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex}};
 use futures_util::StreamExt;
 use k8s_openapi::{api::{core::v1::ConfigMap}, apimachinery::pkg::apis::meta::v1::Time};
@@ -7,7 +8,7 @@ use kube::{Api, runtime::watcher};
 #[derive(Debug, Clone)]
 pub struct ConfigMapItem {
     pub name: String,
-    //pub labels: BTreeMap<String, String>,
+    pub labels: BTreeMap<String, String>,
     pub keys: Vec<String>,
     pub type_: String,
     pub creation_timestamp: Option<Time>,
@@ -16,8 +17,8 @@ pub struct ConfigMapItem {
 
 pub fn convert_configmap(cm: ConfigMap) -> Option<ConfigMapItem> {
     Some(ConfigMapItem {
+        labels: cm.metadata.labels.clone().unwrap_or_default(),
         name: cm.metadata.name.clone()?,
-        //labels: cm.metadata.labels.unwrap_or_default(),
         keys: cm.data.as_ref().map(|d| d.keys().cloned().collect()).unwrap_or_default(),
         type_: "Opaque".to_string(),
         creation_timestamp: cm.metadata.creation_timestamp,
@@ -51,6 +52,7 @@ pub async fn watch_configmaps(client: Arc<Client>, configmaps_list: Arc<Mutex<Ve
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("ConfigMap");
                 }
                 watcher::Event::Apply(cm) => {
                     if !initialized {
@@ -75,7 +77,7 @@ pub async fn watch_configmaps(client: Arc<Client>, configmaps_list: Arc<Mutex<Ve
                     }
                 }
             },
-            Err(e) => log::error!("ConfigMap watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("ConfigMap", &e, &load_status).await,
         }
     }
 }
@@ -0,0 +1,93 @@
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind};
+use kube::{Client, Api, runtime::watcher, runtime::watcher::Event};
+use kube::ResourceExt;
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
+use futures_util::StreamExt;
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CrdInstance {
+    pub name: String,
+    pub kind: String,
+    pub namespace: Option<String>,
+    pub data: Value,
+    pub group: String,
+    pub version: String,
+    pub plural: String,
+    pub scope: String,
+}
+
+fn convert_cr_instance(obj: &DynamicObject, crd: &crate::CRDItem) -> CrdInstance {
+    let mut data = serde_json::to_value(&obj.data).unwrap_or(Value::Null);
+
+    if let Value::Object(ref mut map) = data {
+        map.insert("metadata".into(), serde_json::to_value(&obj.metadata).unwrap_or(Value::Null));
+    }
+
+    CrdInstance {
+        name: obj.name_any(),
+        kind: crd.kind.clone(),
+        namespace: obj.namespace(),
+        data,
+        group: crd.group.clone(),
+        version: crd.version.clone(),
+        plural: crd.plural.clone(),
+        scope: crd.scope.clone(),
+    }
+}
+
+pub async fn watch_cr_instances(client: Arc<Client>, list: Arc<Mutex<Vec<CrdInstance>>>, load_status: Arc<AtomicBool>, crd: crate::CRDItem) {
+    let ar = ApiResource::from_gvk_with_plural(&GroupVersionKind::gvk(&crd.group, &crd.version, &crd.kind), &crd.plural);
+    let api: Api<DynamicObject> = Api::all_with(client.as_ref().clone(), &ar);
+
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    load_status.store(true, Ordering::Relaxed);
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(obj) => {
+                    initial.push(convert_cr_instance(&obj, &crd));
+                }
+                Event::InitDone => {
+                    let mut list_guard = list.lock().unwrap();
+                    *list_guard = initial.clone();
+                    initialized = true;
+
+                    load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied(&crd.kind);
+                }
+                Event::Apply(obj) => {
+                    if !initialized {
+                        continue;
+                    }
+                    let item = convert_cr_instance(&obj, &crd);
+                    let mut list_guard = list.lock().unwrap();
+                    if let Some(existing) = list_guard.iter_mut().find(|f| f.name == item.name && f.namespace == item.namespace) {
+                        *existing = item; // renew
+                    } else {
+                        list_guard.push(item); // add new
+                    }
+                }
+                Event::Delete(obj) => {
+                    if !initialized {
+                        continue;
+                    }
+                    let name = obj.name_any();
+                    let namespace = obj.namespace();
+                    let mut list_guard = list.lock().unwrap();
+                    list_guard.retain(|n| !(n.name == name && n.namespace == namespace));
+                }
+            },
+            Err(e) => {
+                crate::record_watch_error(&crd.kind, &e, &load_status).await;
+                load_status.store(false, Ordering::Relaxed);
+            }
+        }
+    }
+}
@@ -80,6 +80,7 @@ pub async fn watch_crds(client: Arc<Client>, list: Arc<Mutex<Vec<CRDItem>>>, loa
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("CRDs");
                 }
                 Event::Apply(obj) => {
                     if !initialized {
@@ -101,7 +102,7 @@ pub async fn watch_crds(client: Arc<Client>, list: Arc<Mutex<Vec<CRDItem>>>, loa
                     }
                 }
             },
-            Err(e) => log::error!("CRDs watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("CRDs", &e, &load_status).await,
         }
     }
 }
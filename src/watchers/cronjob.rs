@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::batch::v1::CronJob, apimachinery::pkg::apis::meta::v1::Time};
@@ -7,6 +8,7 @@ use kube::{Api, runtime::watcher, runtime::watcher::Event};
 #[derive(Debug, Clone)]
 pub struct CronJobItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub schedule: String,
     pub suspend: String,
     pub active: usize,
@@ -36,6 +38,7 @@ pub fn convert_cronjob(cj: CronJob) -> Option<CronJobItem> {
         .unwrap_or_else(|| "-".to_string());
 
     Some(CronJobItem {
+        labels: cj.metadata.labels.clone().unwrap_or_default(),
         name,
         schedule,
         suspend: if suspend { "true".into() } else { "false".into() },
@@ -72,6 +75,7 @@ pub async fn watch_cronjobs(client: Arc<Client>, cronjob_list: Arc<Mutex<Vec<Cro
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("CronJob");
                 }
                 Event::Apply(cronjob) => {
                     if !initialized {
@@ -96,7 +100,7 @@ pub async fn watch_cronjobs(client: Arc<Client>, cronjob_list: Arc<Mutex<Vec<Cro
                     }
                 }
             },
-            Err(e) => log::error!("CronJob watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("CronJob", &e, &load_status).await,
         }
     }
 }
@@ -61,6 +61,7 @@ pub async fn watch_csi_drivers(client: Arc<Client>, csi_list: Arc<Mutex<Vec<CSID
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("CSIDriver");
                 }
                 Event::Apply(driver) => {
                     if !initialized {
@@ -78,7 +79,7 @@ pub async fn watch_csi_drivers(client: Arc<Client>, csi_list: Arc<Mutex<Vec<CSID
                     }
                 }
             },
-            Err(e) => log::error!("CSIDriver watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("CSIDriver", &e, &load_status).await,
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::apps::v1::DaemonSet, apimachinery::pkg::apis::meta::v1::Time};
@@ -7,6 +8,7 @@ use kube::{Api, runtime::watcher, runtime::watcher::Event};
 #[derive(Debug, Clone)]
 pub struct DaemonSetItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub desired: i32,
     pub current: i32,
     pub ready: i32,
@@ -21,6 +23,7 @@ pub fn convert_daemonset(ds: DaemonSet) -> Option<DaemonSetItem> {
 
     let status = ds.status?;
     Some(DaemonSetItem {
+        labels: ds.metadata.labels.clone().unwrap_or_default(),
         name,
         desired: status.desired_number_scheduled,
         current: status.current_number_scheduled,
@@ -56,6 +59,7 @@ pub async fn watch_daemonsets(client: Arc<Client>, daemonsets_list: Arc<Mutex<Ve
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("DaemonSet");
                 }
                 Event::Apply(ds) => {
                     if !initialized {
@@ -80,7 +84,7 @@ pub async fn watch_daemonsets(client: Arc<Client>, daemonsets_list: Arc<Mutex<Ve
                     }
                 }
             },
-            Err(e) => log::error!("DaemonSet watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("DaemonSet", &e, &load_status).await,
         }
     }
 }
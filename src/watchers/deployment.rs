@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::apps::v1::{Deployment}, apimachinery::pkg::apis::meta::v1::Time};
@@ -8,6 +9,7 @@ use kube::runtime::watcher::Event as WatcherEvent;
 #[derive(Clone)]
 pub struct DeploymentItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub ready_replicas: i32,
     pub available_replicas: i32,
     pub unavailable_replicas: i32,
@@ -17,11 +19,12 @@ pub struct DeploymentItem {
     pub namespace: Option<String>,
 }
 
-fn convert_deployment(deploy: Deployment) -> Option<DeploymentItem> {
+pub fn convert_deployment(deploy: Deployment) -> Option<DeploymentItem> {
     let name = deploy.metadata.name.unwrap_or_default();
     let status = deploy.status.unwrap_or_default();
     let namespace = deploy.metadata.namespace.clone();
     Some(DeploymentItem {
+        labels: deploy.metadata.labels.clone().unwrap_or_default(),
         name,
         namespace,
         ready_replicas: status.ready_replicas.unwrap_or(0),
@@ -61,6 +64,7 @@ pub async fn watch_deployments(client: Arc<Client>, deployments_list: Arc<Mutex<
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Deployment");
                 }
 
                 WatcherEvent::Apply(deploy) => {
@@ -88,7 +92,7 @@ pub async fn watch_deployments(client: Arc<Client>, deployments_list: Arc<Mutex<
                 }
             },
             Err(e) => {
-                log::error!("Deployment watch error: {:?}", e);
+                crate::record_watch_error("Deployment", &e, &load_status).await;
             }
         }
     }
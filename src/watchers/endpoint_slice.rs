@@ -0,0 +1,254 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{atomic::{AtomicBool, AtomicU64, Ordering}, Arc, Mutex};
+use futures_util::StreamExt;
+use k8s_openapi::{api::discovery::v1::EndpointSlice, apimachinery::pkg::apis::meta::v1::Time};
+use kube::Client;
+use kube::{Api, runtime::watcher, runtime::watcher::Event};
+
+/// Label the EndpointSlice controller puts on every slice of a Service
+pub const SERVICE_NAME_LABEL: &str = "kubernetes.io/service-name";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlicePort {
+    pub name: Option<String>,
+    pub port: Option<i32>,
+    pub protocol: String,
+}
+
+impl SlicePort {
+    pub fn describe(&self) -> String {
+        let port = self.port.map(|p| p.to_string()).unwrap_or("*".to_string());
+        match &self.name {
+            Some(name) if !name.is_empty() => format!("{}:{}/{}", name, port, self.protocol),
+            _ => format!("{}/{}", port, self.protocol),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SliceEndpoint {
+    pub addresses: Vec<String>,
+    /// IPv4, IPv6 or FQDN, from the owning slice
+    pub address_type: String,
+    pub slice: String,
+    /// Missing conditions are reported as ready and serving, as kube-proxy treats them
+    pub ready: bool,
+    pub serving: bool,
+    pub terminating: bool,
+    pub hostname: Option<String>,
+    pub node_name: Option<String>,
+    pub zone: Option<String>,
+    pub hint_zones: Vec<String>,
+    pub hint_nodes: Vec<String>,
+    /// "Pod/name" style reference to the backing object
+    pub target: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EndpointSliceItem {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub service_name: Option<String>,
+    pub address_type: String,
+    pub ports: Vec<SlicePort>,
+    pub endpoints: Vec<SliceEndpoint>,
+    pub creation_timestamp: Option<Time>,
+}
+
+/// All slices of one Service, which is what the deprecated Endpoints object used to hold
+#[derive(Debug, Clone)]
+pub struct ServiceEndpoints {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub slices: Vec<String>,
+    pub address_types: BTreeSet<String>,
+    pub ports: Vec<SlicePort>,
+    pub endpoints: Vec<SliceEndpoint>,
+    pub creation_timestamp: Option<Time>,
+}
+
+impl ServiceEndpoints {
+    /// A dual-stack pod shows up once in the IPv4 slice and once in the IPv6 one, counts go by backend
+    fn unique_endpoints(&self) -> impl Iterator<Item = &SliceEndpoint> {
+        let mut seen = HashSet::new();
+        self.endpoints.iter().filter(move |e| {
+            let key = e.target.as_deref()
+                .or(e.hostname.as_deref())
+                .or(e.addresses.first().map(String::as_str));
+            key.is_none_or(|key| seen.insert(key))
+        })
+    }
+
+    pub fn ready(&self) -> usize {
+        self.unique_endpoints().filter(|e| e.ready).count()
+    }
+
+    /// Failing readiness probes or still starting, terminating ones are counted apart
+    pub fn not_ready(&self) -> usize {
+        self.unique_endpoints().filter(|e| !e.ready && !e.terminating).count()
+    }
+
+    pub fn terminating(&self) -> usize {
+        self.unique_endpoints().filter(|e| e.terminating).count()
+    }
+
+    pub fn zones(&self) -> BTreeSet<&str> {
+        self.endpoints.iter().filter_map(|e| e.zone.as_deref()).collect()
+    }
+
+    /// Topology aware routing is on when the controller publishes zone hints
+    pub fn has_hints(&self) -> bool {
+        self.endpoints.iter().any(|e| !e.hint_zones.is_empty() || !e.hint_nodes.is_empty())
+    }
+}
+
+/// Groups slices by the Service they belong to, slices without the service label are listed on their own
+pub fn aggregate_endpoint_slices(slices: &[EndpointSliceItem]) -> Vec<ServiceEndpoints> {
+    let mut services: Vec<ServiceEndpoints> = Vec::new();
+    let mut index_of: HashMap<(Option<&str>, &str), usize> = HashMap::new();
+    for slice in slices {
+        let name = slice.service_name.as_deref().unwrap_or(&slice.name);
+        let index = *index_of.entry((slice.namespace.as_deref(), name)).or_insert_with(|| {
+            services.push(ServiceEndpoints {
+                name: name.to_string(),
+                namespace: slice.namespace.clone(),
+                slices: vec![],
+                address_types: BTreeSet::new(),
+                ports: vec![],
+                endpoints: vec![],
+                creation_timestamp: slice.creation_timestamp.clone(),
+            });
+            services.len() - 1
+        });
+        let service = &mut services[index];
+        service.slices.push(slice.name.clone());
+        service.address_types.insert(slice.address_type.clone());
+        for port in &slice.ports {
+            if !service.ports.contains(port) {
+                service.ports.push(port.clone());
+            }
+        }
+        service.endpoints.extend(slice.endpoints.iter().cloned());
+        if slice.creation_timestamp < service.creation_timestamp {
+            service.creation_timestamp = slice.creation_timestamp.clone();
+        }
+    }
+    services
+}
+
+/// Per Service aggregation of the watched slices, kept until the watcher changes the list
+#[derive(Default)]
+pub struct ServiceEndpointsCache {
+    generation: Option<u64>,
+    services: Arc<Vec<ServiceEndpoints>>,
+}
+
+impl ServiceEndpointsCache {
+    pub fn get(&mut self, slices: &Mutex<Vec<EndpointSliceItem>>, generation: &AtomicU64) -> Arc<Vec<ServiceEndpoints>> {
+        let slices = slices.lock().unwrap();
+        let generation = generation.load(Ordering::Relaxed);
+        if self.generation != Some(generation) {
+            self.services = Arc::new(aggregate_endpoint_slices(&slices));
+            self.generation = Some(generation);
+        }
+        Arc::clone(&self.services)
+    }
+}
+
+pub fn convert_endpoint_slice(slice: EndpointSlice) -> Option<EndpointSliceItem> {
+    let metadata = &slice.metadata;
+    let name = metadata.name.clone()?;
+    let labels = metadata.labels.as_ref();
+
+    let endpoints = slice.endpoints.iter().flatten().map(|e| {
+        let conditions = e.conditions.as_ref();
+        let hints = e.hints.as_ref();
+        SliceEndpoint {
+            addresses: e.addresses.clone(),
+            address_type: slice.address_type.clone(),
+            slice: name.clone(),
+            ready: conditions.and_then(|c| c.ready).unwrap_or(true),
+            serving: conditions.and_then(|c| c.serving).unwrap_or(true),
+            terminating: conditions.and_then(|c| c.terminating).unwrap_or(false),
+            hostname: e.hostname.clone(),
+            node_name: e.node_name.clone(),
+            zone: e.zone.clone(),
+            hint_zones: hints.and_then(|h| h.for_zones.as_ref()).map(|z| z.iter().map(|z| z.name.clone()).collect()).unwrap_or_default(),
+            hint_nodes: hints.and_then(|h| h.for_nodes.as_ref()).map(|n| n.iter().map(|n| n.name.clone()).collect()).unwrap_or_default(),
+            target: e.target_ref.as_ref().map(|t| format!("{}/{}", t.kind.clone().unwrap_or_default(), t.name.clone().unwrap_or_default())),
+        }
+    }).collect();
+
+    Some(EndpointSliceItem {
+        service_name: labels.and_then(|l| l.get(SERVICE_NAME_LABEL)).cloned(),
+        address_type: slice.address_type.clone(),
+        ports: slice.ports.iter().flatten().map(|p| SlicePort {
+            name: p.name.clone(),
+            port: p.port,
+            protocol: p.protocol.clone().unwrap_or("TCP".to_string()),
+        }).collect(),
+        endpoints,
+        creation_timestamp: metadata.creation_timestamp.clone(),
+        namespace: metadata.namespace.clone(),
+        name,
+    })
+}
+
+/// `generation` is bumped under the list lock on every change, so the cache reaggregates only when it moves
+pub async fn watch_endpoint_slices(client: Arc<Client>, slices_list: Arc<Mutex<Vec<EndpointSliceItem>>>, load_status: Arc<AtomicBool>, generation: Arc<AtomicU64>) {
+    let api: Api<EndpointSlice> = Api::all(client.as_ref().clone());
+
+    load_status.store(true, Ordering::Relaxed);
+
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(slice) => {
+                    if let Some(item) = convert_endpoint_slice(slice) {
+                        initial.push(item);
+                    }
+                }
+                Event::InitDone => {
+                    let mut list = slices_list.lock().unwrap();
+                    *list = initial.clone();
+                    generation.fetch_add(1, Ordering::Relaxed);
+                    initialized = true;
+
+                    load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("EndpointSlice");
+                }
+                Event::Apply(slice) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(item) = convert_endpoint_slice(slice) {
+                        let mut list = slices_list.lock().unwrap();
+                        if let Some(existing) = list.iter_mut().find(|f| f.name == item.name && f.namespace == item.namespace) {
+                            *existing = item; // renew
+                        } else {
+                            list.push(item); // add new
+                        }
+                        generation.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+                Event::Delete(slice) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let (Some(name), Some(namespace)) = (slice.metadata.name, slice.metadata.namespace) {
+                        let mut list = slices_list.lock().unwrap();
+                        list.retain(|item| !(item.name == name && item.namespace.as_ref() == Some(&namespace)));
+                        generation.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            },
+            Err(e) => crate::record_watch_error("EndpointSlice", &e, &load_status).await,
+        }
+    }
+}
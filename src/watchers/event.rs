@@ -1,8 +1,10 @@
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
-use k8s_openapi::{apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::{apimachinery::pkg::apis::meta::v1::Time, jiff::Timestamp};
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind};
 use kube::{Api, runtime::watcher};
 use kube::Client;
+use serde_json::Value;
 
 #[derive(Clone)]
 pub struct EventItem {
@@ -10,35 +12,75 @@ pub struct EventItem {
     pub message: String,
     pub reason: String,
     pub involved_object: String,
+    pub object_kind: String,
+    pub object_name: String,
     pub event_type: String,
     pub timestamp: String,
     pub namespace: Option<String>,
     pub creation_timestamp: Option<Time>,
     pub count: Option<i32>,
+    pub first_seen: Option<Timestamp>,
+    pub last_seen: Option<Timestamp>,
+    pub reporting_controller: String,
 }
 
-pub fn convert_event(ev: k8s_openapi::api::core::v1::Event) -> Option<EventItem> {
-    let involved_object = format!(
-        "{}/{}",
-        ev.involved_object.kind.clone().unwrap_or_else(|| "Unknown".to_string()),
-        ev.involved_object.name.clone().unwrap_or_else(|| "Unknown".to_string())
-    );
+fn event_field<'a>(data: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter().try_fold(data, |value, key| value.get(key)).filter(|v| !v.is_null())
+}
+
+fn event_str(data: &Value, path: &[&str]) -> Option<String> {
+    event_field(data, path).and_then(|v| v.as_str()).map(|s| s.to_string())
+}
+
+fn event_time(data: &Value, path: &[&str]) -> Option<Timestamp> {
+    event_field(data, path).and_then(|v| v.as_str()).and_then(|s| s.parse().ok())
+}
+
+/// events.k8s.io/v1 Event, read from the raw object because `eventTime` is null for events
+/// recorded through the core/v1 API
+pub fn convert_event(ev: DynamicObject) -> Option<EventItem> {
+    let data = &ev.data;
+    let object_kind = event_str(data, &["regarding", "kind"]).unwrap_or_else(|| "Unknown".to_string());
+    let object_name = event_str(data, &["regarding", "name"]).unwrap_or_else(|| "Unknown".to_string());
+    let creation = ev.metadata.creation_timestamp.as_ref().map(|t| t.0);
+
+    // A series keeps one Event object and bumps its count/lastObservedTime
+    let count = event_field(data, &["series", "count"])
+        .or_else(|| event_field(data, &["deprecatedCount"]))
+        .and_then(|v| v.as_i64())
+        .map(|c| c as i32);
+    let first_seen = event_time(data, &["eventTime"])
+        .or_else(|| event_time(data, &["deprecatedFirstTimestamp"]))
+        .or(creation);
+    let last_seen = event_time(data, &["series", "lastObservedTime"])
+        .or_else(|| event_time(data, &["deprecatedLastTimestamp"]))
+        .or(first_seen);
+
     Some(EventItem {
-        name: ev.metadata.name.unwrap_or_else(|| "Empty".to_string()),
-        creation_timestamp: ev.metadata.creation_timestamp,
-        message: ev.message.clone().unwrap_or_else(|| "Empty".to_string()),
-        reason: ev.reason.clone().unwrap_or_else(|| "Unknown".to_string()),
-        involved_object,
-        event_type: ev.type_.clone().unwrap_or_else(|| "Normal".to_string()),
-        timestamp: ev.event_time.as_ref().map(|t| t.0.to_string()).or_else(|| ev.last_timestamp.as_ref().map(|t| t.0.to_string()))
-            .unwrap_or_else(|| "N/A".to_string()),
-        namespace: Some(ev.involved_object.namespace.clone().unwrap_or_else(|| "default".to_string())),
-        count: ev.count,
+        name: ev.metadata.name.clone().unwrap_or_else(|| "Empty".to_string()),
+        message: event_str(data, &["note"]).unwrap_or_else(|| "Empty".to_string()),
+        reason: event_str(data, &["reason"]).unwrap_or_else(|| "Unknown".to_string()),
+        involved_object: format!("{}/{}", object_kind, object_name),
+        object_kind,
+        object_name,
+        event_type: event_str(data, &["type"]).unwrap_or_else(|| "Normal".to_string()),
+        timestamp: last_seen.map(|t| t.to_string()).unwrap_or_else(|| "N/A".to_string()),
+        namespace: Some(event_str(data, &["regarding", "namespace"])
+            .or_else(|| ev.metadata.namespace.clone())
+            .unwrap_or_else(|| "default".to_string())),
+        creation_timestamp: ev.metadata.creation_timestamp.clone(),
+        count,
+        first_seen,
+        last_seen,
+        reporting_controller: event_str(data, &["reportingController"])
+            .or_else(|| event_str(data, &["deprecatedSource", "component"]))
+            .unwrap_or_default(),
     })
 }
 
 pub async fn watch_events(client: Arc<Client>, events_list: Arc<Mutex<Vec<EventItem>>>, load_status: Arc<AtomicBool>) {
-    let api: Api<k8s_openapi::api::core::v1::Event> = Api::all(client.as_ref().clone());
+    let ar = ApiResource::from_gvk(&GroupVersionKind::gvk("events.k8s.io", "v1", "Event"));
+    let api: Api<DynamicObject> = Api::all_with(client.as_ref().clone(), &ar);
     let mut event_stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
 
     let mut initial = vec![];
@@ -61,6 +103,7 @@ pub async fn watch_events(client: Arc<Client>, events_list: Arc<Mutex<Vec<EventI
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Event");
                 }
                 watcher::Event::Apply(ev) => {
                     if !initialized {
@@ -68,13 +111,24 @@ pub async fn watch_events(client: Arc<Client>, events_list: Arc<Mutex<Vec<EventI
                     }
                     if let Some(item) = convert_event(ev) {
                         let mut list = events_list.lock().unwrap();
-                        list.push(item);
+                        // Series updates modify the same Event object
+                        if let Some(existing) = list.iter_mut().find(|e| e.name == item.name && e.namespace == item.namespace) {
+                            *existing = item;
+                        } else {
+                            list.push(item);
+                        }
+                    }
+                }
+                watcher::Event::Delete(ev) => {
+                    // Expired by the apiserver event TTL
+                    if let Some(item) = convert_event(ev) {
+                        let mut list = events_list.lock().unwrap();
+                        list.retain(|e| e.name != item.name || e.namespace != item.namespace);
                     }
                 }
-                watcher::Event::Delete(_) => {} // Events should not be deleted
             },
             Err(e) => {
-                log::error!("Event watch error: {:?}", e);
+                crate::record_watch_error("Event", &e, &load_status).await;
             }
         }
     }
@@ -0,0 +1,329 @@
+use std::sync::{atomic::{AtomicBool, AtomicUsize, Ordering}, Arc, Mutex};
+use futures_util::StreamExt;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use kube::api::{ApiResource, DynamicObject};
+use kube::discovery::{self, Scope};
+use kube::{Client, Api, ResourceExt, runtime::watcher, runtime::watcher::Event};
+use serde_json::Value;
+
+pub const GATEWAY_API_GROUP: &str = "gateway.networking.k8s.io";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GatewayApiKind {
+    GatewayClass,
+    Gateway,
+    HTTPRoute,
+    GRPCRoute,
+    TCPRoute,
+}
+
+impl GatewayApiKind {
+    pub const ALL: [GatewayApiKind; 5] = [
+        GatewayApiKind::GatewayClass,
+        GatewayApiKind::Gateway,
+        GatewayApiKind::HTTPRoute,
+        GatewayApiKind::GRPCRoute,
+        GatewayApiKind::TCPRoute,
+    ];
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            GatewayApiKind::GatewayClass => "GatewayClass",
+            GatewayApiKind::Gateway => "Gateway",
+            GatewayApiKind::HTTPRoute => "HTTPRoute",
+            GatewayApiKind::GRPCRoute => "GRPCRoute",
+            GatewayApiKind::TCPRoute => "TCPRoute",
+        }
+    }
+
+    pub fn is_route(&self) -> bool {
+        matches!(self, GatewayApiKind::HTTPRoute | GatewayApiKind::GRPCRoute | GatewayApiKind::TCPRoute)
+    }
+}
+
+/// What discovery found, kinds missing from `served` are not installed (TCPRoute is experimental)
+#[derive(Debug, Clone, Default)]
+pub struct GatewayApiDiscovery {
+    pub served: Vec<(GatewayApiKind, String)>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GatewayListener {
+    pub name: String,
+    pub hostname: Option<String>,
+    pub port: i64,
+    pub protocol: String,
+    /// Same, All or Selector
+    pub allowed_namespaces: String,
+    pub attached_routes: Option<i64>,
+    pub conditions: Vec<crate::ConditionDetails>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParentRef {
+    pub kind: String,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub section_name: Option<String>,
+    pub port: Option<i64>,
+}
+
+impl ParentRef {
+    /// "ns/name:section", the namespace defaults to the route namespace
+    pub fn describe(&self, route_namespace: Option<&str>) -> String {
+        let mut text = format!("{}/{}", self.namespace.as_deref().or(route_namespace).unwrap_or_default(), self.name);
+        if let Some(section) = &self.section_name {
+            text.push_str(&format!(":{}", section));
+        }
+        if let Some(port) = self.port {
+            text.push_str(&format!(" port {}", port));
+        }
+        if self.kind != "Gateway" {
+            text = format!("{} {}", self.kind, text);
+        }
+        text
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RouteParentStatus {
+    pub parent: ParentRef,
+    pub controller_name: String,
+    pub conditions: Vec<crate::ConditionDetails>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BackendRef {
+    pub kind: String,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub port: Option<i64>,
+    pub weight: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GatewayApiObject {
+    pub kind: GatewayApiKind,
+    pub api_resource: ApiResource,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub creation_timestamp: Option<Time>,
+    /// GatewayClass and Gateway status conditions
+    pub conditions: Vec<crate::ConditionDetails>,
+    pub controller_name: Option<String>,
+    pub description: Option<String>,
+    pub class_name: Option<String>,
+    pub addresses: Vec<String>,
+    pub listeners: Vec<GatewayListener>,
+    pub hostnames: Vec<String>,
+    pub parent_refs: Vec<ParentRef>,
+    pub parents: Vec<RouteParentStatus>,
+    pub backends: Vec<BackendRef>,
+    pub rules: usize,
+}
+
+impl GatewayApiObject {
+    /// Whether the route points at this gateway, optionally at one of its listeners
+    pub fn attaches_to(&self, gateway: &GatewayApiObject, listener: Option<&str>) -> bool {
+        self.parent_refs.iter().any(|p| {
+            p.kind == "Gateway"
+                && p.name == gateway.name
+                && p.namespace.as_ref().or(self.namespace.as_ref()) == gateway.namespace.as_ref()
+                && listener.is_none_or(|l| p.section_name.as_deref().is_none_or(|s| s == l))
+        })
+    }
+
+    /// Status the controller reported for one parentRef
+    pub fn parent_status(&self, parent: &ParentRef) -> Option<&RouteParentStatus> {
+        self.parents.iter().find(|s| {
+            s.parent.name == parent.name
+                && s.parent.kind == parent.kind
+                && s.parent.section_name == parent.section_name
+                && s.parent.namespace.as_ref().or(self.namespace.as_ref()) == parent.namespace.as_ref().or(self.namespace.as_ref())
+        })
+    }
+}
+
+fn str_field(value: &Value, field: &str) -> Option<String> {
+    value.get(field).and_then(|v| v.as_str()).map(|s| s.to_string())
+}
+
+fn array_field<'a>(value: &'a Value, field: &str) -> &'a [Value] {
+    value.get(field).and_then(|v| v.as_array()).map(|a| a.as_slice()).unwrap_or_default()
+}
+
+pub fn parse_gateway_conditions(value: &Value) -> Vec<crate::ConditionDetails> {
+    array_field(value, "conditions").iter().map(|c| crate::ConditionDetails {
+        type_: str_field(c, "type").unwrap_or_default(),
+        status: str_field(c, "status").unwrap_or_default(),
+        reason: str_field(c, "reason"),
+        message: str_field(c, "message"),
+        last_transition_time: str_field(c, "lastTransitionTime"),
+    }).collect()
+}
+
+fn parse_parent_ref(value: &Value) -> ParentRef {
+    ParentRef {
+        kind: str_field(value, "kind").unwrap_or("Gateway".to_string()),
+        namespace: str_field(value, "namespace"),
+        name: str_field(value, "name").unwrap_or_default(),
+        section_name: str_field(value, "sectionName"),
+        port: value.get("port").and_then(|p| p.as_i64()),
+    }
+}
+
+fn parse_listener(value: &Value, status: &[Value]) -> GatewayListener {
+    let name = str_field(value, "name").unwrap_or_default();
+    let listener_status = status.iter().find(|s| str_field(s, "name").as_ref() == Some(&name));
+    GatewayListener {
+        hostname: str_field(value, "hostname"),
+        port: value.get("port").and_then(|p| p.as_i64()).unwrap_or_default(),
+        protocol: str_field(value, "protocol").unwrap_or_default(),
+        allowed_namespaces: value.pointer("/allowedRoutes/namespaces/from").and_then(|f| f.as_str()).unwrap_or("Same").to_string(),
+        attached_routes: listener_status.and_then(|s| s.get("attachedRoutes")).and_then(|a| a.as_i64()),
+        conditions: listener_status.map(parse_gateway_conditions).unwrap_or_default(),
+        name,
+    }
+}
+
+pub fn convert_gateway_api_object(obj: &DynamicObject, kind: GatewayApiKind, api_resource: &ApiResource) -> GatewayApiObject {
+    let spec = obj.data.get("spec").cloned().unwrap_or(Value::Null);
+    let status = obj.data.get("status").cloned().unwrap_or(Value::Null);
+    let rules = array_field(&spec, "rules");
+
+    GatewayApiObject {
+        kind,
+        api_resource: api_resource.clone(),
+        name: obj.name_any(),
+        namespace: obj.namespace(),
+        creation_timestamp: obj.metadata.creation_timestamp.clone(),
+        conditions: parse_gateway_conditions(&status),
+        controller_name: str_field(&spec, "controllerName"),
+        description: str_field(&spec, "description"),
+        class_name: str_field(&spec, "gatewayClassName"),
+        addresses: array_field(&status, "addresses").iter().filter_map(|a| str_field(a, "value")).collect(),
+        listeners: array_field(&spec, "listeners").iter().map(|l| parse_listener(l, array_field(&status, "listeners"))).collect(),
+        hostnames: array_field(&spec, "hostnames").iter().filter_map(|h| h.as_str().map(|s| s.to_string())).collect(),
+        parent_refs: array_field(&spec, "parentRefs").iter().map(parse_parent_ref).collect(),
+        parents: array_field(&status, "parents").iter().map(|p| RouteParentStatus {
+            parent: p.get("parentRef").map(parse_parent_ref).unwrap_or(parse_parent_ref(&Value::Null)),
+            controller_name: str_field(p, "controllerName").unwrap_or_default(),
+            conditions: parse_gateway_conditions(p),
+        }).collect(),
+        backends: rules.iter().flat_map(|r| array_field(r, "backendRefs")).map(|b| BackendRef {
+            kind: str_field(b, "kind").unwrap_or("Service".to_string()),
+            namespace: str_field(b, "namespace"),
+            name: str_field(b, "name").unwrap_or_default(),
+            port: b.get("port").and_then(|p| p.as_i64()),
+            weight: b.get("weight").and_then(|w| w.as_i64()),
+        }).collect(),
+        rules: rules.len(),
+    }
+}
+
+/// Finds the Gateway API kinds the cluster serves, each at its preferred version
+pub async fn discover_gateway_api(client: &Client) -> Result<Vec<(GatewayApiKind, ApiResource, bool)>, String> {
+    let group = match discovery::group(client, GATEWAY_API_GROUP).await {
+        Ok(group) => group,
+        Err(kube::Error::Discovery(_)) => return Err(format!("The Gateway API CRDs ({}) are not installed in this cluster", GATEWAY_API_GROUP)),
+        Err(e) => return Err(e.to_string()),
+    };
+    Ok(GatewayApiKind::ALL.iter().filter_map(|kind| {
+        group.recommended_kind(kind.kind()).map(|(ar, caps)| (*kind, ar, caps.scope == Scope::Namespaced))
+    }).collect())
+}
+
+async fn watch_gateway_api_kind(
+        client: Arc<Client>,
+        list: Arc<Mutex<Vec<GatewayApiObject>>>,
+        pending: Arc<AtomicUsize>,
+        load_status: Arc<AtomicBool>,
+        kind: GatewayApiKind,
+        ar: ApiResource,
+) {
+    let api: Api<DynamicObject> = Api::all_with(client.as_ref().clone(), &ar);
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(obj) => initial.push(convert_gateway_api_object(&obj, kind, &ar)),
+                Event::InitDone => {
+                    let mut list_guard = list.lock().unwrap();
+                    list_guard.retain(|o| o.kind != kind);
+                    list_guard.append(&mut initial);
+                    if !initialized && pending.fetch_sub(1, Ordering::Relaxed) == 1 {
+                        load_status.store(false, Ordering::Relaxed);
+                    }
+                    initialized = true;
+                    crate::clear_watch_denied(kind.kind());
+                }
+                Event::Apply(obj) => {
+                    if !initialized {
+                        continue;
+                    }
+                    let item = convert_gateway_api_object(&obj, kind, &ar);
+                    let mut list_guard = list.lock().unwrap();
+                    if let Some(existing) = list_guard.iter_mut().find(|o| o.kind == kind && o.name == item.name && o.namespace == item.namespace) {
+                        *existing = item; // renew
+                    } else {
+                        list_guard.push(item); // add new
+                    }
+                }
+                Event::Delete(obj) => {
+                    if !initialized {
+                        continue;
+                    }
+                    let (name, namespace) = (obj.name_any(), obj.namespace());
+                    let mut list_guard = list.lock().unwrap();
+                    list_guard.retain(|o| !(o.kind == kind && o.name == name && o.namespace == namespace));
+                }
+            },
+            Err(e) => {
+                // A denied kind must not keep the whole view loading
+                let kind_loading = AtomicBool::new(true);
+                crate::record_watch_error(kind.kind(), &e, &kind_loading).await;
+                if !initialized && !kind_loading.load(Ordering::Relaxed) {
+                    initialized = true;
+                    if pending.fetch_sub(1, Ordering::Relaxed) == 1 {
+                        load_status.store(false, Ordering::Relaxed);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Discovers the Gateway API group and watches every served kind into one list
+pub async fn watch_gateway_api(client: Arc<Client>, list: Arc<Mutex<Vec<GatewayApiObject>>>, load_status: Arc<AtomicBool>, discovered: Arc<Mutex<GatewayApiDiscovery>>) {
+    load_status.store(true, Ordering::Relaxed);
+
+    let kinds = match discover_gateway_api(&client).await {
+        Ok(kinds) => kinds,
+        Err(error) => {
+            log::info!("Gateway API not available: {}", error);
+            *discovered.lock().unwrap() = GatewayApiDiscovery { served: vec![], error: Some(error) };
+            load_status.store(false, Ordering::Relaxed);
+            return;
+        }
+    };
+    *discovered.lock().unwrap() = GatewayApiDiscovery {
+        served: kinds.iter().map(|(kind, ar, _)| (*kind, ar.api_version.clone())).collect(),
+        error: None,
+    };
+    if kinds.is_empty() {
+        load_status.store(false, Ordering::Relaxed);
+        return;
+    }
+
+    let pending = Arc::new(AtomicUsize::new(kinds.len()));
+    let watches = kinds.into_iter().map(|(kind, ar, _)| {
+        watch_gateway_api_kind(Arc::clone(&client), Arc::clone(&list), Arc::clone(&pending), Arc::clone(&load_status), kind, ar)
+    });
+    futures::future::join_all(watches).await;
+}
@@ -0,0 +1,193 @@
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
+use k8s_openapi::api::autoscaling::v2::{HorizontalPodAutoscaler, MetricSpec, MetricStatus, MetricTarget, MetricValueStatus};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use kube::{Client, Api, runtime::watcher, runtime::watcher::Event};
+use futures_util::StreamExt;
+
+/// One metric of the HPA spec with the value the controller last observed for it
+#[derive(Debug, Clone)]
+pub struct HpaMetricValue {
+    pub kind: String,
+    pub name: String,
+    pub target: String,
+    pub current: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HpaItem {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub creation_timestamp: Option<Time>,
+    pub target_kind: String,
+    pub target_name: String,
+    pub min_replicas: i32,
+    pub max_replicas: i32,
+    pub current_replicas: i32,
+    pub desired_replicas: i32,
+    pub metrics: Vec<HpaMetricValue>,
+    pub last_scale_time: Option<Time>,
+}
+
+impl HpaItem {
+    /// "cpu 45%/80%, memory <unknown>/1Gi" like kubectl get hpa
+    pub fn targets(&self) -> String {
+        if self.metrics.is_empty() {
+            return "-".to_string();
+        }
+        self.metrics.iter()
+            .map(|m| format!("{} {}/{}", m.name, m.current.as_deref().unwrap_or("<unknown>"), m.target))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+fn format_target(target: &MetricTarget) -> String {
+    if let Some(utilization) = target.average_utilization {
+        format!("{}%", utilization)
+    } else if let Some(value) = &target.average_value {
+        format!("{} (avg)", value.0)
+    } else if let Some(value) = &target.value {
+        value.0.clone()
+    } else {
+        "-".to_string()
+    }
+}
+
+fn format_current(current: &MetricValueStatus) -> String {
+    if let Some(utilization) = current.average_utilization {
+        format!("{}%", utilization)
+    } else if let Some(value) = &current.average_value {
+        format!("{} (avg)", value.0)
+    } else if let Some(value) = &current.value {
+        value.0.clone()
+    } else {
+        "-".to_string()
+    }
+}
+
+fn spec_metric(metric: &MetricSpec) -> Option<(String, &MetricTarget)> {
+    if let Some(m) = &metric.resource {
+        Some((m.name.clone(), &m.target))
+    } else if let Some(m) = &metric.container_resource {
+        Some((format!("{}/{}", m.container, m.name), &m.target))
+    } else if let Some(m) = &metric.pods {
+        Some((m.metric.name.clone(), &m.target))
+    } else if let Some(m) = &metric.object {
+        Some((format!("{}/{} {}", m.described_object.kind, m.described_object.name, m.metric.name), &m.target))
+    } else {
+        metric.external.as_ref().map(|m| (m.metric.name.clone(), &m.target))
+    }
+}
+
+fn status_metric(metric: &MetricStatus) -> Option<(String, &MetricValueStatus)> {
+    if let Some(m) = &metric.resource {
+        Some((m.name.clone(), &m.current))
+    } else if let Some(m) = &metric.container_resource {
+        Some((format!("{}/{}", m.container, m.name), &m.current))
+    } else if let Some(m) = &metric.pods {
+        Some((m.metric.name.clone(), &m.current))
+    } else if let Some(m) = &metric.object {
+        Some((format!("{}/{} {}", m.described_object.kind, m.described_object.name, m.metric.name), &m.current))
+    } else {
+        metric.external.as_ref().map(|m| (m.metric.name.clone(), &m.current))
+    }
+}
+
+/// Pairs each spec metric with its status entry by type and name
+pub fn hpa_metric_values(hpa: &HorizontalPodAutoscaler) -> Vec<HpaMetricValue> {
+    let current: Vec<(String, String, &MetricValueStatus)> = hpa.status.as_ref()
+        .and_then(|s| s.current_metrics.as_ref())
+        .map(|metrics| metrics.iter().filter_map(|m| status_metric(m).map(|(name, value)| (m.type_.clone(), name, value))).collect())
+        .unwrap_or_default();
+
+    hpa.spec.metrics.as_ref()
+        .map(|metrics| metrics.iter().filter_map(|m| {
+            let (name, target) = spec_metric(m)?;
+            let value = current.iter()
+                .find(|(kind, current_name, _)| *kind == m.type_ && *current_name == name)
+                .map(|(_, _, value)| format_current(value));
+            Some(HpaMetricValue { kind: m.type_.clone(), name, target: format_target(target), current: value })
+        }).collect())
+        .unwrap_or_default()
+}
+
+pub fn convert_hpa(hpa: HorizontalPodAutoscaler) -> Option<HpaItem> {
+    let metadata = &hpa.metadata;
+    let name = metadata.name.clone()?;
+    let spec = &hpa.spec;
+    let status = hpa.status.as_ref();
+
+    Some(HpaItem {
+        name,
+        namespace: metadata.namespace.clone(),
+        creation_timestamp: metadata.creation_timestamp.clone(),
+        target_kind: spec.scale_target_ref.kind.clone(),
+        target_name: spec.scale_target_ref.name.clone(),
+        min_replicas: spec.min_replicas.unwrap_or(1),
+        max_replicas: spec.max_replicas,
+        current_replicas: status.and_then(|s| s.current_replicas).unwrap_or(0),
+        desired_replicas: status.map(|s| s.desired_replicas).unwrap_or(0),
+        metrics: hpa_metric_values(&hpa),
+        last_scale_time: status.and_then(|s| s.last_scale_time.clone()),
+    })
+}
+
+/// HPA scaling the given workload, if any
+pub fn find_hpa_for<'a>(hpas: &'a [HpaItem], kind: &str, name: &str, namespace: Option<&str>) -> Option<&'a HpaItem> {
+    hpas.iter().find(|h| h.target_kind == kind && h.target_name == name && h.namespace.as_deref() == namespace)
+}
+
+pub async fn watch_hpas(client: Arc<Client>, hpa_list: Arc<Mutex<Vec<HpaItem>>>, load_status: Arc<AtomicBool>) {
+    let api: Api<HorizontalPodAutoscaler> = Api::all(client.as_ref().clone());
+
+    load_status.store(true, Ordering::Relaxed);
+
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(hpa) => {
+                    if let Some(item) = convert_hpa(hpa) {
+                        initial.push(item);
+                    }
+                }
+                Event::InitDone => {
+                    let mut list_guard = hpa_list.lock().unwrap();
+                    *list_guard = initial.clone();
+                    initialized = true;
+
+                    load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("HPA");
+                }
+                Event::Apply(hpa) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(item) = convert_hpa(hpa) {
+                        let mut list = hpa_list.lock().unwrap();
+                        if let Some(existing) = list.iter_mut().find(|f| f.name == item.name && f.namespace == item.namespace) {
+                            *existing = item; // renew
+                        } else {
+                            list.push(item); // add new
+                        }
+                    }
+                }
+                Event::Delete(hpa) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let (Some(name), Some(namespace)) = (hpa.metadata.name, hpa.metadata.namespace) {
+                        let mut list = hpa_list.lock().unwrap();
+                        list.retain(|item| !(item.name == name && item.namespace.as_ref() == Some(&namespace)));
+                    }
+                }
+            },
+            Err(e) => crate::record_watch_error("HPA", &e, &load_status).await,
+        }
+    }
+}
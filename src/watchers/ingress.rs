@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::networking::v1::Ingress, apimachinery::pkg::apis::meta::v1::Time};
@@ -7,6 +8,7 @@ use kube::{Api, runtime::watcher, runtime::watcher::Event};
 #[derive(Debug, Clone)]
 pub struct IngressItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub host: String,
     pub paths: String,
     pub service: String,
@@ -57,6 +59,7 @@ pub fn convert_ingress(ing: Ingress) -> Option<IngressItem> {
         .unwrap_or_else(|| "-".to_string());
 
     Some(IngressItem {
+        labels: ing.metadata.labels.clone().unwrap_or_default(),
         name,
         host: if hosts.is_empty() { "-".into() } else { hosts.join(", ") },
         paths: if paths.is_empty() { "-".into() } else { paths.join(", ") },
@@ -93,6 +96,7 @@ pub async fn watch_ingresses(client: Arc<Client>, ingresses_list: Arc<Mutex<Vec<
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Ingress");
                 }
                 Event::Apply(ing) => {
                     if !initialized {
@@ -117,7 +121,7 @@ pub async fn watch_ingresses(client: Arc<Client>, ingresses_list: Arc<Mutex<Vec<
                     }
                 }
             },
-            Err(e) => log::error!("Ingress watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("Ingress", &e, &load_status).await,
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::{batch::v1::Job}, apimachinery::pkg::apis::meta::v1::Time};
@@ -8,6 +9,7 @@ use kube::{Api, runtime::watcher, runtime::watcher::Event};
 #[derive(Debug, Clone)]
 pub struct JobItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub completions: i32,
     pub condition: String,
     pub creation_timestamp: Option<Time>,
@@ -33,8 +35,8 @@ pub fn convert_job(job: Job) -> Option<JobItem> {
     let namespace = job.metadata.namespace.clone();
 
     Some(JobItem {
+        labels: job.metadata.labels.clone().unwrap_or_default(),
         name: job.metadata.name.clone()?,
-        //labels: job.metadata.labels.unwrap_or_default(),
         completions: job
             .status
             .as_ref()
@@ -71,6 +73,7 @@ pub async fn watch_jobs(client: Arc<Client>, jobs_list: Arc<Mutex<Vec<JobItem>>>
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Job");
                 }
                 Event::Apply(job) => {
                     if !initialized {
@@ -95,7 +98,7 @@ pub async fn watch_jobs(client: Arc<Client>, jobs_list: Arc<Mutex<Vec<JobItem>>>
                     }
                 }
             },
-            Err(e) => log::error!("Job watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("Job", &e, &load_status).await,
         }
     }
 }
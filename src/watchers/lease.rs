@@ -51,6 +51,7 @@ pub async fn watch_leases(client: Arc<Client>, list: Arc<Mutex<Vec<LeaseItem>>>,
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Lease");
                 }
                 Event::Apply(lease) => {
                     if !initialized {
@@ -75,7 +76,7 @@ pub async fn watch_leases(client: Arc<Client>, list: Arc<Mutex<Vec<LeaseItem>>>,
                     }
                 }
             },
-            Err(e) => log::error!("Lease watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("Lease", &e, &load_status).await,
         }
     }
 }
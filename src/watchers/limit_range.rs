@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
+use k8s_openapi::{api::core::v1::LimitRange, apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::{Client, Api, runtime::watcher, runtime::watcher::Event};
+use futures_util::StreamExt;
+
+/// One entry of spec.limits, for a Container, Pod or PersistentVolumeClaim
+#[derive(Debug, Clone)]
+pub struct LimitRangeLimit {
+    pub type_: String,
+    pub default: BTreeMap<String, String>,
+    pub default_request: BTreeMap<String, String>,
+    pub min: BTreeMap<String, String>,
+    pub max: BTreeMap<String, String>,
+    pub max_limit_request_ratio: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LimitRangeItem {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub creation_timestamp: Option<Time>,
+    pub limits: Vec<LimitRangeLimit>,
+}
+
+impl LimitRangeItem {
+    /// Defaults applied to containers without requests/limits
+    pub fn container_defaults(&self) -> Option<&LimitRangeLimit> {
+        self.limits.iter().find(|l| l.type_ == "Container")
+    }
+}
+
+fn to_strings(values: Option<&BTreeMap<String, Quantity>>) -> BTreeMap<String, String> {
+    values.map(|v| v.iter().map(|(k, q)| (k.clone(), q.0.clone())).collect()).unwrap_or_default()
+}
+
+pub fn convert_limit_range(limit_range: LimitRange) -> Option<LimitRangeItem> {
+    let metadata = &limit_range.metadata;
+    let name = metadata.name.clone()?;
+    let limits = limit_range.spec.as_ref()
+        .map(|spec| spec.limits.iter().map(|l| LimitRangeLimit {
+            type_: l.type_.clone(),
+            default: to_strings(l.default.as_ref()),
+            default_request: to_strings(l.default_request.as_ref()),
+            min: to_strings(l.min.as_ref()),
+            max: to_strings(l.max.as_ref()),
+            max_limit_request_ratio: to_strings(l.max_limit_request_ratio.as_ref()),
+        }).collect())
+        .unwrap_or_default();
+
+    Some(LimitRangeItem {
+        name,
+        namespace: metadata.namespace.clone(),
+        creation_timestamp: metadata.creation_timestamp.clone(),
+        limits,
+    })
+}
+
+pub async fn watch_limit_ranges(client: Arc<Client>, limit_range_list: Arc<Mutex<Vec<LimitRangeItem>>>, load_status: Arc<AtomicBool>) {
+    let api: Api<LimitRange> = Api::all(client.as_ref().clone());
+
+    load_status.store(true, Ordering::Relaxed);
+
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(limit_range) => {
+                    if let Some(item) = convert_limit_range(limit_range) {
+                        initial.push(item);
+                    }
+                }
+                Event::InitDone => {
+                    let mut list_guard = limit_range_list.lock().unwrap();
+                    *list_guard = initial.clone();
+                    initialized = true;
+
+                    load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("LimitRange");
+                }
+                Event::Apply(limit_range) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(item) = convert_limit_range(limit_range) {
+                        let mut list = limit_range_list.lock().unwrap();
+                        if let Some(existing) = list.iter_mut().find(|f| f.name == item.name && f.namespace == item.namespace) {
+                            *existing = item; // renew
+                        } else {
+                            list.push(item); // add new
+                        }
+                    }
+                }
+                Event::Delete(limit_range) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let (Some(name), Some(namespace)) = (limit_range.metadata.name, limit_range.metadata.namespace) {
+                        let mut list = limit_range_list.lock().unwrap();
+                        list.retain(|item| !(item.name == name && item.namespace.as_ref() == Some(&namespace)));
+                    }
+                }
+            },
+            Err(e) => crate::record_watch_error("LimitRange", &e, &load_status).await,
+        }
+    }
+}
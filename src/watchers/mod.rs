@@ -18,7 +18,7 @@ pub mod csi_driver;
 pub mod daemonset;
 pub mod cronjob;
 pub mod ingress;
-pub mod endpoint;
+pub mod endpoint_slice;
 pub mod service;
 pub mod service_account;
 pub mod role;
@@ -27,6 +27,14 @@ pub mod cluster_rb;
 pub mod role_binding;
 pub mod app_log_viewer;
 pub mod lease;
+pub mod cr_instance;
+pub mod access_denied;
+pub mod hpa;
+pub mod resource_quota;
+pub mod limit_range;
+pub mod admission_webhook;
+pub mod admission_policy;
+pub mod gateway_api;
 
 pub use pvc::*;
 pub use pv::*;
@@ -42,7 +50,7 @@ pub use replicaset::*;
 pub use namespace::*;
 pub use csi_driver::*;
 pub use service::*;
-pub use endpoint::*;
+pub use endpoint_slice::*;
 pub use ingress::*;
 pub use cronjob::*;
 pub use daemonset::*;
@@ -57,3 +65,11 @@ pub use cluster_rb::*;
 pub use role_binding::*;
 pub use app_log_viewer::*;
 pub use lease::*;
+pub use cr_instance::*;
+pub use access_denied::*;
+pub use hpa::*;
+pub use resource_quota::*;
+pub use limit_range::*;
+pub use admission_webhook::*;
+pub use admission_policy::*;
+pub use gateway_api::*;
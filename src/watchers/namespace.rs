@@ -45,6 +45,7 @@ pub async fn watch_namespaces(client: Arc<Client>, ns_list: Arc<Mutex<Vec<Namesp
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Namespace");
                 }
                 watcher::Event::Apply(ns) => {
                     if !initialized {
@@ -69,7 +70,7 @@ pub async fn watch_namespaces(client: Arc<Client>, ns_list: Arc<Mutex<Vec<Namesp
                 }
             },
             Err(e) => {
-                log::error!("Namespace watch error: {:?}", e);
+                crate::record_watch_error("Namespace", &e, &load_status).await;
             }
         }
     }
@@ -1,5 +1,6 @@
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
-use k8s_openapi::{api::networking::v1::NetworkPolicy, apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::api::networking::v1::{NetworkPolicy, NetworkPolicyEgressRule, NetworkPolicyIngressRule};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, Time};
 use kube::{Client, Api, runtime::watcher, runtime::watcher::Event};
 use futures_util::StreamExt;
 
@@ -10,6 +11,12 @@ pub struct NetworkPolicyItem {
     pub policy_types: String,
     pub creation_timestamp: Option<Time>,
     pub namespace: Option<String>,
+    pub selector: LabelSelector,
+    /// Effective policyTypes: Ingress is implied, Egress when egress rules are present
+    pub isolates_ingress: bool,
+    pub isolates_egress: bool,
+    pub ingress: Vec<NetworkPolicyIngressRule>,
+    pub egress: Vec<NetworkPolicyEgressRule>,
 }
 
 pub fn convert_network_policy(policy: NetworkPolicy) -> Option<NetworkPolicyItem> {
@@ -20,15 +27,9 @@ pub fn convert_network_policy(policy: NetworkPolicy) -> Option<NetworkPolicyItem
         .spec
         .as_ref()
         .and_then(|spec| spec.pod_selector.as_ref())
-        .and_then(|sel| sel.match_labels.as_ref())
-        .map(|labels| {
-            labels
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join(", ")
-        })
-        .unwrap_or_else(|| "None".to_string());
+        .map(crate::format_label_selector)
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "(all pods)".to_string());
 
     let policy_types = policy
         .spec
@@ -37,12 +38,25 @@ pub fn convert_network_policy(policy: NetworkPolicy) -> Option<NetworkPolicyItem
         .map(|types| types.join(", "))
         .unwrap_or_else(|| "None".to_string());
 
+    let spec = policy.spec.clone().unwrap_or_default();
+    let ingress = spec.ingress.unwrap_or_default();
+    let egress = spec.egress.unwrap_or_default();
+    let (isolates_ingress, isolates_egress) = match &spec.policy_types {
+        Some(types) => (types.iter().any(|t| t == "Ingress"), types.iter().any(|t| t == "Egress")),
+        None => (true, !egress.is_empty()),
+    };
+
     Some(NetworkPolicyItem {
         name,
         pod_selector,
         policy_types,
         creation_timestamp: metadata.creation_timestamp.clone(),
         namespace: metadata.namespace.clone(),
+        selector: spec.pod_selector.unwrap_or_default(),
+        isolates_ingress,
+        isolates_egress,
+        ingress,
+        egress,
     })
 }
 
@@ -72,6 +86,7 @@ pub async fn watch_network_policies(client: Arc<Client>, np_list: Arc<Mutex<Vec<
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("NetworkPolicy");
                 }
                 Event::Apply(policy) => {
                     if !initialized {
@@ -96,7 +111,7 @@ pub async fn watch_network_policies(client: Arc<Client>, np_list: Arc<Mutex<Vec<
                     }
                 }
             },
-            Err(e) => log::error!("NetworkPolicy watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("NetworkPolicy", &e, &load_status).await,
         }
     }
 }
@@ -59,6 +59,15 @@ pub struct NodeItem {
     pub storage_total: Option<f32>,
     pub storage_used: Option<f32>,
     pub storage_percent: Option<f32>,
+    pub conditions: Vec<NodeConditionItem>,
+}
+
+#[derive(Clone)]
+pub struct NodeConditionItem {
+    pub type_: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub last_transition: Option<Time>,
 }
 
 async fn get_cpu_usage_nanos(client: &Client, node_name: &str) -> anyhow::Result<u64> {
@@ -250,6 +259,15 @@ pub fn convert_node(node: Node) -> Option<NodeItem> {
         })
         .unwrap_or("Unknown")
         .to_string();
+    let conditions = node.status.as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map(|conds| conds.iter().map(|c| NodeConditionItem {
+            type_: c.type_.clone(),
+            status: c.status.clone(),
+            reason: c.reason.clone(),
+            last_transition: c.last_transition_time.clone(),
+        }).collect())
+        .unwrap_or_default();
 
 
     Some(NodeItem {
@@ -270,6 +288,7 @@ pub fn convert_node(node: Node) -> Option<NodeItem> {
         mem_total: None,
         mem_used: None,
         mem_percent: None,
+        conditions,
     })
 }
 
@@ -376,6 +395,7 @@ pub async fn watch_nodes(client: Arc<Client>, list: Arc<Mutex<Vec<NodeItem>>>, l
                     *list_guard = initial.clone();
                     initialized = true;
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Nodes");
                 }
 
                 Event::Apply(obj) => {
@@ -432,7 +452,7 @@ pub async fn watch_nodes(client: Arc<Client>, list: Arc<Mutex<Vec<NodeItem>>>, l
             },
 
             Err(e) => {
-                log::error!("Nodes watch error: {:?}", e);
+                crate::record_watch_error("Nodes", &e, &load_status).await;
             }
         }
     }
@@ -69,6 +69,7 @@ pub async fn watch_pod_disruption_budgets(client: Arc<Client>, list: Arc<Mutex<V
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("PDB");
                 }
                 Event::Apply(pdb) => {
                     if !initialized {
@@ -93,7 +94,7 @@ pub async fn watch_pod_disruption_budgets(client: Arc<Client>, list: Arc<Mutex<V
                     }
                 }
             },
-            Err(e) => log::error!("PDB watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("PDB", &e, &load_status).await,
         }
     }
 }
@@ -1,6 +1,9 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::{core::v1::Pod}, apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::api::core::v1::{Container, PodSpec, ResourceRequirements};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use kube::Client;
 use kube::{Api, runtime::watcher};
 
@@ -14,6 +17,7 @@ pub struct ContainerStatusItem {
 #[derive(Clone)]
 pub struct PodItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub phase: Option<String>,
     pub ready_containers: u32,
     pub total_containers: u32,
@@ -26,9 +30,41 @@ pub struct PodItem {
     pub controller: Option<String>,
     pub namespace: Option<String>,
     pub qos_class: Option<String>,
+    pub last_restart: Option<Time>, // Latest lastState.terminated.finishedAt of the containers
+    pub pod_ip: Option<String>,
+    pub named_ports: BTreeMap<String, (i32, String)>, // Container port name -> (port, protocol), NetworkPolicies may refer to ports by name
+    pub requests: BTreeMap<String, f64>, // Effective pod requests as quotas count them
+    pub limits: BTreeMap<String, f64>,
 }
 
-fn convert_pod(pod: Pod) -> Option<PodItem> {
+/// Sum of the containers, or the largest init container when that is higher, plus the pod overhead
+fn effective_resources(spec: &PodSpec, pick: fn(&ResourceRequirements) -> Option<&BTreeMap<String, Quantity>>) -> BTreeMap<String, f64> {
+    let parsed = |c: &Container| -> BTreeMap<String, f64> {
+        c.resources.as_ref().and_then(pick).map(|values| {
+            values.iter().filter_map(|(k, v)| Some((k.clone(), crate::parse_quantity(&v.0)?))).collect()
+        }).unwrap_or_default()
+    };
+    let mut total: BTreeMap<String, f64> = BTreeMap::new();
+    for container in &spec.containers {
+        for (k, v) in parsed(container) {
+            *total.entry(k).or_default() += v;
+        }
+    }
+    for container in spec.init_containers.iter().flatten() {
+        for (k, v) in parsed(container) {
+            let entry = total.entry(k).or_default();
+            *entry = entry.max(v);
+        }
+    }
+    for (k, v) in spec.overhead.iter().flatten() {
+        if let Some(v) = crate::parse_quantity(&v.0) {
+            *total.entry(k.clone()).or_default() += v;
+        }
+    }
+    total
+}
+
+pub fn convert_pod(pod: Pod) -> Option<PodItem> {
     let name = pod.metadata.name?;
     let phase = pod.status.as_ref().and_then(|s| s.phase.clone());
     let node_name = pod.spec.as_ref().and_then(|s| s.node_name.clone());
@@ -38,7 +74,19 @@ fn convert_pod(pod: Pod) -> Option<PodItem> {
     let mut ready = 0;
     let mut restart_count = 0;
     let mut pod_has_crashloop = false;
+    let mut last_restart: Option<Time> = None;
     let qos_class = pod.status.as_ref().and_then(|s| s.qos_class.clone());
+    let pod_ip = pod.status.as_ref().and_then(|s| s.pod_ip.clone());
+    let named_ports = pod.spec.as_ref()
+        .map(|spec| {
+            spec.containers.iter()
+                .flat_map(|c| c.ports.iter().flatten())
+                .filter_map(|p| Some((p.name.clone()?, (p.container_port, p.protocol.clone().unwrap_or_else(|| "TCP".to_string())))))
+                .collect()
+        })
+        .unwrap_or_default();
+    let requests = pod.spec.as_ref().map(|spec| effective_resources(spec, |r| r.requests.as_ref())).unwrap_or_default();
+    let limits = pod.spec.as_ref().map(|spec| effective_resources(spec, |r| r.limits.as_ref())).unwrap_or_default();
 
     let controller = pod.metadata.owner_references.as_ref()
         .and_then(|owners| {
@@ -73,6 +121,13 @@ fn convert_pod(pod: Pod) -> Option<PodItem> {
 
             restart_count += cs.restart_count;
 
+            let finished_at = cs.last_state.as_ref()
+                .and_then(|s| s.terminated.as_ref())
+                .and_then(|t| t.finished_at.clone());
+            if finished_at.as_ref().is_some_and(|f| last_restart.as_ref().is_none_or(|l| f.0 > l.0)) {
+                last_restart = finished_at;
+            }
+
             let message = cs.state.as_ref().and_then(|s| {
                 if let Some(waiting) = &s.waiting {
                     waiting.message.clone()
@@ -95,6 +150,7 @@ fn convert_pod(pod: Pod) -> Option<PodItem> {
         }
     }
     Some(PodItem {
+        labels: pod.metadata.labels.clone().unwrap_or_default(),
         name,
         phase,
         ready_containers: ready,
@@ -108,6 +164,11 @@ fn convert_pod(pod: Pod) -> Option<PodItem> {
         controller,
         namespace,
         qos_class,
+        last_restart,
+        pod_ip,
+        named_ports,
+        requests,
+        limits,
     })
 }
 
@@ -136,6 +197,7 @@ pub async fn watch_pods(client: Arc<Client>, pods_list: Arc<Mutex<Vec<PodItem>>>
                     *list = initial.clone();
                     initialized = true;
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Pod");
                 }
                 watcher::Event::Apply(pod) => {
                     if !initialized {
@@ -160,7 +222,7 @@ pub async fn watch_pods(client: Arc<Client>, pods_list: Arc<Mutex<Vec<PodItem>>>
                     }
                 }
             },
-            Err(e) => log::error!("Pod watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("Pod", &e, &load_status).await,
         }
     }
 }
@@ -73,6 +73,7 @@ pub async fn watch_pvs(client: Arc<Client>, pv_list: Arc<Mutex<Vec<PvItem>>>, lo
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("PV");
                 }
                 Event::Apply(pv) => {
                     if !initialized {
@@ -94,7 +95,7 @@ pub async fn watch_pvs(client: Arc<Client>, pv_list: Arc<Mutex<Vec<PvItem>>>, lo
                     }
                 }
             },
-            Err(e) => log::error!("PV watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("PV", &e, &load_status).await,
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use k8s_openapi::{api::core::v1::PersistentVolumeClaim, apimachinery::pkg::apis::meta::v1::Time};
 use kube::Client;
@@ -7,6 +8,7 @@ use futures_util::StreamExt;
 #[derive(Debug, Clone)]
 pub struct PvcItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub storage_class: String,
     pub size: String,
     pub volume_name: String,
@@ -17,6 +19,7 @@ pub struct PvcItem {
 
 pub fn convert_pvc(pvc: PersistentVolumeClaim) -> Option<PvcItem> {
     Some(PvcItem {
+        labels: pvc.metadata.labels.clone().unwrap_or_default(),
         name: pvc.metadata.name.clone()?,
         storage_class: pvc
             .spec
@@ -67,6 +70,7 @@ pub async fn watch_pvcs(client: Arc<Client>, pvc_list: Arc<Mutex<Vec<PvcItem>>>,
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("PVC");
                 }
                 Event::Apply(pvc) => {
                     if !initialized {
@@ -91,7 +95,7 @@ pub async fn watch_pvcs(client: Arc<Client>, pvc_list: Arc<Mutex<Vec<PvcItem>>>,
                     }
                 }
             },
-            Err(e) => log::error!("PVC watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("PVC", &e, &load_status).await,
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::{apps::v1::ReplicaSet}, apimachinery::pkg::apis::meta::v1::Time};
@@ -7,6 +8,7 @@ use kube::{Api, runtime::watcher, runtime::watcher::Event};
 #[derive(Debug, Clone)]
 pub struct ReplicaSetItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub desired: i32,
     pub current: i32,
     pub ready: i32,
@@ -16,6 +18,7 @@ pub struct ReplicaSetItem {
 
 pub fn convert_replicaset(rs: ReplicaSet) -> Option<ReplicaSetItem> {
     Some(ReplicaSetItem {
+        labels: rs.metadata.labels.clone().unwrap_or_default(),
         name: rs.metadata.name.clone()?,
         desired: rs.spec.as_ref()?.replicas.unwrap_or(0),
         current: rs.status.as_ref()?.replicas,
@@ -51,6 +54,7 @@ pub async fn watch_replicasets(client: Arc<Client>, rs_list: Arc<Mutex<Vec<Repli
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("ReplicaSet");
                 }
                 Event::Apply(rs) => {
                     if !initialized {
@@ -75,7 +79,7 @@ pub async fn watch_replicasets(client: Arc<Client>, rs_list: Arc<Mutex<Vec<Repli
                     }
                 }
             },
-            Err(e) => log::error!("ReplicaSet watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("ReplicaSet", &e, &load_status).await,
         }
     }
 }
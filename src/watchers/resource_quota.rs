@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
+use k8s_openapi::{api::core::v1::ResourceQuota, apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::{Client, Api, runtime::watcher, runtime::watcher::Event};
+use futures_util::StreamExt;
+
+#[derive(Debug, Clone)]
+pub struct ResourceQuotaItem {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub creation_timestamp: Option<Time>,
+    pub scopes: Vec<String>,
+    pub hard: BTreeMap<String, String>,
+    pub used: BTreeMap<String, String>,
+}
+
+impl ResourceQuotaItem {
+    /// used/hard of every resource with both values numeric, highest first
+    pub fn usage(&self) -> Vec<(String, f64)> {
+        let mut usage: Vec<(String, f64)> = self.hard.iter().filter_map(|(resource, hard)| {
+            let hard = crate::parse_quantity(hard)?;
+            let used = self.used.get(resource).and_then(|u| crate::parse_quantity(u)).unwrap_or(0.0);
+            let ratio = if hard > 0.0 { used / hard } else if used > 0.0 { f64::INFINITY } else { 0.0 };
+            Some((resource.clone(), ratio))
+        }).collect();
+        usage.sort_by(|a, b| b.1.total_cmp(&a.1));
+        usage
+    }
+
+    pub fn max_usage(&self) -> Option<(String, f64)> {
+        self.usage().into_iter().next()
+    }
+}
+
+fn to_strings(values: Option<&BTreeMap<String, Quantity>>) -> BTreeMap<String, String> {
+    values.map(|v| v.iter().map(|(k, q)| (k.clone(), q.0.clone())).collect()).unwrap_or_default()
+}
+
+pub fn convert_resource_quota(quota: ResourceQuota) -> Option<ResourceQuotaItem> {
+    let metadata = &quota.metadata;
+    let name = metadata.name.clone()?;
+
+    Some(ResourceQuotaItem {
+        name,
+        namespace: metadata.namespace.clone(),
+        creation_timestamp: metadata.creation_timestamp.clone(),
+        scopes: quota.spec.as_ref().and_then(|s| s.scopes.clone()).unwrap_or_default(),
+        // status.hard is what the controller enforces, it lags spec.hard only for a moment
+        hard: to_strings(quota.status.as_ref().and_then(|s| s.hard.as_ref()).or(quota.spec.as_ref().and_then(|s| s.hard.as_ref()))),
+        used: to_strings(quota.status.as_ref().and_then(|s| s.used.as_ref())),
+    })
+}
+
+pub async fn watch_resource_quotas(client: Arc<Client>, quota_list: Arc<Mutex<Vec<ResourceQuotaItem>>>, load_status: Arc<AtomicBool>) {
+    let api: Api<ResourceQuota> = Api::all(client.as_ref().clone());
+
+    load_status.store(true, Ordering::Relaxed);
+
+    let mut stream = watcher(api, watcher::Config::default().page_size(crate::WATCHER_PAGE_SIZE)).boxed();
+
+    let mut initial = vec![];
+    let mut initialized = false;
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(ev) => match ev {
+                Event::Init => initial.clear(),
+                Event::InitApply(quota) => {
+                    if let Some(item) = convert_resource_quota(quota) {
+                        initial.push(item);
+                    }
+                }
+                Event::InitDone => {
+                    let mut list_guard = quota_list.lock().unwrap();
+                    *list_guard = initial.clone();
+                    initialized = true;
+
+                    load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("ResourceQuota");
+                }
+                Event::Apply(quota) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let Some(item) = convert_resource_quota(quota) {
+                        let mut list = quota_list.lock().unwrap();
+                        if let Some(existing) = list.iter_mut().find(|f| f.name == item.name && f.namespace == item.namespace) {
+                            *existing = item; // renew
+                        } else {
+                            list.push(item); // add new
+                        }
+                    }
+                }
+                Event::Delete(quota) => {
+                    if !initialized {
+                        continue;
+                    }
+                    if let (Some(name), Some(namespace)) = (quota.metadata.name, quota.metadata.namespace) {
+                        let mut list = quota_list.lock().unwrap();
+                        list.retain(|item| !(item.name == name && item.namespace.as_ref() == Some(&namespace)));
+                    }
+                }
+            },
+            Err(e) => crate::record_watch_error("ResourceQuota", &e, &load_status).await,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
-use k8s_openapi::{api::{rbac::v1::Role}, apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::{api::{rbac::v1::{PolicyRule, Role}}, apimachinery::pkg::apis::meta::v1::Time};
 use kube::Client;
 use kube::{Api, runtime::watcher};
 use kube::runtime::reflector::Lookup;
@@ -10,6 +10,7 @@ pub struct RoleItem {
     pub name: String,
     pub creation_timestamp: Option<Time>,
     pub namespace: Option<String>,
+    pub rules: Vec<PolicyRule>,
 }
 
 fn convert_role(role: Role) -> Option<RoleItem> {
@@ -19,6 +20,7 @@ fn convert_role(role: Role) -> Option<RoleItem> {
         name,
         creation_timestamp: role.metadata.creation_timestamp,
         namespace,
+        rules: role.rules.unwrap_or_default(),
     })
 }
 
@@ -48,6 +50,7 @@ pub async fn watch_roles(client: Arc<Client>, roles_list: Arc<Mutex<Vec<RoleItem
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Role");
                 }
                 watcher::Event::Apply(role) => {
                     if !initialized {
@@ -72,7 +75,7 @@ pub async fn watch_roles(client: Arc<Client>, roles_list: Arc<Mutex<Vec<RoleItem
                     }
                 }
             },
-            Err(e) => log::error!("Role watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("Role", &e, &load_status).await,
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
-use k8s_openapi::{api::{rbac::v1::RoleBinding}, apimachinery::pkg::apis::meta::v1::Time};
+use k8s_openapi::{api::{rbac::v1::{RoleBinding, RoleRef, Subject}}, apimachinery::pkg::apis::meta::v1::Time};
 use kube::Client;
 use kube::{Api, runtime::watcher};
 use kube::runtime::reflector::Lookup;
@@ -10,6 +10,8 @@ pub struct RoleBindingItem {
     pub name: String,
     pub creation_timestamp: Option<Time>,
     pub namespace: Option<String>,
+    pub role_ref: RoleRef,
+    pub subjects: Vec<Subject>,
 }
 
 fn convert_rb(rb: RoleBinding) -> Option<RoleBindingItem> {
@@ -19,6 +21,8 @@ fn convert_rb(rb: RoleBinding) -> Option<RoleBindingItem> {
         name,
         creation_timestamp: rb.metadata.creation_timestamp,
         namespace,
+        role_ref: rb.role_ref,
+        subjects: rb.subjects.unwrap_or_default(),
     })
 }
 
@@ -48,6 +52,7 @@ pub async fn watch_rbs(client: Arc<Client>, rbs_list: Arc<Mutex<Vec<RoleBindingI
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("RoleBinding");
                 }
                 watcher::Event::Apply(rb) => {
                     if !initialized {
@@ -72,7 +77,7 @@ pub async fn watch_rbs(client: Arc<Client>, rbs_list: Arc<Mutex<Vec<RoleBindingI
                     }
                 }
             },
-            Err(e) => log::error!("RoleBinding watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("RoleBinding", &e, &load_status).await,
         }
     }
 }
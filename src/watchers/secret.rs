@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::{core::v1::Secret}, apimachinery::pkg::apis::meta::v1::Time};
@@ -8,15 +9,17 @@ use kube::runtime::reflector::Lookup;
 #[derive(Clone, Debug)]
 pub struct SecretItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub secret_type: String,
     pub creation_timestamp: Option<Time>,
     pub namespace: Option<String>,
 }
 
-fn convert_secret(secret: Secret) -> Option<SecretItem> {
+pub fn convert_secret(secret: Secret) -> Option<SecretItem> {
     let name = secret.name().unwrap().to_string();
     let namespace = secret.metadata.namespace.clone();
     Some(SecretItem {
+        labels: secret.metadata.labels.clone().unwrap_or_default(),
         name,
         secret_type: secret.type_.unwrap_or_else(|| "-".into()),
         creation_timestamp: secret.metadata.creation_timestamp,
@@ -49,6 +52,7 @@ pub async fn watch_secrets(client: Arc<Client>, secrets_list: Arc<Mutex<Vec<Secr
                     *list = initial.clone();
                     initialized = true;
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Secret");
                 }
                 watcher::Event::Apply(secret) => {
                     if !initialized {
@@ -73,7 +77,7 @@ pub async fn watch_secrets(client: Arc<Client>, secrets_list: Arc<Mutex<Vec<Secr
                     }
                 }
             },
-            Err(e) => log::error!("Secret watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("Secret", &e, &load_status).await,
         }
     }
 }
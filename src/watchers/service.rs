@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::core::v1::{Service}, apimachinery::pkg::apis::meta::v1::Time};
@@ -7,6 +8,7 @@ use kube::{Api, runtime::watcher, runtime::watcher::Event};
 #[derive(Debug, Clone)]
 pub struct ServiceItem {
     pub name: String,
+    pub labels: BTreeMap<String, String>,
     pub svc_type: String,
     pub cluster_ip: String,
     pub ports: String,
@@ -64,6 +66,7 @@ pub fn convert_service(svc: Service) -> Option<ServiceItem> {
     };
 
     Some(ServiceItem {
+        labels: svc.metadata.labels.clone().unwrap_or_default(),
         name,
         svc_type,
         cluster_ip,
@@ -101,6 +104,7 @@ pub async fn watch_services(client: Arc<Client>, services_list: Arc<Mutex<Vec<Se
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("Service");
                 }
                 Event::Apply(svc) => {
                     if !initialized {
@@ -125,7 +129,7 @@ pub async fn watch_services(client: Arc<Client>, services_list: Arc<Mutex<Vec<Se
                     }
                 }
             },
-            Err(e) => log::error!("Service watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("Service", &e, &load_status).await,
         }
     }
 }
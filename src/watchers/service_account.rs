@@ -48,6 +48,7 @@ pub async fn watch_service_accounts(client: Arc<Client>, service_accounts_list:
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("ServiceAccount");
                 }
                 watcher::Event::Apply(service_account) => {
                     if !initialized {
@@ -72,7 +73,7 @@ pub async fn watch_service_accounts(client: Arc<Client>, service_accounts_list:
                     }
                 }
             },
-            Err(e) => log::error!("ServiceAccount watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("ServiceAccount", &e, &load_status).await,
         }
     }
 }
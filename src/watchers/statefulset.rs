@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex};
 use futures_util::StreamExt;
 use k8s_openapi::{api::apps::v1::{StatefulSet}, apimachinery::pkg::apis::meta::v1::Time};
@@ -7,7 +8,7 @@ use kube::{Api, runtime::watcher, runtime::watcher::Event};
 #[derive(Debug, Clone)]
 pub struct StatefulSetItem {
     pub name: String,
-    //pub labels: BTreeMap<String, String>,
+    pub labels: BTreeMap<String, String>,
     pub replicas: i32,
     pub service_name: String,
     pub ready_replicas: i32,
@@ -19,8 +20,8 @@ pub fn convert_statefulset(ss: StatefulSet) -> Option<StatefulSetItem> {
     let spec = ss.spec.unwrap();
     let namespace = ss.metadata.namespace.clone();
     Some(StatefulSetItem {
+        labels: ss.metadata.labels.clone().unwrap_or_default(),
         name: ss.metadata.name.clone()?,
-        //labels: ss.metadata.labels.unwrap_or_default(),
         service_name: spec.service_name.unwrap_or("-".to_string()),
         replicas: spec.replicas.unwrap_or(0),
         ready_replicas: ss.status.as_ref()?.ready_replicas.unwrap_or(0),
@@ -55,6 +56,7 @@ pub async fn watch_statefulsets(client: Arc<Client>, ss_list: Arc<Mutex<Vec<Stat
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("StatefulSet");
                 }
                 Event::Apply(ss) => {
                     if !initialized {
@@ -79,7 +81,7 @@ pub async fn watch_statefulsets(client: Arc<Client>, ss_list: Arc<Mutex<Vec<Stat
                     }
                 }
             },
-            Err(e) => log::error!("StatefulSet watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("StatefulSet", &e, &load_status).await,
         }
     }
 }
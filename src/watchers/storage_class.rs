@@ -69,6 +69,7 @@ pub async fn watch_storage_classes(client: Arc<Client>, sc_list: Arc<Mutex<Vec<S
                     initialized = true;
 
                     load_status.store(false, Ordering::Relaxed);
+                    crate::clear_watch_denied("StorageClass");
                 }
                 Event::Apply(sc) => {
                     if !initialized {
@@ -93,7 +94,7 @@ pub async fn watch_storage_classes(client: Arc<Client>, sc_list: Arc<Mutex<Vec<S
                     }
                 }
             },
-            Err(e) => log::error!("StorageClass watch error: {:?}", e),
+            Err(e) => crate::record_watch_error("StorageClass", &e, &load_status).await,
         }
     }
 }